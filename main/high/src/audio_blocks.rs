@@ -0,0 +1,155 @@
+use crate::{Reaper, ReaperError, ReaperResult};
+use reaper_medium::{Hz, OwnedAudioAccessor, PositionInSeconds};
+
+/// Defines in which format audio is read block by block.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct AudioBlockSpec {
+    /// Must be a whole number.
+    pub sample_rate: Hz,
+    pub channel_count: u32,
+    pub frames_per_block: u32,
+}
+
+/// A block of interleaved audio samples.
+#[derive(Clone, PartialEq, Debug)]
+pub struct AudioBlock {
+    pub start_time: PositionInSeconds,
+    pub channel_count: u32,
+    /// Interleaved samples. Contains `frame_count() * channel_count` samples.
+    pub samples: Vec<f64>,
+    /// `false` if there was no audio within this block (samples are silent then).
+    pub has_audio: bool,
+}
+
+impl AudioBlock {
+    pub fn frame_count(&self) -> u32 {
+        self.samples.len() as u32 / self.channel_count
+    }
+
+    /// Returns the samples of the given frame (one sample for each channel).
+    pub fn frame(&self, index: u32) -> &[f64] {
+        let start = (index * self.channel_count) as usize;
+        &self.samples[start..start + self.channel_count as usize]
+    }
+}
+
+/// Iterator which reads the audio of a take or track block by block.
+///
+/// The last block might be shorter than the others. Once an error occurs, iteration ends.
+#[derive(Debug)]
+pub struct AudioBlocks {
+    accessor: OwnedAudioAccessor,
+    spec: AudioBlockSpec,
+    /// Index of the next frame (relative to the start time).
+    next_frame: u64,
+    start_time: f64,
+    end_time: f64,
+    failed: bool,
+}
+
+impl AudioBlocks {
+    pub(crate) fn new(
+        accessor: OwnedAudioAccessor,
+        spec: AudioBlockSpec,
+    ) -> ReaperResult<AudioBlocks> {
+        if spec.channel_count == 0 {
+            return Err(ReaperError::new("channel count must be greater than 0"));
+        }
+        if spec.frames_per_block == 0 {
+            return Err(ReaperError::new("block size must be greater than 0"));
+        }
+        let sample_rate = spec.sample_rate.get();
+        if sample_rate <= 0.0 || sample_rate.fract() != 0.0 {
+            return Err(ReaperError::new(
+                "sample rate must be a positive whole number",
+            ));
+        }
+        if spec
+            .frames_per_block
+            .checked_mul(spec.channel_count)
+            .is_none()
+        {
+            return Err(ReaperError::new("block size too large for channel count"));
+        }
+        let reaper = Reaper::get().medium_reaper();
+        let start_time = unsafe { reaper.get_audio_accessor_start_time(&accessor) };
+        let end_time = unsafe { reaper.get_audio_accessor_end_time(&accessor) };
+        Ok(AudioBlocks {
+            accessor,
+            spec,
+            next_frame: 0,
+            start_time: start_time.get(),
+            end_time: end_time.get(),
+            failed: false,
+        })
+    }
+
+    pub fn spec(&self) -> AudioBlockSpec {
+        self.spec
+    }
+
+    pub fn start_time(&self) -> PositionInSeconds {
+        PositionInSeconds::new(self.start_time)
+    }
+
+    pub fn end_time(&self) -> PositionInSeconds {
+        PositionInSeconds::new(self.end_time)
+    }
+
+    fn total_frame_count(&self) -> u64 {
+        let duration = (self.end_time - self.start_time).max(0.0);
+        (duration * self.spec.sample_rate.get()).ceil() as u64
+    }
+}
+
+impl Iterator for AudioBlocks {
+    type Item = ReaperResult<AudioBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let remaining_frames = self.total_frame_count().checked_sub(self.next_frame)?;
+        if remaining_frames == 0 {
+            return None;
+        }
+        let frame_count = remaining_frames.min(self.spec.frames_per_block as u64) as u32;
+        // Calculating the time from the frame index avoids accumulating rounding errors
+        let start_time = PositionInSeconds::new(
+            self.start_time + self.next_frame as f64 / self.spec.sample_rate.get(),
+        );
+        let sample_count = match frame_count.checked_mul(self.spec.channel_count) {
+            None => {
+                self.failed = true;
+                return Some(Err(ReaperError::new(
+                    "block size too large for channel count",
+                )));
+            }
+            Some(c) => c,
+        };
+        let mut samples = vec![0.0; sample_count as usize];
+        let result = unsafe {
+            Reaper::get().medium_reaper().get_audio_accessor_samples(
+                &self.accessor,
+                self.spec.sample_rate,
+                self.spec.channel_count,
+                start_time,
+                frame_count,
+                &mut samples,
+            )
+        };
+        self.next_frame += frame_count as u64;
+        match result {
+            Ok(has_audio) => Some(Ok(AudioBlock {
+                start_time,
+                channel_count: self.spec.channel_count,
+                samples,
+                has_audio,
+            })),
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e.into()))
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use crate::{Project, Reaper};
-use reaper_medium::{BookmarkId, EnumProjectMarkers3Result, NativeColor, PositionInSeconds};
+use crate::{Project, Reaper, ReaperResult};
+use reaper_medium::{
+    BookmarkId, EnumProjectMarkers3Result, NativeColor, PositionInSeconds, ReaperStringArg,
+};
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum BookmarkType {
@@ -43,6 +45,88 @@ impl IndexBasedBookmark {
             })
             .expect("bookmark doesn't exist")
     }
+
+    pub fn set_name<'a>(&self, name: impl Into<ReaperStringArg<'a>>) -> ReaperResult<()> {
+        let info = self.basic_info();
+        self.set(info.position, info.region_end_position, None, name, None)
+    }
+
+    /// Moves the marker or the start of the region.
+    pub fn set_position(&self, position: PositionInSeconds) -> ReaperResult<()> {
+        let info = self.basic_info();
+        self.set(position, info.region_end_position, None, self.name(), None)
+    }
+
+    /// Sets start and end of the region.
+    ///
+    /// Returns an error if this bookmark is a marker.
+    pub fn set_region_range(
+        &self,
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+    ) -> ReaperResult<()> {
+        if self.basic_info().bookmark_type() != BookmarkType::Region {
+            return Err("bookmark is not a region".into());
+        }
+        self.set(start, Some(end), None, self.name(), None)
+    }
+
+    pub fn set_color(&self, color: NativeColor) -> ReaperResult<()> {
+        let info = self.basic_info();
+        self.set(
+            info.position,
+            info.region_end_position,
+            None,
+            self.name(),
+            Some(color),
+        )
+    }
+
+    /// Changes the displayed marker/region ID.
+    ///
+    /// Fails if the ID is already taken by another region.
+    pub fn set_id(&self, id: BookmarkId) -> ReaperResult<()> {
+        let info = self.basic_info();
+        self.set(
+            info.position,
+            info.region_end_position,
+            Some(id),
+            self.name(),
+            None,
+        )
+    }
+
+    /// Deletes this bookmark.
+    ///
+    /// Afterwards the index refers to the next bookmark (if any).
+    pub fn delete(&self) -> ReaperResult<()> {
+        Reaper::get()
+            .medium_reaper()
+            .delete_project_marker_by_index(self.project.context(), self.index)?;
+        Ok(())
+    }
+
+    fn set<'a>(
+        &self,
+        position: PositionInSeconds,
+        region_end_position: Option<PositionInSeconds>,
+        id: Option<BookmarkId>,
+        name: impl Into<ReaperStringArg<'a>>,
+        color: Option<NativeColor>,
+    ) -> ReaperResult<()> {
+        Reaper::get()
+            .medium_reaper()
+            .set_project_marker_by_index_2(
+                self.project.context(),
+                self.index,
+                position,
+                region_end_position,
+                id,
+                name,
+                color,
+            )?;
+        Ok(())
+    }
 }
 
 pub struct BasicBookmarkInfo {
@@ -359,6 +359,13 @@ impl ChunkRegion {
         self.create_region_from_relative_start_pos(0, self.length + count)
     }
 
+    pub fn move_right_cursor_left_by(&self, count: usize) -> ChunkRegion {
+        if !self.is_valid() || count > self.length {
+            return self.create_invalid_region();
+        }
+        self.create_region_from_relative_start_pos(0, self.length - count)
+    }
+
     // Returns the tag completely from < to >
     pub fn find_first_tag_named(
         &self,
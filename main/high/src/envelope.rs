@@ -0,0 +1,115 @@
+use crate::{Reaper, ReaperResult};
+use reaper_medium::{
+    EnvelopePoint, Hz, PositionInSeconds, ReaperFunctionError, ReaperString, SortBehavior,
+    TrackEnvelope,
+};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Envelope {
+    raw: TrackEnvelope,
+}
+
+impl Envelope {
+    pub fn new(raw: TrackEnvelope) -> Envelope {
+        Envelope { raw }
+    }
+
+    pub fn raw(&self) -> TrackEnvelope {
+        self.raw
+    }
+
+    pub fn name(&self) -> ReaperResult<ReaperString> {
+        let name = unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .get_envelope_name(self.raw, 256)?
+        };
+        Ok(name)
+    }
+
+    pub fn point_count(&self) -> u32 {
+        unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .count_envelope_points(self.raw)
+        }
+    }
+
+    pub fn point_by_index(&self, index: u32) -> Option<EnvelopePoint> {
+        unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .get_envelope_point(self.raw, index)
+                .ok()
+        }
+    }
+
+    pub fn points(&self) -> impl ExactSizeIterator<Item = EnvelopePoint> + '_ {
+        (0..self.point_count()).map(move |i| {
+            self.point_by_index(i)
+                .expect("envelope point disappeared while iterating")
+        })
+    }
+
+    pub fn insert_point(&self, point: EnvelopePoint) -> Result<(), ReaperFunctionError> {
+        unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .insert_envelope_point(self.raw, point, SortBehavior::Sort)
+        }
+    }
+
+    /// Inserts all given points and sorts only once at the end.
+    pub fn insert_points(
+        &self,
+        points: impl IntoIterator<Item = EnvelopePoint>,
+    ) -> Result<(), ReaperFunctionError> {
+        let reaper = Reaper::get().medium_reaper();
+        for point in points {
+            unsafe {
+                reaper.insert_envelope_point(self.raw, point, SortBehavior::DontSort)?;
+            }
+        }
+        self.sort_points()
+    }
+
+    pub fn set_point(&self, index: u32, point: EnvelopePoint) -> Result<(), ReaperFunctionError> {
+        unsafe {
+            Reaper::get().medium_reaper().set_envelope_point(
+                self.raw,
+                index,
+                point,
+                SortBehavior::Sort,
+            )
+        }
+    }
+
+    pub fn delete_points_in_range(
+        &self,
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+    ) -> Result<(), ReaperFunctionError> {
+        unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .delete_envelope_point_range(self.raw, start, end)
+        }
+    }
+
+    pub fn sort_points(&self) -> Result<(), ReaperFunctionError> {
+        unsafe { Reaper::get().medium_reaper().envelope_sort_points(self.raw) }
+    }
+
+    /// Returns the effective (raw) envelope value at the given position.
+    ///
+    /// The sample rate should be the one of the project the envelope belongs to. It doesn't affect
+    /// the returned value, only the derivatives which REAPER calculates along the way.
+    pub fn value_at(&self, time: PositionInSeconds, sample_rate: Hz) -> f64 {
+        unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .envelope_evaluate(self.raw, time, sample_rate, 1)
+                .value
+        }
+    }
+}
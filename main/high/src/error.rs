@@ -1,4 +1,4 @@
-use reaper_medium::ReaperFunctionError;
+use reaper_medium::{DecodeMidiEventsError, ReaperFunctionError};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -39,6 +39,12 @@ impl From<ReaperFunctionError> for ReaperError {
     }
 }
 
+impl From<DecodeMidiEventsError> for ReaperError {
+    fn from(e: DecodeMidiEventsError) -> Self {
+        Self::new(e.message())
+    }
+}
+
 impl From<&'static str> for ReaperError {
     fn from(e: &'static str) -> Self {
         Self::new(e)
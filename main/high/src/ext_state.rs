@@ -0,0 +1,225 @@
+use crate::{Project, Reaper, ReaperError, ReaperResult};
+use reaper_medium::{ReaperStr, ReaperStringArg};
+
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Gives access to the global extension state of one section.
+///
+/// Values are strings. With the `serde` feature enabled, arbitrary values can be stored as JSON.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExtStateStore {
+    section: String,
+    persist: bool,
+}
+
+impl ExtStateStore {
+    /// Creates a store whose values are saved to `reaper-extstate.ini` and survive REAPER
+    /// restarts.
+    pub fn persistent(section: impl Into<String>) -> Self {
+        Self {
+            section: section.into(),
+            persist: true,
+        }
+    }
+
+    /// Creates a store whose values live only as long as REAPER is running.
+    pub fn temporary(section: impl Into<String>) -> Self {
+        Self {
+            section: section.into(),
+            persist: false,
+        }
+    }
+
+    pub fn section(&self) -> &str {
+        &self.section
+    }
+
+    pub fn is_persistent(&self) -> bool {
+        self.persist
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        Reaper::get()
+            .medium_reaper()
+            .get_ext_state(self.section.as_str(), key, |value| {
+                value.map(|v| v.to_str().to_owned())
+            })
+    }
+
+    /// Sets the given value.
+    ///
+    /// The value must not contain line breaks.
+    pub fn set<'a>(&'a self, key: &'a str, value: impl Into<ReaperStringArg<'a>>) {
+        Reaper::get().medium_reaper().set_ext_state(
+            self.section.as_str(),
+            key,
+            value,
+            self.persist,
+        );
+    }
+
+    pub fn has(&self, key: &str) -> bool {
+        Reaper::get()
+            .medium_reaper()
+            .has_ext_state(self.section.as_str(), key)
+    }
+
+    pub fn delete(&self, key: &str) {
+        Reaper::get()
+            .medium_reaper()
+            .delete_ext_state(self.section.as_str(), key, self.persist);
+    }
+
+    /// Deserializes the value from JSON.
+    #[cfg(feature = "serde")]
+    pub fn get_value<T: DeserializeOwned>(&self, key: &str) -> ReaperResult<Option<T>> {
+        self.get(key)
+            .map(|json| deserialize_value(&json))
+            .transpose()
+    }
+
+    /// Serializes the value as JSON.
+    #[cfg(feature = "serde")]
+    pub fn set_value<T: Serialize>(&self, key: &str, value: &T) -> ReaperResult<()> {
+        self.set(key, serialize_value(value)?);
+        Ok(())
+    }
+}
+
+/// Gives access to the extension state of one section which is saved with a project.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ProjectExtStateStore {
+    project: Project,
+    section: String,
+}
+
+impl ProjectExtStateStore {
+    pub fn new(project: Project, section: impl Into<String>) -> Self {
+        Self {
+            project,
+            section: section.into(),
+        }
+    }
+
+    pub fn project(&self) -> Project {
+        self.project
+    }
+
+    pub fn section(&self) -> &str {
+        &self.section
+    }
+
+    /// Returns the value of the given key.
+    ///
+    /// Returns an error if the value is too large to be read completely.
+    pub fn get(&self, key: &str) -> ReaperResult<Option<String>> {
+        let get_value = |buffer_size| {
+            Reaper::get().medium_reaper().get_proj_ext_state(
+                self.project.context(),
+                self.section.as_str(),
+                key,
+                buffer_size,
+            )
+        };
+        let value = match get_value(SMALL_PROJ_EXT_STATE_BUFFER_SIZE) {
+            None => return Ok(None),
+            Some(v) => v,
+        };
+        if !might_be_truncated(&value, SMALL_PROJ_EXT_STATE_BUFFER_SIZE) {
+            return Ok(Some(value.into_string()));
+        }
+        let value = match get_value(MAX_PROJ_EXT_STATE_BUFFER_SIZE) {
+            None => return Ok(None),
+            Some(v) => v,
+        };
+        if might_be_truncated(&value, MAX_PROJ_EXT_STATE_BUFFER_SIZE) {
+            return Err(ReaperError::new("extension state value too large"));
+        }
+        Ok(Some(value.into_string()))
+    }
+
+    pub fn set<'a>(&'a self, key: &'a str, value: impl Into<ReaperStringArg<'a>>) {
+        Reaper::get().medium_reaper().set_proj_ext_state(
+            self.project.context(),
+            self.section.as_str(),
+            key,
+            value,
+        );
+    }
+
+    pub fn delete(&self, key: &str) {
+        Reaper::get().medium_reaper().delete_proj_ext_state(
+            self.project.context(),
+            self.section.as_str(),
+            Some(key.into()),
+        );
+    }
+
+    /// Deletes all values of this section.
+    pub fn clear(&self) {
+        Reaper::get().medium_reaper().delete_proj_ext_state(
+            self.project.context(),
+            self.section.as_str(),
+            None,
+        );
+    }
+
+    /// Returns all keys and values of this section.
+    ///
+    /// Yields an error for each value which is too large to be read completely.
+    pub fn entries(&self) -> impl Iterator<Item = ReaperResult<(String, String)>> + '_ {
+        (0..).map_while(move |i| {
+            let res = Reaper::get().medium_reaper().enum_proj_ext_state(
+                self.project.context(),
+                self.section.as_str(),
+                i,
+                SMALL_PROJ_EXT_STATE_BUFFER_SIZE,
+            )?;
+            let key = res.key.into_string();
+            if !might_be_truncated(&res.value, SMALL_PROJ_EXT_STATE_BUFFER_SIZE) {
+                return Some(Ok((key, res.value.into_string())));
+            }
+            let value = self.get(&key).transpose()?;
+            Some(value.map(|v| (key, v)))
+        })
+    }
+
+    /// Deserializes the value from JSON.
+    #[cfg(feature = "serde")]
+    pub fn get_value<T: DeserializeOwned>(&self, key: &str) -> ReaperResult<Option<T>> {
+        self.get(key)?
+            .map(|json| deserialize_value(&json))
+            .transpose()
+    }
+
+    /// Serializes the value as JSON.
+    #[cfg(feature = "serde")]
+    pub fn set_value<T: Serialize>(&self, key: &str, value: &T) -> ReaperResult<()> {
+        self.set(key, serialize_value(value)?);
+        Ok(())
+    }
+}
+
+/// Most values are small, so we try with a small buffer first and only use the large one if the
+/// value might have been truncated.
+const SMALL_PROJ_EXT_STATE_BUFFER_SIZE: u32 = 4 * 1024;
+const MAX_PROJ_EXT_STATE_BUFFER_SIZE: u32 = 1024 * 1024;
+
+fn might_be_truncated(value: &ReaperStr, buffer_size: u32) -> bool {
+    // One byte of the buffer is taken by the nul terminator.
+    value.as_c_str().to_bytes().len() + 1 >= buffer_size as usize
+}
+
+#[cfg(feature = "serde")]
+fn serialize_value<T: Serialize>(value: &T) -> ReaperResult<String> {
+    // Compact JSON doesn't contain line breaks, which is important for the INI file.
+    serde_json::to_string(value)
+        .map_err(|_| ReaperError::new("couldn't serialize extension state value"))
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_value<T: DeserializeOwned>(json: &str) -> ReaperResult<T> {
+    serde_json::from_str(json)
+        .map_err(|_| ReaperError::new("couldn't deserialize extension state value"))
+}
@@ -7,10 +7,11 @@ use crate::fx_chain::FxChain;
 use crate::fx_parameter::FxParameter;
 use crate::guid::Guid;
 use crate::option_util::OptionExt;
-use crate::{ChunkRegion, FxChainContext, Project, Reaper, Track};
+use crate::{ChunkRegion, FxChainContext, FxPinMatrix, Project, Reaper, Track};
 use reaper_medium::{
-    FxPresetRef, FxShowInstruction, Hwnd, ParamId, ReaperFunctionError, ReaperString,
-    ReaperStringArg, TrackFxGetPresetIndexResult, TrackFxLocation,
+    FxContainerAddress, FxContainerLevel, FxPinDirection, FxPinMask, FxPresetRef,
+    FxShowInstruction, Hwnd, ParamId, ReaperFunctionError, ReaperString, ReaperStringArg,
+    TakeFxShowInstruction, TrackFxChainType, TrackFxGetPresetIndexResult, TrackFxLocation,
 };
 use std::hash::{Hash, Hasher};
 
@@ -85,7 +86,12 @@ impl Fx {
         self.load_if_necessary_or_complain();
         let buffer_size = 256;
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_fx_name(take.raw(), self.index(), buffer_size)
+                    .expect("Couldn't get take FX name")
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -145,14 +151,14 @@ impl Fx {
     // Attention: Currently implemented by parsing chunk
     pub fn info(&self) -> Result<FxInfo, &'static str> {
         self.load_if_necessary_or_complain();
-        let loc = self.track_and_location();
-        let fx_type = self.get_named_config_param_as_string_internal("fx_type", 10, &loc);
+        let index = self.index();
+        let fx_type = self.get_named_config_param_as_string_internal("fx_type", 10, index);
         if let Ok(fx_type) = fx_type {
             // This must be REAPER >= 6.37. Use function to determine remaining FX info.
             let fx_type = fx_type.into_string();
             let info = FxInfo {
                 effect_name: self
-                    .get_named_config_param_as_string_internal("fx_name", 64, &loc)
+                    .get_named_config_param_as_string_internal("fx_name", 64, index)
                     .map(|rs| rs.into_inner().to_string_lossy().into_owned())
                     .unwrap_or_default(),
                 type_expression: match fx_type.as_str() {
@@ -163,7 +169,7 @@ impl Fx {
                 .to_owned(),
                 sub_type_expression: fx_type,
                 file_name: self
-                    .get_named_config_param_as_string_internal("fx_ident", 1000, &loc)
+                    .get_named_config_param_as_string_internal("fx_ident", 1000, index)
                     .ok()
                     .and_then(|rs| {
                         let c_string = rs.into_inner();
@@ -194,7 +200,11 @@ impl Fx {
 
     fn parameter_count_internal(&self) -> u32 {
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_num_params(take.raw(), self.index())
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -208,7 +218,11 @@ impl Fx {
 
     pub fn is_enabled(&self) -> bool {
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_enabled(take.raw(), self.index())
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -222,7 +236,11 @@ impl Fx {
 
     pub fn is_online(&self) -> bool {
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                !Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_offline(take.raw(), self.index())
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -240,7 +258,14 @@ impl Fx {
         buffer_size: u32,
     ) -> Result<Vec<u8>, ReaperFunctionError> {
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_get_named_config_parm(
+                    take.raw(),
+                    self.index(),
+                    name,
+                    buffer_size,
+                )
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -252,24 +277,40 @@ impl Fx {
         }
     }
 
+    pub fn get_named_config_param_as_string<'a>(
+        &self,
+        name: impl Into<ReaperStringArg<'a>>,
+        buffer_size: u32,
+    ) -> Result<ReaperString, ReaperFunctionError> {
+        self.get_named_config_param_as_string_internal(name, buffer_size, self.index())
+    }
+
     fn get_named_config_param_as_string_internal<'a>(
         &self,
         name: impl Into<ReaperStringArg<'a>>,
         buffer_size: u32,
-        (track, location): &(Track, TrackFxLocation),
+        index: u32,
     ) -> Result<ReaperString, ReaperFunctionError> {
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
-            _ => unsafe {
+            FxChainContext::Take(take) => unsafe {
                 Reaper::get()
                     .medium_reaper()
-                    .track_fx_get_named_config_parm_as_string(
-                        track.raw(),
-                        *location,
-                        name,
-                        buffer_size,
-                    )
+                    .take_fx_get_named_config_parm_as_string(take.raw(), index, name, buffer_size)
             },
+            _ => {
+                let (track, location) =
+                    get_track_and_location(&self.chain, index).expect("must be track FX");
+                unsafe {
+                    Reaper::get()
+                        .medium_reaper()
+                        .track_fx_get_named_config_parm_as_string(
+                            track.raw(),
+                            location,
+                            name,
+                            buffer_size,
+                        )
+                }
+            }
         }
     }
 
@@ -279,7 +320,14 @@ impl Fx {
         buffer: &[u8],
     ) -> Result<(), ReaperFunctionError> {
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_set_named_config_parm(
+                    take.raw(),
+                    self.index(),
+                    name,
+                    buffer,
+                )
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -291,6 +339,99 @@ impl Fx {
         }
     }
 
+    /// Reads the current pin connections of this FX.
+    pub fn pin_matrix(&self) -> Result<FxPinMatrix, ReaperFunctionError> {
+        let io_size = match self.chain.context() {
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_io_size(take.raw(), self.index())?
+            },
+            _ => {
+                let (track, location) = self.track_and_location();
+                unsafe {
+                    Reaper::get()
+                        .medium_reaper()
+                        .track_fx_get_io_size(track.raw(), location)?
+                }
+            }
+        };
+        let read_pins = |direction: FxPinDirection, count: u32| -> Vec<FxPinMask> {
+            (0..count)
+                .map(|pin_index| self.pin_mapping(direction, pin_index))
+                .collect()
+        };
+        Ok(FxPinMatrix::new(
+            read_pins(FxPinDirection::Input, io_size.input_pin_count),
+            read_pins(FxPinDirection::Output, io_size.output_pin_count),
+        ))
+    }
+
+    /// Writes the given pin connections back to this FX.
+    pub fn set_pin_matrix(&self, matrix: &FxPinMatrix) -> Result<(), ReaperFunctionError> {
+        for direction in [FxPinDirection::Input, FxPinDirection::Output] {
+            for (pin_index, mask) in matrix.pins(direction).iter().enumerate() {
+                self.set_pin_mapping(direction, pin_index as u32, *mask)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn pin_mapping(&self, direction: FxPinDirection, pin_index: u32) -> FxPinMask {
+        match self.chain.context() {
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_get_pin_mappings(
+                    take.raw(),
+                    self.index(),
+                    direction,
+                    pin_index,
+                )
+            },
+            _ => {
+                let (track, location) = self.track_and_location();
+                unsafe {
+                    Reaper::get().medium_reaper().track_fx_get_pin_mappings(
+                        track.raw(),
+                        location,
+                        direction,
+                        pin_index,
+                    )
+                }
+            }
+        }
+    }
+
+    fn set_pin_mapping(
+        &self,
+        direction: FxPinDirection,
+        pin_index: u32,
+        mask: FxPinMask,
+    ) -> Result<(), ReaperFunctionError> {
+        match self.chain.context() {
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_set_pin_mappings(
+                    take.raw(),
+                    self.index(),
+                    direction,
+                    pin_index,
+                    mask,
+                )
+            },
+            _ => {
+                let (track, location) = self.track_and_location();
+                unsafe {
+                    Reaper::get().medium_reaper().track_fx_set_pin_mappings(
+                        track.raw(),
+                        location,
+                        direction,
+                        pin_index,
+                        mask,
+                    )
+                }
+            }
+        }
+    }
+
     pub fn parameters(&self) -> impl Iterator<Item = FxParameter> + ExactSizeIterator + '_ {
         self.load_if_necessary_or_complain();
         (0..self.parameter_count()).map(move |i| self.parameter_by_index(i))
@@ -339,7 +480,13 @@ impl Fx {
             return Some(FxParameter::new(self.clone(), index));
         }
         let index = match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_get_param_from_ident(
+                    take.raw(),
+                    self.index(),
+                    id,
+                )?
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -352,7 +499,7 @@ impl Fx {
         Some(self.parameter_by_index(index))
     }
 
-    /// Will return None if monitoring FX.
+    /// Will return None if monitoring FX or take FX.
     ///
     /// In some scenarios it makes sense to fall back to the master track of the current project.
     pub fn track(&self) -> Option<&Track> {
@@ -360,12 +507,55 @@ impl Fx {
     }
 
     pub fn query_index(&self) -> TrackFxLocation {
-        get_track_fx_location(self.index(), self.is_input_fx())
+        match self.chain.context() {
+            FxChainContext::Container(_) => self.track_and_location().1,
+            _ => get_track_fx_location(self.index(), self.is_input_fx()),
+        }
+    }
+
+    /// Returns whether this FX is an FX container (REAPER 7+).
+    pub fn is_container(&self) -> bool {
+        self.load_if_necessary_or_complain();
+        self.get_named_config_param_as_string_internal("container_count", 32, self.index())
+            .is_ok()
+    }
+
+    /// Returns the chain within this FX container or `None` if this is not a container.
+    ///
+    /// Containers in take FX chains are not supported.
+    pub fn container_chain(&self) -> Option<FxChain> {
+        if matches!(self.chain.root().context(), FxChainContext::Take(_)) || !self.is_container() {
+            return None;
+        }
+        Some(FxChain::from_container(self.clone()))
+    }
+
+    /// Returns the FX within this FX container. Empty if this is not a container.
+    pub fn container_children(&self) -> Vec<Fx> {
+        match self.container_chain() {
+            None => vec![],
+            Some(chain) => chain.fxs().collect(),
+        }
+    }
+
+    /// Returns the container which contains this FX or `None` if this is a top-level FX.
+    pub fn parent_container(&self) -> Option<&Fx> {
+        match self.chain.context() {
+            FxChainContext::Container(container) => Some(container),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn container_fx_count(&self) -> u32 {
+        self.get_named_config_param_as_string_internal("container_count", 32, self.index())
+            .ok()
+            .and_then(|count| count.to_str().parse().ok())
+            .unwrap_or(0)
     }
 
     /// Panics if this is a take FX.
     pub(crate) fn track_and_location(&self) -> (Track, TrackFxLocation) {
-        get_track_and_location(&self.chain, self.index()).expect("must be take FX")
+        get_track_and_location(&self.chain, self.index()).expect("must be track FX")
     }
 
     pub fn index(&self) -> u32 {
@@ -431,7 +621,6 @@ impl Fx {
     // just a &str,  we would need to copy to achieve that ownership. We might need to
     // reconsider the ownership  requirement of ChunkRegions as a whole (but then we need to
     // care about lifetimes).
-    // TODO-low Supports track FX only
     pub fn set_chunk(&self, chunk_region: ChunkRegion) -> Result<(), &'static str> {
         // First replace GUID in chunk with the one of this FX
         let mut parent_chunk = chunk_region.parent_chunk();
@@ -441,24 +630,26 @@ impl Fx {
             parent_chunk.replace_region(&fx_id_line, get_fx_id_line(&guid).as_str());
         }
         // Then set new chunk
-        self.replace_track_chunk_region(self.chunk()?, chunk_region.content().deref())?;
+        self.replace_chunk_region(self.chunk()?, chunk_region.content().deref())?;
         Ok(())
     }
 
-    // TODO-low Supports track FX only
     pub fn set_tag_chunk(&self, chunk: &str) -> Result<(), &'static str> {
-        self.replace_track_chunk_region(self.tag_chunk()?, chunk)
+        self.replace_chunk_region(self.tag_chunk()?, chunk)
     }
 
-    // TODO-low Supports track FX only
     pub fn set_state_chunk(&self, chunk: &str) -> Result<(), &'static str> {
-        self.replace_track_chunk_region(self.state_chunk()?, chunk)
+        self.replace_chunk_region(self.state_chunk()?, chunk)
     }
 
     pub fn floating_window(&self) -> Option<Hwnd> {
         self.load_if_necessary_or_complain();
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_floating_window(take.raw(), self.index())
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -472,7 +663,11 @@ impl Fx {
 
     pub fn window_is_open(&self) -> bool {
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_open(take.raw(), self.index())
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -491,7 +686,12 @@ impl Fx {
     pub fn show_in_floating_window(&self) {
         self.load_if_necessary_or_complain();
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_show(
+                    take.raw(),
+                    TakeFxShowInstruction::ShowFloatingWindow(self.index()),
+                );
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -507,7 +707,12 @@ impl Fx {
     pub fn hide_floating_window(&self) {
         self.load_if_necessary_or_complain();
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_show(
+                    take.raw(),
+                    TakeFxShowInstruction::HideFloatingWindow(self.index()),
+                );
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -523,7 +728,11 @@ impl Fx {
     pub fn show_in_chain(&self) {
         self.load_if_necessary_or_complain();
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_show(take.raw(), TakeFxShowInstruction::ShowChain(self.index()));
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -535,8 +744,7 @@ impl Fx {
         }
     }
 
-    // TODO-low Supports track FX only
-    fn replace_track_chunk_region(
+    fn replace_chunk_region(
         &self,
         old_chunk_region: ChunkRegion,
         new_content: &str,
@@ -544,10 +752,7 @@ impl Fx {
         let mut old_chunk = old_chunk_region.parent_chunk();
         old_chunk.replace_region(&old_chunk_region, new_content);
         std::mem::drop(old_chunk_region);
-        self.track()
-            .ok_or("only track FX supported")?
-            .set_chunk(old_chunk)?;
-        Ok(())
+        self.chain.write_owner_chunk(old_chunk)
     }
 
     pub fn chain(&self) -> &FxChain {
@@ -564,7 +769,13 @@ impl Fx {
 
     fn set_enabled(&self, enabled: bool) {
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_set_enabled(
+                    take.raw(),
+                    self.index(),
+                    enabled,
+                );
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -580,7 +791,13 @@ impl Fx {
 
     pub fn set_online(&self, online: bool) {
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_set_offline(
+                    take.raw(),
+                    self.index(),
+                    !online,
+                );
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -620,7 +837,11 @@ impl Fx {
     pub fn preset_index_and_count(&self) -> TrackFxGetPresetIndexResult {
         self.load_if_necessary_or_complain();
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_preset_index(take.raw(), self.index())
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -635,7 +856,13 @@ impl Fx {
     pub fn activate_preset(&self, preset: FxPresetRef) {
         self.load_if_necessary_or_complain();
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                let _ = Reaper::get().medium_reaper().take_fx_set_preset_by_index(
+                    take.raw(),
+                    self.index(),
+                    preset,
+                );
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -652,7 +879,14 @@ impl Fx {
     pub fn preset_is_dirty(&self) -> bool {
         self.load_if_necessary_or_complain();
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => {
+                let result = unsafe {
+                    Reaper::get()
+                        .medium_reaper()
+                        .take_fx_get_preset(take.raw(), self.index(), 0)
+                };
+                !result.state_matches_preset
+            }
             _ => {
                 let (track, location) = self.track_and_location();
                 let result = unsafe {
@@ -668,7 +902,12 @@ impl Fx {
     pub fn preset_name(&self) -> Option<ReaperString> {
         self.load_if_necessary_or_complain();
         match self.chain.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_preset(take.raw(), self.index(), 2000)
+                    .name
+            },
             _ => {
                 let (track, location) = self.track_and_location();
                 unsafe {
@@ -682,8 +921,32 @@ impl Fx {
     }
 }
 
-fn get_track_and_location(chain: &FxChain, index: u32) -> Option<(Track, TrackFxLocation)> {
+pub(crate) fn get_track_and_location(
+    chain: &FxChain,
+    index: u32,
+) -> Option<(Track, TrackFxLocation)> {
     match chain.context() {
+        FxChainContext::Container(_) => {
+            // Walk up to the top-level chain, collecting the containers on the way
+            let mut containers = vec![];
+            let mut current_chain = chain;
+            while let FxChainContext::Container(container) = current_chain.context() {
+                containers.push(FxContainerLevel {
+                    index: container.index(),
+                    chain_fx_count: container.chain().fx_count(),
+                });
+                current_chain = container.chain();
+            }
+            containers.reverse();
+            let (track, _) = get_track_and_location(current_chain, 0)?;
+            let chain_type = if current_chain.is_input_fx() {
+                TrackFxChainType::InputFxChain
+            } else {
+                TrackFxChainType::NormalFxChain
+            };
+            let address = FxContainerAddress::from_path(chain_type, &containers, index);
+            Some((track, TrackFxLocation::ContainerFx(address)))
+        }
         FxChainContext::Monitoring => {
             let track = Reaper::get()
                 .current_project()
@@ -702,9 +965,14 @@ fn get_track_and_location(chain: &FxChain, index: u32) -> Option<(Track, TrackFx
 
 pub fn get_fx_guid(chain: &FxChain, index: u32) -> Option<Guid> {
     let raw_guid = match chain.context() {
-        FxChainContext::Take(_) => todo!(),
+        FxChainContext::Take(take) => unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .take_fx_get_fx_guid(take.raw(), index)
+                .ok()
+        },
         _ => {
-            let (track, location) = get_track_and_location(chain, index).expect("must be take FX");
+            let (track, location) = get_track_and_location(chain, index).expect("must be track FX");
             unsafe {
                 Reaper::get()
                     .medium_reaper()
@@ -804,7 +1072,7 @@ impl FxInfo {
     }
 }
 
-fn get_fx_id_line(guid: &Guid) -> String {
+pub(crate) fn get_fx_id_line(guid: &Guid) -> String {
     format!("FXID {}", guid.to_string_with_braces())
 }
 
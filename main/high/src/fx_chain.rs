@@ -1,13 +1,17 @@
-use crate::fx::{get_fx_guid, Fx};
+use crate::fx::{get_fx_guid, get_fx_id_line, get_track_and_location, Fx};
 use crate::guid::Guid;
+use crate::template::regenerate_guids;
 use crate::{
-    get_track_fx_location, Chunk, ChunkRegion, Project, Reaper, Take, Track, MAX_TRACK_CHUNK_SIZE,
+    Chunk, ChunkRegion, FxChainTemplate, Project, Reaper, ReaperResult, Take, Track,
+    MAX_TRACK_CHUNK_SIZE,
 };
 
 use reaper_medium::{
     AddFxBehavior, ChunkCacheHint, FxChainVisibility, FxShowInstruction, ReaperStringArg,
-    TrackFxChainType, TransferBehavior,
+    TakeFxShowInstruction, TrackFxChainType, TrackFxLocation, TransferBehavior,
 };
+use std::fs;
+use std::path::Path;
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum FxChainContext {
@@ -21,8 +25,13 @@ pub enum FxChainContext {
     //  projects? 2 FX chains won't equal if they both are master tracks and is_input_fx = true
     //  but master tracks from different projects!
     Monitoring,
-    Track { track: Track, is_input_fx: bool },
+    Track {
+        track: Track,
+        is_input_fx: bool,
+    },
     Take(Take),
+    /// The chain within an FX container (REAPER 7+). Contains the container FX itself.
+    Container(Box<Fx>),
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -43,6 +52,12 @@ impl FxChain {
         }
     }
 
+    pub(crate) fn from_container(container: Fx) -> FxChain {
+        FxChain {
+            context: FxChainContext::Container(Box::new(container)),
+        }
+    }
+
     pub(crate) fn from_monitoring() -> FxChain {
         FxChain {
             context: FxChainContext::Monitoring,
@@ -54,7 +69,10 @@ impl FxChain {
     }
 
     pub fn project(&self) -> Option<Project> {
-        self.track().map(|t| t.project())
+        match &self.context {
+            FxChainContext::Take(take) => take.project(),
+            _ => self.track().map(|t| t.project()),
+        }
     }
 
     pub fn fx_count(&self) -> u32 {
@@ -74,7 +92,8 @@ impl FxChain {
                     .expect("master track of current project should exist");
                 unsafe { reaper.track_fx_get_rec_count(track.raw()) }
             }
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe { reaper.take_fx_get_count(take.raw()) },
+            FxChainContext::Container(container) => container.container_fx_count(),
         }
     }
 
@@ -95,13 +114,20 @@ impl FxChain {
                     .expect("master track of current project should exist");
                 unsafe { reaper.track_fx_get_rec_chain_visible(track.raw()) }
             }
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe { reaper.take_fx_get_chain_visible(take.raw()) },
+            // Containers are shown within the chain window of their top-level chain
+            FxChainContext::Container(_) => self.root().visibility(),
         }
     }
 
     pub fn hide(&self) {
         match self.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_show(take.raw(), TakeFxShowInstruction::HideChain);
+            },
+            FxChainContext::Container(_) => self.root().hide(),
             _ => {
                 let track = self.track_or_master_track();
                 let instruction = FxShowInstruction::HideChain(if self.is_input_fx() {
@@ -122,23 +148,23 @@ impl FxChain {
     pub fn move_fx(&self, fx: &Fx, new_index: u32) -> Result<(), &'static str> {
         assert_eq!(fx.chain(), self);
         let reaper = Reaper::get().medium_reaper();
-        if reaper.low().pointers().TrackFX_CopyToTrack.is_some() {
-            match self.context() {
-                FxChainContext::Take(_) => todo!(),
-                _ => {
-                    let (track, location) = fx.track_and_location();
-                    unsafe {
-                        reaper.track_fx_copy_to_track(
-                            (track.raw(), location),
-                            (
-                                track.raw(),
-                                get_track_fx_location(new_index, self.is_input_fx()),
-                            ),
-                            TransferBehavior::Move,
-                        );
-                    }
-                }
-            };
+        if let FxChainContext::Take(take) = self.context() {
+            unsafe {
+                reaper.take_fx_copy_to_take(
+                    (take.raw(), fx.index()),
+                    (take.raw(), new_index),
+                    TransferBehavior::Move,
+                );
+            }
+        } else if reaper.low().pointers().TrackFX_CopyToTrack.is_some() {
+            let (track, location) = fx.track_and_location();
+            unsafe {
+                reaper.track_fx_copy_to_track(
+                    (track.raw(), location),
+                    (track.raw(), self.track_fx_location(new_index)?),
+                    TransferBehavior::Move,
+                );
+            }
         } else {
             if !fx.is_available() {
                 return Err("FX not available");
@@ -184,6 +210,45 @@ impl FxChain {
         Ok(())
     }
 
+    /// Moves the given FX from another chain of the same track (e.g. from the top-level chain or
+    /// from another container) into this chain at the given index.
+    ///
+    /// Only supported for track FX and REAPER >= 7.
+    pub fn move_fx_into(&self, fx: &Fx, index: u32) -> Result<(), &'static str> {
+        if !fx.is_available() {
+            return Err("FX not available");
+        }
+        let (source_track, source_location) =
+            get_track_and_location(fx.chain(), fx.index()).ok_or("working on track FX only")?;
+        let (track, _) = get_track_and_location(self, 0).ok_or("working on track FX only")?;
+        if source_track != track {
+            return Err("FX must be on the same track");
+        }
+        let destination_location = self.track_fx_location(index)?;
+        unsafe {
+            Reaper::get().medium_reaper().track_fx_copy_to_track(
+                (source_track.raw(), source_location),
+                (track.raw(), destination_location),
+                TransferBehavior::Move,
+            );
+        }
+        Ok(())
+    }
+
+    fn track_fx_location(&self, index: u32) -> Result<TrackFxLocation, &'static str> {
+        let (_, location) =
+            get_track_and_location(self, index).ok_or("working on track FX only")?;
+        Ok(location)
+    }
+
+    /// Returns the top-level chain if this is the chain of an FX container, otherwise itself.
+    pub(crate) fn root(&self) -> &FxChain {
+        match self.context() {
+            FxChainContext::Container(container) => container.chain().root(),
+            _ => self,
+        }
+    }
+
     fn track_fx_track(&self) -> Option<&Track> {
         match self.context() {
             FxChainContext::Track { track, .. } => Some(track),
@@ -197,17 +262,18 @@ impl FxChain {
             return Err("FX not available");
         }
         let reaper = Reaper::get().medium_reaper();
-        if reaper.low().pointers().TrackFX_Delete.is_some() {
-            match self.context() {
-                FxChainContext::Take(_) => todo!(),
-                _ => {
-                    let (track, location) = fx.track_and_location();
-                    unsafe {
-                        reaper
-                            .track_fx_delete(track.raw(), location)
-                            .map_err(|_| "couldn't delete track FX")?
-                    };
-                }
+        if let FxChainContext::Take(take) = self.context() {
+            unsafe {
+                reaper
+                    .take_fx_delete(take.raw(), fx.index())
+                    .map_err(|_| "couldn't delete take FX")?
+            };
+        } else if reaper.low().pointers().TrackFX_Delete.is_some() {
+            let (track, location) = fx.track_and_location();
+            unsafe {
+                reaper
+                    .track_fx_delete(track.raw(), location)
+                    .map_err(|_| "couldn't delete track FX")?
             };
         } else {
             let new_chunk = {
@@ -290,53 +356,161 @@ DOCKED 0
     // In Track this returns Chunk, here it returns ChunkRegion. Because REAPER always returns
     // the chunk of the complete track, not just of the FX chain.
     pub fn chunk(&self) -> Result<Option<ChunkRegion>, &'static str> {
-        let res = self.find_chunk_region(
-            self.track_fx_track()
-                .ok_or("working on track FX only")?
-                .chunk(MAX_TRACK_CHUNK_SIZE, ChunkCacheHint::NormalMode)?,
-        );
-        Ok(res)
+        Ok(self.find_chunk_region(self.owner_chunk()?))
     }
 
     pub fn set_chunk(&self, chunk: &str) -> Result<(), &'static str> {
-        let mut track_chunk = self
-            .track_fx_track()
-            .ok_or("works on track FX only")?
-            .chunk(MAX_TRACK_CHUNK_SIZE, ChunkCacheHint::NormalMode)?;
-        let chain_tag = self.find_chunk_region(track_chunk.clone());
+        let mut owner_chunk = self.owner_chunk()?;
+        let chain_tag = self.find_chunk_region(owner_chunk.clone());
         match chain_tag {
             Some(r) => {
                 // There's an FX chain already. Replace it.
-                track_chunk.replace_region(&r, chunk);
+                owner_chunk.replace_region(&r, chunk);
             }
             None => {
                 // There's no FX chain yet. Insert it.
-                track_chunk.insert_after_region_as_block(&track_chunk.region().first_line(), chunk);
+                let anchor = match self.context() {
+                    // Take FX chains follow the source of the take
+                    FxChainContext::Take(take) => take_chunk_section(&owner_chunk, take.index())
+                        .and_then(|section| section.find_first_tag_named(0, "SOURCE"))
+                        .ok_or("take source not found in item chunk")?,
+                    FxChainContext::Container(_) => return Err("container not found in chunk"),
+                    _ => owner_chunk.region().first_line(),
+                };
+                owner_chunk.insert_after_region_as_block(&anchor, chunk);
             }
         }
-        self.track_fx_track()
-            .ok_or("works on track FX only")?
-            .set_chunk(track_chunk)?;
+        self.write_owner_chunk(owner_chunk)
+    }
+
+    /// Returns the FX of this chain in the format of an FX chain file (`.RfxChain`).
+    pub fn template(&self) -> Result<FxChainTemplate, &'static str> {
+        let template = match self.chunk()? {
+            Some(chain_tag) => FxChainTemplate::from_chain_tag(&chain_tag),
+            None => FxChainTemplate::parse(""),
+        };
+        Ok(template)
+    }
+
+    /// Saves the FX of this chain as FX chain file (`.RfxChain`).
+    pub fn export_to_file(&self, path: &Path) -> ReaperResult<()> {
+        self.template()?.save(path)
+    }
+
+    /// Appends the FX contained in the given FX chain template to this chain.
+    ///
+    /// The FX get new GUIDs, so the same template can be added multiple times.
+    pub fn add_fxs_from_template(&self, template: &FxChainTemplate) -> Result<(), &'static str> {
+        if template.is_empty() {
+            return Ok(());
+        }
+        let fx_chunk = regenerate_guids(template.content());
+        let mut owner_chunk = self.owner_chunk()?;
+        match self.find_chunk_region(owner_chunk.clone()) {
+            Some(chain_tag) => {
+                // There's an FX chain already. Add after last FX.
+                owner_chunk.insert_before_region_as_block(&chain_tag.last_line(), &fx_chunk);
+                self.write_owner_chunk(owner_chunk)
+            }
+            None => self.set_chunk(&format!(
+                "<{}\nSHOW 0\nLASTSEL 0\nDOCKED 0\n{}\n>",
+                self.chunk_tag_name(),
+                fx_chunk
+            )),
+        }
+    }
+
+    /// Appends the FX contained in the given FX chain file (`.RfxChain`) to this chain.
+    pub fn add_fxs_from_file(&self, path: &Path) -> ReaperResult<()> {
+        self.add_fxs_from_template(&FxChainTemplate::load(path)?)?;
         Ok(())
     }
 
-    fn find_chunk_region(&self, track_chunk: Chunk) -> Option<ChunkRegion> {
-        track_chunk
-            .region()
-            .find_first_tag_named(0, self.chunk_tag_name())
+    /// Returns the chunk which contains this FX chain.
+    ///
+    /// That's the chunk of the track or item. For containers, it's the chunk of the top-level
+    /// chain's owner. For the monitoring FX chain, it's the content of `reaper-hwoutfx.ini`.
+    fn owner_chunk(&self) -> Result<Chunk, &'static str> {
+        match self.context() {
+            FxChainContext::Track { track, .. } => {
+                track.chunk(MAX_TRACK_CHUNK_SIZE, ChunkCacheHint::NormalMode)
+            }
+            FxChainContext::Take(take) => take
+                .item()
+                .ok_or("take doesn't have item")?
+                .chunk(MAX_TRACK_CHUNK_SIZE, ChunkCacheHint::NormalMode),
+            FxChainContext::Container(_) => self.root().owner_chunk(),
+            FxChainContext::Monitoring => {
+                let path = Reaper::get().resource_path().join(MONITORING_FX_FILE_NAME);
+                let content = fs::read_to_string(path).unwrap_or_default();
+                let content = content.trim();
+                let chunk = if content.starts_with("<FXCHAIN") {
+                    content.to_string()
+                } else {
+                    format!("<FXCHAIN\n{content}\n>")
+                };
+                Ok(Chunk::new(chunk))
+            }
+        }
     }
 
-    fn chunk_tag_name(&self) -> &'static str {
-        if self.is_input_fx() {
-            "FXCHAIN_REC"
-        } else {
-            "FXCHAIN"
+    /// Writes the given chunk back to the owner of this FX chain.
+    ///
+    /// Fails for the monitoring FX chain because REAPER reads `reaper-hwoutfx.ini` on startup
+    /// only.
+    pub(crate) fn write_owner_chunk(&self, chunk: Chunk) -> Result<(), &'static str> {
+        match self.context() {
+            FxChainContext::Track { track, .. } => track.set_chunk(chunk),
+            FxChainContext::Take(take) => take
+                .item()
+                .ok_or("take doesn't have item")?
+                .set_chunk(chunk),
+            FxChainContext::Container(_) => self.root().write_owner_chunk(chunk),
+            FxChainContext::Monitoring => {
+                Err("monitoring FX chain can't be changed via chunk while REAPER is running")
+            }
+        }
+    }
+
+    fn find_chunk_region(&self, owner_chunk: Chunk) -> Option<ChunkRegion> {
+        match self.context() {
+            FxChainContext::Take(take) => take_chunk_section(&owner_chunk, take.index())?
+                .find_first_tag_named(0, self.chunk_tag_name()),
+            FxChainContext::Container(container) => {
+                let parent_chain_tag = container.chain().find_chunk_region(owner_chunk)?;
+                let fx_id_line = get_fx_id_line(&container.guid()?);
+                find_container_tag(&parent_chain_tag, &fx_id_line)
+            }
+            _ => owner_chunk
+                .region()
+                .find_first_tag_named(0, self.chunk_tag_name()),
+        }
+    }
+
+    pub(crate) fn chunk_tag_name(&self) -> &'static str {
+        match self.context() {
+            FxChainContext::Take(_) => "TAKEFX",
+            FxChainContext::Container(_) => "CONTAINER",
+            FxChainContext::Monitoring => "FXCHAIN",
+            FxChainContext::Track { is_input_fx, .. } => {
+                if *is_input_fx {
+                    "FXCHAIN_REC"
+                } else {
+                    "FXCHAIN"
+                }
+            }
         }
     }
 
     pub fn first_instrument_fx(&self) -> Option<Fx> {
         match self.context() {
-            FxChainContext::Take(_) => todo!(),
+            // There's no TakeFX_GetInstrument and TrackFX_GetInstrument doesn't look into
+            // containers, so we look for the first instrument ourselves
+            FxChainContext::Take(_) | FxChainContext::Container(_) => self.fxs().find(|fx| {
+                fx.info()
+                    .map(|info| info.sub_type_expression.ends_with('i'))
+                    .unwrap_or(false)
+            }),
             FxChainContext::Monitoring => None,
             FxChainContext::Track { track, is_input_fx } => {
                 if *is_input_fx {
@@ -363,7 +537,19 @@ DOCKED 0
         original_fx_name: impl Into<ReaperStringArg<'a>>,
     ) -> Option<Fx> {
         let fx_index = match self.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_add_by_name_add(take.raw(), original_fx_name, AddFxBehavior::AlwaysAdd)
+                    .ok()?
+            },
+            FxChainContext::Container(_) => {
+                // Add to the end of the top-level chain and move it into the container from there
+                let fx = self.root().add_fx_by_original_name(original_fx_name)?;
+                let index = self.fx_count();
+                self.move_fx_into(&fx, index).ok()?;
+                index
+            }
             _ => unsafe {
                 Reaper::get()
                     .medium_reaper()
@@ -398,17 +584,17 @@ DOCKED 0
                 .master_track()
                 .expect("master track of current project should exist"),
             FxChainContext::Track { track, .. } => track.clone(),
-            FxChainContext::Take(take) => take.track().clone(),
+            FxChainContext::Take(take) => take.track().expect("take should belong to a track"),
+            FxChainContext::Container(_) => self.root().track_or_master_track(),
         }
     }
 
+    /// Returns `None` for monitoring and take FX chains.
     pub fn track(&self) -> Option<&Track> {
         match &self.context {
             FxChainContext::Track { track, .. } => Some(track),
-            // TODO-low This is dangerous. Some chunk functions which call track assume this is
-            //  a track FX when this returns a track. Clean them up!
-            FxChainContext::Take(take) => Some(take.track()),
-            FxChainContext::Monitoring => None,
+            FxChainContext::Container(_) => self.root().track(),
+            FxChainContext::Take(_) | FxChainContext::Monitoring => None,
         }
     }
 
@@ -418,13 +604,22 @@ DOCKED 0
             // In REAPER, monitoring FX chain is usually referred to as input FX of the master
             // track, so it's just consequent to report it as input FX.
             FxChainContext::Monitoring => true,
-            _ => false,
+            FxChainContext::Container(_) => self.root().is_input_fx(),
+            FxChainContext::Take(_) => false,
         }
     }
 
     pub fn first_fx_by_name<'a>(&self, name: impl Into<ReaperStringArg<'a>>) -> Option<Fx> {
         let fx_index = match self.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_add_by_name_query(take.raw(), name)?
+            },
+            FxChainContext::Container(_) => {
+                let name = name.into().into_inner();
+                return self.fxs().find(|fx| fx.name().as_reaper_str() == &*name);
+            }
             FxChainContext::Track { track, .. } => unsafe {
                 Reaper::get().medium_reaper().track_fx_add_by_name_query(
                     track.raw(),
@@ -497,9 +692,62 @@ DOCKED 0
 
     pub fn is_available(&self) -> bool {
         match self.context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => take.is_available(),
+            FxChainContext::Container(container) => container.is_available(),
             FxChainContext::Monitoring => true,
             FxChainContext::Track { track, .. } => track.is_available(),
         }
     }
 }
+
+/// File in the REAPER resource directory which contains the monitoring FX chain.
+const MONITORING_FX_FILE_NAME: &str = "reaper-hwoutfx.ini";
+
+/// Returns the `<CONTAINER` tag of the container FX with the given FX ID line.
+///
+/// The container must be a direct child of the given chain tag. Its FX ID line follows the
+/// container tag, so the container tag is the last top-level tag before the FX ID line.
+fn find_container_tag(chain_tag: &ChunkRegion, fx_id_line: &str) -> Option<ChunkRegion> {
+    let fx_id_pos = chain_tag.find_line_starting_with(fx_id_line)?.start_pos();
+    // Skip the opener of the chain tag itself
+    let mut rel_pos = chain_tag.first_line().length();
+    let mut last_tag = None;
+    while let Some(tag) = chain_tag.find_first_tag(rel_pos) {
+        if tag.start_pos() > fx_id_pos {
+            break;
+        }
+        rel_pos = tag.end_pos_plus_one() - chain_tag.start_pos();
+        last_tag = Some(tag);
+    }
+    last_tag.filter(|tag| tag.starts_with("<CONTAINER"))
+}
+
+/// Returns the part of the given item chunk which belongs to the take with the given index.
+fn take_chunk_section(item_chunk: &Chunk, take_index: u32) -> Option<ChunkRegion> {
+    let content = item_chunk.content();
+    let content = content.borrow();
+    // Takes are separated by top-level "TAKE" lines
+    let mut boundaries = vec![0];
+    let mut level = 0;
+    let mut pos = 0;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed.starts_with('<') {
+            level += 1;
+        } else if trimmed.starts_with('>') {
+            level -= 1;
+        } else if level == 1 && (trimmed == "TAKE" || trimmed.starts_with("TAKE ")) {
+            boundaries.push(pos);
+        }
+        pos += line.len();
+    }
+    boundaries.push(content.len());
+    let start = *boundaries.get(take_index as usize)?;
+    let end = *boundaries.get(take_index as usize + 1)?;
+    Some(
+        item_chunk
+            .region()
+            .move_left_cursor_right_by(start)
+            .move_right_cursor_left_by(content.len() - end),
+    )
+}
@@ -1,8 +1,8 @@
 use crate::fx::Fx;
 
-use crate::{FxChain, FxChainContext, Reaper};
+use crate::{Envelope, FxChain, FxChainContext, Reaper};
 use reaper_medium::{
-    GetParamExResult, GetParameterStepSizesResult, ReaperFunctionError,
+    CreateBehavior, GetParamExResult, GetParameterStepSizesResult, ReaperFunctionError,
     ReaperNormalizedFxParamValue, ReaperString,
 };
 
@@ -23,7 +23,14 @@ impl FxParameter {
     ) -> Result<(), ReaperFunctionError> {
         Reaper::get().require_main_thread();
         match self.chain().context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_set_param_normalized(
+                    take.raw(),
+                    self.fx().index(),
+                    self.index,
+                    reaper_value.into(),
+                )
+            },
             _ => {
                 let (track, location) = self.fx().track_and_location();
                 unsafe {
@@ -41,7 +48,13 @@ impl FxParameter {
     pub fn reaper_normalized_value(&self) -> ReaperNormalizedFxParamValue {
         Reaper::get().require_main_thread();
         match self.chain().context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_get_param_normalized(
+                    take.raw(),
+                    self.fx().index(),
+                    self.index,
+                )
+            },
             _ => {
                 let (track, location) = self.fx().track_and_location();
                 unsafe {
@@ -58,7 +71,13 @@ impl FxParameter {
     pub fn end_edit(&self) -> Result<(), ReaperFunctionError> {
         Reaper::get().require_main_thread();
         match self.chain().context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_end_param_edit(
+                    take.raw(),
+                    self.fx().index(),
+                    self.index,
+                )
+            },
             _ => {
                 let (track, location) = self.fx().track_and_location();
                 unsafe {
@@ -72,6 +91,34 @@ impl FxParameter {
         }
     }
 
+    pub fn envelope(&self, create_behavior: CreateBehavior) -> Option<Envelope> {
+        match self.chain().context() {
+            FxChainContext::Take(take) => {
+                let raw = unsafe {
+                    Reaper::get().medium_reaper().take_fx_get_envelope(
+                        take.raw(),
+                        self.fx().index(),
+                        self.index,
+                        create_behavior,
+                    )?
+                };
+                Some(Envelope::new(raw))
+            }
+            _ => {
+                let (track, location) = self.fx().track_and_location();
+                let raw = unsafe {
+                    Reaper::get().medium_reaper().get_fx_envelope(
+                        track.raw(),
+                        location,
+                        self.index,
+                        create_behavior,
+                    )?
+                };
+                Some(Envelope::new(raw))
+            }
+        }
+    }
+
     fn chain(&self) -> &FxChain {
         self.fx().chain()
     }
@@ -82,7 +129,12 @@ impl FxParameter {
 
     pub fn name(&self) -> ReaperString {
         match self.chain().context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_param_name(take.raw(), self.fx().index(), self.index, 256)
+                    .expect("Couldn't get FX parameter name")
+            },
             _ => {
                 let (track, location) = self.fx().track_and_location();
                 unsafe {
@@ -107,38 +159,51 @@ impl FxParameter {
 
     pub fn step_sizes(&self) -> Option<GetParameterStepSizesResult> {
         Reaper::get().require_main_thread();
-        match self.chain().context() {
-            FxChainContext::Take(_) => todo!(),
+        let result = match self.chain().context() {
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_parameter_step_sizes(take.raw(), self.fx().index(), self.index)?
+            },
             _ => {
                 let (track, location) = self.fx().track_and_location();
-                let result = unsafe {
+                unsafe {
                     Reaper::get()
                         .medium_reaper()
                         .track_fx_get_parameter_step_sizes(track.raw(), location, self.index)?
-                };
-                // Try to fix some invalid results (which are most likely invalid because of messy
-                // plug-ins, not because of REAPER itself)
-                if let GetParameterStepSizesResult::Normal { normal_step, .. } = result {
-                    if normal_step.is_infinite() {
-                        // There was a bug (REAPER <= 6.12) which makes JS FX "Bypass" and "Wet"
-                        // parameters return an infinite step size. This
-                        // isn't correct, therefore we fix it here.
-                        return None;
-                    }
-                    if normal_step == 0.0 {
-                        // Some plug-ins report a parameter as discrete but then report a step size
-                        // of zero, which is of course pointless.
-                        return None;
-                    }
                 }
-                Some(result)
+            }
+        };
+        // Try to fix some invalid results (which are most likely invalid because of messy
+        // plug-ins, not because of REAPER itself)
+        if let GetParameterStepSizesResult::Normal { normal_step, .. } = result {
+            if normal_step.is_infinite() {
+                // There was a bug (REAPER <= 6.12) which makes JS FX "Bypass" and "Wet"
+                // parameters return an infinite step size. This
+                // isn't correct, therefore we fix it here.
+                return None;
+            }
+            if normal_step == 0.0 {
+                // Some plug-ins report a parameter as discrete but then report a step size
+                // of zero, which is of course pointless.
+                return None;
             }
         }
+        Some(result)
     }
 
     pub fn formatted_value(&self) -> Result<ReaperString, ReaperFunctionError> {
         match self.chain().context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_get_formatted_param_value(
+                        take.raw(),
+                        self.fx().index(),
+                        self.index,
+                        256,
+                    )
+            },
             _ => {
                 let (track, location) = self.fx().track_and_location();
                 unsafe {
@@ -163,7 +228,17 @@ impl FxParameter {
         reaper_value: ReaperNormalizedFxParamValue,
     ) -> Result<ReaperString, ReaperFunctionError> {
         match self.chain().context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get()
+                    .medium_reaper()
+                    .take_fx_format_param_value_normalized(
+                        take.raw(),
+                        self.fx().index(),
+                        self.index,
+                        reaper_value,
+                        256,
+                    )
+            },
             _ => {
                 let (track, location) = self.fx().track_and_location();
                 unsafe {
@@ -214,7 +289,13 @@ impl FxParameter {
     pub fn value_range(&self) -> GetParamExResult {
         Reaper::get().require_main_thread();
         match self.chain().context() {
-            FxChainContext::Take(_) => todo!(),
+            FxChainContext::Take(take) => unsafe {
+                Reaper::get().medium_reaper().take_fx_get_param_ex(
+                    take.raw(),
+                    self.fx().index(),
+                    self.index,
+                )
+            },
             _ => {
                 let (track, location) = self.fx().track_and_location();
                 unsafe {
@@ -0,0 +1,425 @@
+use crate::{FxParameter, Reaper, ReaperError, ReaperResult};
+use reaper_medium::{Db, ReaperVersion};
+
+/// Modulation and linking settings of an FX parameter.
+///
+/// Obtain it via [`FxParameter::modulation()`], change it and write it back via
+/// [`FxParameter::set_modulation()`]. Everything is read and written via the `param.N.*` named
+/// configuration parameters of the FX.
+///
+/// [`FxParameter::modulation()`]: crate::FxParameter::modulation
+/// [`FxParameter::set_modulation()`]: crate::FxParameter::set_modulation
+#[derive(Clone, PartialEq, Debug)]
+pub struct FxParameterModulation {
+    /// Whether parameter modulation is enabled at all.
+    pub active: bool,
+    /// Base value of the modulated parameter (normalized).
+    pub base_value: f64,
+    pub lfo: FxParameterLfo,
+    pub audio_control: FxParameterAudioControl,
+    pub link: FxParameterLink,
+    /// Whether this parameter is mapped to a parameter (macro) of the parent container.
+    ///
+    /// Always `false` if the FX is not inside a container. When mapping, REAPER chooses the
+    /// container parameter.
+    pub macro_linked: bool,
+}
+
+/// LFO settings of a parameter modulation.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FxParameterLfo {
+    pub active: bool,
+    pub direction: ModulationDirection,
+    pub shape: LfoShape,
+    /// Phase (0.0 to 1.0).
+    pub phase: f64,
+    /// Speed in Hz or in quarter notes if tempo-synced.
+    pub speed: f64,
+    /// Strength (0.0 to 1.0).
+    pub strength: f64,
+    pub tempo_sync: bool,
+    /// Whether the LFO keeps running when playback is stopped.
+    pub free_running: bool,
+}
+
+/// Audio control signal (sidechain) settings of a parameter modulation.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FxParameterAudioControl {
+    pub active: bool,
+    pub direction: ModulationDirection,
+    /// Strength (0.0 to 1.0).
+    pub strength: f64,
+    /// Attack in milliseconds.
+    pub attack: f64,
+    /// Release in milliseconds.
+    pub release: f64,
+    pub min_volume: Db,
+    pub max_volume: Db,
+    /// First track channel (zero-based) which is used as control signal.
+    pub channel: u32,
+    /// Whether two channels are used as control signal instead of one.
+    pub stereo: bool,
+}
+
+/// Parameter link or MIDI link settings of a parameter modulation.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FxParameterLink {
+    pub active: bool,
+    pub scale: f64,
+    pub offset: f64,
+    pub source: FxParameterLinkSource,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FxParameterLinkSource {
+    None,
+    /// Parameter of another FX in the same chain.
+    Parameter {
+        fx_index: u32,
+        param_index: u32,
+    },
+    Midi {
+        bus: u32,
+        /// Zero means omni.
+        channel: u32,
+        /// MIDI status byte (without channel), e.g. 0xB0 for CC.
+        message: u32,
+        /// First data byte, e.g. the CC number.
+        message_2: u32,
+    },
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ModulationDirection {
+    Negative,
+    Centered,
+    Positive,
+}
+
+impl ModulationDirection {
+    fn from_raw(v: i32) -> ModulationDirection {
+        use ModulationDirection::*;
+        match v {
+            x if x < 0 => Negative,
+            0 => Centered,
+            _ => Positive,
+        }
+    }
+
+    fn to_raw(self) -> i32 {
+        use ModulationDirection::*;
+        match self {
+            Negative => -1,
+            Centered => 0,
+            Positive => 1,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum LfoShape {
+    Sine,
+    Square,
+    SawLeft,
+    SawRight,
+    Triangle,
+    Random,
+}
+
+impl LfoShape {
+    fn from_raw(v: i32) -> ReaperResult<LfoShape> {
+        use LfoShape::*;
+        let shape = match v {
+            0 => Sine,
+            1 => Square,
+            2 => SawLeft,
+            3 => SawRight,
+            4 => Triangle,
+            5 => Random,
+            _ => return Err(ReaperError::new("unknown LFO shape")),
+        };
+        Ok(shape)
+    }
+
+    fn to_raw(self) -> i32 {
+        use LfoShape::*;
+        match self {
+            Sine => 0,
+            Square => 1,
+            SawLeft => 2,
+            SawRight => 3,
+            Triangle => 4,
+            Random => 5,
+        }
+    }
+}
+
+// Modulation (`mod.*`, `lfo.*`, `acs.*`) and link (`plink.*`) settings became accessible via named
+// config params with REAPER 6.37.
+const MODULATION_MIN_VERSION: &str = "6.37";
+// Container parameter mappings (`container_map.*`) became accessible with REAPER 7.06.
+const CONTAINER_MAP_MIN_VERSION: &str = "7.06";
+// Value of `plink.effect` which marks a MIDI link.
+const MIDI_LINK_EFFECT: i32 = -100;
+
+impl FxParameter {
+    /// Reads the modulation settings of this parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the REAPER version doesn't support parameter modulation via named
+    /// config params or if the FX doesn't exist anymore.
+    pub fn modulation(&self) -> ReaperResult<FxParameterModulation> {
+        let a = ModulationAccess::new(self);
+        let modulation = FxParameterModulation {
+            active: a.get_bool("mod.active")?,
+            base_value: a.get_f64("mod.baseline")?,
+            lfo: FxParameterLfo {
+                active: a.get_bool("lfo.active")?,
+                direction: ModulationDirection::from_raw(a.get_i32("lfo.dir")?),
+                shape: LfoShape::from_raw(a.get_i32("lfo.shape")?)?,
+                phase: a.get_f64("lfo.phase")?,
+                speed: a.get_f64("lfo.speed")?,
+                strength: a.get_f64("lfo.strength")?,
+                tempo_sync: a.get_bool("lfo.temposync")?,
+                free_running: a.get_bool("lfo.free")?,
+            },
+            audio_control: FxParameterAudioControl {
+                active: a.get_bool("acs.active")?,
+                direction: ModulationDirection::from_raw(a.get_i32("acs.dir")?),
+                strength: a.get_f64("acs.strength")?,
+                attack: a.get_f64("acs.attack")?,
+                release: a.get_f64("acs.release")?,
+                min_volume: a.get_db("acs.dblo")?,
+                max_volume: a.get_db("acs.dbhi")?,
+                channel: a.get_i32("acs.chan")?.max(0) as u32,
+                stereo: a.get_bool("acs.stereo")?,
+            },
+            link: FxParameterLink {
+                active: a.get_bool("plink.active")?,
+                scale: a.get_f64("plink.scale")?,
+                offset: a.get_f64("plink.offset")?,
+                source: a.get_link_source()?,
+            },
+            macro_linked: a.get_macro_link().is_some(),
+        };
+        Ok(modulation)
+    }
+
+    /// Writes the given modulation settings to this parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the REAPER version doesn't support one of the settings or if REAPER
+    /// refuses to apply it.
+    pub fn set_modulation(&self, modulation: &FxParameterModulation) -> ReaperResult<()> {
+        let a = ModulationAccess::new(self);
+        let lfo = &modulation.lfo;
+        a.set_f64("lfo.phase", lfo.phase)?;
+        a.set_f64("lfo.speed", lfo.speed)?;
+        a.set_f64("lfo.strength", lfo.strength)?;
+        a.set_i32("lfo.dir", lfo.direction.to_raw())?;
+        a.set_i32("lfo.shape", lfo.shape.to_raw())?;
+        a.set_bool("lfo.temposync", lfo.tempo_sync)?;
+        a.set_bool("lfo.free", lfo.free_running)?;
+        a.set_bool("lfo.active", lfo.active)?;
+        let acs = &modulation.audio_control;
+        a.set_f64("acs.strength", acs.strength)?;
+        a.set_f64("acs.attack", acs.attack)?;
+        a.set_f64("acs.release", acs.release)?;
+        a.set_f64("acs.dblo", acs.min_volume.get())?;
+        a.set_f64("acs.dbhi", acs.max_volume.get())?;
+        a.set_i32("acs.dir", acs.direction.to_raw())?;
+        a.set_i32("acs.chan", acs.channel as i32)?;
+        a.set_bool("acs.stereo", acs.stereo)?;
+        a.set_bool("acs.active", acs.active)?;
+        let link = &modulation.link;
+        a.set_f64("plink.scale", link.scale)?;
+        a.set_f64("plink.offset", link.offset)?;
+        a.set_link_source(link.source)?;
+        a.set_bool("plink.active", link.active)?;
+        a.set_f64("mod.baseline", modulation.base_value)?;
+        a.set_bool("mod.active", modulation.active)?;
+        a.set_macro_link(modulation.macro_linked)?;
+        Ok(())
+    }
+}
+
+/// Reads and writes `param.N.*` named config params, checking the REAPER version for each setting.
+struct ModulationAccess<'a> {
+    param: &'a FxParameter,
+    version: ReaperVersion<'static>,
+}
+
+impl<'a> ModulationAccess<'a> {
+    fn new(param: &'a FxParameter) -> Self {
+        Self {
+            param,
+            version: Reaper::get().version(),
+        }
+    }
+
+    fn supports(&self, min_version: &'static str) -> bool {
+        self.version >= ReaperVersion::new(min_version)
+    }
+
+    /// Returns the REAPER version which introduced the given setting (e.g. `lfo.speed`).
+    fn min_version(setting: &str) -> &'static str {
+        match setting.split('.').next() {
+            Some("container_map") => CONTAINER_MAP_MIN_VERSION,
+            _ => MODULATION_MIN_VERSION,
+        }
+    }
+
+    fn require(&self, setting: &str) -> ReaperResult<()> {
+        if !self.supports(Self::min_version(setting)) {
+            return Err(ReaperError::new(
+                "modulation setting not supported by this REAPER version",
+            ));
+        }
+        Ok(())
+    }
+
+    fn key(&self, setting: &str) -> String {
+        format!("param.{}.{}", self.param.index(), setting)
+    }
+
+    fn get_string(&self, setting: &str) -> ReaperResult<String> {
+        self.require(setting)?;
+        let value = self
+            .param
+            .fx()
+            .get_named_config_param_as_string(self.key(setting), 64)?;
+        Ok(value.into_string())
+    }
+
+    fn get_f64(&self, setting: &str) -> ReaperResult<f64> {
+        self.get_string(setting)?
+            .trim()
+            .parse()
+            .map_err(|_| ReaperError::new("modulation setting is not a number"))
+    }
+
+    fn get_i32(&self, setting: &str) -> ReaperResult<i32> {
+        // REAPER sometimes reports integers in decimal notation
+        Ok(self.get_f64(setting)?.round() as i32)
+    }
+
+    fn get_bool(&self, setting: &str) -> ReaperResult<bool> {
+        Ok(self.get_i32(setting)? != 0)
+    }
+
+    fn get_db(&self, setting: &str) -> ReaperResult<Db> {
+        Db::try_from(self.get_f64(setting)?)
+            .map_err(|_| ReaperError::new("modulation setting is not a valid dB value"))
+    }
+
+    fn get_link_source(&self) -> ReaperResult<FxParameterLinkSource> {
+        let effect = self.get_i32("plink.effect")?;
+        let source = match effect {
+            MIDI_LINK_EFFECT => FxParameterLinkSource::Midi {
+                bus: self.get_i32("plink.midi_bus")?.max(0) as u32,
+                channel: self.get_i32("plink.midi_chan")?.max(0) as u32,
+                message: self.get_i32("plink.midi_msg")?.max(0) as u32,
+                message_2: self.get_i32("plink.midi_msg2")?.max(0) as u32,
+            },
+            x if x >= 0 => FxParameterLinkSource::Parameter {
+                fx_index: x as u32,
+                param_index: self.get_i32("plink.param")?.max(0) as u32,
+            },
+            _ => FxParameterLinkSource::None,
+        };
+        Ok(source)
+    }
+
+    fn get_macro_link(&self) -> Option<u32> {
+        if !self.supports(CONTAINER_MAP_MIN_VERSION) {
+            return None;
+        }
+        let fx = self.param.fx();
+        let container = fx.parent_container()?;
+        let key = format!(
+            "container_map.get.{}.{}",
+            fx.query_index().to_raw(),
+            self.param.index()
+        );
+        container
+            .get_named_config_param_as_string(key, 64)
+            .ok()?
+            .to_str()
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    fn set_string(&self, setting: &str, value: String) -> ReaperResult<()> {
+        self.require(setting)?;
+        let mut bytes = value.into_bytes();
+        bytes.push(0);
+        self.param
+            .fx()
+            .set_named_config_param(self.key(setting), &bytes)?;
+        Ok(())
+    }
+
+    fn set_f64(&self, setting: &str, value: f64) -> ReaperResult<()> {
+        self.set_string(setting, value.to_string())
+    }
+
+    fn set_i32(&self, setting: &str, value: i32) -> ReaperResult<()> {
+        self.set_string(setting, value.to_string())
+    }
+
+    fn set_bool(&self, setting: &str, value: bool) -> ReaperResult<()> {
+        self.set_i32(setting, value as i32)
+    }
+
+    fn set_link_source(&self, source: FxParameterLinkSource) -> ReaperResult<()> {
+        match source {
+            FxParameterLinkSource::None => self.set_i32("plink.effect", -1),
+            FxParameterLinkSource::Parameter {
+                fx_index,
+                param_index,
+            } => {
+                self.set_i32("plink.effect", fx_index as i32)?;
+                self.set_i32("plink.param", param_index as i32)
+            }
+            FxParameterLinkSource::Midi {
+                bus,
+                channel,
+                message,
+                message_2,
+            } => {
+                self.set_i32("plink.effect", MIDI_LINK_EFFECT)?;
+                self.set_i32("plink.midi_bus", bus as i32)?;
+                self.set_i32("plink.midi_chan", channel as i32)?;
+                self.set_i32("plink.midi_msg", message as i32)?;
+                self.set_i32("plink.midi_msg2", message_2 as i32)
+            }
+        }
+    }
+
+    fn set_macro_link(&self, linked: bool) -> ReaperResult<()> {
+        let current = self.get_macro_link();
+        if linked == current.is_some() {
+            return Ok(());
+        }
+        self.require("container_map")?;
+        let fx = self.param.fx();
+        let container = fx.parent_container().ok_or(ReaperError::new(
+            "only FX inside a container can be macro-linked",
+        ))?;
+        let key = match current {
+            // Reading this value creates the mapping
+            None => format!(
+                "container_map.add.{}.{}",
+                fx.query_index().to_raw(),
+                self.param.index()
+            ),
+            // Reading this value removes the mapping
+            Some(macro_index) => format!("param.{macro_index}.container_map.delete"),
+        };
+        container.get_named_config_param_as_string(key, 64)?;
+        Ok(())
+    }
+}
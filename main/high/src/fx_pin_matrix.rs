@@ -0,0 +1,149 @@
+use crate::ReaperError;
+use reaper_medium::{FxPinDirection, FxPinMask};
+
+/// Snapshot of the pin connections of an FX.
+///
+/// Obtain it via [`Fx::pin_matrix()`], change it and write it back via
+/// [`Fx::set_pin_matrix()`]. Pin and channel indexes are zero-based.
+///
+/// [`Fx::pin_matrix()`]: crate::Fx::pin_matrix
+/// [`Fx::set_pin_matrix()`]: crate::Fx::set_pin_matrix
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct FxPinMatrix {
+    inputs: Vec<FxPinMask>,
+    outputs: Vec<FxPinMask>,
+}
+
+impl FxPinMatrix {
+    pub fn new(inputs: Vec<FxPinMask>, outputs: Vec<FxPinMask>) -> FxPinMatrix {
+        FxPinMatrix { inputs, outputs }
+    }
+
+    pub fn pin_count(&self, direction: FxPinDirection) -> u32 {
+        self.pins(direction).len() as u32
+    }
+
+    pub fn pins(&self, direction: FxPinDirection) -> &[FxPinMask] {
+        match direction {
+            FxPinDirection::Input => &self.inputs,
+            FxPinDirection::Output => &self.outputs,
+        }
+    }
+
+    pub fn pin(&self, direction: FxPinDirection, pin_index: u32) -> Option<FxPinMask> {
+        self.pins(direction).get(pin_index as usize).copied()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the pin doesn't exist.
+    pub fn set_pin(&mut self, direction: FxPinDirection, pin_index: u32, mask: FxPinMask) {
+        self.pins_mut(direction)[pin_index as usize] = mask;
+    }
+
+    /// Connects the given pin to the given track channel, keeping existing connections.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pin doesn't exist or the channel is greater than 63.
+    pub fn connect(&mut self, direction: FxPinDirection, pin_index: u32, channel: u32) {
+        let pin = &mut self.pins_mut(direction)[pin_index as usize];
+        *pin = pin.with_channel(channel, true);
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the pin doesn't exist or the channel is greater than 63.
+    pub fn disconnect(&mut self, direction: FxPinDirection, pin_index: u32, channel: u32) {
+        let pin = &mut self.pins_mut(direction)[pin_index as usize];
+        *pin = pin.with_channel(channel, false);
+    }
+
+    /// Disconnects all pins of the given direction.
+    pub fn clear(&mut self, direction: FxPinDirection) {
+        for pin in self.pins_mut(direction) {
+            *pin = FxPinMask::EMPTY;
+        }
+    }
+
+    /// Connects `count` consecutive pins starting at `first_pin` exclusively to consecutive track
+    /// channels starting at `first_channel`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX doesn't have enough pins.
+    pub fn route(
+        &mut self,
+        direction: FxPinDirection,
+        first_pin: u32,
+        first_channel: u32,
+        count: u32,
+    ) -> Result<(), ReaperError> {
+        if first_pin + count > self.pin_count(direction) {
+            return Err(ReaperError::new("FX doesn't have enough pins"));
+        }
+        if first_channel + count > 64 {
+            return Err(ReaperError::new(
+                "pins can only be connected to 64 channels",
+            ));
+        }
+        for i in 0..count {
+            self.set_pin(
+                direction,
+                first_pin + i,
+                FxPinMask::channel(first_channel + i),
+            );
+        }
+        Ok(())
+    }
+
+    /// Routes input pins 3/4 (the usual sidechain input of stereo plug-ins) exclusively to the
+    /// track channels starting at `first_channel`.
+    ///
+    /// Make sure the track has enough channels, e.g. via [`Track::set_channel_count()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX has less than 4 input pins.
+    ///
+    /// [`Track::set_channel_count()`]: crate::Track::set_channel_count
+    pub fn route_sidechain_input(&mut self, first_channel: u32) -> Result<(), ReaperError> {
+        self.route(FxPinDirection::Input, 2, first_channel, 2)
+    }
+
+    fn pins_mut(&mut self, direction: FxPinDirection) -> &mut Vec<FxPinMask> {
+        match direction {
+            FxPinDirection::Input => &mut self.inputs,
+            FxPinDirection::Output => &mut self.outputs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn route_sidechain_input() {
+        // Given
+        let mut matrix = FxPinMatrix::new(
+            (0..4).map(FxPinMask::channel).collect(),
+            (0..2).map(FxPinMask::channel).collect(),
+        );
+        // When
+        matrix.route_sidechain_input(4).unwrap();
+        // Then
+        assert_eq!(
+            matrix.pins(FxPinDirection::Input),
+            &[
+                FxPinMask::channel(0),
+                FxPinMask::channel(1),
+                FxPinMask::channel(4),
+                FxPinMask::channel(5)
+            ]
+        );
+        assert!(FxPinMatrix::new(vec![FxPinMask::EMPTY; 2], vec![])
+            .route_sidechain_input(2)
+            .is_err());
+    }
+}
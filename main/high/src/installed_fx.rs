@@ -0,0 +1,163 @@
+use reaper_medium::EnumInstalledFxResult;
+
+/// Plug-in format of an installed FX.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum InstalledFxKind {
+    Vst2,
+    Vst3,
+    Clap,
+    Js,
+    Au,
+    Lv2,
+    Dx,
+    /// Format not known to this library.
+    Unknown,
+}
+
+/// Descriptor of a plug-in which is installed on this system.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct InstalledFx {
+    full_name: String,
+    ident: String,
+    kind: InstalledFxKind,
+    is_instrument: bool,
+    name: String,
+    vendor: Option<String>,
+}
+
+impl InstalledFx {
+    pub(crate) fn from_medium(res: EnumInstalledFxResult) -> InstalledFx {
+        InstalledFx::parse(res.name.to_str(), res.ident.to_str())
+    }
+
+    fn parse(full_name: &str, ident: &str) -> InstalledFx {
+        let (prefix, rest) = full_name.split_once(": ").unwrap_or(("", full_name));
+        let (kind_prefix, is_instrument) = match prefix.strip_suffix('i') {
+            Some(p) => (p, true),
+            None => (prefix, false),
+        };
+        let kind = match kind_prefix {
+            "VST" => InstalledFxKind::Vst2,
+            "VST3" => InstalledFxKind::Vst3,
+            "CLAP" => InstalledFxKind::Clap,
+            "JS" => InstalledFxKind::Js,
+            "AU" => InstalledFxKind::Au,
+            "LV2" => InstalledFxKind::Lv2,
+            "DX" => InstalledFxKind::Dx,
+            _ => InstalledFxKind::Unknown,
+        };
+        // JS effects don't have vendors, their trailing parentheses are part of the description.
+        let (name, vendor) = match rest.strip_suffix(')').and_then(|r| r.rsplit_once(" (")) {
+            Some((name, vendor)) if kind != InstalledFxKind::Js => {
+                (name.to_string(), Some(vendor.to_string()))
+            }
+            _ => (rest.to_string(), None),
+        };
+        InstalledFx {
+            full_name: full_name.to_string(),
+            ident: ident.to_string(),
+            kind,
+            is_instrument,
+            name,
+            vendor,
+        }
+    }
+
+    /// Returns the name including format prefix and vendor, e.g. "VST: ReaEQ (Cockos)".
+    ///
+    /// This is the name to be used with [`FxChain::add_fx_by_original_name()`] or
+    /// `track_fx_add_by_name_add()`.
+    ///
+    /// [`FxChain::add_fx_by_original_name()`]: crate::FxChain::add_fx_by_original_name
+    pub fn full_name(&self) -> &str {
+        &self.full_name
+    }
+
+    /// Returns REAPER's identifier of the plug-in, e.g. the file name of a VST2 plug-in.
+    pub fn ident(&self) -> &str {
+        &self.ident
+    }
+
+    pub fn kind(&self) -> InstalledFxKind {
+        self.kind
+    }
+
+    pub fn is_instrument(&self) -> bool {
+        self.is_instrument
+    }
+
+    /// Returns the display name without format prefix and vendor, e.g. "ReaEQ".
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn vendor(&self) -> Option<&str> {
+        self.vendor.as_deref()
+    }
+
+    /// Returns how well this FX matches the given search query, or `None` if it doesn't match.
+    ///
+    /// Each whitespace-separated word of the query must appear in the full name with its
+    /// characters in order (case-insensitive). Higher scores mean better matches, e.g.
+    /// consecutive characters and matches at word starts score higher.
+    pub fn fuzzy_match_score(&self, query: &str) -> Option<u32> {
+        let haystack: Vec<char> = self.full_name.to_lowercase().chars().collect();
+        query
+            .to_lowercase()
+            .split_whitespace()
+            .try_fold(0, |sum, word| {
+                Some(sum + fuzzy_word_score(&haystack, word)?)
+            })
+    }
+}
+
+fn fuzzy_word_score(haystack: &[char], word: &str) -> Option<u32> {
+    let mut score = 0;
+    let mut pos = 0;
+    let mut previous_match: Option<usize> = None;
+    for c in word.chars() {
+        let i = pos + haystack[pos..].iter().position(|h| *h == c)?;
+        score += 1;
+        if previous_match == Some(i.wrapping_sub(1)) {
+            score += 5;
+        }
+        if i == 0 || !haystack[i - 1].is_alphanumeric() {
+            score += 3;
+        }
+        previous_match = Some(i);
+        pos = i + 1;
+    }
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_names() {
+        let fx = InstalledFx::parse("VSTi: ReaSynth (x64) (Cockos)", "reasynth.dll");
+        assert_eq!(fx.kind(), InstalledFxKind::Vst2);
+        assert!(fx.is_instrument());
+        assert_eq!(fx.name(), "ReaSynth (x64)");
+        assert_eq!(fx.vendor(), Some("Cockos"));
+        let fx = InstalledFx::parse("JS: Delay (stereo)", "delay");
+        assert_eq!(fx.kind(), InstalledFxKind::Js);
+        assert!(!fx.is_instrument());
+        assert_eq!(fx.name(), "Delay (stereo)");
+        assert_eq!(fx.vendor(), None);
+        let fx = InstalledFx::parse("Something", "x");
+        assert_eq!(fx.kind(), InstalledFxKind::Unknown);
+        assert_eq!(fx.name(), "Something");
+    }
+
+    #[test]
+    fn fuzzy_match() {
+        let eq = InstalledFx::parse("VST: ReaEQ (Cockos)", "reaeq.dll");
+        let comp = InstalledFx::parse("VST: ReaComp (Cockos)", "reacomp.dll");
+        assert!(eq.fuzzy_match_score("rea eq").is_some());
+        assert!(eq.fuzzy_match_score("comp").is_none());
+        assert!(eq.fuzzy_match_score("reaeq") > comp.fuzzy_match_score("reaeq"));
+        assert!(comp.fuzzy_match_score("rcmp").is_some());
+    }
+}
@@ -1,7 +1,10 @@
-use crate::{Project, Reaper, Take};
+use crate::{Chunk, Project, Reaper, Take, Track, Volume};
 use reaper_medium::{
-    DurationInSeconds, MediaItem, PositionInSeconds, ReaperFunctionError, UiRefreshBehavior,
+    ChunkCacheHint, DurationInSeconds, FadeShape, ItemAttributeKey, MediaItem, NativeColor,
+    PositionInSeconds, ReaperFunctionError, ReaperString, ReaperStringArg, ReaperVolumeValue,
+    RgbColor, UiRefreshBehavior,
 };
+use std::convert::TryInto;
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Item {
@@ -40,6 +43,31 @@ impl Item {
         Ok(Take::new(raw_take))
     }
 
+    pub fn chunk(
+        self,
+        max_chunk_size: u32,
+        undo_is_optional: ChunkCacheHint,
+    ) -> Result<Chunk, &'static str> {
+        let chunk_content = unsafe {
+            Reaper::get()
+                .medium_reaper
+                .get_item_state_chunk(self.raw, max_chunk_size, undo_is_optional)
+                .map_err(|_| "Couldn't load item chunk")?
+        };
+        Ok(chunk_content.into())
+    }
+
+    pub fn set_chunk(&self, chunk: Chunk) -> Result<(), &'static str> {
+        let string: String = chunk.try_into().map_err(|_| "chunk is still referenced")?;
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .set_item_state_chunk(self.raw, string, ChunkCacheHint::UndoMode)
+                .map_err(|_| "Couldn't set item chunk")?
+        };
+        Ok(())
+    }
+
     pub fn set_position(
         &self,
         pos: PositionInSeconds,
@@ -71,4 +99,186 @@ impl Item {
                 .set_media_item_selected(self.raw, selected);
         }
     }
+
+    pub fn track(self) -> Option<Track> {
+        let raw_track = unsafe {
+            Reaper::get()
+                .medium_reaper
+                .get_set_media_item_info_get_track(self.raw)?
+        };
+        Some(Track::new(raw_track, None))
+    }
+
+    pub fn position(self) -> PositionInSeconds {
+        PositionInSeconds::new(self.get_value(ItemAttributeKey::Position))
+    }
+
+    pub fn length(self) -> DurationInSeconds {
+        DurationInSeconds::new(self.get_value(ItemAttributeKey::Length))
+    }
+
+    pub fn is_selected(self) -> bool {
+        self.get_value(ItemAttributeKey::UiSel) != 0.0
+    }
+
+    pub fn is_muted(self) -> bool {
+        self.get_value(ItemAttributeKey::Mute) != 0.0
+    }
+
+    pub fn set_muted(&self, muted: bool) -> Result<(), ReaperFunctionError> {
+        self.set_value(ItemAttributeKey::Mute, bool_value(muted))
+    }
+
+    pub fn loops_source(self) -> bool {
+        self.get_value(ItemAttributeKey::LoopSrc) != 0.0
+    }
+
+    pub fn set_loops_source(&self, loop_source: bool) -> Result<(), ReaperFunctionError> {
+        self.set_value(ItemAttributeKey::LoopSrc, bool_value(loop_source))
+    }
+
+    pub fn is_locked(self) -> bool {
+        (self.get_value(ItemAttributeKey::Lock) as i32) & 1 != 0
+    }
+
+    pub fn set_locked(&self, locked: bool) -> Result<(), ReaperFunctionError> {
+        // Other bits of C_LOCK might be used by REAPER, so we only touch the lock bit.
+        let old_value = self.get_value(ItemAttributeKey::Lock) as i32;
+        let new_value = if locked {
+            old_value | 1
+        } else {
+            old_value & !1
+        };
+        self.set_value(ItemAttributeKey::Lock, new_value as f64)
+    }
+
+    pub fn volume(self) -> Volume {
+        let reaper_value = ReaperVolumeValue::new(self.get_value(ItemAttributeKey::Vol));
+        Volume::from_reaper_value(reaper_value)
+    }
+
+    pub fn set_volume(&self, volume: Volume) -> Result<(), ReaperFunctionError> {
+        self.set_value(ItemAttributeKey::Vol, volume.reaper_value().get())
+    }
+
+    pub fn snap_offset(self) -> DurationInSeconds {
+        DurationInSeconds::new(self.get_value(ItemAttributeKey::SnapOffset))
+    }
+
+    pub fn set_snap_offset(&self, offset: DurationInSeconds) -> Result<(), ReaperFunctionError> {
+        self.set_value(ItemAttributeKey::SnapOffset, offset.get())
+    }
+
+    pub fn fade_in_length(self) -> DurationInSeconds {
+        DurationInSeconds::new(self.get_value(ItemAttributeKey::FadeInLen))
+    }
+
+    pub fn set_fade_in_length(&self, length: DurationInSeconds) -> Result<(), ReaperFunctionError> {
+        self.set_value(ItemAttributeKey::FadeInLen, length.get())
+    }
+
+    pub fn fade_out_length(self) -> DurationInSeconds {
+        DurationInSeconds::new(self.get_value(ItemAttributeKey::FadeOutLen))
+    }
+
+    pub fn set_fade_out_length(
+        &self,
+        length: DurationInSeconds,
+    ) -> Result<(), ReaperFunctionError> {
+        self.set_value(ItemAttributeKey::FadeOutLen, length.get())
+    }
+
+    pub fn fade_in_shape(self) -> FadeShape {
+        FadeShape::from_raw(self.get_value(ItemAttributeKey::FadeInShape) as i32)
+    }
+
+    pub fn set_fade_in_shape(&self, shape: FadeShape) -> Result<(), ReaperFunctionError> {
+        self.set_value(ItemAttributeKey::FadeInShape, shape.to_raw() as f64)
+    }
+
+    pub fn fade_out_shape(self) -> FadeShape {
+        FadeShape::from_raw(self.get_value(ItemAttributeKey::FadeOutShape) as i32)
+    }
+
+    pub fn set_fade_out_shape(&self, shape: FadeShape) -> Result<(), ReaperFunctionError> {
+        self.set_value(ItemAttributeKey::FadeOutShape, shape.to_raw() as f64)
+    }
+
+    /// Returns the group ID or `None` if the item is not grouped.
+    pub fn group_id(self) -> Option<u32> {
+        let id = self.get_value(ItemAttributeKey::GroupId) as i32;
+        if id > 0 {
+            Some(id as u32)
+        } else {
+            None
+        }
+    }
+
+    pub fn set_group_id(&self, id: Option<u32>) -> Result<(), ReaperFunctionError> {
+        self.set_value(ItemAttributeKey::GroupId, id.unwrap_or(0) as f64)
+    }
+
+    pub fn custom_color(self) -> Option<RgbColor> {
+        let value = self.get_value(ItemAttributeKey::CustomColor) as i32;
+        if value & CUSTOM_COLOR_FLAG == 0 {
+            return None;
+        }
+        let native_color = NativeColor::new((value & !CUSTOM_COLOR_FLAG) as u32);
+        Some(Reaper::get().medium_reaper.color_from_native(native_color))
+    }
+
+    /// Sets the custom color or resets it to the default color if `None`.
+    pub fn set_custom_color(&self, color: Option<RgbColor>) -> Result<(), ReaperFunctionError> {
+        let value = match color {
+            None => 0,
+            Some(c) => Reaper::get().medium_reaper.color_to_native(c).to_raw() | CUSTOM_COLOR_FLAG,
+        };
+        self.set_value(ItemAttributeKey::CustomColor, value as f64)
+    }
+
+    pub fn notes(self) -> ReaperString {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .get_set_media_item_info_string_get(self.raw, ItemAttributeKey::Notes, 10_000)
+                .unwrap_or_default()
+        }
+    }
+
+    pub fn set_notes<'a>(
+        &self,
+        notes: impl Into<ReaperStringArg<'a>>,
+    ) -> Result<(), ReaperFunctionError> {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .get_set_media_item_info_string_set(self.raw, ItemAttributeKey::Notes, notes)
+        }
+    }
+
+    fn get_value(self, key: ItemAttributeKey) -> f64 {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .get_media_item_info_value(self.raw, key)
+        }
+    }
+
+    fn set_value(&self, key: ItemAttributeKey, value: f64) -> Result<(), ReaperFunctionError> {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .set_media_item_info_value(self.raw, key, value)
+        }
+    }
+}
+
+const CUSTOM_COLOR_FLAG: i32 = 0x1000000;
+
+fn bool_value(value: bool) -> f64 {
+    if value {
+        1.0
+    } else {
+        0.0
+    }
 }
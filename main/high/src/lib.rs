@@ -52,6 +52,9 @@ pub use track::*;
 mod take;
 pub use take::*;
 
+mod envelope;
+pub use envelope::*;
+
 mod track_route;
 pub use track_route::*;
 
@@ -61,6 +64,9 @@ pub use fx::*;
 mod fx_parameter;
 pub use fx_parameter::*;
 
+mod fx_parameter_modulation;
+pub use fx_parameter_modulation::*;
+
 mod section;
 pub use section::*;
 
@@ -73,6 +79,18 @@ pub use guid::*;
 mod fx_chain;
 pub use fx_chain::*;
 
+mod fx_pin_matrix;
+pub use fx_pin_matrix::*;
+
+mod installed_fx;
+pub use installed_fx::*;
+
+mod template;
+pub use template::*;
+
+mod mixer_snapshot;
+pub use mixer_snapshot::*;
+
 mod midi_input_device;
 pub use midi_input_device::*;
 
@@ -94,6 +112,9 @@ pub use width::*;
 mod tempo;
 pub use tempo::*;
 
+mod tempo_map;
+pub use tempo_map::*;
+
 mod chunk;
 pub use chunk::*;
 
@@ -103,6 +124,9 @@ pub use item::*;
 mod source;
 pub use source::*;
 
+mod audio_blocks;
+pub use audio_blocks::*;
+
 mod action_character;
 pub use action_character::*;
 
@@ -124,6 +148,17 @@ mod option_util;
 mod bookmark;
 pub use bookmark::*;
 
+mod ext_state;
+pub use ext_state::*;
+
+#[cfg(feature = "serde")]
+mod project_config;
+#[cfg(feature = "serde")]
+pub use project_config::*;
+
+mod render;
+pub use render::*;
+
 mod accelerator;
 pub use accelerator::*;
 
@@ -0,0 +1,497 @@
+use crate::{Fx, Guid, Pan, Project, ReaperResult, Track, TrackRoutePartner, Volume, Width};
+use reaper_medium::{
+    EditMode, GangBehavior, ReaperNormalizedFxParamValue, ReaperPanValue, ReaperVolumeValue,
+    ReaperWidthValue,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::iter;
+
+/// Mixer state of all tracks of a project (including the master track), keyed by track GUID.
+///
+/// Capture it via [`MixerSnapshot::capture()`] and restore it via [`MixerSnapshot::recall()`].
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MixerSnapshot {
+    pub tracks: HashMap<Guid, TrackMixerState>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrackMixerState {
+    pub volume: ReaperVolumeValue,
+    pub pan: ReaperPanValue,
+    pub width: ReaperWidthValue,
+    pub muted: bool,
+    pub solo: bool,
+    /// Sends in REAPER's order (hardware outputs first).
+    pub sends: Vec<SendMixerState>,
+    /// State of the FX in the normal FX chain, keyed by FX GUID.
+    pub fxs: HashMap<Guid, FxMixerState>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SendMixerState {
+    /// GUID of the destination track or `None` if this is a hardware output.
+    pub target_track: Option<Guid>,
+    pub volume: ReaperVolumeValue,
+    pub pan: ReaperPanValue,
+    pub muted: bool,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FxMixerState {
+    pub enabled: bool,
+    pub parameter_values: Vec<ReaperNormalizedFxParamValue>,
+}
+
+/// Determines which parts of a [`MixerSnapshot`] are recalled.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct MixerRecallOptions {
+    /// Recalls only the send states, leaving track and FX states untouched.
+    pub only_sends: bool,
+    /// Recalls only the states of tracks which are currently selected.
+    pub only_selected_tracks: bool,
+}
+
+/// A single difference between two mixer snapshots.
+#[derive(Clone, PartialEq, Debug)]
+pub enum MixerChange {
+    TrackAdded {
+        track: Guid,
+    },
+    TrackRemoved {
+        track: Guid,
+    },
+    Volume {
+        track: Guid,
+        old: ReaperVolumeValue,
+        new: ReaperVolumeValue,
+    },
+    Pan {
+        track: Guid,
+        old: ReaperPanValue,
+        new: ReaperPanValue,
+    },
+    Width {
+        track: Guid,
+        old: ReaperWidthValue,
+        new: ReaperWidthValue,
+    },
+    Mute {
+        track: Guid,
+        new: bool,
+    },
+    Solo {
+        track: Guid,
+        new: bool,
+    },
+    /// The number of sends or their destinations differ, so the sends can't be compared.
+    SendsRouted {
+        track: Guid,
+    },
+    SendVolume {
+        track: Guid,
+        send_index: u32,
+        old: ReaperVolumeValue,
+        new: ReaperVolumeValue,
+    },
+    SendPan {
+        track: Guid,
+        send_index: u32,
+        old: ReaperPanValue,
+        new: ReaperPanValue,
+    },
+    SendMute {
+        track: Guid,
+        send_index: u32,
+        new: bool,
+    },
+    FxAdded {
+        track: Guid,
+        fx: Guid,
+    },
+    FxRemoved {
+        track: Guid,
+        fx: Guid,
+    },
+    FxEnabled {
+        track: Guid,
+        fx: Guid,
+        new: bool,
+    },
+    FxParameterValue {
+        track: Guid,
+        fx: Guid,
+        parameter_index: u32,
+        old: ReaperNormalizedFxParamValue,
+        new: ReaperNormalizedFxParamValue,
+    },
+}
+
+impl MixerSnapshot {
+    /// Captures the current mixer state of the given project.
+    pub fn capture(project: Project) -> ReaperResult<MixerSnapshot> {
+        let tracks = iter::once(project.master_track()?)
+            .chain(project.tracks())
+            .map(|track| (*track.guid(), TrackMixerState::capture(&track)))
+            .collect();
+        Ok(MixerSnapshot { tracks })
+    }
+
+    /// Returns what changed from this snapshot to the given newer one.
+    pub fn diff(&self, newer: &MixerSnapshot) -> Vec<MixerChange> {
+        let mut changes = vec![];
+        for (guid, old) in &self.tracks {
+            match newer.tracks.get(guid) {
+                None => changes.push(MixerChange::TrackRemoved { track: *guid }),
+                Some(new) => old.diff(*guid, new, &mut changes),
+            }
+        }
+        for guid in newer.tracks.keys() {
+            if !self.tracks.contains_key(guid) {
+                changes.push(MixerChange::TrackAdded { track: *guid });
+            }
+        }
+        changes
+    }
+
+    /// Restores the captured state in the given project within one undo point.
+    ///
+    /// Tracks, sends and FX which don't exist anymore are skipped. Sends are matched by index and
+    /// only recalled if they still point to the same destination.
+    pub fn recall(&self, project: Project, options: MixerRecallOptions) -> ReaperResult<()> {
+        let master_track = project.master_track()?;
+        project.undoable("Recall mixer snapshot", || {
+            for track in iter::once(master_track).chain(project.tracks()) {
+                if options.only_selected_tracks && !track.is_selected() {
+                    continue;
+                }
+                if let Some(state) = self.tracks.get(track.guid()) {
+                    state.recall(&track, options);
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+impl TrackMixerState {
+    fn capture(track: &Track) -> TrackMixerState {
+        TrackMixerState {
+            volume: track.volume().reaper_value(),
+            pan: track.pan().reaper_value(),
+            width: track.width().reaper_value(),
+            muted: track.is_muted(),
+            solo: track.is_solo(),
+            // Stops at the first unreadable send so that indexes stay aligned
+            sends: track
+                .sends()
+                .map_while(|send| {
+                    let state = SendMixerState {
+                        target_track: match send.partner()? {
+                            TrackRoutePartner::Track(t) => Some(*t.guid()),
+                            TrackRoutePartner::HardwareOutput(_) => None,
+                        },
+                        volume: send.volume().ok()?.reaper_value(),
+                        pan: send.pan().ok()?.reaper_value(),
+                        muted: send.is_muted(),
+                    };
+                    Some(state)
+                })
+                .collect(),
+            fxs: track
+                .normal_fx_chain()
+                .fxs()
+                .filter_map(|fx| Some((fx.guid()?, FxMixerState::capture(&fx))))
+                .collect(),
+        }
+    }
+
+    fn recall(&self, track: &Track, options: MixerRecallOptions) {
+        if !options.only_sends {
+            // Setting unchanged values would needlessly degrade precision and notify surfaces
+            if track.volume().reaper_value() != self.volume {
+                track.set_volume(
+                    Volume::from_reaper_value(self.volume),
+                    GangBehavior::DenyGang,
+                );
+            }
+            if track.pan().reaper_value() != self.pan {
+                track.set_pan(Pan::from_reaper_value(self.pan), GangBehavior::DenyGang);
+            }
+            if track.width().reaper_value() != self.width {
+                track.set_width(Width::from_reaper_value(self.width), GangBehavior::DenyGang);
+            }
+            if self.muted != track.is_muted() {
+                if self.muted {
+                    track.mute(GangBehavior::DenyGang);
+                } else {
+                    track.unmute(GangBehavior::DenyGang);
+                }
+            }
+            if self.solo != track.is_solo() {
+                if self.solo {
+                    track.solo(GangBehavior::DenyGang);
+                } else {
+                    track.unsolo(GangBehavior::DenyGang);
+                }
+            }
+            for fx in track.normal_fx_chain().fxs() {
+                let state = fx.guid().and_then(|guid| self.fxs.get(&guid));
+                if let Some(state) = state {
+                    state.recall(&fx);
+                }
+            }
+        }
+        for (send, state) in track.sends().zip(&self.sends) {
+            let target_track = match send.partner() {
+                Some(TrackRoutePartner::Track(t)) => Some(*t.guid()),
+                _ => None,
+            };
+            if target_track != state.target_track {
+                continue;
+            }
+            if send.volume().map(|v| v.reaper_value()) != Ok(state.volume) {
+                let _ = send.set_volume(
+                    Volume::from_reaper_value(state.volume),
+                    EditMode::NormalTweak,
+                );
+            }
+            if send.pan().map(|p| p.reaper_value()) != Ok(state.pan) {
+                let _ = send.set_pan(Pan::from_reaper_value(state.pan), EditMode::NormalTweak);
+            }
+            if state.muted != send.is_muted() {
+                if state.muted {
+                    send.mute();
+                } else {
+                    send.unmute();
+                }
+            }
+        }
+    }
+
+    fn diff(&self, track: Guid, newer: &TrackMixerState, changes: &mut Vec<MixerChange>) {
+        if self.volume != newer.volume {
+            changes.push(MixerChange::Volume {
+                track,
+                old: self.volume,
+                new: newer.volume,
+            });
+        }
+        if self.pan != newer.pan {
+            changes.push(MixerChange::Pan {
+                track,
+                old: self.pan,
+                new: newer.pan,
+            });
+        }
+        if self.width != newer.width {
+            changes.push(MixerChange::Width {
+                track,
+                old: self.width,
+                new: newer.width,
+            });
+        }
+        if self.muted != newer.muted {
+            changes.push(MixerChange::Mute {
+                track,
+                new: newer.muted,
+            });
+        }
+        if self.solo != newer.solo {
+            changes.push(MixerChange::Solo {
+                track,
+                new: newer.solo,
+            });
+        }
+        let same_routing = self.sends.len() == newer.sends.len()
+            && self
+                .sends
+                .iter()
+                .zip(&newer.sends)
+                .all(|(old, new)| old.target_track == new.target_track);
+        if same_routing {
+            for (i, (old, new)) in self.sends.iter().zip(&newer.sends).enumerate() {
+                old.diff(track, i as u32, new, changes);
+            }
+        } else {
+            changes.push(MixerChange::SendsRouted { track });
+        }
+        for (fx, old) in &self.fxs {
+            match newer.fxs.get(fx) {
+                None => changes.push(MixerChange::FxRemoved { track, fx: *fx }),
+                Some(new) => old.diff(track, *fx, new, changes),
+            }
+        }
+        for fx in newer.fxs.keys() {
+            if !self.fxs.contains_key(fx) {
+                changes.push(MixerChange::FxAdded { track, fx: *fx });
+            }
+        }
+    }
+}
+
+impl SendMixerState {
+    fn diff(
+        &self,
+        track: Guid,
+        send_index: u32,
+        newer: &SendMixerState,
+        changes: &mut Vec<MixerChange>,
+    ) {
+        if self.volume != newer.volume {
+            changes.push(MixerChange::SendVolume {
+                track,
+                send_index,
+                old: self.volume,
+                new: newer.volume,
+            });
+        }
+        if self.pan != newer.pan {
+            changes.push(MixerChange::SendPan {
+                track,
+                send_index,
+                old: self.pan,
+                new: newer.pan,
+            });
+        }
+        if self.muted != newer.muted {
+            changes.push(MixerChange::SendMute {
+                track,
+                send_index,
+                new: newer.muted,
+            });
+        }
+    }
+}
+
+impl FxMixerState {
+    fn capture(fx: &Fx) -> FxMixerState {
+        FxMixerState {
+            enabled: fx.is_enabled(),
+            parameter_values: fx
+                .parameters()
+                .map(|p| p.reaper_normalized_value())
+                .collect(),
+        }
+    }
+
+    fn recall(&self, fx: &Fx) {
+        if self.enabled != fx.is_enabled() {
+            if self.enabled {
+                fx.enable();
+            } else {
+                fx.disable();
+            }
+        }
+        for (param, value) in fx.parameters().zip(&self.parameter_values) {
+            // Setting unchanged values would needlessly trigger plug-in change notifications
+            if param.reaper_normalized_value() != *value {
+                let _ = param.set_reaper_normalized_value(*value);
+            }
+        }
+    }
+
+    fn diff(&self, track: Guid, fx: Guid, newer: &FxMixerState, changes: &mut Vec<MixerChange>) {
+        if self.enabled != newer.enabled {
+            changes.push(MixerChange::FxEnabled {
+                track,
+                fx,
+                new: newer.enabled,
+            });
+        }
+        let params = self.parameter_values.iter().zip(&newer.parameter_values);
+        for (i, (old, new)) in params.enumerate() {
+            if old != new {
+                changes.push(MixerChange::FxParameterValue {
+                    track,
+                    fx,
+                    parameter_index: i as u32,
+                    old: *old,
+                    new: *new,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reaper_low::raw::GUID;
+
+    fn guid(n: u32) -> Guid {
+        Guid::new(GUID {
+            Data1: n,
+            ..Default::default()
+        })
+    }
+
+    fn track_state() -> TrackMixerState {
+        TrackMixerState {
+            volume: ReaperVolumeValue::ZERO_DB,
+            pan: ReaperPanValue::CENTER,
+            width: ReaperWidthValue::MAX,
+            muted: false,
+            solo: false,
+            sends: vec![SendMixerState {
+                target_track: Some(guid(2)),
+                volume: ReaperVolumeValue::ZERO_DB,
+                pan: ReaperPanValue::CENTER,
+                muted: false,
+            }],
+            fxs: iter::once((
+                guid(10),
+                FxMixerState {
+                    enabled: true,
+                    parameter_values: vec![ReaperNormalizedFxParamValue::new(0.5); 3],
+                },
+            ))
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn diff() {
+        // Given
+        let old = MixerSnapshot {
+            tracks: iter::once((guid(1), track_state())).collect(),
+        };
+        let mut new = old.clone();
+        {
+            let state = new.tracks.get_mut(&guid(1)).unwrap();
+            state.pan = ReaperPanValue::LEFT;
+            state.sends[0].muted = true;
+            let fx_state = state.fxs.get_mut(&guid(10)).unwrap();
+            fx_state.parameter_values[2] = ReaperNormalizedFxParamValue::new(1.0);
+        }
+        new.tracks.insert(guid(3), track_state());
+        // When
+        let changes = old.diff(&new);
+        // Then
+        assert!(old.diff(&old).is_empty());
+        assert_eq!(changes.len(), 4);
+        assert!(changes.contains(&MixerChange::Pan {
+            track: guid(1),
+            old: ReaperPanValue::CENTER,
+            new: ReaperPanValue::LEFT
+        }));
+        assert!(changes.contains(&MixerChange::SendMute {
+            track: guid(1),
+            send_index: 0,
+            new: true
+        }));
+        assert!(changes.contains(&MixerChange::FxParameterValue {
+            track: guid(1),
+            fx: guid(10),
+            parameter_index: 2,
+            old: ReaperNormalizedFxParamValue::new(0.5),
+            new: ReaperNormalizedFxParamValue::new(1.0)
+        }));
+        assert!(changes.contains(&MixerChange::TrackAdded { track: guid(3) }));
+    }
+}
@@ -1,19 +1,25 @@
 use crate::guid::Guid;
+use crate::render::render_targets;
+use crate::template::regenerate_guids;
 use crate::{
-    BasicBookmarkInfo, BookmarkType, IndexBasedBookmark, Item, PlayRate, Reaper, ReaperResult,
-    Tempo, Track,
+    BasicBookmarkInfo, BookmarkType, Chunk, IndexBasedBookmark, Item, PlayRate,
+    ProjectExtStateStore, Reaper, ReaperResult, RenderSettings, Tempo, TempoMap, Track,
+    TrackTemplate, TrackTemplateOptions,
 };
 use std::fmt::Debug;
 use std::{iter, mem};
 
 use either::Either;
+use enumflags2::BitFlags;
 use reaper_medium::ProjectContext::{CurrentProject, Proj};
 use reaper_medium::{
-    AutoSeekBehavior, BookmarkId, BookmarkRef, CountProjectMarkersResult, DurationInSeconds,
-    GetLastMarkerAndCurRegionResult, GetLoopTimeRange2Result, MasterTrackBehavior, PanMode,
-    PlayState, PositionInSeconds, ProjectContext, ProjectRef, ReaProject, ReaperString,
-    ReaperStringArg, SetEditCurPosOptions, TimeMap2TimeToBeatsResult, TimeMode, TimeModeOverride,
-    TimeRangeType, TrackDefaultsBehavior, TrackLocation, UndoBehavior,
+    AutoSeekBehavior, BookmarkId, BookmarkKind, BookmarkRef, CommandId, CountProjectMarkersResult,
+    DurationInSeconds, GetLastMarkerAndCurRegionResult, GetLoopTimeRange2Result,
+    MasterTrackBehavior, NativeColor, PanMode, PlayState, PositionInSeconds, ProjectContext,
+    ProjectInfoStringKey, ProjectRef, ReaProject, ReaperString, ReaperStringArg,
+    ReorderTracksBehavior, SetEditCurPosOptions, TempoMarkerPosition, TimeMap2TimeToBeatsResult,
+    TimeMode, TimeModeOverride, TimeRangeType, TimeSignature, TrackDefaultsBehavior, TrackLocation,
+    UndoBehavior,
 };
 use std::path::{Path, PathBuf};
 
@@ -49,6 +55,105 @@ impl Project {
             .file_path
     }
 
+    /// Returns the file name of the project without path or an empty string if the project has
+    /// not been saved yet.
+    pub fn name(self) -> ReaperString {
+        Reaper::get()
+            .medium_reaper()
+            .get_project_name(self.context(), MAX_PATH_LENGTH)
+    }
+
+    /// Returns the title from the project settings (`PROJECT_TITLE`).
+    pub fn title(self) -> ReaperResult<ReaperString> {
+        let title = Reaper::get()
+            .medium_reaper()
+            .get_set_project_info_string_get(
+                self.context(),
+                ProjectInfoStringKey::ProjectTitle,
+                MAX_PATH_LENGTH,
+            )?;
+        Ok(title)
+    }
+
+    /// Sets the title in the project settings (`PROJECT_TITLE`).
+    pub fn set_title<'a>(self, title: impl Into<ReaperStringArg<'a>>) -> ReaperResult<()> {
+        Reaper::get()
+            .medium_reaper()
+            .get_set_project_info_string_set(
+                self.context(),
+                ProjectInfoStringKey::ProjectTitle,
+                title,
+            )?;
+        Ok(())
+    }
+
+    /// Saves the project to its file, showing the "Save as" dialog if it has never been saved.
+    pub fn save(self) -> ReaperResult<()> {
+        self.complain_if_not_available()?;
+        Reaper::get()
+            .medium_reaper()
+            .main_save_project(self.context(), false);
+        Ok(())
+    }
+
+    /// Saves the project to the given file without showing any dialog.
+    ///
+    /// The given file becomes the project file.
+    pub fn save_as(self, path: &Path) -> ReaperResult<()> {
+        self.complain_if_not_available()?;
+        Reaper::get()
+            .medium_reaper()
+            .main_save_project_ex(self.context(), path, BitFlags::empty());
+        Ok(())
+    }
+
+    /// Makes this project the current project (switches to its tab).
+    pub fn activate(self) -> ReaperResult<()> {
+        self.complain_if_not_available()?;
+        Reaper::get()
+            .medium_reaper()
+            .select_project_instance(self.rea_project);
+        Ok(())
+    }
+
+    /// Closes the project tab.
+    ///
+    /// REAPER might prompt the user to save unsaved changes.
+    pub fn close(self) -> ReaperResult<()> {
+        self.activate()?;
+        Reaper::get()
+            .main_section()
+            .action_by_command_id(CommandId::new(40860))
+            .invoke_as_trigger(None)?;
+        Ok(())
+    }
+
+    /// Reads the render settings of this project.
+    pub fn render_settings(self) -> ReaperResult<RenderSettings> {
+        RenderSettings::from_project(self)
+    }
+
+    /// Changes the render settings of this project (only the ones which are set).
+    pub fn set_render_settings(self, settings: &RenderSettings) -> ReaperResult<()> {
+        settings.apply_to(self)
+    }
+
+    /// Returns the files which would be written when rendering with the current render settings.
+    pub fn render_targets(self) -> ReaperResult<Vec<PathBuf>> {
+        render_targets(self)
+    }
+
+    /// Renders this project using its current render settings.
+    ///
+    /// Makes this project the current project first.
+    pub fn render(self) -> ReaperResult<()> {
+        self.activate()?;
+        Reaper::get()
+            .main_section()
+            .action_by_command_id(CommandId::new(42230))
+            .invoke_as_trigger(None)
+    }
+
     pub fn any_solo(self) -> bool {
         Reaper::get().medium_reaper().any_track_solo(self.context())
     }
@@ -184,6 +289,141 @@ impl Project {
         Either::Right(iter)
     }
 
+    /// Returns the top-level tracks of this project with their folder children.
+    pub fn track_tree(self) -> Vec<TrackTreeNode> {
+        fn attach(
+            node: TrackTreeNode,
+            open_folders: &mut [TrackTreeNode],
+            roots: &mut Vec<TrackTreeNode>,
+        ) {
+            match open_folders.last_mut() {
+                None => roots.push(node),
+                Some(parent) => parent.children.push(node),
+            }
+        }
+        let mut roots = vec![];
+        let mut open_folders: Vec<TrackTreeNode> = vec![];
+        for track in self.tracks() {
+            let depth_change = track.folder_depth_change();
+            let node = TrackTreeNode {
+                track,
+                children: vec![],
+            };
+            if depth_change > 0 {
+                open_folders.push(node);
+                continue;
+            }
+            attach(node, &mut open_folders, &mut roots);
+            for _ in 0..-depth_change {
+                match open_folders.pop() {
+                    None => break,
+                    Some(folder) => attach(folder, &mut open_folders, &mut roots),
+                }
+            }
+        }
+        // Folders which are not closed properly
+        while let Some(folder) = open_folders.pop() {
+            attach(folder, &mut open_folders, &mut roots);
+        }
+        roots
+    }
+
+    /// Moves the given tracks together with their descendants (so folders stay intact).
+    ///
+    /// Works only for the current project. The track selection is restored afterwards.
+    pub fn move_tracks(self, tracks: &[Track], target: &Track, mode: MoveMode) -> ReaperResult<()> {
+        if self != Reaper::get().current_project() {
+            return Err("tracks can only be moved within the current project".into());
+        }
+        let target_index = target
+            .index()
+            .ok_or("master track can't be a move target")?;
+        let mut tracks_to_move: Vec<Track> = vec![];
+        for track in tracks {
+            if track.is_master_track() {
+                return Err("master track can't be moved".into());
+            }
+            for t in iter::once(track.clone()).chain(track.descendants()) {
+                if !tracks_to_move.contains(&t) {
+                    tracks_to_move.push(t);
+                }
+            }
+        }
+        if tracks_to_move.contains(target) {
+            return Err("target track is part of the moved tracks".into());
+        }
+        let (before_track_index, behavior) = match mode {
+            MoveMode::AboveTarget => (target_index, ReorderTracksBehavior::Normal),
+            MoveMode::IntoTarget => {
+                // Inserting right behind the folder track makes the moved tracks its first children
+                let behavior = if target.folder_depth_change() > 0 {
+                    ReorderTracksBehavior::Normal
+                } else {
+                    ReorderTracksBehavior::MakePreviousFolder
+                };
+                (target_index + 1, behavior)
+            }
+        };
+        let previously_selected: Vec<_> = self
+            .selected_tracks(MasterTrackBehavior::IncludeMasterTrack)
+            .collect();
+        self.unselect_all_tracks();
+        for t in &tracks_to_move {
+            t.select();
+        }
+        let result = Reaper::get()
+            .medium_reaper()
+            .reorder_selected_tracks(before_track_index, behavior);
+        self.unselect_all_tracks();
+        for t in &previously_selected {
+            t.select();
+        }
+        result?;
+        Ok(())
+    }
+
+    /// Saves the selected tracks as track template file (`.RTrackTemplate`).
+    pub fn export_selected_tracks_to_template_file(
+        self,
+        path: &Path,
+        options: TrackTemplateOptions,
+    ) -> ReaperResult<()> {
+        let tracks: Vec<_> = self
+            .selected_tracks(MasterTrackBehavior::ExcludeMasterTrack)
+            .collect();
+        if tracks.is_empty() {
+            return Err("no tracks selected".into());
+        }
+        TrackTemplate::from_tracks(&tracks, options)?.save(path)
+    }
+
+    /// Inserts the tracks of the given template starting at the given index.
+    ///
+    /// The GUIDs of tracks, FX, items and envelopes are regenerated, so the same template can be
+    /// inserted multiple times.
+    pub fn insert_track_template(
+        self,
+        template: &TrackTemplate,
+        index: u32,
+    ) -> ReaperResult<Vec<Track>> {
+        template
+            .track_chunks()
+            .enumerate()
+            .map(|(i, track_chunk)| {
+                let track = self.insert_track_at(index + i as u32)?;
+                track.set_chunk(Chunk::new(regenerate_guids(track_chunk)))?;
+                // The chunk changed the track GUID
+                Ok(Track::new(track.raw(), Some(self.rea_project)))
+            })
+            .collect()
+    }
+
+    /// Inserts the tracks of the given track template file (`.RTrackTemplate`) starting at the
+    /// given index.
+    pub fn insert_track_template_file(self, path: &Path, index: u32) -> ReaperResult<Vec<Track>> {
+        self.insert_track_template(&TrackTemplate::load(path)?, index)
+    }
+
     pub fn context(self) -> ProjectContext {
         Proj(self.rea_project)
     }
@@ -308,6 +548,72 @@ impl Project {
         Tempo::from_bpm(bpm)
     }
 
+    /// Returns the extension state of the given section which is saved with this project.
+    pub fn ext_state(self, section: impl Into<String>) -> ProjectExtStateStore {
+        ProjectExtStateStore::new(self, section)
+    }
+
+    /// Takes a snapshot of the tempo/time signature markers.
+    pub fn tempo_map(self) -> TempoMap {
+        let reaper = Reaper::get().medium_reaper();
+        let measure_info = reaper.time_map_get_measure_info(self.context(), 0);
+        let markers = (0..reaper.count_tempo_time_sig_markers(self.context()))
+            .filter_map(|i| reaper.get_tempo_time_sig_marker(self.context(), i).ok())
+            .collect();
+        TempoMap::new(measure_info.tempo, measure_info.time_signature, markers)
+    }
+
+    /// Adds a tempo/time signature marker.
+    ///
+    /// If `time_signature` is `None`, the marker doesn't change the time signature.
+    pub fn add_tempo_marker(
+        self,
+        position: PositionInSeconds,
+        tempo: Tempo,
+        time_signature: Option<TimeSignature>,
+        linear_tempo: bool,
+    ) -> ReaperResult<()> {
+        let reaper = Reaper::get().medium_reaper();
+        reaper.add_tempo_time_sig_marker(
+            self.context(),
+            position,
+            tempo.bpm(),
+            time_signature,
+            linear_tempo,
+        )?;
+        reaper.update_timeline();
+        Ok(())
+    }
+
+    /// Changes the tempo/time signature marker at the given index.
+    pub fn set_tempo_marker(
+        self,
+        index: u32,
+        position: PositionInSeconds,
+        tempo: Tempo,
+        time_signature: Option<TimeSignature>,
+        linear_tempo: bool,
+    ) -> ReaperResult<()> {
+        let reaper = Reaper::get().medium_reaper();
+        reaper.set_tempo_time_sig_marker(
+            self.context(),
+            index,
+            TempoMarkerPosition::Time(position),
+            tempo.bpm(),
+            time_signature,
+            linear_tempo,
+        )?;
+        reaper.update_timeline();
+        Ok(())
+    }
+
+    pub fn delete_tempo_marker(self, index: u32) -> ReaperResult<()> {
+        let reaper = Reaper::get().medium_reaper();
+        reaper.delete_tempo_time_sig_marker(self.context(), index)?;
+        reaper.update_timeline();
+        Ok(())
+    }
+
     pub fn play_rate(self) -> PlayRate {
         let factor = Reaper::get()
             .medium_reaper()
@@ -493,6 +799,54 @@ impl Project {
         (0..self.bookmark_count().total_count).map(move |i| IndexBasedBookmark::new(self, i))
     }
 
+    /// Adds a marker and returns its ID.
+    pub fn add_marker<'a>(
+        self,
+        position: PositionInSeconds,
+        name: impl Into<ReaperStringArg<'a>>,
+        color: Option<NativeColor>,
+    ) -> ReaperResult<BookmarkId> {
+        let id = Reaper::get().medium_reaper().add_project_marker_2(
+            self.context(),
+            position,
+            None,
+            name,
+            None,
+            color,
+        )?;
+        Ok(id)
+    }
+
+    /// Adds a region and returns its ID.
+    pub fn add_region<'a>(
+        self,
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+        name: impl Into<ReaperStringArg<'a>>,
+        color: Option<NativeColor>,
+    ) -> ReaperResult<BookmarkId> {
+        let id = Reaper::get().medium_reaper().add_project_marker_2(
+            self.context(),
+            start,
+            Some(end),
+            name,
+            None,
+            color,
+        )?;
+        Ok(id)
+    }
+
+    pub fn delete_bookmark(self, bookmark_type: BookmarkType, id: BookmarkId) -> ReaperResult<()> {
+        let kind = match bookmark_type {
+            BookmarkType::Marker => BookmarkKind::Marker,
+            BookmarkType::Region => BookmarkKind::Region,
+        };
+        Reaper::get()
+            .medium_reaper()
+            .delete_project_marker(self.context(), id, kind)?;
+        Ok(())
+    }
+
     pub fn bookmark_count(self) -> CountProjectMarkersResult {
         Reaper::get()
             .medium_reaper()
@@ -704,6 +1058,22 @@ impl Project {
     }
 }
 
+/// A track together with its folder children.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TrackTreeNode {
+    pub track: Track,
+    pub children: Vec<TrackTreeNode>,
+}
+
+/// Determines where [`Project::move_tracks()`] puts the moved tracks.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MoveMode {
+    /// Directly above the target track, on the same folder level.
+    AboveTarget,
+    /// As first children of the target track, turning it into a folder if necessary.
+    IntoTarget,
+}
+
 pub struct FindBookmarkResult {
     pub index: u32,
     pub index_within_type: u32,
@@ -0,0 +1,256 @@
+use crate::{Project, Reaper, ReaperError, ReaperResult};
+use reaper_medium::{BorrowedProjectStateContext, ProjectConfigExtension, ReaperStr};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Stores one serializable value per project within the project file (`.RPP`).
+///
+/// The value is written as JSON into a `<TAG ...>` block and restored when the project is loaded.
+/// Because REAPER saves the block in undo points as well, undo and redo restore the value, too.
+///
+/// The store is cheap to clone and all clones share the same values. Register one clone via
+/// [`plugin_register_add_project_config()`] and keep another one for accessing the values:
+///
+/// ```no_run
+/// # use reaper_high::{ProjectConfigStore, Reaper};
+/// let store: ProjectConfigStore<Vec<String>> = ProjectConfigStore::new("MYEXT");
+/// let handle = Reaper::get()
+///     .medium_session()
+///     .plugin_register_add_project_config(Box::new(store.clone()))?;
+/// let project = Reaper::get().current_project();
+/// store.set(project, vec!["hello".to_string()]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Use [`Project::mark_as_dirty()`] or create an undo point after changing a value so REAPER knows
+/// that there's something to save.
+///
+/// [`plugin_register_add_project_config()`]: reaper_medium::ReaperSession::plugin_register_add_project_config
+#[derive(Debug)]
+pub struct ProjectConfigStore<T> {
+    tag: Rc<String>,
+    values: Rc<RefCell<HashMap<Project, T>>>,
+}
+
+impl<T> Clone for ProjectConfigStore<T> {
+    fn clone(&self) -> Self {
+        Self {
+            tag: self.tag.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + Clone> ProjectConfigStore<T> {
+    /// Creates a store which saves its values in a block with the given tag.
+    ///
+    /// The tag should be unique among all extensions, in uppercase and without whitespace.
+    pub fn new(tag: impl Into<String>) -> Self {
+        Self {
+            tag: Rc::new(tag.into()),
+            values: Default::default(),
+        }
+    }
+
+    /// Returns the tag of the block in which the values are saved.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Returns the value of the given project.
+    pub fn get(&self, project: Project) -> Option<T> {
+        self.values.borrow().get(&project).cloned()
+    }
+
+    /// Sets the value of the given project.
+    pub fn set(&self, project: Project, value: T) {
+        self.values.borrow_mut().insert(project, value);
+    }
+
+    /// Removes the value of the given project so nothing is saved for it anymore.
+    pub fn remove(&self, project: Project) -> Option<T> {
+        self.values.borrow_mut().remove(&project)
+    }
+
+    fn current_project() -> Option<Project> {
+        let rea_project = Reaper::get()
+            .medium_reaper()
+            .get_current_project_in_load_save()?;
+        Some(Project::new(rea_project))
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + Clone> ProjectConfigExtension for ProjectConfigStore<T> {
+    fn process_extension_line(
+        &mut self,
+        line: &ReaperStr,
+        ctx: &BorrowedProjectStateContext,
+        _is_undo: bool,
+    ) -> bool {
+        if !is_block_start(line.to_str(), &self.tag) {
+            return false;
+        }
+        // Consume the complete block first, even if we can't make use of it.
+        let lines = read_block_lines(std::iter::from_fn(|| {
+            ctx.get_line().map(|l| l.into_string())
+        }));
+        let value = deserialize_lines(lines.into_iter());
+        match (value, Self::current_project()) {
+            (Ok(value), Some(project)) => self.set(project, value),
+            (Err(e), _) => {
+                slog::warn!(Reaper::get().logger(), "Couldn't restore project config";
+                    "tag" => self.tag.as_str(),
+                    "error" => %e,
+                );
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn save_extension_config(&mut self, ctx: &BorrowedProjectStateContext, _is_undo: bool) {
+        let value = match Self::current_project().and_then(|p| self.get(p)) {
+            None => return,
+            Some(v) => v,
+        };
+        let lines = match serialize_lines(&value) {
+            Ok(lines) => lines,
+            Err(e) => {
+                slog::warn!(Reaper::get().logger(), "Couldn't save project config";
+                    "tag" => self.tag.as_str(),
+                    "error" => %e,
+                );
+                return;
+            }
+        };
+        ctx.add_line(format!("<{}", self.tag));
+        for line in lines {
+            ctx.add_line(line);
+        }
+        ctx.add_line(">");
+    }
+
+    fn begin_load_project_state(&mut self, _is_undo: bool) {
+        // The project might not contain our block (anymore), in which case there's no value.
+        if let Some(project) = Self::current_project() {
+            self.remove(project);
+        }
+    }
+}
+
+/// Maximum number of JSON bytes per line, keeps lines well below REAPER's line length limits.
+const MAX_CHUNK_LEN: usize = 1024;
+
+fn is_block_start(line: &str, tag: &str) -> bool {
+    line.strip_prefix('<')
+        .and_then(|rest| rest.split_whitespace().next())
+        .is_some_and(|t| t == tag)
+}
+
+fn is_block_end(line: &str) -> bool {
+    line.trim_start().starts_with('>')
+}
+
+/// Reads the lines of a block (after its opener) up to and including the block end.
+///
+/// Returns the lines without the block end.
+fn read_block_lines(lines: impl Iterator<Item = String>) -> Vec<String> {
+    let mut block_lines = Vec::new();
+    for line in lines {
+        if is_block_end(&line) {
+            break;
+        }
+        block_lines.push(line);
+    }
+    block_lines
+}
+
+/// Serializes the value as JSON and splits it into lines.
+///
+/// Each line is a JSON string literal containing one chunk of the JSON text. That way whitespace
+/// at chunk boundaries survives and no line can be mistaken for the end of the block.
+fn serialize_lines<T: Serialize>(value: &T) -> ReaperResult<Vec<String>> {
+    let json = serde_json::to_string(value)
+        .map_err(|_| ReaperError::new("couldn't serialize project config value"))?;
+    let mut lines = Vec::new();
+    let mut rest = json.as_str();
+    while !rest.is_empty() {
+        let mut end = rest.len().min(MAX_CHUNK_LEN);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (chunk, remainder) = rest.split_at(end);
+        lines.push(serde_json::to_string(chunk).expect("string serialization can't fail"));
+        rest = remainder;
+    }
+    Ok(lines)
+}
+
+fn deserialize_lines<T: DeserializeOwned>(lines: impl Iterator<Item = String>) -> ReaperResult<T> {
+    let mut json = String::new();
+    for line in lines {
+        let chunk: String = serde_json::from_str(line.trim())
+            .map_err(|_| ReaperError::new("project config line is not a JSON string"))?;
+        json.push_str(&chunk);
+    }
+    serde_json::from_str(&json)
+        .map_err(|_| ReaperError::new("couldn't deserialize project config value"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_boundaries() {
+        assert!(is_block_start("<MYEXT", "MYEXT"));
+        assert!(is_block_start("<MYEXT 1", "MYEXT"));
+        assert!(!is_block_start("<MYEXTRA", "MYEXT"));
+        assert!(!is_block_start("MYEXT", "MYEXT"));
+        assert!(is_block_end(">"));
+        assert!(!is_block_end("\">\""));
+    }
+
+    #[test]
+    fn round_trip() {
+        // Given
+        let value: Vec<String> = vec![" leading".to_string(), "ä".repeat(2000), ">".to_string()];
+        // When
+        let lines = serialize_lines(&value).unwrap();
+        let restored: Vec<String> = deserialize_lines(lines.clone().into_iter()).unwrap();
+        // Then
+        assert!(lines.len() > 1);
+        assert!(lines
+            .iter()
+            .all(|l| l.len() <= MAX_CHUNK_LEN * 6 + 2 && !is_block_end(l)));
+        assert_eq!(restored, value);
+    }
+
+    #[test]
+    fn corrupt_block() {
+        // Given
+        let mut lines = vec![
+            "\"[\\\"a\"".to_string(),
+            "not json".to_string(),
+            "\"\\\"]\"".to_string(),
+            ">".to_string(),
+            "<OTHER".to_string(),
+        ]
+        .into_iter();
+        // When
+        let block_lines = read_block_lines(&mut lines);
+        let result = deserialize_lines::<Vec<String>>(block_lines.into_iter());
+        // Then
+        assert!(result.is_err());
+        assert_eq!(lines.next().as_deref(), Some("<OTHER"));
+    }
+
+    #[test]
+    fn invalid_lines() {
+        let lines = vec!["{\"a\":1}".to_string()];
+        assert!(deserialize_lines::<Vec<String>>(lines.into_iter()).is_err());
+    }
+}
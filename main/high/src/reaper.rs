@@ -23,10 +23,11 @@ use crossbeam_channel::{Receiver, Sender};
 use reaper_medium::ProjectContext::Proj;
 use reaper_medium::UndoScope::All;
 use reaper_medium::{
-    ActionValueChange, CommandId, HookCommand, HookPostCommand2, OnAudioBuffer, OnAudioBufferArgs,
+    ActionValueChange, CommandId, CustomActionHandle, HookCommand, HookCommand2, HookPostCommand2,
+    OnAudioBuffer, OnAudioBufferArgs, OwnedActionHelpRegister, OwnedCustomActionRegister,
     OwnedGaccelRegister, ReaProject, RealTimeAudioThreadScope, ReaperStr, ReaperString,
-    ReaperStringArg, RegistrationHandle, SectionContext, ToggleAction, ToggleActionResult,
-    WindowContext,
+    ReaperStringArg, RegistrationHandle, SectionContext, SectionId, ToggleAction,
+    ToggleActionResult, WindowContext,
 };
 use slog::{debug, Logger};
 use std::fmt;
@@ -113,6 +114,7 @@ impl ReaperBuilder {
                     medium_real_time_reaper,
                     logger: logger.clone(),
                     command_by_id: RefCell::new(HashMap::new()),
+                    section_command_by_name: RefCell::new(HashMap::new()),
                     action_value_change_history: RefCell::new(Default::default()),
                     undo_block_is_active: Cell::new(false),
                     audio_thread_task_sender: at_sender,
@@ -199,6 +201,9 @@ pub struct Reaper {
     // Or is it  possible to give up the map borrow after obtaining the command/operation
     // reference???  Look into that!!!
     command_by_id: RefCell<HashMap<CommandId, Command>>,
+    // Actions of non-main sections. Keyed by command name because REAPER assigns the command ID
+    // only when registering.
+    section_command_by_name: RefCell<HashMap<ReaperString, SectionCommand>>,
     action_value_change_history: RefCell<HashMap<CommandId, ActionValueChange>>,
     undo_block_is_active: Cell<bool>,
     audio_thread_task_sender: Sender<AudioThreadTaskOp>,
@@ -228,6 +233,8 @@ impl Debug for SleepingState {
 struct AwakeState {
     audio_hook_register_handle: RegistrationHandle<HighOnAudioBuffer>,
     gaccel_registers: HashMap<CommandId, NonNull<raw::gaccel_register_t>>,
+    action_help_registers: HashMap<CommandId, NonNull<raw::action_help_t>>,
+    custom_action_handles: HashMap<ReaperString, CustomActionHandle>,
 }
 
 pub enum ActionKind {
@@ -346,6 +353,9 @@ impl Reaper {
         medium
             .plugin_register_add_hook_command::<HighLevelHookCommand>()
             .map_err(|_| "couldn't register hook command")?;
+        medium
+            .plugin_register_add_hook_command_2::<HighLevelHookCommand2>()
+            .map_err(|_| "couldn't register hook command 2")?;
         medium
             .plugin_register_add_toggle_action::<HighLevelToggleAction>()
             .map_err(|_| "couldn't register toggle command")?;
@@ -366,6 +376,28 @@ impl Reaper {
                     (*id, handle)
                 })
                 .collect(),
+            action_help_registers: self
+                .command_by_id
+                .borrow()
+                .iter()
+                .filter_map(|(id, command)| {
+                    let handle = medium
+                        .plugin_register_add_action_help(command.create_help_register()?)
+                        .ok()?;
+                    Some((*id, handle))
+                })
+                .collect(),
+            custom_action_handles: self
+                .section_command_by_name
+                .borrow()
+                .iter()
+                .filter_map(|(name, command)| {
+                    let handle = medium
+                        .plugin_register_add_custom_action(command.create_register(name))
+                        .ok()?;
+                    Some((name.clone(), handle))
+                })
+                .collect(),
             audio_hook_register_handle: {
                 medium
                     .audio_reg_hardware_hook_add(sleeping_state.audio_hook)
@@ -393,9 +425,16 @@ impl Reaper {
         for gaccel_handle in awake_state.gaccel_registers.values() {
             medium.plugin_register_remove_gaccel(*gaccel_handle);
         }
+        for action_help_handle in awake_state.action_help_registers.values() {
+            medium.plugin_register_remove_action_help(*action_help_handle);
+        }
+        for custom_action_handle in awake_state.custom_action_handles.values() {
+            medium.plugin_register_remove_custom_action(*custom_action_handle);
+        }
         // Remove functions
         medium.plugin_register_remove_hook_post_command_2::<HighLevelHookPostCommand2>();
         medium.plugin_register_remove_toggle_action::<HighLevelToggleAction>();
+        medium.plugin_register_remove_hook_command_2::<HighLevelHookCommand2>();
         medium.plugin_register_remove_hook_command::<HighLevelHookCommand>();
         *session_status = SessionStatus::Sleeping(Some(SleepingState { audio_hook }));
         debug!(self.logger(), "Sleeping");
@@ -423,6 +462,33 @@ impl Reaper {
         description: impl Into<ReaperStringArg<'static>>,
         operation: impl FnMut() + 'static,
         kind: ActionKind,
+    ) -> RegisteredAction {
+        self.register_action_internal(command_name, description, None, operation, kind)
+    }
+
+    /// Like [`register_action()`] but additionally registers a help text which REAPER shows in
+    /// the action list.
+    ///
+    /// [`register_action()`]: #method.register_action
+    pub fn register_action_with_help(
+        &self,
+        command_name: impl Into<ReaperStringArg<'static>>,
+        description: impl Into<ReaperStringArg<'static>>,
+        help: impl Into<ReaperStringArg<'static>>,
+        operation: impl FnMut() + 'static,
+        kind: ActionKind,
+    ) -> RegisteredAction {
+        let help = help.into().into_inner().to_reaper_string();
+        self.register_action_internal(command_name, description, Some(help), operation, kind)
+    }
+
+    fn register_action_internal(
+        &self,
+        command_name: impl Into<ReaperStringArg<'static>>,
+        description: impl Into<ReaperStringArg<'static>>,
+        help: Option<ReaperString>,
+        operation: impl FnMut() + 'static,
+        kind: ActionKind,
     ) -> RegisteredAction {
         self.require_main_thread();
         let mut medium = self.medium_session();
@@ -432,7 +498,9 @@ impl Reaper {
             Rc::new(RefCell::new(operation)),
             kind,
             description.to_reaper_string(),
+            help,
         );
+        let help_register = command.create_help_register();
         if let Entry::Vacant(p) = self.command_by_id.borrow_mut().entry(command_id) {
             p.insert(command);
         }
@@ -450,6 +518,13 @@ impl Reaper {
             ))
             .unwrap();
         awake_state.gaccel_registers.insert(command_id, address);
+        if let Some(help_register) = help_register {
+            if let Ok(address) = medium.plugin_register_add_action_help(help_register) {
+                awake_state
+                    .action_help_registers
+                    .insert(command_id, address);
+            }
+        }
         registered_action
     }
 
@@ -469,6 +544,83 @@ impl Reaper {
             self.medium_session()
                 .plugin_register_remove_gaccel(*gaccel_handle);
         }
+        if let Some(action_help_handle) = awake_state.action_help_registers.remove(&command_id) {
+            self.medium_session()
+                .plugin_register_remove_action_help(action_help_handle);
+        }
+    }
+
+    /// Registers an action in the given section, e.g. the MIDI editor.
+    ///
+    /// The command name must be unique across all sections. REAPER assigns the command ID when the
+    /// action gets registered, so it's only available while the session is awake.
+    pub fn register_action_in_section(
+        &self,
+        section_id: SectionId,
+        command_name: impl Into<ReaperStringArg<'static>>,
+        description: impl Into<ReaperStringArg<'static>>,
+        operation: impl FnMut() + 'static,
+    ) -> RegisteredSectionAction {
+        self.require_main_thread();
+        let command_name = command_name.into().into_inner().to_reaper_string();
+        let command = SectionCommand {
+            section_id,
+            description: description.into().into_inner().to_reaper_string(),
+            operation: Rc::new(RefCell::new(operation)),
+        };
+        let register = command.create_register(&command_name);
+        self.section_command_by_name
+            .borrow_mut()
+            .insert(command_name.clone(), command);
+        let registered_action = RegisteredSectionAction::new(command_name.clone());
+        // Immediately register if active
+        let mut session_status = self.session_status.borrow_mut();
+        let awake_state = match session_status.deref_mut() {
+            SessionStatus::Sleeping(_) => return registered_action,
+            SessionStatus::Awake(s) => s,
+        };
+        if let Ok(handle) = self
+            .medium_session()
+            .plugin_register_add_custom_action(register)
+        {
+            awake_state
+                .custom_action_handles
+                .insert(command_name, handle);
+        }
+        registered_action
+    }
+
+    fn unregister_section_action(&self, command_name: &ReaperStr) {
+        self.section_command_by_name
+            .borrow_mut()
+            .remove(command_name);
+        // Unregister if active
+        let mut session_status = self.session_status.borrow_mut();
+        let awake_state = match session_status.deref_mut() {
+            SessionStatus::Sleeping(_) => return,
+            SessionStatus::Awake(s) => s,
+        };
+        if let Some(handle) = awake_state.custom_action_handles.remove(command_name) {
+            self.medium_session()
+                .plugin_register_remove_custom_action(handle);
+        }
+    }
+
+    fn action_help_handle(&self, command_id: CommandId) -> Option<NonNull<raw::action_help_t>> {
+        match self.session_status.borrow().deref() {
+            SessionStatus::Sleeping(_) => None,
+            SessionStatus::Awake(s) => s.action_help_registers.get(&command_id).copied(),
+        }
+    }
+
+    fn section_action_command_id(&self, command_name: &ReaperStr) -> Option<CommandId> {
+        match self.session_status.borrow().deref() {
+            SessionStatus::Sleeping(_) => None,
+            SessionStatus::Awake(s) => s
+                .custom_action_handles
+                .get(command_name)
+                .map(|h| h.command_id()),
+        }
     }
 
     pub(crate) fn find_last_action_value_change(
@@ -567,6 +719,7 @@ struct Command {
     operation: Rc<RefCell<dyn FnMut()>>,
     kind: ActionKind,
     description: ReaperString,
+    help: Option<ReaperString>,
 }
 
 impl Debug for Command {
@@ -580,13 +733,20 @@ impl Command {
         operation: Rc<RefCell<dyn FnMut()>>,
         kind: ActionKind,
         description: ReaperString,
+        help: Option<ReaperString>,
     ) -> Command {
         Command {
             operation,
             kind,
             description,
+            help,
         }
     }
+
+    fn create_help_register(&self) -> Option<OwnedActionHelpRegister> {
+        let help = self.help.clone()?;
+        Some(OwnedActionHelpRegister::new(self.description.clone(), help))
+    }
 }
 
 pub struct RegisteredAction {
@@ -599,12 +759,62 @@ impl RegisteredAction {
         RegisteredAction { command_id }
     }
 
+    /// Returns the handle of the registered help text or `None` if the action doesn't have one
+    /// or the session is sleeping.
+    pub fn help_handle(&self) -> Option<NonNull<raw::action_help_t>> {
+        Reaper::get().action_help_handle(self.command_id)
+    }
+
     pub fn unregister(&self) {
         require_main_thread(Reaper::get().medium_reaper().low().plugin_context());
         Reaper::get().unregister_action(self.command_id);
     }
 }
 
+struct SectionCommand {
+    section_id: SectionId,
+    /// See [`Command::operation`].
+    operation: Rc<RefCell<dyn FnMut()>>,
+    description: ReaperString,
+}
+
+impl Debug for SectionCommand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SectionCommand").finish()
+    }
+}
+
+impl SectionCommand {
+    fn create_register(&self, command_name: &ReaperStr) -> OwnedCustomActionRegister {
+        OwnedCustomActionRegister::new(
+            self.section_id,
+            command_name.to_reaper_string(),
+            self.description.clone(),
+        )
+    }
+}
+
+pub struct RegisteredSectionAction {
+    // For identifying the registered command (REAPER assigns the command ID when registering)
+    command_name: ReaperString,
+}
+
+impl RegisteredSectionAction {
+    fn new(command_name: ReaperString) -> RegisteredSectionAction {
+        RegisteredSectionAction { command_name }
+    }
+
+    /// Returns the command ID which REAPER assigned or `None` if the session is sleeping.
+    pub fn command_id(&self) -> Option<CommandId> {
+        Reaper::get().section_action_command_id(&self.command_name)
+    }
+
+    pub fn unregister(&self) {
+        require_main_thread(Reaper::get().medium_reaper().low().plugin_context());
+        Reaper::get().unregister_section_action(&self.command_name);
+    }
+}
+
 // Called by REAPER (using a delegate function)!
 // Only for main section
 struct HighLevelHookCommand {}
@@ -645,6 +855,45 @@ impl HookPostCommand2 for HighLevelHookPostCommand2 {
     }
 }
 
+// Called by REAPER (using a delegate function)!
+// Only for actions registered via `register_action_in_section()`
+struct HighLevelHookCommand2 {}
+
+impl HookCommand2 for HighLevelHookCommand2 {
+    fn call(
+        _: SectionContext,
+        command_id: CommandId,
+        _: ActionValueChange,
+        _: WindowContext,
+    ) -> bool {
+        let reaper = Reaper::get();
+        let operation = {
+            let session_status = reaper.session_status.borrow();
+            let awake_state = match session_status.deref() {
+                SessionStatus::Sleeping(_) => return false,
+                SessionStatus::Awake(s) => s,
+            };
+            let command_name = awake_state
+                .custom_action_handles
+                .iter()
+                .find(|(_, handle)| handle.command_id() == command_id)
+                .map(|(name, _)| name);
+            let command_name = match command_name {
+                None => return false,
+                Some(n) => n,
+            };
+            match reaper.section_command_by_name.borrow().get(command_name) {
+                None => return false,
+                Some(command) => command.operation.clone(),
+            }
+        };
+        // Borrows are released, so the operation may register or unregister actions
+        let mut operation = operation.borrow_mut();
+        operation();
+        true
+    }
+}
+
 // Called by REAPER directly!
 // Only for main section
 struct HighLevelToggleAction {}
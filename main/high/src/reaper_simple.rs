@@ -2,18 +2,18 @@
 //! just access to `reaper_medium::Reaper` - without all the advanced stuff like subjects,
 //! channels etc. Although they end up in the same struct, this gives a little bit of structure.
 use crate::{
-    Action, Fx, FxChain, FxParameter, Guid, MidiInputDevice, MidiOutputDevice, Project, Reaper,
-    Section,
+    Action, Fx, FxChain, FxParameter, Guid, InstalledFx, MidiInputDevice, MidiOutputDevice,
+    Project, Reaper, ReaperResult, Section,
 };
 use helgoboss_midi::ShortMessage;
 use reaper_medium::{
     AudioDeviceAttributeKey, CommandId, EnumPitchShiftModesResult, GetLastTouchedFxResult,
-    GlobalAutomationModeOverride, Hwnd, Hz, MidiInputDeviceId, MidiOutputDeviceId, PitchShiftMode,
-    PitchShiftSubMode, ProjectRef, ReaperStr, ReaperString, ReaperStringArg, ReaperVersion,
-    ResampleMode, SectionId, StuffMidiMessageTarget, TrackLocation,
+    GlobalAutomationModeOverride, Hwnd, Hz, MidiInputDeviceId, MidiOutputDeviceId,
+    OpenProjectBehavior, PitchShiftMode, PitchShiftSubMode, ProjectRef, ReaperStr, ReaperString,
+    ReaperStringArg, ReaperVersion, ResampleMode, SectionId, StuffMidiMessageTarget, TrackLocation,
 };
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::{mem, os};
 
 impl Reaper {
@@ -47,6 +47,10 @@ impl Reaper {
         Section::new(SectionId::new(0))
     }
 
+    pub fn section_by_id(&self, id: SectionId) -> Section {
+        Section::new(id)
+    }
+
     pub fn monitoring_fx_chain(&self) -> FxChain {
         FxChain::from_monitoring()
     }
@@ -233,6 +237,24 @@ impl Reaper {
         MidiOutputDevice::new(id)
     }
 
+    /// Returns all plug-ins installed on this system.
+    pub fn installed_fxs(&self) -> impl Iterator<Item = InstalledFx> + '_ {
+        (0..).map_while(move |i| {
+            self.medium_reaper()
+                .enum_installed_fx(i, |res| res.map(InstalledFx::from_medium))
+        })
+    }
+
+    /// Returns the installed plug-ins matching the given fuzzy query, best matches first.
+    pub fn search_installed_fxs(&self, query: &str) -> Vec<InstalledFx> {
+        let mut matches: Vec<_> = self
+            .installed_fxs()
+            .filter_map(|fx| Some((fx.fuzzy_match_score(query)?, fx)))
+            .collect();
+        matches.sort_by(|(a, _), (b, _)| b.cmp(a));
+        matches.into_iter().map(|(_, fx)| fx).collect()
+    }
+
     pub fn midi_input_devices(
         &self,
     ) -> impl Iterator<Item = MidiInputDevice> + ExactSizeIterator + '_ {
@@ -321,6 +343,27 @@ impl Reaper {
         self.current_project()
     }
 
+    /// Opens the given project file in the current project tab and returns the resulting
+    /// current project.
+    pub fn open_project(&self, path: &Path, behavior: OpenProjectBehavior) -> Project {
+        self.medium_reaper().main_open_project(path, behavior);
+        self.current_project()
+    }
+
+    /// Opens the given project file in a new project tab.
+    ///
+    /// Returns an error if the new project tab couldn't be created.
+    pub fn open_project_in_new_tab(
+        &self,
+        path: &Path,
+        behavior: OpenProjectBehavior,
+    ) -> ReaperResult<Project> {
+        self.main_section()
+            .action_by_command_id(CommandId::new(40859))
+            .invoke_as_trigger(None)?;
+        Ok(self.open_project(path, behavior))
+    }
+
     pub fn enable_record_in_current_project(&self) {
         if self.current_project().is_recording() {
             return;
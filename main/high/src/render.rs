@@ -0,0 +1,441 @@
+use crate::{BookmarkType, Project, Reaper, ReaperResult, Track};
+use enumflags2::BitFlags;
+use reaper_medium::{
+    BookmarkId, Hz, PositionInSeconds, ProjectInfoKey, ProjectInfoStringKey, ReaperString,
+};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Render configuration of a project.
+///
+/// Each setting is optional. When reading the settings from a project, all of them are filled.
+/// When writing them to a project, only the ones which are set are changed. This makes it
+/// possible to tweak just a few settings:
+///
+/// ```no_run
+/// use reaper_high::{Reaper, RenderSettings, RenderSource};
+///
+/// let project = Reaper::get().current_project();
+/// let settings = RenderSettings::new()
+///     .pattern("$track")
+///     .source(RenderSource::Stems);
+/// project.set_render_settings(&settings)?;
+/// project.render()?;
+/// # Ok::<_, reaper_high::ReaperError>(())
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct RenderSettings {
+    /// Output directory (`RENDER_FILE`).
+    pub directory: Option<PathBuf>,
+    /// File name pattern, may contain wildcards (`RENDER_PATTERN`).
+    pub pattern: Option<String>,
+    /// Base64-encoded sink configuration (`RENDER_FORMAT`).
+    pub format: Option<String>,
+    /// Part of `RENDER_SETTINGS`.
+    pub source: Option<RenderSource>,
+    /// Part of `RENDER_SETTINGS`.
+    pub options: Option<BitFlags<RenderOption>>,
+    /// `RENDER_BOUNDSFLAG`, `RENDER_STARTPOS` and `RENDER_ENDPOS`.
+    pub bounds: Option<RenderBounds>,
+    /// `RENDER_SRATE`.
+    pub sample_rate: Option<RenderSampleRate>,
+    /// `RENDER_CHANNELS`.
+    pub channel_count: Option<u32>,
+    /// Whether to render a tail for the selected bounds (`RENDER_TAILFLAG`).
+    ///
+    /// Marker bounds don't support a tail. For them, this is read as `None` and ignored when
+    /// writing.
+    pub tail_enabled: Option<bool>,
+    /// `RENDER_TAILMS`.
+    pub tail_length: Option<Duration>,
+    /// Tracks to be rendered per region when using [`RenderSource::RegionRenderMatrix`].
+    ///
+    /// When writing, only the listed regions are changed.
+    pub region_render_matrix: Option<Vec<RegionRenderTracks>>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RenderSource {
+    MasterMix,
+    MasterMixAndStems,
+    Stems,
+    RegionRenderMatrix,
+    SelectedMediaItems,
+    SelectedMediaItemsViaMaster,
+    SelectedTracksViaMaster,
+    RazorEditAreas,
+    RazorEditAreasViaMaster,
+}
+
+#[enumflags2::bitflags]
+#[repr(u32)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RenderOption {
+    MultichannelTracksToMultichannelFiles = 4,
+    MonoMediaToMonoFiles = 16,
+    EmbedStretchMarkers = 256,
+    EmbedMetadata = 512,
+    EmbedTakeMarkers = 1024,
+    SecondPassRender = 2048,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum RenderBounds {
+    Custom {
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+    },
+    EntireProject,
+    TimeSelection,
+    AllRegions,
+    SelectedMediaItems,
+    SelectedRegions,
+    AllMarkers,
+    SelectedMarkers,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum RenderSampleRate {
+    ProjectSampleRate,
+    Custom(Hz),
+}
+
+/// The tracks which are rendered for one region.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RegionRenderTracks {
+    pub region_id: BookmarkId,
+    pub tracks: Vec<Track>,
+}
+
+impl RenderSettings {
+    pub fn new() -> RenderSettings {
+        Default::default()
+    }
+
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> RenderSettings {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    pub fn pattern(mut self, pattern: impl Into<String>) -> RenderSettings {
+        self.pattern = Some(pattern.into());
+        self
+    }
+
+    pub fn format(mut self, base64_sink_config: impl Into<String>) -> RenderSettings {
+        self.format = Some(base64_sink_config.into());
+        self
+    }
+
+    pub fn source(mut self, source: RenderSource) -> RenderSettings {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn options(mut self, options: BitFlags<RenderOption>) -> RenderSettings {
+        self.options = Some(options);
+        self
+    }
+
+    pub fn bounds(mut self, bounds: RenderBounds) -> RenderSettings {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    pub fn sample_rate(mut self, sample_rate: RenderSampleRate) -> RenderSettings {
+        self.sample_rate = Some(sample_rate);
+        self
+    }
+
+    pub fn channel_count(mut self, channel_count: u32) -> RenderSettings {
+        self.channel_count = Some(channel_count);
+        self
+    }
+
+    pub fn tail(mut self, length: Option<Duration>) -> RenderSettings {
+        self.tail_enabled = Some(length.is_some());
+        if let Some(l) = length {
+            self.tail_length = Some(l);
+        }
+        self
+    }
+
+    pub fn region_tracks(mut self, region_id: BookmarkId, tracks: Vec<Track>) -> RenderSettings {
+        self.region_render_matrix
+            .get_or_insert_with(Vec::new)
+            .push(RegionRenderTracks { region_id, tracks });
+        self
+    }
+
+    /// Reads all render settings from the given project.
+    pub fn from_project(project: Project) -> ReaperResult<RenderSettings> {
+        let settings = get_value(project, ProjectInfoKey::RenderSettings) as u32;
+        let bounds_flag = get_value(project, ProjectInfoKey::RenderBoundsFlag) as u32;
+        let bounds = match bounds_flag {
+            0 => RenderBounds::Custom {
+                start: PositionInSeconds::new(get_value(project, ProjectInfoKey::RenderStartPos)),
+                end: PositionInSeconds::new(get_value(project, ProjectInfoKey::RenderEndPos)),
+            },
+            1 => RenderBounds::EntireProject,
+            2 => RenderBounds::TimeSelection,
+            3 => RenderBounds::AllRegions,
+            4 => RenderBounds::SelectedMediaItems,
+            5 => RenderBounds::SelectedRegions,
+            6 => RenderBounds::AllMarkers,
+            7 => RenderBounds::SelectedMarkers,
+            _ => return Err("unknown render bounds".into()),
+        };
+        let sample_rate = match get_value(project, ProjectInfoKey::RenderSrate) {
+            r if r > 0.0 => RenderSampleRate::Custom(Hz::new(r)),
+            _ => RenderSampleRate::ProjectSampleRate,
+        };
+        let tail_flag = get_value(project, ProjectInfoKey::RenderTailFlag) as u32;
+        let tail_ms = get_value(project, ProjectInfoKey::RenderTailMs).max(0.0);
+        let settings = RenderSettings {
+            directory: Some(get_string(project, ProjectInfoStringKey::RenderFile)?.into()),
+            pattern: Some(get_string(project, ProjectInfoStringKey::RenderPattern)?),
+            format: Some(get_string(project, ProjectInfoStringKey::RenderFormat)?),
+            source: Some(RenderSource::from_raw(settings)),
+            options: Some(BitFlags::from_bits_truncate(settings)),
+            bounds: Some(bounds),
+            sample_rate: Some(sample_rate),
+            channel_count: Some(get_value(project, ProjectInfoKey::RenderChannels) as u32),
+            tail_enabled: tail_flag_bit(bounds_flag).map(|bit| tail_flag & bit != 0),
+            tail_length: Some(Duration::from_secs_f64(tail_ms / 1000.0)),
+            region_render_matrix: Some(read_region_render_matrix(project)),
+        };
+        Ok(settings)
+    }
+
+    /// Writes the render settings which are set to the given project.
+    pub fn apply_to(&self, project: Project) -> ReaperResult<()> {
+        let reaper = Reaper::get().medium_reaper();
+        if let Some(directory) = &self.directory {
+            let directory = directory.to_str().ok_or("directory is not valid UTF-8")?;
+            reaper.get_set_project_info_string_set(
+                project.context(),
+                ProjectInfoStringKey::RenderFile,
+                directory,
+            )?;
+        }
+        if let Some(pattern) = &self.pattern {
+            reaper.get_set_project_info_string_set(
+                project.context(),
+                ProjectInfoStringKey::RenderPattern,
+                pattern.as_str(),
+            )?;
+        }
+        if let Some(format) = &self.format {
+            reaper.get_set_project_info_string_set(
+                project.context(),
+                ProjectInfoStringKey::RenderFormat,
+                format.as_str(),
+            )?;
+        }
+        if self.source.is_some() || self.options.is_some() {
+            let mut settings = get_value(project, ProjectInfoKey::RenderSettings) as u32;
+            if let Some(source) = self.source {
+                settings = (settings & !RenderSource::MASK) | source.to_raw();
+            }
+            if let Some(options) = self.options {
+                settings = (settings & !BitFlags::<RenderOption>::all().bits()) | options.bits();
+            }
+            set_value(project, ProjectInfoKey::RenderSettings, settings as f64);
+        }
+        if let Some(bounds) = self.bounds {
+            if let RenderBounds::Custom { start, end } = bounds {
+                set_value(project, ProjectInfoKey::RenderStartPos, start.get());
+                set_value(project, ProjectInfoKey::RenderEndPos, end.get());
+            }
+            set_value(
+                project,
+                ProjectInfoKey::RenderBoundsFlag,
+                bounds.to_raw() as f64,
+            );
+        }
+        if let Some(sample_rate) = self.sample_rate {
+            let raw = match sample_rate {
+                RenderSampleRate::ProjectSampleRate => 0.0,
+                RenderSampleRate::Custom(r) => r.get(),
+            };
+            set_value(project, ProjectInfoKey::RenderSrate, raw);
+        }
+        if let Some(channel_count) = self.channel_count {
+            set_value(
+                project,
+                ProjectInfoKey::RenderChannels,
+                channel_count as f64,
+            );
+        }
+        if let Some(enabled) = self.tail_enabled {
+            let bounds_flag = get_value(project, ProjectInfoKey::RenderBoundsFlag) as u32;
+            // Bounds without tail bit don't support a tail, so there's nothing to write.
+            if let Some(bit) = tail_flag_bit(bounds_flag) {
+                let tail_flag = get_value(project, ProjectInfoKey::RenderTailFlag) as u32;
+                let new_tail_flag = if enabled {
+                    tail_flag | bit
+                } else {
+                    tail_flag & !bit
+                };
+                set_value(
+                    project,
+                    ProjectInfoKey::RenderTailFlag,
+                    new_tail_flag as f64,
+                );
+            }
+        }
+        if let Some(length) = self.tail_length {
+            set_value(
+                project,
+                ProjectInfoKey::RenderTailMs,
+                length.as_millis() as f64,
+            );
+        }
+        if let Some(matrix) = &self.region_render_matrix {
+            for entry in matrix {
+                write_region_render_tracks(project, entry);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RenderSource {
+    const MASK: u32 = 1 | 2 | 8 | 32 | 64 | 128 | 8192 | 16384;
+
+    fn from_raw(settings: u32) -> RenderSource {
+        use RenderSource::*;
+        if settings & 8 != 0 {
+            RegionRenderMatrix
+        } else if settings & 32 != 0 {
+            SelectedMediaItems
+        } else if settings & 64 != 0 {
+            SelectedMediaItemsViaMaster
+        } else if settings & 128 != 0 {
+            SelectedTracksViaMaster
+        } else if settings & 8192 != 0 {
+            RazorEditAreas
+        } else if settings & 16384 != 0 {
+            RazorEditAreasViaMaster
+        } else if settings & 1 != 0 {
+            MasterMixAndStems
+        } else if settings & 2 != 0 {
+            Stems
+        } else {
+            MasterMix
+        }
+    }
+
+    fn to_raw(self) -> u32 {
+        use RenderSource::*;
+        match self {
+            MasterMix => 0,
+            MasterMixAndStems => 1,
+            Stems => 2,
+            RegionRenderMatrix => 8,
+            SelectedMediaItems => 32,
+            SelectedMediaItemsViaMaster => 64,
+            SelectedTracksViaMaster => 128,
+            RazorEditAreas => 8192,
+            RazorEditAreasViaMaster => 16384,
+        }
+    }
+}
+
+impl RenderBounds {
+    fn to_raw(self) -> u32 {
+        use RenderBounds::*;
+        match self {
+            Custom { .. } => 0,
+            EntireProject => 1,
+            TimeSelection => 2,
+            AllRegions => 3,
+            SelectedMediaItems => 4,
+            SelectedRegions => 5,
+            AllMarkers => 6,
+            SelectedMarkers => 7,
+        }
+    }
+}
+
+/// Returns the bit of `RENDER_TAILFLAG` which belongs to the given `RENDER_BOUNDSFLAG` value.
+///
+/// REAPER doesn't define tail bits for marker bounds.
+fn tail_flag_bit(bounds_flag: u32) -> Option<u32> {
+    let bit = match bounds_flag {
+        0 => 1,
+        1 => 2,
+        2 => 4,
+        3 => 8,
+        4 => 16,
+        5 => 32,
+        _ => return None,
+    };
+    Some(bit)
+}
+
+/// Returns the files which would be written when rendering with the current render settings.
+pub(crate) fn render_targets(project: Project) -> ReaperResult<Vec<PathBuf>> {
+    let targets = get_string(project, ProjectInfoStringKey::RenderTargets)?;
+    let paths = targets
+        .split(';')
+        .filter(|t| !t.is_empty())
+        .map(PathBuf::from)
+        .collect();
+    Ok(paths)
+}
+
+fn read_region_render_matrix(project: Project) -> Vec<RegionRenderTracks> {
+    project
+        .bookmarks()
+        .map(|b| b.basic_info())
+        .filter(|info| info.bookmark_type() == BookmarkType::Region)
+        .map(|info| RegionRenderTracks {
+            region_id: info.id,
+            tracks: region_render_tracks(project, info.id),
+        })
+        .collect()
+}
+
+fn region_render_tracks(project: Project, region_id: BookmarkId) -> Vec<Track> {
+    let reaper = Reaper::get().medium_reaper();
+    (0..)
+        .map_while(|i| reaper.enum_region_render_matrix(project.context(), region_id, i))
+        .map(|t| Track::new(t, Some(project.raw())))
+        .collect()
+}
+
+fn write_region_render_tracks(project: Project, entry: &RegionRenderTracks) {
+    let reaper = Reaper::get().medium_reaper();
+    for track in region_render_tracks(project, entry.region_id) {
+        unsafe {
+            reaper.set_region_render_matrix(project.context(), entry.region_id, track.raw(), false);
+        }
+    }
+    for track in &entry.tracks {
+        unsafe {
+            reaper.set_region_render_matrix(project.context(), entry.region_id, track.raw(), true);
+        }
+    }
+}
+
+fn get_value(project: Project, key: ProjectInfoKey) -> f64 {
+    Reaper::get()
+        .medium_reaper()
+        .get_set_project_info_get(project.context(), key)
+}
+
+fn set_value(project: Project, key: ProjectInfoKey, value: f64) {
+    Reaper::get()
+        .medium_reaper()
+        .get_set_project_info_set(project.context(), key, value);
+}
+
+fn get_string(project: Project, key: ProjectInfoStringKey) -> ReaperResult<String> {
+    let value: ReaperString = Reaper::get()
+        .medium_reaper()
+        .get_set_project_info_string_get(project.context(), key, STRING_BUFFER_SIZE)?;
+    Ok(value.into_string())
+}
+
+const STRING_BUFFER_SIZE: u32 = 64 * 1024;
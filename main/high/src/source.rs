@@ -4,12 +4,12 @@ use reaper_low::{
     save_pcm_source_state_to_heap_buf,
 };
 use reaper_medium::{
-    BorrowedPcmSource, Bpm, DurationInSeconds, ExtGetPooledMidiIdResult, MidiImportBehavior,
-    OwnedPcmSource, PcmSource, ReaperFunctionError, ReaperStringArg,
+    BorrowedPcmSource, Bpm, DurationInSeconds, ExtGetPooledMidiIdResult, Hz, MidiImportBehavior,
+    OwnedPcmSource, PcmSource, PositionInSeconds, ReaperFunctionError, ReaperStringArg,
 };
 use ref_cast::RefCast;
 use std::borrow::Borrow;
-use std::ops::{Deref, DerefMut};
+use std::ops::{Deref, DerefMut, Range};
 use std::path::{Path, PathBuf};
 
 /// Pointer to a PCM source that's owned and managed by REAPER.
@@ -99,6 +99,50 @@ impl BorrowedSource {
         self.0.get_length()
     }
 
+    /// Reads the min/max peaks within the given time range of the source.
+    ///
+    /// Returns one envelope per channel, each containing one peak per `1 / peak_rate` seconds.
+    pub fn peaks(&self, range: Range<PositionInSeconds>, peak_rate: Hz) -> Peaks {
+        let channel_count = self.0.get_num_channels().unwrap_or(0).max(1);
+        let total_count =
+            ((range.end.get() - range.start.get()).max(0.0) * peak_rate.get()).ceil() as u32;
+        let mut channels = vec![ChannelPeaks::default(); channel_count as usize];
+        let max_chunk_size = total_count.min(PEAKS_CHUNK_SIZE);
+        let mut buffer = vec![0.0; (channel_count * max_chunk_size * 2) as usize];
+        let mut done_count = 0;
+        while done_count < total_count {
+            let chunk_size = (total_count - done_count).min(max_chunk_size);
+            let chunk_start = range.start.get() + done_count as f64 / peak_rate.get();
+            let res = Reaper::get().medium_reaper().pcm_source_get_peaks(
+                &self.0,
+                peak_rate,
+                PositionInSeconds::new(chunk_start),
+                channel_count,
+                chunk_size,
+                false,
+                &mut buffer,
+            );
+            if res.sample_count == 0 {
+                break;
+            }
+            // Layout: max values of all channels (interleaved), then min values
+            let min_offset = (chunk_size * channel_count) as usize;
+            for i in 0..res.sample_count.min(chunk_size) as usize {
+                for (ch, peaks) in channels.iter_mut().enumerate() {
+                    let index = i * channel_count as usize + ch;
+                    peaks.max.push(buffer[index]);
+                    peaks.min.push(buffer[min_offset + index]);
+                }
+            }
+            done_count += chunk_size;
+        }
+        Peaks {
+            start_time: range.start,
+            peak_rate,
+            channels,
+        }
+    }
+
     pub fn duplicate(&self) -> Option<OwnedSource> {
         let raw_duplicate = self.0.duplicate()?;
         Some(OwnedSource::new(raw_duplicate))
@@ -187,6 +231,24 @@ impl BorrowedSource {
     }
 }
 
+/// Min/max peaks of a source, one envelope per channel.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Peaks {
+    pub start_time: PositionInSeconds,
+    pub peak_rate: Hz,
+    pub channels: Vec<ChannelPeaks>,
+}
+
+/// Min/max peaks of one channel.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ChannelPeaks {
+    pub min: Vec<f64>,
+    pub max: Vec<f64>,
+}
+
+/// REAPER can return at most 2^20 - 1 peaks per call.
+const PEAKS_CHUNK_SIZE: u32 = 64 * 1024;
+
 /// Owned PCM source.
 #[derive(Debug)]
 #[repr(transparent)]
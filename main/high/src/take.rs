@@ -1,5 +1,12 @@
-use crate::{FxChain, OwnedSource, Reaper, ReaperSource, Track};
-use reaper_medium::{MediaItemTake, PositionInSeconds, ReaperFunctionError, TakeAttributeKey};
+use crate::{
+    AudioBlockSpec, AudioBlocks, FxChain, Item, OwnedSource, Project, Reaper, ReaperError,
+    ReaperResult, ReaperSource, Track,
+};
+use reaper_medium::{
+    decode_packed_midi_events, encode_packed_midi_events, MediaItemTake, MidiNote, PackedMidiEvent,
+    PositionInPpq, PositionInSeconds, ProjectContext, ReaperFunctionError, SortBehavior,
+    TakeAttributeKey,
+};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Take {
@@ -21,8 +28,55 @@ impl Take {
         FxChain::from_take(*self)
     }
 
-    pub fn track(&self) -> &Track {
-        todo!()
+    pub fn item(&self) -> Option<Item> {
+        let raw_item = unsafe {
+            Reaper::get()
+                .medium_reaper
+                .get_media_item_take_item(self.raw)?
+        };
+        Some(Item::new(raw_item))
+    }
+
+    pub fn track(&self) -> Option<Track> {
+        let raw_track = unsafe {
+            Reaper::get()
+                .medium_reaper
+                .get_media_item_take_track(self.raw)?
+        };
+        Some(Track::new(raw_track, None))
+    }
+
+    /// Returns the index of this take within its item.
+    pub fn index(&self) -> u32 {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .get_set_media_item_take_info_get_take_number(self.raw)
+        }
+    }
+
+    pub fn project(&self) -> Option<Project> {
+        self.item()?.project()
+    }
+
+    /// Returns whether this take still exists in any of the open projects.
+    pub fn is_available(&self) -> bool {
+        let reaper = Reaper::get();
+        reaper.projects().any(|p| {
+            reaper
+                .medium_reaper()
+                .validate_ptr_2(ProjectContext::Proj(p.raw()), self.raw)
+        })
+    }
+
+    /// Returns an iterator which reads the audio of this take block by block.
+    pub fn audio_blocks(&self, spec: AudioBlockSpec) -> ReaperResult<AudioBlocks> {
+        let accessor = unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .create_take_audio_accessor(self.raw)?
+        };
+        AudioBlocks::new(accessor, spec)
     }
 
     pub fn name(&self) -> String {
@@ -60,4 +114,170 @@ impl Take {
             )
         }
     }
+
+    pub fn is_midi(&self) -> bool {
+        unsafe { Reaper::get().medium_reaper.take_is_midi(self.raw) }
+    }
+
+    pub fn ppq_pos_from_time(&self, time: PositionInSeconds) -> PositionInPpq {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .midi_get_ppq_pos_from_proj_time(self.raw, time)
+        }
+    }
+
+    pub fn time_from_ppq_pos(&self, ppq_pos: PositionInPpq) -> PositionInSeconds {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .midi_get_proj_time_from_ppq_pos(self.raw, ppq_pos)
+        }
+    }
+
+    pub fn note_count(&self) -> u32 {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .midi_count_evts(self.raw)
+                .note_count
+        }
+    }
+
+    pub fn note_by_index(&self, index: u32) -> Option<MidiNote> {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .midi_get_note(self.raw, index)
+                .ok()
+        }
+    }
+
+    pub fn notes(&self) -> impl ExactSizeIterator<Item = MidiNote> + '_ {
+        (0..self.note_count()).map(move |i| {
+            self.note_by_index(i)
+                .expect("MIDI note disappeared while iterating")
+        })
+    }
+
+    pub fn insert_note(&self, note: MidiNote) -> Result<(), ReaperFunctionError> {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .midi_insert_note(self.raw, note, SortBehavior::Sort)
+        }
+    }
+
+    /// Inserts all given notes and sorts only once at the end.
+    pub fn insert_notes(
+        &self,
+        notes: impl IntoIterator<Item = MidiNote>,
+    ) -> Result<(), ReaperFunctionError> {
+        let reaper = &Reaper::get().medium_reaper;
+        for note in notes {
+            unsafe {
+                reaper.midi_insert_note(self.raw, note, SortBehavior::DontSort)?;
+            }
+        }
+        unsafe {
+            reaper.midi_sort(self.raw);
+        }
+        Ok(())
+    }
+
+    pub fn set_note(&self, index: u32, note: MidiNote) -> Result<(), ReaperFunctionError> {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .midi_set_note(self.raw, index, note, SortBehavior::Sort)
+        }
+    }
+
+    /// Lets the given function modify each note and sorts only once at the end.
+    ///
+    /// Notes which are not changed by the function are not written back.
+    pub fn modify_notes(
+        &self,
+        mut modify: impl FnMut(&mut MidiNote),
+    ) -> Result<(), ReaperFunctionError> {
+        let reaper = &Reaper::get().medium_reaper;
+        for i in 0..self.note_count() {
+            let mut note = unsafe { reaper.midi_get_note(self.raw, i)? };
+            let original_note = note;
+            modify(&mut note);
+            if note != original_note {
+                unsafe {
+                    reaper.midi_set_note(self.raw, i, note, SortBehavior::DontSort)?;
+                }
+            }
+        }
+        unsafe {
+            reaper.midi_sort(self.raw);
+        }
+        Ok(())
+    }
+
+    pub fn delete_note(&self, index: u32) -> Result<(), ReaperFunctionError> {
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .midi_delete_note(self.raw, index)
+        }
+    }
+
+    /// Returns all MIDI events of this take in one go.
+    ///
+    /// Returns an error if the events are too large to be read completely, so that a truncated list
+    /// is never returned.
+    pub fn midi_events(&self) -> ReaperResult<Vec<PackedMidiEvent>> {
+        let mut buffer_size = INITIAL_MIDI_EVENTS_BUFFER_SIZE;
+        loop {
+            let result = unsafe {
+                Reaper::get()
+                    .medium_reaper
+                    .midi_get_all_evts(self.raw, buffer_size)
+            };
+            // REAPER either fails or fills the buffer completely if it's too small.
+            let buffer = match result {
+                Ok(buffer) if buffer.len() < buffer_size as usize => buffer,
+                Ok(_) | Err(_) if buffer_size < MAX_MIDI_EVENTS_BUFFER_SIZE => {
+                    buffer_size = (buffer_size * 2).min(MAX_MIDI_EVENTS_BUFFER_SIZE);
+                    continue;
+                }
+                Ok(_) => return Err(ReaperError::new("MIDI events too large")),
+                Err(e) => return Err(e.into()),
+            };
+            let events = decode_packed_midi_events(&buffer)?;
+            return Ok(events);
+        }
+    }
+
+    /// Replaces all MIDI events of this take in one go.
+    pub fn set_midi_events<'a>(
+        &self,
+        events: impl IntoIterator<Item = &'a PackedMidiEvent>,
+    ) -> Result<(), ReaperFunctionError> {
+        let buffer = encode_packed_midi_events(events);
+        unsafe {
+            Reaper::get()
+                .medium_reaper
+                .midi_set_all_evts(self.raw, &buffer)
+        }
+    }
+
+    /// Lets the given function modify all MIDI events and writes them back in one go.
+    pub fn modify_midi_events(
+        &self,
+        modify: impl FnOnce(&mut Vec<PackedMidiEvent>),
+    ) -> ReaperResult<()> {
+        let mut events = self.midi_events()?;
+        modify(&mut events);
+        self.set_midi_events(&events)?;
+        Ok(())
+    }
 }
+
+/// Most takes are small, so we start with a moderate buffer and grow it as long as the events
+/// might not fit.
+const INITIAL_MIDI_EVENTS_BUFFER_SIZE: u32 = 1024 * 1024;
+const MAX_MIDI_EVENTS_BUFFER_SIZE: u32 = 256 * 1024 * 1024;
@@ -0,0 +1,320 @@
+use crate::{Chunk, ChunkRegion, Guid, Reaper, ReaperResult, Track, MAX_TRACK_CHUNK_SIZE};
+use reaper_medium::ChunkCacheHint;
+use std::convert::TryInto;
+use std::fs;
+use std::path::Path;
+
+/// Determines what ends up in a track template.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TrackTemplateOptions {
+    pub include_items: bool,
+    pub include_envelopes: bool,
+}
+
+impl Default for TrackTemplateOptions {
+    fn default() -> Self {
+        Self {
+            include_items: true,
+            include_envelopes: true,
+        }
+    }
+}
+
+/// Content of a track template file (`.RTrackTemplate`), consisting of one or more track chunks.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct TrackTemplate {
+    track_chunks: Vec<String>,
+}
+
+impl TrackTemplate {
+    /// Creates a template from the chunks of the given tracks.
+    pub fn from_tracks<'a>(
+        tracks: impl IntoIterator<Item = &'a Track>,
+        options: TrackTemplateOptions,
+    ) -> ReaperResult<TrackTemplate> {
+        let track_chunks: Result<Vec<_>, &'static str> = tracks
+            .into_iter()
+            .map(|t| {
+                let mut chunk = t.chunk(MAX_TRACK_CHUNK_SIZE, ChunkCacheHint::NormalMode)?;
+                if !options.include_items {
+                    remove_tags(&mut chunk, |name| name == "ITEM");
+                }
+                if !options.include_envelopes {
+                    remove_tags(&mut chunk, is_envelope_tag);
+                }
+                chunk.try_into()
+            })
+            .collect();
+        Ok(TrackTemplate {
+            track_chunks: track_chunks?,
+        })
+    }
+
+    /// Parses the content of a track template file.
+    pub fn parse(content: &str) -> ReaperResult<TrackTemplate> {
+        let chunk = Chunk::new(normalize_indentation(content));
+        let region = chunk.region();
+        let mut track_chunks = vec![];
+        let mut pos = 0;
+        while let Some(tag) = region.find_first_tag(pos) {
+            if tag_name(&tag) == "TRACK" {
+                track_chunks.push(tag.content().to_string());
+            }
+            pos = tag.end_pos_plus_one();
+        }
+        if track_chunks.is_empty() {
+            return Err("track template doesn't contain any tracks".into());
+        }
+        Ok(TrackTemplate { track_chunks })
+    }
+
+    pub fn load(path: &Path) -> ReaperResult<TrackTemplate> {
+        let content = fs::read_to_string(path).map_err(|_| "couldn't read track template")?;
+        TrackTemplate::parse(&content)
+    }
+
+    pub fn save(&self, path: &Path) -> ReaperResult<()> {
+        fs::write(path, self.to_string()).map_err(|_| "couldn't write track template")?;
+        Ok(())
+    }
+
+    pub fn track_chunks(&self) -> impl ExactSizeIterator<Item = &str> + '_ {
+        self.track_chunks.iter().map(|c| c.as_str())
+    }
+}
+
+impl std::fmt::Display for TrackTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for chunk in &self.track_chunks {
+            writeln!(f, "{}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+/// Content of an FX chain file (`.RfxChain`), consisting of the FX of a chain without the
+/// surrounding chain tag.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FxChainTemplate {
+    content: String,
+}
+
+impl FxChainTemplate {
+    /// Creates a template from the given FX chain tag (e.g. `<FXCHAIN ... >`).
+    pub(crate) fn from_chain_tag(chain_tag: &ChunkRegion) -> FxChainTemplate {
+        let content = chain_tag.content();
+        let lines: Vec<_> = content.lines().collect();
+        // Skip the tag opener, the lines with the chain window state and the tag closer
+        let fx_lines = match lines.iter().position(|l| l.starts_with("BYPASS ")) {
+            None => &[][..],
+            Some(first) => &lines[first..lines.len() - 1],
+        };
+        FxChainTemplate {
+            content: fx_lines.join("\n"),
+        }
+    }
+
+    pub fn parse(content: &str) -> FxChainTemplate {
+        FxChainTemplate {
+            content: normalize_indentation(content).trim().to_string(),
+        }
+    }
+
+    pub fn load(path: &Path) -> ReaperResult<FxChainTemplate> {
+        let content = fs::read_to_string(path).map_err(|_| "couldn't read FX chain file")?;
+        Ok(FxChainTemplate::parse(&content))
+    }
+
+    pub fn save(&self, path: &Path) -> ReaperResult<()> {
+        fs::write(path, format!("{}\n", self.content))
+            .map_err(|_| "couldn't write FX chain file")?;
+        Ok(())
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+}
+
+/// Replaces all GUIDs which identify tracks, FX, items and envelopes with new ones.
+///
+/// A GUID which occurs multiple times is consistently replaced with the same new GUID.
+pub(crate) fn regenerate_guids(chunk: &str) -> String {
+    replace_guids(chunk, || {
+        Guid::new(Reaper::get().medium_reaper().gen_guid()).to_string_with_braces()
+    })
+}
+
+const GUID_LINE_KEYS: &[&str] = &["TRACK", "TRACKID", "FXID", "GUID", "IGUID", "EGUID"];
+const GUID_STRING_LENGTH: usize = 38;
+
+fn replace_guids(chunk: &str, mut create_guid: impl FnMut() -> String) -> String {
+    let mut replacements: Vec<(String, String)> = vec![];
+    let mut result = String::with_capacity(chunk.len());
+    for line in chunk.split_inclusive('\n') {
+        let key = line
+            .trim_start()
+            .trim_start_matches('<')
+            .split_whitespace()
+            .next()
+            .unwrap_or("");
+        if !GUID_LINE_KEYS.contains(&key) {
+            result.push_str(line);
+            continue;
+        }
+        let mut rest = line;
+        while let Some(start) = rest.find('{') {
+            let candidate = rest.get(start..start + GUID_STRING_LENGTH);
+            match candidate {
+                Some(old) if old.ends_with('}') => {
+                    result.push_str(&rest[..start]);
+                    let new = match replacements.iter().find(|(o, _)| o == old) {
+                        Some((_, new)) => new.clone(),
+                        None => {
+                            let new = create_guid();
+                            replacements.push((old.to_string(), new.clone()));
+                            new
+                        }
+                    };
+                    result.push_str(&new);
+                    rest = &rest[start + GUID_STRING_LENGTH..];
+                }
+                _ => {
+                    result.push_str(&rest[..=start]);
+                    rest = &rest[start + 1..];
+                }
+            }
+        }
+        result.push_str(rest);
+    }
+    result
+}
+
+fn normalize_indentation(content: &str) -> String {
+    content
+        .lines()
+        .map(|l| l.trim_start())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn tag_name(tag: &ChunkRegion) -> String {
+    tag.content()
+        .trim_start_matches('<')
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+        .to_string()
+}
+
+fn is_envelope_tag(name: &str) -> bool {
+    name.trim_end_matches(|c: char| c.is_ascii_digit())
+        .contains("ENV")
+}
+
+/// Removes all (also nested) tags whose name matches the given predicate.
+fn remove_tags(chunk: &mut Chunk, predicate: impl Fn(&str) -> bool) {
+    let mut pos = 0;
+    loop {
+        let region = chunk.region();
+        let tag = match region.find_first_tag(pos) {
+            None => break,
+            Some(t) => t,
+        };
+        if predicate(&tag_name(&tag)) && tag.start_pos() > 0 {
+            // Also remove the line break in front of the tag
+            let tag_with_line_break = tag.move_left_cursor_to(tag.start_pos() - 1);
+            pos = tag_with_line_break.start_pos();
+            chunk.delete_region(&tag_with_line_break);
+        } else {
+            pos = tag.start_pos() + 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACK_CHUNK: &str = r#"<TRACK
+NAME "Bass"
+TRACKID {11111111-1111-1111-1111-111111111111}
+<VOLENV2
+EGUID {22222222-2222-2222-2222-222222222222}
+PT 0 1 0
+>
+<FXCHAIN
+SHOW 0
+LASTSEL 0
+DOCKED 0
+BYPASS 0 0 0
+<JS utility/volume ""
+0 0 - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+>
+FXID {33333333-3333-3333-3333-333333333333}
+WAK 0 0
+>
+<ITEM
+POSITION 0
+IGUID {44444444-4444-4444-4444-444444444444}
+>
+>"#;
+
+    #[test]
+    fn parse_indented_template() {
+        let content = "<TRACK\n  NAME \"A\"\n>\n<TRACK\n  NAME \"B\"\n  <ITEM\n  >\n>\n";
+        let template = TrackTemplate::parse(content).unwrap();
+        assert_eq!(
+            template.track_chunks().collect::<Vec<_>>(),
+            vec!["<TRACK\nNAME \"A\"\n>", "<TRACK\nNAME \"B\"\n<ITEM\n>\n>"]
+        );
+        assert!(TrackTemplate::parse("NAME \"A\"").is_err());
+    }
+
+    #[test]
+    fn remove_items_and_envelopes() {
+        let mut chunk = Chunk::new(TRACK_CHUNK.to_string());
+        remove_tags(&mut chunk, |name| name == "ITEM");
+        remove_tags(&mut chunk, is_envelope_tag);
+        let content = chunk.to_string();
+        assert!(!content.contains("<ITEM"));
+        assert!(!content.contains("<VOLENV2"));
+        assert!(content.contains("<FXCHAIN"));
+        assert!(content.ends_with("WAK 0 0\n>\n>"));
+    }
+
+    #[test]
+    fn regenerate_guids() {
+        let mut count = 0;
+        let content = format!(
+            "{}\nTRACKID {{11111111-1111-1111-1111-111111111111}}",
+            TRACK_CHUNK
+        );
+        let result = replace_guids(&content, || {
+            count += 1;
+            format!("{{{:08}-0000-0000-0000-000000000000}}", count)
+        });
+        assert_eq!(count, 4);
+        assert!(!result.contains("{1111"));
+        assert_eq!(
+            result
+                .matches("TRACKID {00000001-0000-0000-0000-000000000000}")
+                .count(),
+            2
+        );
+        assert!(result.contains("FXID {00000003-0000-0000-0000-000000000000}"));
+    }
+
+    #[test]
+    fn fx_chain_template_from_chain_tag() {
+        let chunk = Chunk::new(TRACK_CHUNK.to_string());
+        let chain_tag = chunk.region().find_first_tag_named(0, "FXCHAIN").unwrap();
+        let template = FxChainTemplate::from_chain_tag(&chain_tag);
+        assert!(template.content().starts_with("BYPASS 0 0 0\n<JS"));
+        assert!(template.content().ends_with("WAK 0 0"));
+    }
+}
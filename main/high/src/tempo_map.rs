@@ -0,0 +1,337 @@
+use reaper_medium::{
+    Bpm, PositionInBeats, PositionInQuarterNotes, PositionInSeconds, TempoTimeSigMarker,
+    TimeSignature,
+};
+
+/// A snapshot of the tempo/time signature markers of a project.
+///
+/// Conversions are done without asking REAPER, so they reflect the state at the time the snapshot
+/// was taken.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TempoMap {
+    markers: Vec<TempoTimeSigMarker>,
+    tempo_segments: Vec<TempoSegment>,
+    time_sig_segments: Vec<TimeSigSegment>,
+}
+
+/// A position expressed in measures.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MeasurePosition {
+    pub measure_index: i32,
+    /// Beats (in units of the time signature denominator) since the start of the measure.
+    pub beats_since_measure: PositionInBeats,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct TempoSegment {
+    start_time: f64,
+    start_qn: f64,
+    start_bpm: f64,
+    end_bpm: f64,
+    /// `None` for the last segment.
+    duration: Option<f64>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct TimeSigSegment {
+    start_qn: f64,
+    measure_index: i32,
+    time_signature: TimeSignature,
+}
+
+impl TempoMap {
+    /// Creates a tempo map from the given markers.
+    ///
+    /// The initial tempo and time signature apply until the first marker.
+    pub fn new(
+        initial_tempo: Bpm,
+        initial_time_signature: TimeSignature,
+        mut markers: Vec<TempoTimeSigMarker>,
+    ) -> TempoMap {
+        markers.sort_by(|a, b| a.position.partial_cmp(&b.position).expect("NaN position"));
+        let mut tempo_segments = vec![TempoSegment {
+            start_time: 0.0,
+            start_qn: 0.0,
+            start_bpm: initial_tempo.get(),
+            end_bpm: initial_tempo.get(),
+            duration: None,
+        }];
+        let mut linear_flags = vec![false];
+        let mut time_sig_segments = vec![TimeSigSegment {
+            start_qn: 0.0,
+            measure_index: 0,
+            time_signature: initial_time_signature,
+        }];
+        for m in &markers {
+            let time = m.position.get().max(0.0);
+            let bpm = m.tempo.get();
+            let previous = tempo_segments.last_mut().expect("impossible");
+            let segment = if time <= previous.start_time {
+                // Marker at project start replaces the initial tempo
+                *linear_flags.last_mut().expect("impossible") = m.linear_tempo;
+                previous.start_bpm = bpm;
+                previous.end_bpm = bpm;
+                *previous
+            } else {
+                let duration = time - previous.start_time;
+                if *linear_flags.last().expect("impossible") {
+                    previous.end_bpm = bpm;
+                }
+                previous.duration = Some(duration);
+                let start_qn = previous.start_qn + previous.qn_delta(duration);
+                let segment = TempoSegment {
+                    start_time: time,
+                    start_qn,
+                    start_bpm: bpm,
+                    end_bpm: bpm,
+                    duration: None,
+                };
+                tempo_segments.push(segment);
+                linear_flags.push(m.linear_tempo);
+                segment
+            };
+            if let Some(time_signature) = m.time_signature {
+                let new_segment = TimeSigSegment {
+                    start_qn: segment.start_qn,
+                    measure_index: m.measure_index,
+                    time_signature,
+                };
+                let last = time_sig_segments.last_mut().expect("impossible");
+                if new_segment.start_qn <= last.start_qn {
+                    *last = new_segment;
+                } else {
+                    time_sig_segments.push(new_segment);
+                }
+            }
+        }
+        TempoMap {
+            markers,
+            tempo_segments,
+            time_sig_segments,
+        }
+    }
+
+    /// Returns the markers sorted by position.
+    pub fn markers(&self) -> impl ExactSizeIterator<Item = &TempoTimeSigMarker> + '_ {
+        self.markers.iter()
+    }
+
+    pub fn tempo_at(&self, time: PositionInSeconds) -> Bpm {
+        let segment = self.tempo_segment_at_time(time.get());
+        Bpm::new(segment.bpm_at(time.get() - segment.start_time))
+    }
+
+    pub fn time_signature_at(&self, time: PositionInSeconds) -> TimeSignature {
+        let qn = self.time_to_qn(time).get();
+        self.time_sig_segment_at_qn(qn).time_signature
+    }
+
+    pub fn time_to_qn(&self, time: PositionInSeconds) -> PositionInQuarterNotes {
+        let segment = self.tempo_segment_at_time(time.get());
+        let qn = segment.start_qn + segment.qn_delta(time.get() - segment.start_time);
+        PositionInQuarterNotes::new(qn)
+    }
+
+    pub fn qn_to_time(&self, qn: PositionInQuarterNotes) -> PositionInSeconds {
+        let segment = self
+            .tempo_segments
+            .iter()
+            .rev()
+            .find(|s| s.start_qn <= qn.get())
+            .unwrap_or(&self.tempo_segments[0]);
+        let time = segment.start_time + segment.time_delta(qn.get() - segment.start_qn);
+        PositionInSeconds::new(time)
+    }
+
+    pub fn qn_to_measure(&self, qn: PositionInQuarterNotes) -> MeasurePosition {
+        let segment = self.time_sig_segment_at_qn(qn.get());
+        let measures = (qn.get() - segment.start_qn) / segment.measure_length_in_qn();
+        let full_measures = (measures + ROUNDING_TOLERANCE).floor();
+        let beats =
+            ((measures - full_measures) * segment.time_signature.numerator.get() as f64).max(0.0);
+        MeasurePosition {
+            measure_index: segment.measure_index + full_measures as i32,
+            beats_since_measure: PositionInBeats::new(beats),
+        }
+    }
+
+    pub fn measure_to_qn(&self, position: MeasurePosition) -> PositionInQuarterNotes {
+        let segment = self
+            .time_sig_segments
+            .iter()
+            .rev()
+            .find(|s| s.measure_index <= position.measure_index)
+            .unwrap_or(&self.time_sig_segments[0]);
+        let measures = (position.measure_index - segment.measure_index) as f64;
+        let qn = segment.start_qn
+            + measures * segment.measure_length_in_qn()
+            + position.beats_since_measure.get() * segment.beat_length_in_qn();
+        PositionInQuarterNotes::new(qn)
+    }
+
+    pub fn time_to_measure(&self, time: PositionInSeconds) -> MeasurePosition {
+        self.qn_to_measure(self.time_to_qn(time))
+    }
+
+    pub fn measure_to_time(&self, position: MeasurePosition) -> PositionInSeconds {
+        self.qn_to_time(self.measure_to_qn(position))
+    }
+
+    fn tempo_segment_at_time(&self, time: f64) -> &TempoSegment {
+        self.tempo_segments
+            .iter()
+            .rev()
+            .find(|s| s.start_time <= time)
+            .unwrap_or(&self.tempo_segments[0])
+    }
+
+    fn time_sig_segment_at_qn(&self, qn: f64) -> &TimeSigSegment {
+        self.time_sig_segments
+            .iter()
+            .rev()
+            .find(|s| s.start_qn <= qn + ROUNDING_TOLERANCE)
+            .unwrap_or(&self.time_sig_segments[0])
+    }
+}
+
+const ROUNDING_TOLERANCE: f64 = 0.000_000_001;
+
+impl TempoSegment {
+    /// Tempo change per second (0.0 if not linear).
+    fn slope(&self) -> f64 {
+        match self.duration {
+            Some(d) if d > 0.0 => (self.end_bpm - self.start_bpm) / d,
+            _ => 0.0,
+        }
+    }
+
+    fn bpm_at(&self, time_delta: f64) -> f64 {
+        let time_delta = match self.duration {
+            Some(d) => time_delta.min(d),
+            None => time_delta,
+        };
+        self.start_bpm + self.slope() * time_delta.max(0.0)
+    }
+
+    fn qn_delta(&self, time_delta: f64) -> f64 {
+        (self.start_bpm * time_delta + self.slope() * time_delta * time_delta / 2.0) / 60.0
+    }
+
+    fn time_delta(&self, qn_delta: f64) -> f64 {
+        let beats_in_minutes = qn_delta * 60.0;
+        let slope = self.slope();
+        // Solves qn_delta() for the time delta in a numerically stable way
+        let discriminant =
+            (self.start_bpm * self.start_bpm + 2.0 * slope * beats_in_minutes).max(0.0);
+        2.0 * beats_in_minutes / (self.start_bpm + discriminant.sqrt())
+    }
+}
+
+impl TimeSigSegment {
+    fn beat_length_in_qn(&self) -> f64 {
+        4.0 / self.time_signature.denominator.get() as f64
+    }
+
+    fn measure_length_in_qn(&self) -> f64 {
+        self.time_signature.numerator.get() as f64 * self.beat_length_in_qn()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::NonZeroU32;
+
+    fn time_sig(numerator: u32, denominator: u32) -> TimeSignature {
+        TimeSignature {
+            numerator: NonZeroU32::new(numerator).unwrap(),
+            denominator: NonZeroU32::new(denominator).unwrap(),
+        }
+    }
+
+    fn marker(
+        secs: f64,
+        measure_index: i32,
+        bpm: f64,
+        time_signature: Option<TimeSignature>,
+        linear_tempo: bool,
+    ) -> TempoTimeSigMarker {
+        TempoTimeSigMarker {
+            position: PositionInSeconds::new(secs),
+            measure_index,
+            beats_since_measure: PositionInBeats::ZERO,
+            tempo: Bpm::new(bpm),
+            time_signature,
+            linear_tempo,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 0.000_001,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn constant_tempo() {
+        // Given
+        let map = TempoMap::new(Bpm::new(120.0), time_sig(4, 4), vec![]);
+        // Then
+        assert_close(map.time_to_qn(PositionInSeconds::new(1.0)).get(), 2.0);
+        assert_close(map.qn_to_time(PositionInQuarterNotes::new(6.0)).get(), 3.0);
+        let pos = map.qn_to_measure(PositionInQuarterNotes::new(6.0));
+        assert_eq!(pos.measure_index, 1);
+        assert_close(pos.beats_since_measure.get(), 2.0);
+        assert_close(map.measure_to_time(pos).get(), 3.0);
+    }
+
+    #[test]
+    fn linear_tempo_ramp() {
+        // Given
+        let map = TempoMap::new(
+            Bpm::new(120.0),
+            time_sig(4, 4),
+            vec![
+                marker(4.0, 1, 120.0, None, false),
+                marker(0.0, 0, 60.0, None, true),
+            ],
+        );
+        // Then
+        assert_eq!(map.markers().len(), 2);
+        assert_close(map.tempo_at(PositionInSeconds::new(2.0)).get(), 90.0);
+        assert_close(map.tempo_at(PositionInSeconds::new(10.0)).get(), 120.0);
+        assert_close(map.time_to_qn(PositionInSeconds::new(4.0)).get(), 6.0);
+        assert_close(map.time_to_qn(PositionInSeconds::new(5.0)).get(), 8.0);
+        let time = map.qn_to_time(map.time_to_qn(PositionInSeconds::new(2.5)));
+        assert_close(time.get(), 2.5);
+    }
+
+    #[test]
+    fn time_signature_change() {
+        // Given
+        let map = TempoMap::new(
+            Bpm::new(120.0),
+            time_sig(4, 4),
+            vec![marker(4.0, 2, 120.0, Some(time_sig(6, 8)), false)],
+        );
+        // Then
+        assert_eq!(
+            map.time_signature_at(PositionInSeconds::new(3.9)),
+            time_sig(4, 4)
+        );
+        assert_eq!(
+            map.time_signature_at(PositionInSeconds::new(4.0)),
+            time_sig(6, 8)
+        );
+        let pos = map.qn_to_measure(PositionInQuarterNotes::new(12.5));
+        assert_eq!(pos.measure_index, 3);
+        assert_close(pos.beats_since_measure.get(), 3.0);
+        assert_close(map.measure_to_qn(pos).get(), 12.5);
+        let pos = map.time_to_measure(PositionInSeconds::new(2.0));
+        assert_eq!(pos.measure_index, 1);
+        assert_close(pos.beats_since_measure.get(), 0.0);
+    }
+}
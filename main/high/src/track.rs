@@ -6,8 +6,8 @@ use crate::guid::Guid;
 use crate::track_route::TrackRoute;
 
 use crate::{
-    Chunk, ChunkRegion, Item, Pan, Project, Reaper, SendPartnerType, TrackRoutePartner, Volume,
-    Width,
+    AudioBlockSpec, AudioBlocks, Chunk, ChunkRegion, Envelope, Item, Pan, Project, Reaper,
+    ReaperError, ReaperResult, SendPartnerType, TrackRoutePartner, Volume, Width,
 };
 
 use reaper_medium::NotificationBehavior::NotifyAll;
@@ -16,10 +16,11 @@ use reaper_medium::SendTarget::OtherTrack;
 use reaper_medium::TrackAttributeKey::{RecArm, RecInput, RecMon, Selected, Solo};
 use reaper_medium::ValueChange::Absolute;
 use reaper_medium::{
-    AutomationMode, ChunkCacheHint, GangBehavior, GlobalAutomationModeOverride,
-    InputMonitoringMode, MediaTrack, ReaProject, ReaperFunctionError, ReaperString,
-    ReaperStringArg, RecordArmMode, RecordingInput, RgbColor, SoloMode, TrackArea,
-    TrackAttributeKey, TrackLocation, TrackSendCategory, TrackSendDirection,
+    AutomationMode, ChunkCacheHint, EnvChunkName, FolderCompactState, GangBehavior,
+    GlobalAutomationModeOverride, InputMonitoringMode, MediaTrack, PositionInSeconds, ReaProject,
+    ReaperFunctionError, ReaperString, ReaperStringArg, RecordArmMode, RecordingInput, RgbColor,
+    SoloMode, TrackArea, TrackAttributeKey, TrackFxLocation, TrackLocation, TrackSendCategory,
+    TrackSendDirection,
 };
 use std::convert::TryInto;
 use std::hash::{Hash, Hasher};
@@ -118,6 +119,21 @@ impl Track {
         Ok(Item::new(raw_item))
     }
 
+    /// Adds an item containing an empty MIDI take which spans the given time range.
+    pub fn add_midi_item(
+        &self,
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+    ) -> Result<Item, ReaperFunctionError> {
+        self.load_and_check_if_necessary_or_complain();
+        let raw_item = unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .create_new_midi_item_in_proj(self.raw(), start, end)?
+        };
+        Ok(Item::new(raw_item))
+    }
+
     // TODO-low It's really annoying to always have to unwrap an option even if we know this is not
     //  a master track. Maybe we should have different types: Track, MasterTrack, NormalTrack
     pub fn name(&self) -> Option<ReaperString> {
@@ -209,6 +225,16 @@ impl Track {
         self.media_track.get().unwrap()
     }
 
+    /// Returns an iterator which reads the audio of this track (post-FX) block by block.
+    pub fn audio_blocks(&self, spec: AudioBlockSpec) -> ReaperResult<AudioBlocks> {
+        let accessor = unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .create_track_audio_accessor(self.raw())?
+        };
+        AudioBlocks::new(accessor, spec)
+    }
+
     pub fn pan(&self) -> Pan {
         self.load_and_check_if_necessary_or_complain();
         // It's important that we don't query D_PAN because that returns the wrong value in case an
@@ -310,6 +336,74 @@ impl Track {
         result as _
     }
 
+    /// Returns the folder track which contains this track or `None` if this is a top-level track.
+    pub fn parent_track(&self) -> Option<Track> {
+        self.load_and_check_if_necessary_or_complain();
+        let raw = unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .get_set_media_track_info_get_par_track(self.raw())?
+        };
+        Some(Track::new(raw, self.rea_project.get()))
+    }
+
+    /// Returns the direct children of this track if it's a folder.
+    pub fn children(&self) -> Vec<Track> {
+        self.descendants_with_levels()
+            .into_iter()
+            .filter(|(_, level)| *level == 1)
+            .map(|(t, _)| t)
+            .collect()
+    }
+
+    /// Returns all tracks which are (directly or indirectly) contained in this folder track.
+    pub fn descendants(&self) -> Vec<Track> {
+        self.descendants_with_levels()
+            .into_iter()
+            .map(|(t, _)| t)
+            .collect()
+    }
+
+    /// Returns descendants together with their nesting level relative to this track.
+    fn descendants_with_levels(&self) -> Vec<(Track, i32)> {
+        let index = match self.index() {
+            None => return vec![],
+            Some(i) => i,
+        };
+        let mut level = self.folder_depth_change();
+        self.project()
+            .tracks()
+            .skip(index as usize + 1)
+            .map_while(|t| {
+                if level <= 0 {
+                    return None;
+                }
+                let track_level = level;
+                level += t.folder_depth_change();
+                Some((t, track_level))
+            })
+            .collect()
+    }
+
+    pub fn folder_compact_state(&self) -> FolderCompactState {
+        let result = unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .get_media_track_info_value(self.raw(), TrackAttributeKey::FolderCompact)
+        };
+        FolderCompactState::from_raw(result as i32)
+    }
+
+    pub fn set_folder_compact_state(&self, state: FolderCompactState) {
+        let _ = unsafe {
+            Reaper::get().medium_reaper().set_media_track_info_value(
+                self.raw(),
+                TrackAttributeKey::FolderCompact,
+                state.to_raw() as f64,
+            )
+        };
+    }
+
     pub fn channel_count(&self) -> u32 {
         let result = unsafe {
             Reaper::get()
@@ -319,6 +413,25 @@ impl Track {
         result as _
     }
 
+    /// Sets the number of track channels.
+    ///
+    /// Returns an error if the count is not an even number between 2 and 128.
+    pub fn set_channel_count(&self, count: u32) -> ReaperResult<()> {
+        if !(2..=128).contains(&count) || count & 1 != 0 {
+            return Err(ReaperError::new(
+                "channel count must be an even number between 2 and 128",
+            ));
+        }
+        unsafe {
+            Reaper::get().medium_reaper().set_media_track_info_value(
+                self.raw(),
+                TrackAttributeKey::Nchan,
+                count as f64,
+            )?;
+        }
+        Ok(())
+    }
+
     pub fn volume(&self) -> Volume {
         // It's important that we don't query D_VOL because that returns the wrong value in case an
         // envelope is written
@@ -920,6 +1033,12 @@ impl Track {
     // FX. The FX could move. So this should do a runtime lookup of the FX and return a stable
     // GUID-backed Fx object if an FX exists at that query index.
     pub fn fx_by_query_index(&self, query_index: i32) -> Option<Fx> {
+        if let TrackFxLocation::ContainerFx(_) = TrackFxLocation::from_raw(query_index) {
+            // The container address can only be resolved by searching the container tree
+            return [self.normal_fx_chain(), self.input_fx_chain()]
+                .iter()
+                .find_map(|chain| find_container_fx(chain, query_index));
+        }
         let (index, is_input_fx) = get_index_from_query_index(query_index);
         let fx_chain = if is_input_fx {
             self.input_fx_chain()
@@ -1073,6 +1192,26 @@ impl Track {
         }
     }
 
+    pub fn envelope_by_chunk_name(&self, chunk_name: EnvChunkName) -> Option<Envelope> {
+        self.load_and_check_if_necessary_or_complain();
+        let raw = unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .get_track_envelope_by_chunk_name(self.raw(), chunk_name)?
+        };
+        Some(Envelope::new(raw))
+    }
+
+    pub fn envelope_by_name<'a>(&self, name: impl Into<ReaperStringArg<'a>>) -> Option<Envelope> {
+        self.load_and_check_if_necessary_or_complain();
+        let raw = unsafe {
+            Reaper::get()
+                .medium_reaper()
+                .get_track_envelope_by_name(self.raw(), name)?
+        };
+        Some(Envelope::new(raw))
+    }
+
     pub fn normal_fx_chain(&self) -> FxChain {
         FxChain::from_track(self.clone(), false)
     }
@@ -1150,3 +1289,13 @@ fn get_show_attribute_key(track_area: TrackArea) -> TrackAttributeKey<'static> {
         Mcp => TrackAttributeKey::ShowInMixer,
     }
 }
+
+fn find_container_fx(chain: &FxChain, query_index: i32) -> Option<Fx> {
+    chain.fxs().find_map(|fx| {
+        let children = fx.container_chain()?;
+        let found = children
+            .fxs()
+            .find(|child| child.query_index().to_raw() == query_index);
+        found.or_else(|| find_container_fx(&children, query_index))
+    })
+}
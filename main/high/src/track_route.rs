@@ -1,8 +1,8 @@
-use crate::{Pan, Reaper, Track, Volume};
+use crate::{Envelope, Pan, Reaper, Track, Volume};
 
 use reaper_medium::{
-    AutomationMode, EditMode, MediaTrack, ReaperFunctionError, ReaperString, TrackSendAttributeKey,
-    TrackSendCategory, TrackSendDirection, TrackSendRef, VolumeAndPan,
+    AutomationMode, EditMode, EnvChunkName, MediaTrack, ReaperFunctionError, ReaperString,
+    TrackSendAttributeKey, TrackSendCategory, TrackSendDirection, TrackSendRef, VolumeAndPan,
 };
 use std::fmt;
 use TrackSendDirection::*;
@@ -218,6 +218,20 @@ impl TrackRoute {
         self.set_prop_enabled(TrackSendAttributeKey::Phase, inverted);
     }
 
+    /// Supported are volume, pan and mute envelopes.
+    pub fn envelope(&self, chunk_name: EnvChunkName) -> Option<Envelope> {
+        let (category, index) = self.category_with_index();
+        let raw = unsafe {
+            Reaper::get().medium_reaper().get_track_send_info_env(
+                self.track().raw(),
+                category,
+                index,
+                chunk_name,
+            )?
+        };
+        Some(Envelope::new(raw))
+    }
+
     pub fn set_automation_mode(&self, mode: AutomationMode) {
         self.set_prop_numeric_value(TrackSendAttributeKey::AutoMode, mode.to_raw() as _);
     }
@@ -170,6 +170,10 @@ mod codegen {
                 .whitelist_var("OPAQUE")
                 .whitelist_type("HINSTANCE")
                 .whitelist_type("reaper_plugin_info_t")
+                .whitelist_type("custom_action_register_t")
+                .whitelist_type("action_help_t")
+                .whitelist_type("reaper_csurf_reg_t")
+                .whitelist_type("project_config_extension_t")
                 .whitelist_type("gaccel_register_t")
                 .whitelist_type("accelerator_register_t")
                 .whitelist_type("audio_hook_register_t")
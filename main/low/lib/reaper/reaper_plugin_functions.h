@@ -1139,6 +1139,14 @@ REAPERAPI_DEF //==============================================
   const char* (*EnumerateSubdirectories)(const char* path, int subdirindex);
 #endif
 
+#if defined(REAPERAPI_WANT_EnumInstalledFX) || !defined(REAPERAPI_MINIMAL)
+REAPERAPI_DEF //==============================================
+// EnumInstalledFX
+// Enumerates installed FX. Returns true if successful, sets nameOut and identOut to name and ident of FX at index.
+
+  bool (*EnumInstalledFX)(int index, const char** nameOut, const char** identOut);
+#endif
+
 #if defined(REAPERAPI_WANT_EnumPitchShiftModes) || !defined(REAPERAPI_MINIMAL)
 REAPERAPI_DEF //==============================================
 // EnumPitchShiftModes
@@ -7545,6 +7553,9 @@ REAPERAPI_DEF //==============================================
       #if defined(REAPERAPI_WANT_EnumerateSubdirectories) || !defined(REAPERAPI_MINIMAL)
         {(void**)&EnumerateSubdirectories,"EnumerateSubdirectories"},
       #endif
+      #if defined(REAPERAPI_WANT_EnumInstalledFX) || !defined(REAPERAPI_MINIMAL)
+        {(void**)&EnumInstalledFX,"EnumInstalledFX"},
+      #endif
       #if defined(REAPERAPI_WANT_EnumPitchShiftModes) || !defined(REAPERAPI_MINIMAL)
         {(void**)&EnumPitchShiftModes,"EnumPitchShiftModes"},
       #endif
@@ -1172,6 +1172,19 @@ pub mod root {
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+    pub struct custom_action_register_t {
+        pub uniqueSectionId: ::std::os::raw::c_int,
+        pub idStr: *const ::std::os::raw::c_char,
+        pub name: *const ::std::os::raw::c_char,
+        pub extra: *mut ::std::os::raw::c_void,
+    }
+    impl Default for custom_action_register_t {
+        fn default() -> Self {
+            unsafe { ::std::mem::zeroed() }
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
     pub struct gaccel_register_t {
         pub accel: root::ACCEL,
         pub desc: *const ::std::os::raw::c_char,
@@ -1183,6 +1196,45 @@ pub mod root {
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+    pub struct action_help_t {
+        pub action_desc: *const ::std::os::raw::c_char,
+        pub action_help: *const ::std::os::raw::c_char,
+    }
+    impl Default for action_help_t {
+        fn default() -> Self {
+            unsafe { ::std::mem::zeroed() }
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+    pub struct project_config_extension_t {
+        pub ProcessExtensionLine: ::std::option::Option<
+            unsafe extern "C" fn(
+                line: *const ::std::os::raw::c_char,
+                ctx: *mut root::ProjectStateContext,
+                isUndo: bool,
+                reg: *mut root::project_config_extension_t,
+            ) -> bool,
+        >,
+        pub SaveExtensionConfig: ::std::option::Option<
+            unsafe extern "C" fn(
+                ctx: *mut root::ProjectStateContext,
+                isUndo: bool,
+                reg: *mut root::project_config_extension_t,
+            ),
+        >,
+        pub BeginLoadProjectState: ::std::option::Option<
+            unsafe extern "C" fn(isUndo: bool, reg: *mut root::project_config_extension_t),
+        >,
+        pub userData: *mut ::std::os::raw::c_void,
+    }
+    impl Default for project_config_extension_t {
+        fn default() -> Self {
+            unsafe { ::std::mem::zeroed() }
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
     pub struct audio_hook_register_t {
         pub OnAudioBuffer: ::std::option::Option<
             unsafe extern "C" fn(
@@ -1323,6 +1375,31 @@ pub mod root {
             unsafe { ::std::mem::zeroed() }
         }
     }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+    pub struct reaper_csurf_reg_t {
+        pub type_string: *const ::std::os::raw::c_char,
+        pub desc_string: *const ::std::os::raw::c_char,
+        pub create: ::std::option::Option<
+            unsafe extern "C" fn(
+                type_string: *const ::std::os::raw::c_char,
+                configString: *const ::std::os::raw::c_char,
+                errStats: *mut ::std::os::raw::c_int,
+            ) -> *mut root::IReaperControlSurface,
+        >,
+        pub ShowConfig: ::std::option::Option<
+            unsafe extern "C" fn(
+                type_string: *const ::std::os::raw::c_char,
+                parent: root::HWND,
+                initConfigString: *const ::std::os::raw::c_char,
+            ) -> root::HWND,
+        >,
+    }
+    impl Default for reaper_csurf_reg_t {
+        fn default() -> Self {
+            unsafe { ::std::mem::zeroed() }
+        }
+    }
     pub mod reaper_functions {
         #[allow(unused_imports)]
         use self::super::super::root;
@@ -2442,6 +2519,16 @@ pub mod root {
                 ) -> *const ::std::os::raw::c_char,
             >;
         }
+        extern "C" {
+            #[link_name = "\u{1}_ZN16reaper_functions15EnumInstalledFXE"]
+            pub static mut EnumInstalledFX: ::std::option::Option<
+                unsafe extern "C" fn(
+                    index: ::std::os::raw::c_int,
+                    nameOut: *mut *const ::std::os::raw::c_char,
+                    identOut: *mut *const ::std::os::raw::c_char,
+                ) -> bool,
+            >;
+        }
         extern "C" {
             #[link_name = "\u{1}_ZN16reaper_functions19EnumPitchShiftModesE"]
             pub static mut EnumPitchShiftModes: ::std::option::Option<
@@ -11227,9 +11314,24 @@ pub mod root {
                 callback_target: *mut ::std::os::raw::c_void,
             ) -> *mut root::IReaperControlSurface;
         }
+        extern "C" {
+            pub fn create_owning_cpp_to_rust_control_surface(
+                callback_target: *mut ::std::os::raw::c_void,
+            ) -> *mut root::IReaperControlSurface;
+        }
         extern "C" {
             pub fn delete_control_surface(surface: *mut root::IReaperControlSurface);
         }
+        extern "C" {
+            pub fn rust_to_cpp_IReaperControlSurface_GetConfigString(
+                self_: *mut root::IReaperControlSurface,
+            ) -> *const ::std::os::raw::c_char;
+        }
+        extern "C" {
+            pub fn cpp_to_rust_IReaperControlSurface_Drop(
+                callback_target: *mut ::std::os::raw::c_void,
+            );
+        }
         extern "C" {
             pub fn cpp_to_rust_IReaperControlSurface_GetTypeString(
                 callback_target: *mut ::std::os::raw::c_void,
@@ -5,7 +5,7 @@ namespace reaper_control_surface {
 
   // This surface just delegates to the free functions implemented in Rust. See header file for an explanation.
   class CppToRustControlSurface : public IReaperControlSurface {
-  private:
+  protected:
     // This pointer points to a Box in Rust which holds an IReaperControlSurface trait implementation.
     void* callback_target_;
   public:
@@ -77,11 +77,31 @@ namespace reaper_control_surface {
     }
   };
 
+  // Same as CppToRustControlSurface but owns the Rust side. Used for control surfaces which are created and destroyed
+  // by REAPER itself (e.g. instances of control surface types registered via "csurf").
+  class OwningCppToRustControlSurface : public CppToRustControlSurface {
+  public:
+    OwningCppToRustControlSurface(void* callback_target) : CppToRustControlSurface(callback_target) {
+    }
+
+    virtual ~OwningCppToRustControlSurface() {
+      ::reaper_control_surface::cpp_to_rust_IReaperControlSurface_Drop(this->callback_target_);
+    }
+  };
+
   IReaperControlSurface* create_cpp_to_rust_control_surface(void* callback_target) {
     return new CppToRustControlSurface(callback_target);
   }
 
+  IReaperControlSurface* create_owning_cpp_to_rust_control_surface(void* callback_target) {
+    return new OwningCppToRustControlSurface(callback_target);
+  }
+
   void delete_control_surface(IReaperControlSurface* surface) {
     delete surface;
   }
+
+  const char* rust_to_cpp_IReaperControlSurface_GetConfigString(IReaperControlSurface* self) {
+    return self->GetConfigString();
+  }
 }
\ No newline at end of file
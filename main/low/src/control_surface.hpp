@@ -9,10 +9,19 @@ namespace reaper_control_surface {
   // its address to Rust.
   extern "C" IReaperControlSurface* create_cpp_to_rust_control_surface(void* callback_target);
 
+  // This function is called from Rust and implemented in C++. Like create_cpp_to_rust_control_surface() but the
+  // returned C++ object takes ownership of the Rust callback target and drops it when destroyed.
+  extern "C" IReaperControlSurface* create_owning_cpp_to_rust_control_surface(void* callback_target);
+
   // This function is called from Rust and implemented in C++. It destroys the given C++ IReaperControlSurface object.
   extern "C" void delete_control_surface(IReaperControlSurface* surface);
 
+  // This function is called from Rust and implemented in C++. It calls GetConfigString() on the given C++
+  // IReaperControlSurface object.
+  extern "C" const char* rust_to_cpp_IReaperControlSurface_GetConfigString(IReaperControlSurface* self);
+
   // All of the following functions are called from C++ and implemented in Rust.
+  extern "C" void cpp_to_rust_IReaperControlSurface_Drop(void* callback_target);
   extern "C" const char* cpp_to_rust_IReaperControlSurface_GetTypeString(void* callback_target);
   extern "C" const char* cpp_to_rust_IReaperControlSurface_GetDescString(void* callback_target);
   extern "C" const char* cpp_to_rust_IReaperControlSurface_GetConfigString(void* callback_target);
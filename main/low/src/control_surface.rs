@@ -6,7 +6,7 @@ use crate::raw;
 
 use downcast_rs::Downcast;
 use std::fmt::Debug;
-use std::os::raw::c_void;
+use std::os::raw::{c_char, c_void};
 use std::ptr::{null, null_mut, NonNull};
 
 /// This is the Rust analog to the C++ virtual base class `IReaperControlSurface`.
@@ -172,6 +172,30 @@ pub unsafe fn create_cpp_to_rust_control_surface(
     NonNull::new_unchecked(instance)
 }
 
+/// Like [`create_cpp_to_rust_control_surface()`] but hands ownership of the Rust control surface
+/// over to the returned C++ object.
+///
+/// The Rust control surface is dropped as soon as the C++ object is destroyed. This is needed for
+/// control surfaces which are instantiated and destroyed by REAPER itself, e.g. instances of control
+/// surface types registered via `plugin_register("csurf", ...)`.
+///
+/// # Safety
+///
+/// The returned object must be destroyed exactly once, either by REAPER or by calling
+/// [`delete_cpp_control_surface()`].
+///
+/// [`create_cpp_to_rust_control_surface()`]: fn.create_cpp_to_rust_control_surface.html
+/// [`delete_cpp_control_surface()`]: fn.delete_cpp_control_surface.html
+pub unsafe fn create_owning_cpp_to_rust_control_surface(
+    callback_target: Box<Box<dyn IReaperControlSurface>>,
+) -> NonNull<raw::IReaperControlSurface> {
+    let instance =
+        crate::bindings::root::reaper_control_surface::create_owning_cpp_to_rust_control_surface(
+            Box::into_raw(callback_target) as *mut c_void,
+        );
+    NonNull::new_unchecked(instance)
+}
+
 /// Destroys a C++ `IReaperControlSurface` object.
 ///
 /// Intended to be used on pointers returned from [`create_cpp_to_rust_control_surface()`].
@@ -186,6 +210,25 @@ pub unsafe fn delete_cpp_control_surface(surface: NonNull<raw::IReaperControlSur
     crate::bindings::root::reaper_control_surface::delete_control_surface(surface.as_ptr());
 }
 
+impl raw::IReaperControlSurface {
+    pub fn GetConfigString(&self) -> *const c_char {
+        unsafe {
+            crate::bindings::root::reaper_control_surface::rust_to_cpp_IReaperControlSurface_GetConfigString(
+                self as *const _ as _,
+            )
+        }
+    }
+}
+
+#[no_mangle]
+extern "C" fn cpp_to_rust_IReaperControlSurface_Drop(
+    callback_target: *mut Box<dyn IReaperControlSurface>,
+) {
+    firewall(|| unsafe {
+        drop(Box::from_raw(callback_target));
+    });
+}
+
 #[no_mangle]
 extern "C" fn cpp_to_rust_IReaperControlSurface_GetTypeString(
     callback_target: *mut Box<dyn IReaperControlSurface>,
@@ -3,8 +3,8 @@
 namespace reaper_project_state_context {
   // Rust -> C++
   void rust_to_cpp_ProjectStateContext_AddLine(ProjectStateContext* self, const char* line) {
-    // TODO-high This can't work. Wait for variadics support in stable Rust.
-//    self->AddLine(line);
+    // We don't pass the line as format string because it could contain format specifiers.
+    self->AddLine("%s", line);
   }
   int rust_to_cpp_ProjectStateContext_GetLine(ProjectStateContext* self, char* buf, int buflen) {
     return self->GetLine(buf, buflen);
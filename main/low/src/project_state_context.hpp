@@ -21,7 +21,6 @@ namespace reaper_project_state_context {
   // All the following functions are called from Rust and implemented in C++. The implementation simply delegates
   // to the respective method of the `self` object. This glue code is necessary because Rust can't call  C++ pure 
   // virtual functions directly.
-  // TODO-high This can't work. Wait for variadics support in stable Rust.
   extern "C" void rust_to_cpp_ProjectStateContext_AddLine(ProjectStateContext* self, const char *line);
   extern "C" int rust_to_cpp_ProjectStateContext_GetLine(ProjectStateContext* self, char *buf, int buflen);
   extern "C" INT64 rust_to_cpp_ProjectStateContext_GetOutputSize(ProjectStateContext* self);
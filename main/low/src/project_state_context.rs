@@ -8,7 +8,7 @@ use std::os::raw::c_void;
 use std::ptr::NonNull;
 
 impl raw::ProjectStateContext {
-    /// Attention: Not really usable yet due to the lack of the variadic parameter in AddLine.
+    /// Adds the given line as is (it's not interpreted as format string).
     ///
     /// # Safety
     ///
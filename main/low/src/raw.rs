@@ -1,12 +1,13 @@
 //! Exposes important raw types, functions and constants from the C++ REAPER API.
 #![allow(non_camel_case_types)]
 
-use std::os::raw::{c_int, c_void};
+use std::os::raw::{c_char, c_int, c_void};
 
 /// Structs, types and constants defined by REAPER.
 pub use super::bindings::root::{
-    accelerator_register_t, audio_hook_register_t, gaccel_register_t, midi_Input, midi_Output,
-    midi_realtime_write_struct_t, preview_register_t, reaper_plugin_info_t, IReaperControlSurface,
+    accelerator_register_t, action_help_t, audio_hook_register_t, custom_action_register_t,
+    gaccel_register_t, midi_Input, midi_Output, midi_realtime_write_struct_t, preview_register_t,
+    project_config_extension_t, reaper_csurf_reg_t, reaper_plugin_info_t, IReaperControlSurface,
     IReaperPitchShift, KbdCmd, KbdSectionInfo, MIDI_event_t, MIDI_eventlist, MediaItem,
     MediaItem_Take, MediaTrack, PCM_sink, PCM_source, PCM_source_peaktransfer_t,
     PCM_source_transfer_t, ProjectStateContext, REAPER_Resample_Interface, ReaProject, ReaSample,
@@ -22,6 +23,9 @@ pub use super::bindings::root::{
     UNDO_STATE_MISCCFG, UNDO_STATE_TRACKCFG,
 };
 
+/// Opaque audio accessor defined by REAPER.
+pub use super::bindings::root::reaper_functions::AudioAccessor;
+
 /// Structs, types and constants defined by `swell.h` (on Linux and Mac OS X) and
 /// `windows.h` (on Windows).
 ///
@@ -173,6 +177,9 @@ pub type ToggleAction = extern "C" fn(command_id: c_int) -> c_int;
 /// Function pointer type for getting notified about invocation of hook command.
 pub type HookPostCommand = extern "C" fn(command_id: c_int, flag: c_int);
 
+/// Function pointer type for getting notified when a custom menu is initialized or shown.
+pub type HookCustomMenu = extern "C" fn(menuidstr: *const c_char, menu: *mut c_void, flag: c_int);
+
 /// Function pointer type for timers.
 pub type TimerFunction = extern "C" fn();
 
@@ -0,0 +1,57 @@
+use crate::{ReaperStr, ReaperStringArg};
+use reaper_low::raw;
+use reaper_low::raw::action_help_t;
+use std::borrow::Cow;
+
+/// Help text for an action.
+///
+/// Contains the action description (which identifies the action) and the help text shown in the
+/// action list.
+//
+// Case 2: Internals exposed: yes | vtable: no
+// ===========================================
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct OwnedActionHelpRegister {
+    owned_desc: Cow<'static, ReaperStr>,
+    owned_help: Cow<'static, ReaperStr>,
+    inner: action_help_t,
+}
+
+impl OwnedActionHelpRegister {
+    /// Creates a help text descriptor.
+    ///
+    /// REAPER maps the help text to the action by its description, so `action_desc` must be
+    /// exactly the description which has been used to register the action.
+    pub fn new(
+        action_desc: impl Into<ReaperStringArg<'static>>,
+        help: impl Into<ReaperStringArg<'static>>,
+    ) -> OwnedActionHelpRegister {
+        let desc = action_desc.into().into_inner();
+        let help = help.into().into_inner();
+        let inner = raw::action_help_t {
+            action_desc: desc.as_ptr(),
+            action_help: help.as_ptr(),
+        };
+        OwnedActionHelpRegister {
+            owned_desc: desc,
+            owned_help: help,
+            inner,
+        }
+    }
+
+    /// Returns the description of the action.
+    pub fn action_desc(&self) -> &ReaperStr {
+        &self.owned_desc
+    }
+
+    /// Returns the help text.
+    pub fn help(&self) -> &ReaperStr {
+        &self.owned_help
+    }
+}
+
+impl AsRef<raw::action_help_t> for OwnedActionHelpRegister {
+    fn as_ref(&self) -> &action_help_t {
+        &self.inner
+    }
+}
@@ -0,0 +1,50 @@
+use crate::AudioAccessor;
+use reaper_low::raw;
+
+/// Owned audio accessor.
+///
+/// This one automatically destroys the associated audio accessor when dropped.
+#[derive(Debug)]
+pub struct OwnedAudioAccessor {
+    raw: AudioAccessor,
+    destroy: unsafe extern "C" fn(accessor: *mut raw::AudioAccessor),
+}
+
+impl OwnedAudioAccessor {
+    /// Takes ownership of the given audio accessor.
+    ///
+    /// # Safety
+    ///
+    /// You must guarantee that the given accessor is currently owner-less, otherwise double-free or
+    /// use-after-free can occur.
+    pub(crate) unsafe fn from_raw(
+        raw: AudioAccessor,
+        destroy: unsafe extern "C" fn(accessor: *mut raw::AudioAccessor),
+    ) -> Self {
+        Self { raw, destroy }
+    }
+
+    /// Returns the pointer to this audio accessor.
+    pub fn as_ptr(&self) -> AudioAccessor {
+        self.raw
+    }
+
+    /// Returns the inner pointer **without** destroying the accessor.
+    ///
+    /// # Safety
+    ///
+    /// You can run into a memory leak or crash if you don't manage the lifetime of the returned
+    /// accessor correctly (it must be destroyed using `DestroyAudioAccessor()`).
+    pub unsafe fn leak(self) -> AudioAccessor {
+        let manually_dropped = std::mem::ManuallyDrop::new(self);
+        manually_dropped.raw
+    }
+}
+
+impl Drop for OwnedAudioAccessor {
+    fn drop(&mut self) {
+        unsafe {
+            (self.destroy)(self.raw.as_ptr());
+        }
+    }
+}
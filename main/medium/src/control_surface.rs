@@ -3,8 +3,8 @@
 use super::MediaTrack;
 use crate::{
     require_non_null_panic, AutomationMode, Bpm, Hidden, InputMonitoringMode, Pan, PanMode,
-    PlaybackSpeedFactor, ReaperNormalizedFxParamValue, ReaperPanValue, ReaperStr, ReaperVersion,
-    ReaperVolumeValue, TrackFxChainType, TrackFxLocation,
+    PlaybackSpeedFactor, ReaperNormalizedFxParamValue, ReaperPanValue, ReaperStr, ReaperString,
+    ReaperVersion, ReaperVolumeValue, TrackFxChainType, TrackFxLocation,
 };
 use std::borrow::Cow;
 
@@ -640,7 +640,8 @@ pub enum VersionDependentTrackFxLocation {
     Old(u32),
     /// This is REAPER >= 5.95.
     ///
-    /// It's possible to distinguish between input and output FX.
+    /// It's possible to distinguish between input and output FX. In REAPER 7+, this can also be
+    /// an FX within an FX container.
     New(TrackFxLocation),
 }
 
@@ -659,6 +660,10 @@ pub(crate) struct ControlSurfaceAdapter {
     // Capabilities depending on REAPER version
     supports_detection_of_input_fx: bool,
     supports_detection_of_input_fx_in_set_fx_change: bool,
+    // Reported to REAPER if the delegate doesn't provide them itself (relevant for instances of
+    // registered control surface types, which REAPER persists using these strings)
+    fallback_type_string: Option<ReaperString>,
+    fallback_config_string: Option<ReaperString>,
 }
 
 impl ControlSurfaceAdapter {
@@ -673,9 +678,23 @@ impl ControlSurfaceAdapter {
             supports_detection_of_input_fx: reaper_version >= &reaper_version_5_95,
             // since pre2 to be accurate but so what
             supports_detection_of_input_fx_in_set_fx_change: reaper_version >= &reaper_version_5_95,
+            fallback_type_string: None,
+            fallback_config_string: None,
         }
     }
 
+    /// Makes the adapter report the given type and config string if the delegate doesn't
+    /// provide them itself.
+    pub fn with_fallback_strings(
+        mut self,
+        type_string: ReaperString,
+        config_string: ReaperString,
+    ) -> ControlSurfaceAdapter {
+        self.fallback_type_string = Some(type_string);
+        self.fallback_config_string = Some(config_string);
+        self
+    }
+
     pub fn into_delegate(self) -> Box<dyn ControlSurface> {
         self.delegate
     }
@@ -723,6 +742,7 @@ impl reaper_low::IReaperControlSurface for ControlSurfaceAdapter {
     fn GetTypeString(&self) -> *const c_char {
         self.delegate
             .get_type_string()
+            .or(self.fallback_type_string.as_deref())
             .map(|o| o.as_ptr())
             .unwrap_or(null_mut())
     }
@@ -737,6 +757,7 @@ impl reaper_low::IReaperControlSurface for ControlSurfaceAdapter {
     fn GetConfigString(&self) -> *const c_char {
         self.delegate
             .get_config_string()
+            .or(self.fallback_config_string.as_deref())
             .map(|o| o.as_ptr())
             .unwrap_or(null_mut())
     }
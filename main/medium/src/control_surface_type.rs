@@ -0,0 +1,167 @@
+use crate::{
+    ControlSurface, ControlSurfaceAdapter, ControlSurfaceDeviceError, Hwnd, ReaperStr,
+    ReaperStringArg, ReaperVersion,
+};
+use enumflags2::BitFlags;
+use once_cell::sync::OnceCell;
+use reaper_low::raw::reaper_csurf_reg_t;
+use reaper_low::{create_owning_cpp_to_rust_control_surface, firewall, raw};
+use std::borrow::Cow;
+use std::os::raw::{c_char, c_int};
+use std::ptr::{null_mut, NonNull};
+
+/// Consumers need to implement this trait in order to offer a control surface type which users
+/// can select in *Preferences → Control/OSC/web*.
+///
+/// See [`plugin_register_add_csurf()`].
+///
+/// [`plugin_register_add_csurf()`]: struct.ReaperSession.html#method.plugin_register_add_csurf
+pub trait ControlSurfaceType {
+    /// The control surface which is instantiated by REAPER for each configured instance of this
+    /// type.
+    type ControlSurface: ControlSurface + 'static;
+
+    /// Creates a new instance of this control surface type.
+    ///
+    /// `config_string` is the string which has been produced by the configuration dialog (see
+    /// [`show_config()`]) or restored from the REAPER configuration. It's reported back to REAPER
+    /// unchanged unless the control surface returns something else from
+    /// [`ControlSurface::get_config_string()`]. The same goes for the type string.
+    ///
+    /// Devices which couldn't be opened should be added to `device_errors`.
+    ///
+    /// Return `None` if instantiating is not supported.
+    ///
+    /// [`show_config()`]: #method.show_config
+    /// [`ControlSurface::get_config_string()`]:
+    /// trait.ControlSurface.html#method.get_config_string
+    fn create(
+        config_string: &ReaperStr,
+        device_errors: &mut BitFlags<ControlSurfaceDeviceError>,
+    ) -> Option<Self::ControlSurface>;
+
+    /// Creates the configuration dialog as child of the given parent window.
+    ///
+    /// When the user confirms the preferences, REAPER sends `WM_USER + 1024` to the returned
+    /// window in order to obtain the new config string. `wParam` points to the buffer which should
+    /// be filled and `lParam` contains its size.
+    ///
+    /// Return `None` if this control surface type doesn't need configuration.
+    fn show_config(parent: Hwnd, config_string: &ReaperStr) -> Option<Hwnd> {
+        let _ = (parent, config_string);
+        None
+    }
+}
+
+/// A control surface type descriptor.
+///
+/// Contains type string, description and the callbacks of a [`ControlSurfaceType`].
+///
+/// [`ControlSurfaceType`]: trait.ControlSurfaceType.html
+//
+// Case 2: Internals exposed: yes | vtable: no
+// ===========================================
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct OwnedCsurfRegister {
+    owned_type_string: Cow<'static, ReaperStr>,
+    owned_desc_string: Cow<'static, ReaperStr>,
+    inner: reaper_csurf_reg_t,
+}
+
+impl OwnedCsurfRegister {
+    /// Creates a control surface type descriptor.
+    ///
+    /// The type string must be a simple unique string with only A-Z, 0-9, no spaces or other
+    /// characters. The description is shown in the list of available control surface types.
+    pub fn new<T: ControlSurfaceType>(
+        type_string: impl Into<ReaperStringArg<'static>>,
+        description: impl Into<ReaperStringArg<'static>>,
+    ) -> OwnedCsurfRegister {
+        let type_string = type_string.into().into_inner();
+        let desc_string = description.into().into_inner();
+        let inner = raw::reaper_csurf_reg_t {
+            type_string: type_string.as_ptr(),
+            desc_string: desc_string.as_ptr(),
+            create: Some(delegating_create_control_surface::<T>),
+            ShowConfig: Some(delegating_show_control_surface_config::<T>),
+        };
+        OwnedCsurfRegister {
+            owned_type_string: type_string,
+            owned_desc_string: desc_string,
+            inner,
+        }
+    }
+}
+
+impl AsRef<raw::reaper_csurf_reg_t> for OwnedCsurfRegister {
+    fn as_ref(&self) -> &reaper_csurf_reg_t {
+        &self.inner
+    }
+}
+
+/// The control surface adapter needs to know the REAPER version but the create callback doesn't
+/// give us any context. That's why we remember the version when registering the first type.
+static REAPER_VERSION: OnceCell<ReaperVersion<'static>> = OnceCell::new();
+
+pub(crate) fn remember_reaper_version(version: ReaperVersion<'static>) {
+    let _ = REAPER_VERSION.set(version);
+}
+
+extern "C" fn delegating_create_control_surface<T: ControlSurfaceType>(
+    type_string: *const c_char,
+    config_string: *const c_char,
+    err_stats: *mut c_int,
+) -> *mut raw::IReaperControlSurface {
+    firewall(|| {
+        if type_string.is_null() {
+            return null_mut();
+        }
+        let type_string = unsafe { ReaperStr::from_ptr(type_string) };
+        let config_string = if config_string.is_null() {
+            reaper_str!("")
+        } else {
+            unsafe { ReaperStr::from_ptr(config_string) }
+        };
+        let mut device_errors = BitFlags::empty();
+        let control_surface = T::create(config_string, &mut device_errors);
+        if !err_stats.is_null() {
+            unsafe { *err_stats |= device_errors.bits() as c_int };
+        }
+        let (control_surface, reaper_version) = match (control_surface, REAPER_VERSION.get()) {
+            (Some(cs), Some(v)) => (cs, v),
+            _ => return null_mut(),
+        };
+        let adapter = ControlSurfaceAdapter::new(Box::new(control_surface), reaper_version)
+            .with_fallback_strings(
+                type_string.to_reaper_string(),
+                config_string.to_reaper_string(),
+            );
+        // REAPER owns the instance from now on and destroys it when the user removes it.
+        let cpp_cs =
+            unsafe { create_owning_cpp_to_rust_control_surface(Box::new(Box::new(adapter))) };
+        cpp_cs.as_ptr()
+    })
+    .unwrap_or(null_mut())
+}
+
+extern "C" fn delegating_show_control_surface_config<T: ControlSurfaceType>(
+    _type_string: *const c_char,
+    parent: raw::HWND,
+    init_config_string: *const c_char,
+) -> raw::HWND {
+    firewall(|| {
+        let parent = match NonNull::new(parent) {
+            None => return null_mut(),
+            Some(p) => p,
+        };
+        let config_string = if init_config_string.is_null() {
+            reaper_str!("")
+        } else {
+            unsafe { ReaperStr::from_ptr(init_config_string) }
+        };
+        T::show_config(parent, config_string)
+            .map(|hwnd| hwnd.as_ptr())
+            .unwrap_or(null_mut())
+    })
+    .unwrap_or(null_mut())
+}
@@ -0,0 +1,83 @@
+use crate::{CommandId, ReaperStr, ReaperStringArg, SectionId};
+use reaper_low::raw;
+use reaper_low::raw::custom_action_register_t;
+use std::borrow::Cow;
+use std::ptr::{null_mut, NonNull};
+
+/// An action descriptor for an arbitrary section.
+///
+/// Contains section ID, unique command name and description.
+//
+// Case 2: Internals exposed: yes | vtable: no
+// ===========================================
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct OwnedCustomActionRegister {
+    owned_id_str: Cow<'static, ReaperStr>,
+    owned_name: Cow<'static, ReaperStr>,
+    inner: custom_action_register_t,
+}
+
+impl OwnedCustomActionRegister {
+    /// Creates an action descriptor.
+    ///
+    /// The command name must be unique across all sections.
+    pub fn new(
+        section_id: SectionId,
+        command_name: impl Into<ReaperStringArg<'static>>,
+        description: impl Into<ReaperStringArg<'static>>,
+    ) -> OwnedCustomActionRegister {
+        let id_str = command_name.into().into_inner();
+        let name = description.into().into_inner();
+        let inner = raw::custom_action_register_t {
+            uniqueSectionId: section_id.to_raw(),
+            idStr: id_str.as_ptr(),
+            name: name.as_ptr(),
+            extra: null_mut(),
+        };
+        OwnedCustomActionRegister {
+            owned_id_str: id_str,
+            owned_name: name,
+            inner,
+        }
+    }
+}
+
+impl AsRef<raw::custom_action_register_t> for OwnedCustomActionRegister {
+    fn as_ref(&self) -> &custom_action_register_t {
+        &self.inner
+    }
+}
+
+/// Handle of an action registered via [`plugin_register_add_custom_action()`].
+///
+/// [`plugin_register_add_custom_action()`]:
+/// struct.ReaperSession.html#method.plugin_register_add_custom_action
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct CustomActionHandle {
+    command_id: CommandId,
+    register: NonNull<raw::custom_action_register_t>,
+}
+
+impl CustomActionHandle {
+    pub(crate) fn new(
+        command_id: CommandId,
+        register: NonNull<raw::custom_action_register_t>,
+    ) -> CustomActionHandle {
+        CustomActionHandle {
+            command_id,
+            register,
+        }
+    }
+
+    /// Returns the command ID which REAPER assigned to the action.
+    ///
+    /// It's unique across all sections.
+    pub fn command_id(&self) -> CommandId {
+        self.command_id
+    }
+
+    /// Returns the pointer to the registered low-level struct.
+    pub fn register(&self) -> NonNull<raw::custom_action_register_t> {
+        self.register
+    }
+}
@@ -57,3 +57,56 @@ impl<V: Copy> TryFromGreaterError<V> {
 }
 
 impl<R: Copy + Display + Debug> std::error::Error for TryFromGreaterError<R> {}
+
+/// An error which can occur when decoding a packed MIDI event buffer as exchanged via
+/// `MIDI_GetAllEvts`.
+///
+/// This error is caused by *reaper-rs*, not by REAPER itself.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Display, Error)]
+#[display(fmt = "invalid MIDI event buffer at byte {}: {}", offset, message)]
+pub struct DecodeMidiEventsError {
+    message: &'static str,
+    offset: usize,
+}
+
+impl DecodeMidiEventsError {
+    pub(crate) fn new(message: &'static str, offset: usize) -> DecodeMidiEventsError {
+        DecodeMidiEventsError { message, offset }
+    }
+
+    /// Returns the error message.
+    pub const fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// Returns the position in the buffer at which decoding failed.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+/// An error which can occur when parsing a `.reapeaks` peak file.
+///
+/// This error is caused by *reaper-rs*, not by REAPER itself.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Display, Error)]
+#[display(fmt = "invalid peak file at byte {}: {}", offset, message)]
+pub struct ParsePeakFileError {
+    message: &'static str,
+    offset: usize,
+}
+
+impl ParsePeakFileError {
+    pub(crate) fn new(message: &'static str, offset: usize) -> ParsePeakFileError {
+        ParsePeakFileError { message, offset }
+    }
+
+    /// Returns the error message.
+    pub const fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// Returns the position in the file at which parsing failed.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
@@ -83,3 +83,27 @@ pub enum AcceleratorBehavior {
     /// If this flag is not specified, key is assumed to specify a character code.
     VirtKey = 0x01,
 }
+
+/// Defines what to save when saving a project to a file.
+#[enumflags2::bitflags]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum SaveProjectOption {
+    /// Saves only the selected tracks as track template.
+    SelectedTracksAsTrackTemplate = 1,
+    /// Includes media items in the track template.
+    IncludeMediaWithTrackTemplate = 2,
+    /// Includes envelopes in the track template.
+    IncludeEnvelopesWithTrackTemplate = 4,
+}
+
+/// Device error which can occur when creating an instance of a control surface type.
+#[enumflags2::bitflags]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(u32)]
+pub enum ControlSurfaceDeviceError {
+    /// The input device couldn't be opened.
+    InputDevice = 1,
+    /// The output device couldn't be opened.
+    OutputDevice = 2,
+}
@@ -1,8 +1,9 @@
 use crate::{
-    ActionValueChange, CommandId, KbdSectionInfo, ReaProject, SectionContext, WindowContext,
+    ActionValueChange, CommandId, Hmenu, KbdSectionInfo, MenuHookFlag, ReaProject, ReaperStr,
+    SectionContext, WindowContext,
 };
 use reaper_low::{firewall, raw};
-use std::os::raw::c_int;
+use std::os::raw::{c_char, c_int, c_void};
 use std::ptr::NonNull;
 
 /// Consumers need to implement this trait in order to define what should happen when a certain
@@ -63,6 +64,37 @@ pub(crate) extern "C" fn delegating_hook_command_2<T: HookCommand2>(
     .unwrap_or(false)
 }
 
+/// Consumers need to implement this trait in order to add items to custom menus or to the
+/// main menu.
+pub trait HookCustomMenu {
+    /// The actual callback function invoked by REAPER whenever a menu is initialized or about to
+    /// be shown.
+    ///
+    /// `menu_id` is the ID of the menu, e.g. the one passed to [`add_customizable_menu()`] or
+    /// `"Main extensions"` for the *Extensions* main menu.
+    ///
+    /// [`add_customizable_menu()`]: struct.Reaper.html#method.add_customizable_menu
+    fn call(menu_id: &ReaperStr, menu: Hmenu, flag: MenuHookFlag);
+}
+
+pub(crate) extern "C" fn delegating_hook_custom_menu<T: HookCustomMenu>(
+    menuidstr: *const c_char,
+    menu: *mut c_void,
+    flag: c_int,
+) {
+    firewall(|| {
+        let menu = match NonNull::new(menu as *mut raw::HMENU__) {
+            None => return,
+            Some(m) => m,
+        };
+        if menuidstr.is_null() {
+            return;
+        }
+        let menu_id = unsafe { ReaperStr::from_ptr(menuidstr) };
+        T::call(menu_id, menu, MenuHookFlag::from_raw(flag));
+    });
+}
+
 /// Consumers need to implement this trait in order to let REAPER know if a toggleable action is
 /// currently *on* or *off*.
 pub trait ToggleAction {
@@ -34,6 +34,10 @@ impl<T: AsRef<R>, R> Keeper<T, R> {
     pub fn release(&mut self, handle: NonNull<R>) -> Option<T> {
         self.map.remove(&handle).map(|boxed| *boxed)
     }
+
+    pub fn get(&self, handle: NonNull<R>) -> Option<&T> {
+        self.map.get(&handle).map(|boxed| boxed.as_ref())
+    }
 }
 
 #[derive(Debug)]
@@ -476,6 +476,8 @@ pub enum TakeAttributeKey<'a> {
     ///
     /// `*mut PCM_source`
     Source,
+    /// Index of the take within its item (read-only).
+    TakeNumber,
     /// If a variant is missing in this enum, you can use this custom one as a resort.
     ///
     /// Use [`custom()`] to create this variant.
@@ -497,6 +499,255 @@ impl<'a> TakeAttributeKey<'a> {
         match self {
             Source => reaper_str!("P_SOURCE").into(),
             StartOffs => reaper_str!("D_STARTOFFS").into(),
+            TakeNumber => reaper_str!("IP_TAKENUMBER").into(),
+            Custom(key) => key,
+        }
+    }
+}
+
+/// Item attribute key which you can pass to [`get_set_media_item_info()`].
+///
+/// [`get_set_media_item_info()`]: struct.Reaper.html#method.get_set_media_item_info
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ItemAttributeKey<'a> {
+    /// Parent track (read-only).
+    ///
+    /// `*mut MediaTrack`
+    Track,
+    /// Item note text.
+    ///
+    /// `*mut char`
+    ///
+    /// Use with a `_string()` function.
+    Notes,
+    /// Extension-specific persistent data.
+    ///
+    /// `*mut char`
+    ///
+    /// Use [`ext()`] to create this variant.
+    ///
+    /// [`ext()`]: #method.ext
+    Ext(Cow<'a, ReaperStr>),
+    /// 16-byte GUID, can query or update.
+    ///
+    /// `*mut GUID`
+    ///
+    /// If using a `_string()` function, GUID is a string `{xyz-...}`.
+    Guid,
+    /// Muted (item solo overrides).
+    ///
+    /// `*mut bool`
+    ///
+    /// Setting this value will clear `C_MUTE_SOLO`.
+    Mute,
+    /// Muted (ignores solo).
+    ///
+    /// `*mut bool`
+    ///
+    /// Setting this value will not affect `C_MUTE_SOLO`.
+    MuteActual,
+    /// Solo override.
+    ///
+    /// `*mut char`
+    ///
+    /// - -1 → soloed
+    /// - 0 → no override
+    /// - 1 → unsoloed
+    MuteSolo,
+    /// Loop source.
+    ///
+    /// `*mut bool`
+    LoopSrc,
+    /// All takes play.
+    ///
+    /// `*mut bool`
+    AllTakesPlay,
+    /// Selected in arrange view.
+    ///
+    /// `*mut bool`
+    UiSel,
+    /// Item timebase.
+    ///
+    /// `*mut char`
+    ///
+    /// - -1 → track or project default
+    /// - 1 → beats (position, length, rate)
+    /// - 2 → beats (position only)
+    BeatAttachMode,
+    /// Auto-stretch at project tempo changes.
+    ///
+    /// `*mut char`
+    ///
+    /// - 1 → enabled (requires `BeatAttachMode` to be beats)
+    AutoStretch,
+    /// Locked.
+    ///
+    /// `*mut char`
+    ///
+    /// - &1 → locked
+    Lock,
+    /// Item volume.
+    ///
+    /// `*mut f64`
+    ///
+    /// 0 → -inf, 0.5 → -6 dB, 1 → +0 dB, 2 → +6 dB etc.
+    Vol,
+    /// Item position in seconds.
+    ///
+    /// `*mut f64`
+    Position,
+    /// Item length in seconds.
+    ///
+    /// `*mut f64`
+    Length,
+    /// Snap offset in seconds.
+    ///
+    /// `*mut f64`
+    SnapOffset,
+    /// Fade-in length in seconds.
+    ///
+    /// `*mut f64`
+    FadeInLen,
+    /// Fade-out length in seconds.
+    ///
+    /// `*mut f64`
+    FadeOutLen,
+    /// Fade-in curvature.
+    ///
+    /// `*mut f64`
+    ///
+    /// -1..=1
+    FadeInDir,
+    /// Fade-out curvature.
+    ///
+    /// `*mut f64`
+    ///
+    /// -1..=1
+    FadeOutDir,
+    /// Auto-fade-in length in seconds.
+    ///
+    /// `*mut f64`
+    ///
+    /// -1 → no auto-fade
+    FadeInLenAuto,
+    /// Auto-fade-out length in seconds.
+    ///
+    /// `*mut f64`
+    ///
+    /// -1 → no auto-fade
+    FadeOutLenAuto,
+    /// Fade-in shape.
+    ///
+    /// `*mut i32`
+    ///
+    /// 0..=6, 0 → linear
+    FadeInShape,
+    /// Fade-out shape.
+    ///
+    /// `*mut i32`
+    ///
+    /// 0..=6, 0 → linear
+    FadeOutShape,
+    /// Group ID.
+    ///
+    /// `*mut i32`
+    ///
+    /// 0 → no group
+    GroupId,
+    /// Y-position (relative to top of track) in pixels (read-only).
+    ///
+    /// `*mut i32`
+    LastY,
+    /// Height in pixels (read-only).
+    ///
+    /// `*mut i32`
+    LastH,
+    /// Custom color.
+    ///
+    /// `*mut i32`
+    ///
+    /// OS dependent color | 0x1000000 (i.e. `ColorToNative(r, g, b) | 0x1000000`). If you
+    /// don't | 0x1000000, then it will not be used, but will store the color.
+    CustomColor,
+    /// Active take number.
+    ///
+    /// `*mut i32`
+    CurTake,
+    /// Item number on this track (read-only, returns the i32 directly).
+    ///
+    /// `i32`
+    ItemNumber,
+    /// Free item positioning Y-position.
+    ///
+    /// `*mut f32`
+    ///
+    /// 0 → top of track, 1 → bottom of track (will never be 1).
+    FreeModeY,
+    /// Free item positioning height.
+    ///
+    /// `*mut f32`
+    ///
+    /// 0 → no height, 1 → full height of track (will never be 0).
+    FreeModeH,
+    /// If a variant is missing in this enum, you can use this custom one as a resort.
+    ///
+    /// Use [`custom()`] to create this variant.
+    ///
+    /// [`custom()`]: #method.custom
+    Custom(Cow<'a, ReaperStr>),
+}
+
+impl<'a> ItemAttributeKey<'a> {
+    /// Convenience function for creating an [`Ext`] key.
+    ///
+    /// [`Ext`]: #variant.Ext
+    pub fn ext(key: impl Into<ReaperStringArg<'a>>) -> ItemAttributeKey<'a> {
+        ItemAttributeKey::Ext(key.into().into_inner())
+    }
+
+    /// Convenience function for creating a [`Custom`] key.
+    ///
+    /// [`Custom`]: #variant.Custom
+    pub fn custom(key: impl Into<ReaperStringArg<'a>>) -> ItemAttributeKey<'a> {
+        ItemAttributeKey::Custom(key.into().into_inner())
+    }
+
+    pub(crate) fn into_raw(self) -> Cow<'a, ReaperStr> {
+        use ItemAttributeKey::*;
+        match self {
+            Track => reaper_str!("P_TRACK").into(),
+            Notes => reaper_str!("P_NOTES").into(),
+            Ext(key) => concat_reaper_strs(reaper_str!("P_EXT:"), key.as_ref()).into(),
+            Guid => reaper_str!("GUID").into(),
+            Mute => reaper_str!("B_MUTE").into(),
+            MuteActual => reaper_str!("B_MUTE_ACTUAL").into(),
+            MuteSolo => reaper_str!("C_MUTE_SOLO").into(),
+            LoopSrc => reaper_str!("B_LOOPSRC").into(),
+            AllTakesPlay => reaper_str!("B_ALLTAKESPLAY").into(),
+            UiSel => reaper_str!("B_UISEL").into(),
+            BeatAttachMode => reaper_str!("C_BEATATTACHMODE").into(),
+            AutoStretch => reaper_str!("C_AUTOSTRETCH").into(),
+            Lock => reaper_str!("C_LOCK").into(),
+            Vol => reaper_str!("D_VOL").into(),
+            Position => reaper_str!("D_POSITION").into(),
+            Length => reaper_str!("D_LENGTH").into(),
+            SnapOffset => reaper_str!("D_SNAPOFFSET").into(),
+            FadeInLen => reaper_str!("D_FADEINLEN").into(),
+            FadeOutLen => reaper_str!("D_FADEOUTLEN").into(),
+            FadeInDir => reaper_str!("D_FADEINDIR").into(),
+            FadeOutDir => reaper_str!("D_FADEOUTDIR").into(),
+            FadeInLenAuto => reaper_str!("D_FADEINLEN_AUTO").into(),
+            FadeOutLenAuto => reaper_str!("D_FADEOUTLEN_AUTO").into(),
+            FadeInShape => reaper_str!("C_FADEINSHAPE").into(),
+            FadeOutShape => reaper_str!("C_FADEOUTSHAPE").into(),
+            GroupId => reaper_str!("I_GROUPID").into(),
+            LastY => reaper_str!("I_LASTY").into(),
+            LastH => reaper_str!("I_LASTH").into(),
+            CustomColor => reaper_str!("I_CUSTOMCOLOR").into(),
+            CurTake => reaper_str!("I_CURTAKE").into(),
+            ItemNumber => reaper_str!("IP_ITEMNUMBER").into(),
+            FreeModeY => reaper_str!("F_FREEMODE_Y").into(),
+            FreeModeH => reaper_str!("F_FREEMODE_H").into(),
             Custom(key) => key,
         }
     }
@@ -652,6 +903,165 @@ impl<'a> TrackSendAttributeKey<'a> {
     }
 }
 
+/// Project info key which you can pass to [`get_set_project_info_string_get()`].
+///
+/// [`get_set_project_info_string_get()`]: struct.Reaper.html#method.get_set_project_info_string_get
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ProjectInfoStringKey<'a> {
+    /// Project name (the project file name without path, read-only).
+    ProjectName,
+    /// Title field from project notes.
+    ProjectTitle,
+    /// Author field from project notes.
+    ProjectAuthor,
+    /// Recording path.
+    RecordPath,
+    /// Render directory.
+    RenderFile,
+    /// Render file name (may contain wildcards).
+    RenderPattern,
+    /// Base64-encoded sink configuration.
+    RenderFormat,
+    /// Base64-encoded secondary sink configuration.
+    RenderFormat2,
+    /// Semicolon separated list of files that would be written if the project were rendered
+    /// using the most recent render settings (read-only).
+    RenderTargets,
+    /// If a variant is missing in this enum, you can use this custom one as a resort.
+    ///
+    /// Use [`custom()`] to create this variant.
+    ///
+    /// [`custom()`]: #method.custom
+    Custom(Cow<'a, ReaperStr>),
+}
+
+impl<'a> ProjectInfoStringKey<'a> {
+    /// Convenience function for creating a [`Custom`] key.
+    ///
+    /// [`Custom`]: #variant.Custom
+    pub fn custom(key: impl Into<ReaperStringArg<'a>>) -> ProjectInfoStringKey<'a> {
+        ProjectInfoStringKey::Custom(key.into().into_inner())
+    }
+
+    pub(crate) fn into_raw(self) -> Cow<'a, ReaperStr> {
+        use ProjectInfoStringKey::*;
+        match self {
+            ProjectName => reaper_str!("PROJECT_NAME").into(),
+            ProjectTitle => reaper_str!("PROJECT_TITLE").into(),
+            ProjectAuthor => reaper_str!("PROJECT_AUTHOR").into(),
+            RecordPath => reaper_str!("RECORD_PATH").into(),
+            RenderFile => reaper_str!("RENDER_FILE").into(),
+            RenderPattern => reaper_str!("RENDER_PATTERN").into(),
+            RenderFormat => reaper_str!("RENDER_FORMAT").into(),
+            RenderFormat2 => reaper_str!("RENDER_FORMAT2").into(),
+            RenderTargets => reaper_str!("RENDER_TARGETS").into(),
+            Custom(key) => key,
+        }
+    }
+}
+
+/// Project info key which you can pass to [`get_set_project_info_get()`].
+///
+/// [`get_set_project_info_get()`]: struct.Reaper.html#method.get_set_project_info_get
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ProjectInfoKey<'a> {
+    /// Render source and options.
+    ///
+    /// - &(1|2) == 0 → master mix
+    /// - &1 → stems + master mix
+    /// - &2 → stems only
+    /// - &4 → multichannel tracks to multichannel files
+    /// - &8 → use render matrix
+    /// - &16 → tracks with only mono media to mono files
+    /// - &32 → selected media items
+    /// - &64 → selected media items via master
+    /// - &128 → selected tracks via master
+    /// - &256 → embed stretch markers/transient guides
+    /// - &512 → embed metadata
+    /// - &1024 → embed take markers
+    /// - &2048 → 2nd pass render
+    /// - &8192 → razor edit areas
+    /// - &16384 → razor edit areas via master
+    RenderSettings,
+    /// Render bounds.
+    ///
+    /// - 0 → custom time bounds
+    /// - 1 → entire project
+    /// - 2 → time selection
+    /// - 3 → all project regions
+    /// - 4 → selected media items
+    /// - 5 → selected project regions
+    /// - 6 → all project markers
+    /// - 7 → selected project markers
+    RenderBoundsFlag,
+    /// Number of channels in rendered file.
+    RenderChannels,
+    /// Sample rate of rendered file (0 → project sample rate).
+    RenderSrate,
+    /// Render start time in seconds (when using custom time bounds).
+    RenderStartPos,
+    /// Render end time in seconds (when using custom time bounds).
+    RenderEndPos,
+    /// Render tail for each bounds mode.
+    ///
+    /// - &1 → custom time bounds
+    /// - &2 → entire project
+    /// - &4 → time selection
+    /// - &8 → all project regions
+    /// - &16 → selected media items
+    /// - &32 → selected project regions
+    RenderTailFlag,
+    /// Render tail length in milliseconds.
+    RenderTailMs,
+    /// Whether to add rendered files to project (1 → add).
+    RenderAddToProj,
+    /// Render dither and noise shaping.
+    ///
+    /// - &1 → dither
+    /// - &2 → noise shaping
+    /// - &4 → dither stems
+    /// - &8 → noise shaping on stems
+    RenderDither,
+    /// Project sample rate (only used if `ProjectSrateUse` is set).
+    ProjectSrate,
+    /// Whether to use the project sample rate instead of the audio device sample rate.
+    ProjectSrateUse,
+    /// If a variant is missing in this enum, you can use this custom one as a resort.
+    ///
+    /// Use [`custom()`] to create this variant.
+    ///
+    /// [`custom()`]: #method.custom
+    Custom(Cow<'a, ReaperStr>),
+}
+
+impl<'a> ProjectInfoKey<'a> {
+    /// Convenience function for creating a [`Custom`] key.
+    ///
+    /// [`Custom`]: #variant.Custom
+    pub fn custom(key: impl Into<ReaperStringArg<'a>>) -> ProjectInfoKey<'a> {
+        ProjectInfoKey::Custom(key.into().into_inner())
+    }
+
+    pub(crate) fn into_raw(self) -> Cow<'a, ReaperStr> {
+        use ProjectInfoKey::*;
+        match self {
+            RenderSettings => reaper_str!("RENDER_SETTINGS").into(),
+            RenderBoundsFlag => reaper_str!("RENDER_BOUNDSFLAG").into(),
+            RenderChannels => reaper_str!("RENDER_CHANNELS").into(),
+            RenderSrate => reaper_str!("RENDER_SRATE").into(),
+            RenderStartPos => reaper_str!("RENDER_STARTPOS").into(),
+            RenderEndPos => reaper_str!("RENDER_ENDPOS").into(),
+            RenderTailFlag => reaper_str!("RENDER_TAILFLAG").into(),
+            RenderTailMs => reaper_str!("RENDER_TAILMS").into(),
+            RenderAddToProj => reaper_str!("RENDER_ADDTOPROJ").into(),
+            RenderDither => reaper_str!("RENDER_DITHER").into(),
+            ProjectSrate => reaper_str!("PROJECT_SRATE").into(),
+            ProjectSrateUse => reaper_str!("PROJECT_SRATE_USE").into(),
+            Custom(key) => key,
+        }
+    }
+}
+
 /// Envelope chunk name which you can pass e.g. to [`TrackAttributeKey::Env()`].
 ///
 /// [`TrackAttributeKey::Env()`]: enum.TrackAttributeKey.html#variant.Env
@@ -734,4 +1144,17 @@ mod tests {
             reaper_str!("BLA")
         );
     }
+
+    #[test]
+    fn serialize_item_attribute_key() {
+        use ItemAttributeKey::*;
+        assert_eq!(
+            FadeInShape.into_raw().as_ref(),
+            reaper_str!("C_FADEINSHAPE")
+        );
+        assert_eq!(
+            ItemAttributeKey::ext("SWS_FOO").into_raw().as_ref(),
+            reaper_str!("P_EXT:SWS_FOO")
+        );
+    }
 }
@@ -354,9 +354,18 @@ pub use reaper_pointer::*;
 mod gaccel_register;
 pub use gaccel_register::*;
 
+mod custom_action_register;
+pub use custom_action_register::*;
+
+mod action_help_register;
+pub use action_help_register::*;
+
 mod accelerator_register;
 pub use accelerator_register::*;
 
+mod project_config_extension;
+pub use project_config_extension::*;
+
 mod preview_register;
 pub use preview_register::*;
 
@@ -368,15 +377,27 @@ mod keeper;
 mod control_surface;
 pub use control_surface::*;
 
+mod control_surface_type;
+pub use control_surface_type::*;
+
 mod midi;
 pub use midi::*;
 
+mod packed_midi_events;
+pub use packed_midi_events::*;
+
+mod peak_file;
+pub use peak_file::*;
+
 mod pcm_source;
 pub use pcm_source::*;
 
 mod pcm_sink;
 pub use pcm_sink::*;
 
+mod audio_accessor;
+pub use audio_accessor::*;
+
 mod pitch_shift;
 pub use pitch_shift::*;
 
@@ -404,6 +425,9 @@ pub use automation_mode::*;
 mod message_box;
 pub use message_box::*;
 
+mod swell_menu;
+pub use swell_menu::*;
+
 mod ptr_wrappers;
 pub use ptr_wrappers::*;
 
@@ -1,6 +1,7 @@
 use crate::{
-    BookmarkId, CommandId, Hidden, Hwnd, KbdSectionInfo, MediaTrack, MidiFrameOffset,
-    MidiOutputDeviceId, ReaProject, ReaperPanValue, ReaperStr, ReaperStringArg, ReaperWidthValue,
+    BookmarkId, CommandId, FxContainerAddress, Hidden, Hwnd, KbdSectionInfo, MediaTrack,
+    MidiFrameOffset, MidiOutputDeviceId, PositionInBeats, PositionInSeconds, ReaProject,
+    ReaperPanValue, ReaperStr, ReaperStringArg, ReaperWidthValue,
 };
 
 use crate::util::concat_reaper_strs;
@@ -42,6 +43,25 @@ pub enum TrackFxChainType {
     InputFxChain,
 }
 
+/// Determines whether we are talking about the input or output pins of a plug-in.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FxPinDirection {
+    /// Pins which receive audio from the track channels.
+    Input,
+    /// Pins which send audio to the track channels.
+    Output,
+}
+
+impl FxPinDirection {
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        match self {
+            FxPinDirection::Input => 0,
+            FxPinDirection::Output => 1,
+        }
+    }
+}
+
 /// Describes which kind of time range we are talking about in a REAPER project.
 ///
 /// They are linked by default in REAPER so users might not even be aware that there's a
@@ -95,6 +115,13 @@ impl BookmarkRef {
     }
 }
 
+/// Determines whether a bookmark is a marker or a region.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BookmarkKind {
+    Marker,
+    Region,
+}
+
 /// A performance/caching hint which determines how REAPER internally gets or sets a chunk.
 ///
 /// Has implications on both performance and chunk content.
@@ -293,6 +320,42 @@ impl FxShowInstruction {
     }
 }
 
+/// Determines if and how to show/hide a take FX user interface.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TakeFxShowInstruction {
+    /// Closes the complete take FX chain.
+    HideChain,
+    /// Shows the complete take FX chain and makes the FX at the given index visible.
+    ShowChain(u32),
+    /// Closes the floating window of the FX at the given index.
+    HideFloatingWindow(u32),
+    /// Shows the floating window of the FX at the given index.
+    ShowFloatingWindow(u32),
+}
+
+impl TakeFxShowInstruction {
+    /// Converts the instruction part of this value to a `showFlag` integer as expected by the
+    /// low-level API.
+    pub fn instruction_to_raw(&self) -> i32 {
+        use TakeFxShowInstruction::*;
+        match self {
+            HideChain => 0,
+            ShowChain(_) => 1,
+            HideFloatingWindow(_) => 2,
+            ShowFloatingWindow(_) => 3,
+        }
+    }
+
+    /// Converts the FX index part of this value to an integer as expected by the low-level API.
+    pub fn index_to_raw(&self) -> i32 {
+        use TakeFxShowInstruction::*;
+        match self {
+            HideChain => 0,
+            ShowChain(i) | HideFloatingWindow(i) | ShowFloatingWindow(i) => *i as i32,
+        }
+    }
+}
+
 /// Defines whether you are referring to a send or a receive.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum TrackSendDirection {
@@ -419,6 +482,8 @@ pub enum TrackFxLocation {
     ///
     /// On the master track (if applicable) this represents an index in the monitoring FX chain.
     InputFxChain(u32),
+    /// FX within an FX container (REAPER 7+).
+    ContainerFx(FxContainerAddress),
     /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
     /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
     Unknown(Hidden<i32>),
@@ -429,7 +494,9 @@ impl TrackFxLocation {
     pub fn from_raw(v: i32) -> TrackFxLocation {
         use TrackFxLocation::*;
         if let Ok(v) = u32::try_from(v) {
-            if v >= 0x0100_0000 {
+            if v >= 0x0200_0000 {
+                ContainerFx(FxContainerAddress(v))
+            } else if v >= 0x0100_0000 {
                 InputFxChain(v - 0x0100_0000)
             } else {
                 NormalFxChain(v)
@@ -445,6 +512,7 @@ impl TrackFxLocation {
         let positive = match self {
             InputFxChain(idx) => 0x0100_0000 + idx,
             NormalFxChain(idx) => idx,
+            ContainerFx(address) => return address.to_raw(),
             Unknown(Hidden(x)) => return x,
         };
         positive as i32
@@ -650,7 +718,16 @@ pub enum RegistrationObject<'a> {
     ///   1=action belongs to this extension and is currently set to "on"
     /// ```
     ToggleAction(raw::ToggleAction),
-    // ActionHelp(*mut c_void),
+    /// A hook which is called whenever a custom menu is initialized or about to be shown.
+    ///
+    /// Extract from `reaper_plugin_functions.h`:
+    ///
+    /// ```text
+    /// "hookcustommenu", void menuhook(const char* menuidstr, void* menu, int flag)
+    /// flag 0: default menu is being initialized
+    /// flag 1: menu is about to be shown
+    /// ```
+    HookCustomMenu(raw::HookCustomMenu),
     /// A command ID for the given command name.
     ///
     /// Extract from `reaper_plugin_functions.h`:
@@ -670,6 +747,24 @@ pub enum RegistrationObject<'a> {
     /// command ID, desc is the description, and accel's other parameters are the key to bind.
     /// ```
     Gaccel(NonNull<raw::gaccel_register_t>),
+    /// An action for an arbitrary section, e.g. the MIDI editor.
+    ///
+    /// Extract from `reaper_plugin.h`:
+    /// ```text
+    /// custom_action_register_t allows you to register ("custom_action") an action into a keyboard
+    /// section action list register("custom_action",ca) will return the command ID
+    /// (instance-dependent but unique across all sections), or 0 if failed (e.g dupe idStr)
+    /// the related callback should be registered with "hookcommand2"
+    /// ```
+    CustomAction(NonNull<raw::custom_action_register_t>),
+    /// Help text for an action.
+    ///
+    /// Extract from `reaper_plugin.h`:
+    /// ```text
+    /// action_help_t lets you register help text ("action_help") for an action, mapped by action
+    /// name (a "help" plugin could register help text for Reaper built-in actions)
+    /// ```
+    ActionHelp(NonNull<raw::action_help_t>),
     /// A record which lets you get a place in the keyboard processing queue.
     BackAccelerator(NonNull<raw::accelerator_register_t>),
     /// A record which lets you get the first place in the keyboard processing queue.
@@ -683,6 +778,33 @@ pub enum RegistrationObject<'a> {
     /// (IReaperControlSurface*)instance
     /// ```
     CsurfInst(NonNull<raw::IReaperControlSurface>),
+    /// A control surface type which users can select in the preferences.
+    ///
+    /// Extract from `reaper_plugin.h`:
+    ///
+    /// ```text
+    /// typedef struct
+    /// {
+    ///   const char *type_string; // simple unique string with only A-Z, 0-9, no spaces or other chars
+    ///   const char *desc_string; // human readable description
+    ///   IReaperControlSurface *(*create)(const char *type_string, const char *configString, int *errStats); // errstats gets |1 if input error, |2 if output error
+    ///   HWND (*ShowConfig)(const char *type_string, HWND parent, const char *initConfigString);
+    /// } reaper_csurf_reg_t; // register using "csurf"/"-csurf"
+    /// ```
+    Csurf(NonNull<raw::reaper_csurf_reg_t>),
+    /// Callbacks for storing custom state in the project file and in undo points.
+    ///
+    /// Extract from `reaper_plugin.h`:
+    ///
+    /// ```text
+    /// typedef struct project_config_extension_t // register with "projectconfig"
+    /// {
+    ///   // plug-ins may or may not want to save their undo states (look at isUndo)
+    ///   // undo states will be saved if UNDO_STATE_MISCCFG is set (for adding your own undo points)
+    ///   ...
+    /// } project_config_extension_t;
+    /// ```
+    ProjectConfig(NonNull<raw::project_config_extension_t>),
     /// If a variant is missing in this enum, you can use this custom one as a resort.
     ///
     /// Use [`custom()`] to create this variant.
@@ -772,6 +894,10 @@ impl<'a> RegistrationObject<'a> {
                 key: reaper_str!("toggleaction").into(),
                 value: func as _,
             },
+            HookCustomMenu(func) => PluginRegistration {
+                key: reaper_str!("hookcustommenu").into(),
+                value: func as _,
+            },
             CommandId(command_name) => PluginRegistration {
                 key: reaper_str!("command_id").into(),
                 value: command_name as _,
@@ -780,6 +906,14 @@ impl<'a> RegistrationObject<'a> {
                 key: reaper_str!("gaccel").into(),
                 value: reg.as_ptr() as _,
             },
+            CustomAction(reg) => PluginRegistration {
+                key: reaper_str!("custom_action").into(),
+                value: reg.as_ptr() as _,
+            },
+            ActionHelp(reg) => PluginRegistration {
+                key: reaper_str!("action_help").into(),
+                value: reg.as_ptr() as _,
+            },
             BackAccelerator(reg) => PluginRegistration {
                 key: reaper_str!("accelerator").into(),
                 value: reg.as_ptr() as _,
@@ -788,6 +922,14 @@ impl<'a> RegistrationObject<'a> {
                 key: reaper_str!("<accelerator").into(),
                 value: reg.as_ptr() as _,
             },
+            Csurf(reg) => PluginRegistration {
+                key: reaper_str!("csurf").into(),
+                value: reg.as_ptr() as _,
+            },
+            ProjectConfig(reg) => PluginRegistration {
+                key: reaper_str!("projectconfig").into(),
+                value: reg.as_ptr() as _,
+            },
             CsurfInst(inst) => PluginRegistration {
                 key: reaper_str!("csurf_inst").into(),
                 value: inst.as_ptr() as _,
@@ -813,6 +955,44 @@ pub(crate) struct PluginRegistration {
     pub(crate) value: *mut c_void,
 }
 
+/// Tells a custom menu hook why it has been called.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MenuHookFlag {
+    /// The default menu is being initialized.
+    ///
+    /// This is the right moment to add menu items.
+    Init,
+    /// The menu is about to be shown.
+    ///
+    /// This is the right moment to update the check and gray state of menu items.
+    Show,
+    /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
+    /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
+    Unknown(Hidden<i32>),
+}
+
+impl MenuHookFlag {
+    /// Converts an integer as returned by the low-level API to a menu hook flag.
+    pub fn from_raw(v: i32) -> MenuHookFlag {
+        use MenuHookFlag::*;
+        match v {
+            0 => Init,
+            1 => Show,
+            _ => Unknown(Hidden(v)),
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use MenuHookFlag::*;
+        match self {
+            Init => 0,
+            Show => 1,
+            Unknown(Hidden(v)) => v,
+        }
+    }
+}
+
 /// Type and location of a certain track.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum TrackLocation {
@@ -860,6 +1040,69 @@ impl InputMonitoringMode {
     }
 }
 
+/// Determines how child tracks of a folder are displayed in the arrange view.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FolderCompactState {
+    /// Child tracks are displayed normally.
+    Normal,
+    /// Child tracks are displayed small.
+    Small,
+    /// Child tracks are collapsed (tiny).
+    Tiny,
+    /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
+    /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
+    Unknown(Hidden<i32>),
+}
+
+impl FolderCompactState {
+    /// Converts an integer as returned by the low-level API to a folder compact state.
+    pub fn from_raw(v: i32) -> FolderCompactState {
+        use FolderCompactState::*;
+        match v {
+            0 => Normal,
+            1 => Small,
+            2 => Tiny,
+            x => Unknown(Hidden(x)),
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use FolderCompactState::*;
+        match self {
+            Normal => 0,
+            Small => 1,
+            Tiny => 2,
+            Unknown(Hidden(x)) => x,
+        }
+    }
+}
+
+/// Determines the folder placement of tracks moved via
+/// [`reorder_selected_tracks()`](struct.Reaper.html#method.reorder_selected_tracks).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ReorderTracksBehavior {
+    /// Tracks are moved without changing the folder of the track before the destination.
+    Normal,
+    /// Tracks become children of the track before the destination.
+    MakePreviousFolder,
+    /// If the track before the destination is the last track in a folder, the folder is
+    /// extended to include the moved tracks.
+    ExtendPreviousFolder,
+}
+
+impl ReorderTracksBehavior {
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use ReorderTracksBehavior::*;
+        match self {
+            Normal => 0,
+            MakePreviousFolder => 1,
+            ExtendPreviousFolder => 2,
+        }
+    }
+}
+
 /// Track solo mode.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum SoloMode {
@@ -1408,3 +1651,234 @@ impl<'a> ParamId<'a> {
         }
     }
 }
+
+/// Shape of an envelope point, determining the curve to the next point.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum EnvelopePointShape {
+    Linear,
+    Square,
+    SlowStartEnd,
+    FastStart,
+    FastEnd,
+    Bezier,
+    /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
+    /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
+    Unknown(Hidden<i32>),
+}
+
+impl EnvelopePointShape {
+    /// Converts an integer as returned by the low-level API to an envelope point shape.
+    pub fn from_raw(v: i32) -> EnvelopePointShape {
+        use EnvelopePointShape::*;
+        match v {
+            0 => Linear,
+            1 => Square,
+            2 => SlowStartEnd,
+            3 => FastStart,
+            4 => FastEnd,
+            5 => Bezier,
+            x => Unknown(Hidden(x)),
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use EnvelopePointShape::*;
+        match self {
+            Linear => 0,
+            Square => 1,
+            SlowStartEnd => 2,
+            FastStart => 3,
+            FastEnd => 4,
+            Bezier => 5,
+            Unknown(Hidden(x)) => x,
+        }
+    }
+}
+
+/// Determines whether to sort points/events right after changing them.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SortBehavior {
+    /// Sorts immediately.
+    Sort,
+    /// Doesn't sort.
+    ///
+    /// Use this when changing multiple points/events at once and sort manually when done.
+    DontSort,
+}
+
+/// Determines which point set of an envelope is addressed.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum EnvelopePointSet {
+    /// The points of the envelope itself.
+    Underlying,
+    /// The visible points of the automation item at the given index, including all loop
+    /// iterations.
+    AutomationItem(u32),
+    /// The points of one full loop iteration of the automation item at the given index, even if
+    /// the automation item is trimmed.
+    AutomationItemLoop(u32),
+}
+
+impl EnvelopePointSet {
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use EnvelopePointSet::*;
+        match self {
+            Underlying => -1,
+            AutomationItem(i) => i as i32,
+            AutomationItemLoop(i) => i as i32 | 0x1000_0000,
+        }
+    }
+}
+
+/// Determines whether to create something if it doesn't exist yet.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CreateBehavior {
+    /// Only returns something if it already exists.
+    DontCreate,
+    /// Creates it if it doesn't exist yet.
+    Create,
+}
+
+/// Shape of a MIDI CC event, determining the curve to the next event of the same lane.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MidiCcShape {
+    Square,
+    Linear,
+    SlowStartEnd,
+    FastStart,
+    FastEnd,
+    Bezier,
+    /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
+    /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
+    Unknown(Hidden<i32>),
+}
+
+impl MidiCcShape {
+    /// Converts an integer as returned by the low-level API to a MIDI CC shape.
+    pub fn from_raw(v: i32) -> MidiCcShape {
+        use MidiCcShape::*;
+        match v {
+            0 => Square,
+            1 => Linear,
+            2 => SlowStartEnd,
+            3 => FastStart,
+            4 => FastEnd,
+            5 => Bezier,
+            x => Unknown(Hidden(x)),
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use MidiCcShape::*;
+        match self {
+            Square => 0,
+            Linear => 1,
+            SlowStartEnd => 2,
+            FastStart => 3,
+            FastEnd => 4,
+            Bezier => 5,
+            Unknown(Hidden(x)) => x,
+        }
+    }
+}
+
+/// Shape of an item fade-in or fade-out.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FadeShape {
+    Linear,
+    FastStart,
+    FastEnd,
+    FastStartSteep,
+    FastEndSteep,
+    SlowStartEnd,
+    SlowStartEndSteep,
+    /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
+    /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
+    Unknown(Hidden<i32>),
+}
+
+impl FadeShape {
+    /// Converts an integer as returned by the low-level API to a fade shape.
+    pub fn from_raw(v: i32) -> FadeShape {
+        use FadeShape::*;
+        match v {
+            0 => Linear,
+            1 => FastStart,
+            2 => FastEnd,
+            3 => FastStartSteep,
+            4 => FastEndSteep,
+            5 => SlowStartEnd,
+            6 => SlowStartEndSteep,
+            x => Unknown(Hidden(x)),
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use FadeShape::*;
+        match self {
+            Linear => 0,
+            FastStart => 1,
+            FastEnd => 2,
+            FastStartSteep => 3,
+            FastEndSteep => 4,
+            SlowStartEnd => 5,
+            SlowStartEndSteep => 6,
+            Unknown(Hidden(x)) => x,
+        }
+    }
+}
+
+/// Type of a MIDI text or sysex event.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MidiTextSysexEventType {
+    /// System exclusive message.
+    Sysex,
+    /// MIDI text meta event of the given type (1 to 14, e.g. 1 = text, 3 = track name,
+    /// 5 = lyrics, 6 = marker).
+    Text(u8),
+    /// REAPER notation event.
+    ReaperNotation,
+    /// Represents a variant unknown to *reaper-rs*. Please contribute if you encounter a variant
+    /// that is supported by REAPER but not yet by *reaper-rs*. Thanks!
+    Unknown(Hidden<i32>),
+}
+
+impl MidiTextSysexEventType {
+    /// Converts an integer as returned by the low-level API to a text/sysex event type.
+    pub fn from_raw(v: i32) -> MidiTextSysexEventType {
+        use MidiTextSysexEventType::*;
+        match v {
+            -1 => Sysex,
+            1..=14 => Text(v as u8),
+            15 => ReaperNotation,
+            x => Unknown(Hidden(x)),
+        }
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        use MidiTextSysexEventType::*;
+        match self {
+            Sysex => -1,
+            Text(t) => t as i32,
+            ReaperNotation => 15,
+            Unknown(Hidden(x)) => x,
+        }
+    }
+}
+
+/// Position of a tempo/time signature marker.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TempoMarkerPosition {
+    /// Position in seconds.
+    Time(PositionInSeconds),
+    /// Musical position.
+    Musical {
+        measure_index: i32,
+        beats_since_measure: PositionInBeats,
+    },
+}
@@ -1,5 +1,5 @@
 //! This module defines various newtypes in order to achieve more type safety.
-use crate::{ReaperStr, ReaperStringArg, TryFromGreaterError};
+use crate::{ReaperStr, ReaperStringArg, TrackFxChainType, TryFromGreaterError};
 use derive_more::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -1118,6 +1118,66 @@ impl TryFrom<f64> for PositionInQuarterNotes {
     }
 }
 
+/// This represents a position within a MIDI take expressed in MIDI ticks (pulses per quarter
+/// note).
+///
+/// Can be negative (before the start of the take).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Display)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "f64")
+)]
+pub struct PositionInPpq(pub(crate) f64);
+
+impl PositionInPpq {
+    /// Position at 0.0 ticks.
+    pub const ZERO: PositionInPpq = PositionInPpq(0.0);
+
+    fn is_valid(value: f64) -> bool {
+        !value.is_infinite() && !value.is_nan()
+    }
+
+    /// Creates a value.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the given value is a special number.
+    pub fn new(value: f64) -> PositionInPpq {
+        assert!(
+            Self::is_valid(value),
+            "{} is not a valid PositionInPpq value",
+            value
+        );
+        PositionInPpq(value)
+    }
+
+    /// Creates a PositionInPpq value without bound checking.
+    ///
+    /// # Safety
+    ///
+    /// You must ensure that the given value is not a special number.
+    pub const unsafe fn new_unchecked(value: f64) -> PositionInPpq {
+        PositionInPpq(value)
+    }
+
+    /// Returns the wrapped value.
+    pub const fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl TryFrom<f64> for PositionInPpq {
+    type Error = TryFromGreaterError<f64>;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !Self::is_valid(value) {
+            return Err(TryFromGreaterError::new("value must be non-special", value));
+        }
+        Ok(PositionInPpq(value))
+    }
+}
+
 /// This represents a volume measured in decibel.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Display)]
 #[cfg_attr(
@@ -1613,9 +1673,203 @@ impl MidiFrameOffset {
     }
 }
 
+/// Address of an FX within an FX container (REAPER 7+).
+///
+/// REAPER encodes the complete path to the FX into one integer (0x2000000 + ...). Because the
+/// encoding depends on the number of FX in each chain along the path, an address is only valid as
+/// long as these chains don't change.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Display)]
+pub struct FxContainerAddress(pub(crate) u32);
+
+impl FxContainerAddress {
+    const OFFSET: u32 = 0x0200_0000;
+
+    /// Creates an FX container address from a raw value as used by REAPER.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the given value is lower than 0x2000000.
+    pub fn new(value: u32) -> FxContainerAddress {
+        assert!(
+            value >= Self::OFFSET,
+            "FX container addresses start at 0x2000000"
+        );
+        FxContainerAddress(value)
+    }
+
+    /// Builds the address of the FX at `fx_index` within the innermost container of the given
+    /// container path.
+    ///
+    /// The path starts with the container in the top-level FX chain of the given type.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the given container path is empty.
+    pub fn from_path(
+        chain_type: TrackFxChainType,
+        containers: &[FxContainerLevel],
+        fx_index: u32,
+    ) -> FxContainerAddress {
+        assert!(!containers.is_empty(), "container path must not be empty");
+        let mut value = Self::OFFSET;
+        if chain_type == TrackFxChainType::InputFxChain {
+            value += 0x0100_0000;
+        }
+        let mut factor = 1;
+        for level in containers {
+            value += (level.index + 1) * factor;
+            factor *= level.chain_fx_count + 1;
+        }
+        value += (fx_index + 1) * factor;
+        FxContainerAddress(value)
+    }
+
+    /// Returns the wrapped value.
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    /// Converts this value to an integer as expected by the low-level API.
+    pub fn to_raw(self) -> i32 {
+        self.0 as i32
+    }
+}
+
+/// One container along the path to an FX within nested FX containers.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FxContainerLevel {
+    /// Index of the container within its FX chain.
+    pub index: u32,
+    /// Number of FX in the FX chain which contains the container.
+    pub chain_fx_count: u32,
+}
+
+/// Bit mask of the track channels which are connected to a plug-in pin.
+///
+/// Bit 0 represents track channel 1, bit 1 track channel 2 and so on (up to 64 channels).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FxPinMask(pub(crate) u64);
+
+impl FxPinMask {
+    /// A mask which doesn't connect the pin to any channel.
+    pub const EMPTY: FxPinMask = FxPinMask(0);
+
+    /// Creates a pin mask from the given bits.
+    pub fn new(bits: u64) -> FxPinMask {
+        FxPinMask(bits)
+    }
+
+    /// Creates a pin mask which connects the pin to the given (zero-based) channel only.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the given channel is greater than 63.
+    pub fn channel(channel: u32) -> FxPinMask {
+        FxPinMask::EMPTY.with_channel(channel, true)
+    }
+
+    /// Returns a copy of this mask with the given (zero-based) channel connected or disconnected.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the given channel is greater than 63.
+    pub fn with_channel(self, channel: u32, connected: bool) -> FxPinMask {
+        assert!(channel < 64, "pins can only be connected to 64 channels");
+        let bit = 1 << channel;
+        if connected {
+            FxPinMask(self.0 | bit)
+        } else {
+            FxPinMask(self.0 & !bit)
+        }
+    }
+
+    /// Returns whether the pin is connected to the given (zero-based) channel.
+    pub fn contains_channel(self, channel: u32) -> bool {
+        channel < 64 && self.0 & (1 << channel) != 0
+    }
+
+    /// Returns the (zero-based) channels to which the pin is connected.
+    pub fn channels(self) -> impl Iterator<Item = u32> {
+        (0..64).filter(move |c| self.contains_channel(*c))
+    }
+
+    /// Returns the wrapped value.
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    pub(crate) fn from_raw(low32: i32, high32: i32) -> FxPinMask {
+        FxPinMask(((high32 as u32 as u64) << 32) | low32 as u32 as u64)
+    }
+
+    pub(crate) fn to_raw(self) -> (i32, i32) {
+        (self.0 as u32 as i32, (self.0 >> 32) as u32 as i32)
+    }
+}
+
 /// Represents a value which can neither be accessed nor created by the consumer.
 ///
 /// It's mainly used inside `Unknown` variants in order to enable forward compatibility without
 /// information loss.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Hidden<T>(pub(crate) T);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TrackFxLocation;
+
+    #[test]
+    fn fx_container_address_from_path() {
+        // Second FX in first container of a chain with 3 FX
+        let address = FxContainerAddress::from_path(
+            TrackFxChainType::NormalFxChain,
+            &[FxContainerLevel {
+                index: 0,
+                chain_fx_count: 3,
+            }],
+            1,
+        );
+        assert_eq!(address.get(), 0x0200_0000 + 1 + 2 * 4);
+        // First FX in a container at index 1 nested in the container at index 2
+        let address = FxContainerAddress::from_path(
+            TrackFxChainType::NormalFxChain,
+            &[
+                FxContainerLevel {
+                    index: 2,
+                    chain_fx_count: 3,
+                },
+                FxContainerLevel {
+                    index: 1,
+                    chain_fx_count: 2,
+                },
+            ],
+            0,
+        );
+        assert_eq!(address.get(), 0x0200_0000 + 3 + 2 * 4 + 4 * 3);
+    }
+
+    #[test]
+    fn fx_pin_mask_raw_conversion() {
+        let mask = FxPinMask::channel(2).with_channel(35, true);
+        let (low32, high32) = mask.to_raw();
+        assert_eq!(low32, 0b100);
+        assert_eq!(high32, 0b1000);
+        assert_eq!(FxPinMask::from_raw(low32, high32), mask);
+        assert_eq!(mask.channels().collect::<Vec<_>>(), vec![2, 35]);
+        assert_eq!(FxPinMask::from_raw(-1, 0).get(), 0xFFFF_FFFF);
+    }
+
+    #[test]
+    fn track_fx_location_round_trip() {
+        let address = FxContainerAddress::new(0x0200_0009);
+        let location = TrackFxLocation::from_raw(address.to_raw());
+        assert_eq!(location, TrackFxLocation::ContainerFx(address));
+        assert_eq!(location.to_raw(), 0x0200_0009);
+        assert_eq!(
+            TrackFxLocation::from_raw(0x0100_0002),
+            TrackFxLocation::InputFxChain(2)
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{DecodeMidiEventsError, MidiCcShape, PositionInPpq};
+use std::convert::TryInto;
+
+const FLAG_SELECTED: u8 = 1;
+const FLAG_MUTED: u8 = 2;
+const CC_SHAPE_MASK: u8 = 0xF0;
+const CC_BEZIER_PREFIX: &[u8] = b"\xFF\x0FCCBZ ";
+
+/// A MIDI event as contained in the packed buffer which REAPER exchanges via `MIDI_GetAllEvts` and
+/// `MIDI_SetAllEvts`.
+///
+/// Each event in that buffer is encoded as `{ i32 offset, u8 flags, i32 length, u8 message[] }`
+/// with little-endian integers. The offset is relative to the previous event. Here the offset is
+/// already resolved to an absolute position.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PackedMidiEvent {
+    /// Absolute position in MIDI ticks, relative to the start of the take's MIDI source.
+    pub position: PositionInPpq,
+    pub selected: bool,
+    pub muted: bool,
+    /// Shape of the curve to the next CC event of the same lane (only relevant for CC events).
+    pub cc_shape: MidiCcShape,
+    /// Flag bits not interpreted by *reaper-rs*.
+    ///
+    /// They are preserved in order to make encoding the decoded events lossless.
+    pub other_flags: u8,
+    /// Raw MIDI message bytes.
+    pub message: Vec<u8>,
+}
+
+impl PackedMidiEvent {
+    /// Creates an unselected, unmuted event with square CC shape.
+    pub fn new(position: PositionInPpq, message: Vec<u8>) -> PackedMidiEvent {
+        PackedMidiEvent {
+            position,
+            selected: false,
+            muted: false,
+            cc_shape: MidiCcShape::Square,
+            other_flags: 0,
+            message,
+        }
+    }
+
+    /// Creates the meta event which carries the Bézier curve data of the preceding CC event.
+    pub fn cc_bezier(position: PositionInPpq, data: CcBezierData) -> PackedMidiEvent {
+        let mut message = CC_BEZIER_PREFIX.to_vec();
+        message.push(data.bezier_type);
+        message.extend_from_slice(&data.tension.to_le_bytes());
+        PackedMidiEvent::new(position, message)
+    }
+
+    /// Returns the Bézier curve data if this is the meta event which carries it for the preceding
+    /// CC event.
+    pub fn cc_bezier_data(&self) -> Option<CcBezierData> {
+        let data = self.message.strip_prefix(CC_BEZIER_PREFIX)?;
+        if data.len() != 5 {
+            return None;
+        }
+        let result = CcBezierData {
+            bezier_type: data[0],
+            tension: f32::from_le_bytes(data[1..5].try_into().ok()?),
+        };
+        Some(result)
+    }
+
+    fn flags(&self) -> u8 {
+        let mut flags = self.other_flags & !(FLAG_SELECTED | FLAG_MUTED | CC_SHAPE_MASK);
+        if self.selected {
+            flags |= FLAG_SELECTED;
+        }
+        if self.muted {
+            flags |= FLAG_MUTED;
+        }
+        flags | (((self.cc_shape.to_raw() as u8) << 4) & CC_SHAPE_MASK)
+    }
+}
+
+/// Bézier curve data of a CC event.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CcBezierData {
+    /// Bézier type (usually 0).
+    pub bezier_type: u8,
+    /// Bézier tension (-1.0 to 1.0).
+    pub tension: f32,
+}
+
+/// Decodes a packed MIDI event buffer as returned by `MIDI_GetAllEvts`.
+///
+/// # Errors
+///
+/// Returns an error if the buffer is truncated.
+pub fn decode_packed_midi_events(
+    buffer: &[u8],
+) -> Result<Vec<PackedMidiEvent>, DecodeMidiEventsError> {
+    let mut events = Vec::new();
+    let mut position: i64 = 0;
+    let mut i = 0;
+    while i < buffer.len() {
+        let header = buffer
+            .get(i..i + 9)
+            .ok_or_else(|| DecodeMidiEventsError::new("truncated event header", i))?;
+        let offset = i32::from_le_bytes(header[0..4].try_into().expect("impossible"));
+        let flags = header[4];
+        let length = i32::from_le_bytes(header[5..9].try_into().expect("impossible"));
+        if length < 0 {
+            return Err(DecodeMidiEventsError::new("negative message length", i + 5));
+        }
+        let message_start = i + 9;
+        let message = buffer
+            .get(message_start..message_start + length as usize)
+            .ok_or_else(|| DecodeMidiEventsError::new("truncated message", message_start))?;
+        position += offset as i64;
+        let event = PackedMidiEvent {
+            position: PositionInPpq::new(position as f64),
+            selected: flags & FLAG_SELECTED != 0,
+            muted: flags & FLAG_MUTED != 0,
+            cc_shape: MidiCcShape::from_raw(((flags & CC_SHAPE_MASK) >> 4) as i32),
+            other_flags: flags & !(FLAG_SELECTED | FLAG_MUTED | CC_SHAPE_MASK),
+            message: message.to_vec(),
+        };
+        events.push(event);
+        i = message_start + length as usize;
+    }
+    Ok(events)
+}
+
+/// Encodes the given events into a packed MIDI event buffer as expected by `MIDI_SetAllEvts`.
+///
+/// Positions are rounded to whole ticks. The events should be sorted by position.
+pub fn encode_packed_midi_events<'a>(
+    events: impl IntoIterator<Item = &'a PackedMidiEvent>,
+) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    let mut previous_position: i64 = 0;
+    for event in events {
+        let position = event.position.get().round() as i64;
+        let offset = (position - previous_position) as i32;
+        buffer.extend_from_slice(&offset.to_le_bytes());
+        buffer.push(event.flags());
+        buffer.extend_from_slice(&(event.message.len() as i32).to_le_bytes());
+        buffer.extend_from_slice(&event.message);
+        previous_position = position;
+    }
+    buffer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_event(offset: i32, flags: u8, message: &[u8]) -> Vec<u8> {
+        let mut bytes = offset.to_le_bytes().to_vec();
+        bytes.push(flags);
+        bytes.extend_from_slice(&(message.len() as i32).to_le_bytes());
+        bytes.extend_from_slice(message);
+        bytes
+    }
+
+    #[test]
+    fn decode_resolves_absolute_positions_and_flags() {
+        // Given
+        let buffer = [
+            raw_event(0, 1, &[0x90, 60, 100]),
+            raw_event(480, 2, &[0x80, 60, 0]),
+            raw_event(120, 0x50 | 1, &[0xB0, 7, 64]),
+        ]
+        .concat();
+        // When
+        let events = decode_packed_midi_events(&buffer).unwrap();
+        // Then
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].position, PositionInPpq::new(0.0));
+        assert!(events[0].selected);
+        assert!(!events[0].muted);
+        assert_eq!(events[1].position, PositionInPpq::new(480.0));
+        assert!(events[1].muted);
+        assert_eq!(events[1].message, vec![0x80, 60, 0]);
+        assert_eq!(events[2].position, PositionInPpq::new(600.0));
+        assert_eq!(events[2].cc_shape, MidiCcShape::Bezier);
+        assert!(events[2].selected);
+    }
+
+    #[test]
+    fn round_trip_is_byte_exact() {
+        // Given
+        let bezier = PackedMidiEvent::cc_bezier(
+            PositionInPpq::new(0.0),
+            CcBezierData {
+                bezier_type: 0,
+                tension: -0.25,
+            },
+        );
+        let buffer = [
+            raw_event(10, 0x10, &[0xB0, 1, 0]),
+            raw_event(0, 0x0C, &bezier.message),
+            raw_event(-5, 0x33, &[0xF0, 1, 2, 3, 0xF7]),
+            raw_event(960, 0, &[0xB0, 0x7B, 0]),
+        ]
+        .concat();
+        // When
+        let events = decode_packed_midi_events(&buffer).unwrap();
+        let encoded = encode_packed_midi_events(&events);
+        // Then
+        assert_eq!(encoded, buffer);
+        assert_eq!(events[2].position, PositionInPpq::new(5.0));
+        assert_eq!(events[2].cc_shape, MidiCcShape::FastStart);
+        assert_eq!(events[1].other_flags, 0x0C);
+        assert_eq!(
+            events[1].cc_bezier_data(),
+            Some(CcBezierData {
+                bezier_type: 0,
+                tension: -0.25
+            })
+        );
+    }
+
+    #[test]
+    fn encode_computes_relative_offsets() {
+        // Given
+        let mut note_on = PackedMidiEvent::new(PositionInPpq::new(240.0), vec![0x90, 64, 90]);
+        note_on.selected = true;
+        let note_off = PackedMidiEvent::new(PositionInPpq::new(720.0), vec![0x80, 64, 0]);
+        // When
+        let encoded = encode_packed_midi_events(&[note_on, note_off]);
+        // Then
+        let expected = [
+            raw_event(240, 1, &[0x90, 64, 90]),
+            raw_event(480, 0, &[0x80, 64, 0]),
+        ]
+        .concat();
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn decode_rejects_truncated_buffers() {
+        let buffer = raw_event(0, 0, &[0x90, 60, 100]);
+        let error = decode_packed_midi_events(&buffer[..buffer.len() - 1]).unwrap_err();
+        assert_eq!(error.offset(), 9);
+        assert!(decode_packed_midi_events(&buffer[..5]).is_err());
+        assert_eq!(decode_packed_midi_events(&[]).unwrap(), vec![]);
+    }
+}
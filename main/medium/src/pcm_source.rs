@@ -7,7 +7,7 @@ use crate::util::{create_passing_c_str, with_string_buffer};
 use crate::{
     BorrowedMidiEventList, Bpm, DurationInBeats, DurationInSeconds, ExtendedArgs, Hwnd, Hz,
     MediaItemTake, PcmSource, PositionInSeconds, ReaperFunctionError, ReaperFunctionResult,
-    ReaperStr, ReaperString,
+    ReaperStr, ReaperString, ReaperStringArg,
 };
 use reaper_low::raw::{PCM_source, PCM_source_peaktransfer_t, PCM_source_transfer_t, HWND__};
 use std::borrow::Borrow;
@@ -177,7 +177,7 @@ impl PcmSourceTransfer {
 //
 // Case 2: Internals exposed: yes | vtable: no
 // ===========================================
-#[derive(Copy, Clone, PartialEq, Debug, RefCast)]
+#[derive(Copy, Clone, PartialEq, Debug, Default, RefCast)]
 #[repr(transparent)]
 pub struct PcmSourcePeakTransfer(raw::PCM_source_peaktransfer_t);
 
@@ -186,6 +186,193 @@ impl PcmSourcePeakTransfer {
     pub fn as_ptr(&self) -> NonNull<raw::PCM_source_peaktransfer_t> {
         NonNull::from(&self.0)
     }
+
+    /// Returns the start time of the requested peaks.
+    pub fn start_time(&self) -> PositionInSeconds {
+        unsafe { PositionInSeconds::new_unchecked(self.0.start_time) }
+    }
+
+    /// Sets the start time of the requested peaks.
+    pub fn set_start_time(&mut self, time: PositionInSeconds) {
+        self.0.start_time = time.get();
+    }
+
+    /// Returns the absolute start time of the requested peaks.
+    pub fn absolute_time_s(&self) -> PositionInSeconds {
+        unsafe { PositionInSeconds::new_unchecked(self.0.absolute_time_s) }
+    }
+
+    /// Sets the absolute start time of the requested peaks.
+    pub fn set_absolute_time_s(&mut self, time: PositionInSeconds) {
+        self.0.absolute_time_s = time.get();
+    }
+
+    /// Returns the number of peaks per second.
+    ///
+    /// Returns `None` if the peak rate hasn't been set (yet).
+    pub fn peak_rate(&self) -> Option<Hz> {
+        if self.0.peakrate > 0.0 {
+            Some(Hz(self.0.peakrate))
+        } else {
+            None
+        }
+    }
+
+    /// Sets the number of peaks per second.
+    pub fn set_peak_rate(&mut self, rate: Hz) {
+        self.0.peakrate = rate.get();
+    }
+
+    /// Returns the sample rate of the source (informational).
+    pub fn sample_rate(&self) -> Option<Hz> {
+        if self.0.samplerate > 0.0 {
+            Some(Hz(self.0.samplerate))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the desired number of peaks per channel.
+    pub fn sample_count(&self) -> u32 {
+        self.0.numpeak_points.max(0) as u32
+    }
+
+    /// Returns the desired number of channels.
+    pub fn channel_count(&self) -> u32 {
+        self.0.nchpeaks.max(0) as u32
+    }
+
+    /// Returns the number of peaks per channel which have actually been written.
+    pub fn peaks_out(&self) -> u32 {
+        self.0.peaks_out.max(0) as u32
+    }
+
+    /// Returns the output mode (0 → peaks, 1 → waveform, 2 → MIDI note, ...).
+    pub fn output_mode(&self) -> i32 {
+        self.0.output_mode
+    }
+
+    /// Returns whether the source has filled the minimum values buffer.
+    ///
+    /// If not, the minimum values are the negated maximum values.
+    pub fn peaks_minvals_used(&self) -> bool {
+        self.0.peaks_minvals_used != 0
+    }
+
+    /// Sets the buffers to be filled with the maximum and (optionally) minimum values.
+    ///
+    /// Sets the desired sample and channel count accordingly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffers have different lengths or if the length is not a multiple of the
+    /// channel count.
+    ///
+    /// # Safety
+    ///
+    /// The buffers must outlive the peak transfer usage.
+    pub unsafe fn set_buffers(
+        &mut self,
+        channel_count: u32,
+        max_values: &mut [f64],
+        min_values: Option<&mut [f64]>,
+    ) {
+        assert!(channel_count > 0, "channel count must be greater than 0");
+        assert_eq!(
+            max_values.len() % channel_count as usize,
+            0,
+            "buffer length must be a multiple of the channel count"
+        );
+        self.0.nchpeaks = channel_count as _;
+        self.0.numpeak_points = (max_values.len() / channel_count as usize) as _;
+        self.0.peaks = max_values.as_mut_ptr();
+        self.0.peaks_minvals = match min_values {
+            None => null_mut(),
+            Some(b) => {
+                assert_eq!(b.len(), max_values.len(), "buffer lengths must be equal");
+                b.as_mut_ptr()
+            }
+        };
+    }
+
+    /// Returns the maximum values (interleaved by channel).
+    ///
+    /// # Safety
+    ///
+    /// If the buffer or counts are set incorrectly, this results in undefined behavior.
+    pub unsafe fn max_values(&self) -> &[f64] {
+        buffer_as_slice(self.0.peaks, self.sample_count() * self.channel_count())
+    }
+
+    /// Returns the minimum values (interleaved by channel).
+    ///
+    /// # Safety
+    ///
+    /// If the buffer or counts are set incorrectly, this results in undefined behavior.
+    pub unsafe fn min_values(&self) -> &[f64] {
+        buffer_as_slice(
+            self.0.peaks_minvals,
+            self.sample_count() * self.channel_count(),
+        )
+    }
+
+    /// Returns the extra buffer at the given index (`exp` array).
+    ///
+    /// Extra buffers receive additional per-peak data, e.g. spectral information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range (there are 30 extra buffers).
+    pub fn extra_buffer(&self, index: usize) -> Option<NonNull<i32>> {
+        NonNull::new(self.0.exp[index])
+    }
+
+    /// Sets the extra buffer at the given index (`exp` array).
+    ///
+    /// It should have the same length as the maximum values buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range (there are 30 extra buffers).
+    ///
+    /// # Safety
+    ///
+    /// The buffer must outlive the peak transfer usage.
+    pub unsafe fn set_extra_buffer(&mut self, index: usize, buffer: Option<&mut [i32]>) {
+        self.0.exp[index] = match buffer {
+            None => null_mut(),
+            Some(b) => b.as_mut_ptr(),
+        };
+    }
+
+    /// Returns the values of the extra buffer at the given index (interleaved by channel).
+    ///
+    /// Returns an empty slice if the buffer is not set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range (there are 30 extra buffers).
+    ///
+    /// # Safety
+    ///
+    /// If the buffer or counts are set incorrectly, this results in undefined behavior.
+    pub unsafe fn extra_values(&self, index: usize) -> &[i32] {
+        let buffer = self.0.exp[index];
+        if buffer.is_null() {
+            return &[];
+        }
+        std::slice::from_raw_parts(
+            buffer,
+            (self.sample_count() * self.channel_count()) as usize,
+        )
+    }
+}
+
+unsafe fn buffer_as_slice<'a>(buffer: *const f64, len: u32) -> &'a [f64] {
+    if buffer.is_null() {
+        return &[];
+    }
+    std::slice::from_raw_parts(buffer, len as usize)
 }
 
 /// Pointer to a project state context.
@@ -201,8 +388,35 @@ impl BorrowedProjectStateContext {
     pub fn as_ptr(&self) -> NonNull<raw::ProjectStateContext> {
         NonNull::from(&self.0)
     }
+
+    /// Writes the given line.
+    pub fn add_line<'a>(&self, line: impl Into<ReaperStringArg<'a>>) {
+        unsafe { (*self.as_ptr().as_ptr()).AddLine(line.into().as_ptr()) }
+    }
+
+    /// Reads the next line.
+    ///
+    /// Returns `None` if there are no more lines. Leading whitespace is already trimmed by
+    /// REAPER.
+    pub fn get_line(&self) -> Option<ReaperString> {
+        let (line, result) =
+            with_string_buffer(MAX_PROJECT_STATE_LINE_LENGTH, |buf, max_size| unsafe {
+                (*self.as_ptr().as_ptr()).GetLine(buf, max_size)
+            });
+        if result != 0 {
+            return None;
+        }
+        Some(line)
+    }
+
+    /// Returns whether this is a temporary context, e.g. for creating an undo point.
+    pub fn get_temp_flag(&self) -> i32 {
+        unsafe { (*self.as_ptr().as_ptr()).GetTempFlag() }
+    }
 }
 
+const MAX_PROJECT_STATE_LINE_LENGTH: u32 = 16 * 1024;
+
 // Case 3: Internals exposed: no | vtable: yes
 // ===========================================
 
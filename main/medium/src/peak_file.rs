@@ -0,0 +1,440 @@
+use crate::ParsePeakFileError;
+use std::convert::TryInto;
+
+const HEADER_SIZE: usize = 18;
+const MIPMAP_HEADER_SIZE: usize = 8;
+const MAX_MIPMAP_COUNT: usize = 16;
+
+/// Contents of a `.reapeaks` file, in which REAPER caches waveform overviews of a source.
+///
+/// The file starts with a header:
+///
+/// - 4 bytes: magic (`RPKM` or `RPKN`)
+/// - 1 byte: channel count
+/// - 1 byte: mipmap count (max. 16)
+/// - 4 bytes: sample rate of the source
+/// - 4 bytes: last modification time of the source file (Unix time)
+/// - 4 bytes: size of the source file
+///
+/// It's followed by the division factor and peak count of each mipmap (4 bytes each) and finally
+/// by the peaks of each mipmap. Each peak consists of a 16-bit maximum and minimum value per
+/// channel. In `RPKN` files, the peaks of each mipmap are followed by one 16-bit spectral value
+/// per channel and peak. All integers are little-endian.
+///
+/// This works without REAPER running.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PeakFile {
+    pub format: PeakFileFormat,
+    pub channel_count: u8,
+    pub sample_rate: u32,
+    /// Last modification time of the source file (Unix time, low 32 bits).
+    pub source_modified_time: u32,
+    /// Size of the source file (low 32 bits).
+    pub source_file_size: u32,
+    /// Mipmaps, usually ordered from the finest to the coarsest resolution.
+    pub mipmaps: Vec<PeakMipmap>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PeakFileFormat {
+    /// Peaks only.
+    Rpkm,
+    /// Peaks plus spectral information.
+    Rpkn,
+}
+
+/// One resolution level of a peak file.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PeakMipmap {
+    /// Number of source samples per peak.
+    pub division_factor: u32,
+    /// Peaks interleaved by channel (peak `i` of channel `c` is at `i * channel_count + c`).
+    pub peaks: Vec<PeakValue>,
+    /// Spectral values interleaved by channel (only in `RPKN` files, otherwise empty).
+    pub spectral: Vec<u16>,
+}
+
+/// Minimum and maximum sample value within one peak, scaled to the 16-bit range.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct PeakValue {
+    pub max: i16,
+    pub min: i16,
+}
+
+impl PeakFileFormat {
+    fn magic(self) -> &'static [u8; 4] {
+        match self {
+            PeakFileFormat::Rpkm => b"RPKM",
+            PeakFileFormat::Rpkn => b"RPKN",
+        }
+    }
+}
+
+impl PeakMipmap {
+    /// Returns the number of peaks per channel.
+    pub fn peak_count(&self, channel_count: u8) -> usize {
+        if channel_count == 0 {
+            return 0;
+        }
+        self.peaks.len() / channel_count as usize
+    }
+}
+
+impl PeakValue {
+    /// Returns the maximum value in the range from -1.0 to 1.0.
+    pub fn max_f64(self) -> f64 {
+        self.max as f64 / i16::MAX as f64
+    }
+
+    /// Returns the minimum value in the range from -1.0 to 1.0.
+    pub fn min_f64(self) -> f64 {
+        self.min as f64 / i16::MAX as f64
+    }
+}
+
+impl PeakFile {
+    /// Computes an `RPKM` peak file from interleaved samples.
+    ///
+    /// Creates one mipmap for each given division factor (number of samples per peak). A trailing
+    /// incomplete frame (fewer samples than channels) is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the channel count is 0, if a division factor is 0 or if more than 16 division
+    /// factors are given.
+    pub fn from_samples(
+        sample_rate: u32,
+        channel_count: u8,
+        samples: &[f64],
+        division_factors: &[u32],
+    ) -> PeakFile {
+        assert!(channel_count > 0, "channel count must be greater than 0");
+        assert!(
+            division_factors.len() <= MAX_MIPMAP_COUNT,
+            "too many mipmaps"
+        );
+        let channel_count_usize = channel_count as usize;
+        // Only complete frames, so that each chunk has at least one sample for each channel.
+        let samples = &samples[..samples.len() - samples.len() % channel_count_usize];
+        let mipmaps = division_factors
+            .iter()
+            .map(|&division_factor| {
+                assert!(
+                    division_factor > 0,
+                    "division factor must be greater than 0"
+                );
+                let chunk_size = division_factor as usize * channel_count_usize;
+                let peaks = samples
+                    .chunks(chunk_size)
+                    .flat_map(|chunk| {
+                        (0..channel_count_usize).map(move |ch| {
+                            let channel_samples =
+                                chunk.iter().skip(ch).step_by(channel_count_usize);
+                            let (min, max) = channel_samples
+                                .fold((f64::MAX, f64::MIN), |(min, max), s| {
+                                    (min.min(*s), max.max(*s))
+                                });
+                            PeakValue {
+                                max: to_i16(max),
+                                min: to_i16(min),
+                            }
+                        })
+                    })
+                    .collect();
+                PeakMipmap {
+                    division_factor,
+                    peaks,
+                    spectral: vec![],
+                }
+            })
+            .collect();
+        PeakFile {
+            format: PeakFileFormat::Rpkm,
+            channel_count,
+            sample_rate,
+            source_modified_time: 0,
+            source_file_size: 0,
+            mipmaps,
+        }
+    }
+
+    /// Parses the contents of a peak file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given bytes are not a valid `RPKM` or `RPKN` peak file.
+    pub fn parse(bytes: &[u8]) -> Result<PeakFile, ParsePeakFileError> {
+        let mut reader = Reader { bytes, offset: 0 };
+        let format = match reader.take(4)? {
+            b"RPKM" => PeakFileFormat::Rpkm,
+            b"RPKN" => PeakFileFormat::Rpkn,
+            _ => return Err(ParsePeakFileError::new("unknown magic", 0)),
+        };
+        let channel_count = reader.u8()?;
+        if channel_count == 0 {
+            return Err(ParsePeakFileError::new("channel count is 0", 4));
+        }
+        let mipmap_count = reader.u8()? as usize;
+        if mipmap_count > MAX_MIPMAP_COUNT {
+            return Err(ParsePeakFileError::new("too many mipmaps", 5));
+        }
+        let sample_rate = reader.u32()?;
+        let source_modified_time = reader.u32()?;
+        let source_file_size = reader.u32()?;
+        let mipmap_headers = (0..mipmap_count)
+            .map(|_| Ok((reader.u32()?, reader.u32()? as usize)))
+            .collect::<Result<Vec<_>, ParsePeakFileError>>()?;
+        let mipmaps = mipmap_headers
+            .into_iter()
+            .map(|(division_factor, peak_count)| {
+                let value_count =
+                    peak_count
+                        .checked_mul(channel_count as usize)
+                        .ok_or_else(|| {
+                            ParsePeakFileError::new("peak count too large", reader.offset)
+                        })?;
+                let peaks = (0..value_count)
+                    .map(|_| {
+                        Ok(PeakValue {
+                            max: reader.i16()?,
+                            min: reader.i16()?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, ParsePeakFileError>>()?;
+                let spectral = match format {
+                    PeakFileFormat::Rpkm => vec![],
+                    PeakFileFormat::Rpkn => (0..value_count)
+                        .map(|_| reader.u16())
+                        .collect::<Result<Vec<_>, ParsePeakFileError>>()?,
+                };
+                Ok(PeakMipmap {
+                    division_factor,
+                    peaks,
+                    spectral,
+                })
+            })
+            .collect::<Result<Vec<_>, ParsePeakFileError>>()?;
+        Ok(PeakFile {
+            format,
+            channel_count,
+            sample_rate,
+            source_modified_time,
+            source_file_size,
+            mipmaps,
+        })
+    }
+
+    /// Serializes this peak file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than 16 mipmaps, if the number of peaks of a mipmap is not a
+    /// multiple of the channel count or if the number of spectral values doesn't match the format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        assert!(self.mipmaps.len() <= MAX_MIPMAP_COUNT, "too many mipmaps");
+        let mut bytes = Vec::with_capacity(
+            HEADER_SIZE
+                + self.mipmaps.len() * MIPMAP_HEADER_SIZE
+                + self
+                    .mipmaps
+                    .iter()
+                    .map(|m| m.peaks.len() * 6)
+                    .sum::<usize>(),
+        );
+        bytes.extend_from_slice(self.format.magic());
+        bytes.push(self.channel_count);
+        bytes.push(self.mipmaps.len() as u8);
+        bytes.extend_from_slice(&self.sample_rate.to_le_bytes());
+        bytes.extend_from_slice(&self.source_modified_time.to_le_bytes());
+        bytes.extend_from_slice(&self.source_file_size.to_le_bytes());
+        for mipmap in &self.mipmaps {
+            assert_eq!(
+                mipmap.peaks.len() % self.channel_count.max(1) as usize,
+                0,
+                "peak count must be a multiple of the channel count"
+            );
+            bytes.extend_from_slice(&mipmap.division_factor.to_le_bytes());
+            let peak_count = mipmap.peak_count(self.channel_count) as u32;
+            bytes.extend_from_slice(&peak_count.to_le_bytes());
+        }
+        for mipmap in &self.mipmaps {
+            for peak in &mipmap.peaks {
+                bytes.extend_from_slice(&peak.max.to_le_bytes());
+                bytes.extend_from_slice(&peak.min.to_le_bytes());
+            }
+            match self.format {
+                PeakFileFormat::Rpkm => {
+                    assert!(mipmap.spectral.is_empty(), "RPKM has no spectral values");
+                }
+                PeakFileFormat::Rpkn => {
+                    assert_eq!(
+                        mipmap.spectral.len(),
+                        mipmap.peaks.len(),
+                        "RPKN needs one spectral value per peak"
+                    );
+                    for value in &mipmap.spectral {
+                        bytes.extend_from_slice(&value.to_le_bytes());
+                    }
+                }
+            }
+        }
+        bytes
+    }
+
+    /// Returns the mipmap whose resolution is closest to the given number of samples per peak.
+    pub fn best_mipmap_for(&self, samples_per_peak: f64) -> Option<&PeakMipmap> {
+        self.mipmaps.iter().min_by(|a, b| {
+            let distance = |m: &PeakMipmap| (m.division_factor as f64 - samples_per_peak).abs();
+            distance(a)
+                .partial_cmp(&distance(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+    }
+}
+
+fn to_i16(value: f64) -> i16 {
+    (value.clamp(-1.0, 1.0) * i16::MAX as f64).round() as i16
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], ParsePeakFileError> {
+        let slice = self
+            .bytes
+            .get(self.offset..self.offset + count)
+            .ok_or_else(|| ParsePeakFileError::new("unexpected end of file", self.offset))?;
+        self.offset += count;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, ParsePeakFileError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, ParsePeakFileError> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn i16(&mut self) -> Result<i16, ParsePeakFileError> {
+        Ok(i16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, ParsePeakFileError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_samples_computes_min_max_per_channel() {
+        // Given
+        let samples = [0.5, -0.5, -1.0, 0.25, 0.0, 1.0];
+        // When
+        let file = PeakFile::from_samples(44100, 2, &samples, &[2, 4]);
+        // Then
+        assert_eq!(file.mipmaps.len(), 2);
+        let fine = &file.mipmaps[0];
+        assert_eq!(fine.peak_count(2), 2);
+        assert_eq!(
+            fine.peaks[0],
+            PeakValue {
+                max: i16::MAX / 2 + 1,
+                min: -i16::MAX
+            }
+        );
+        assert_eq!(
+            fine.peaks[1],
+            PeakValue {
+                max: i16::MAX / 4 + 1,
+                min: -i16::MAX / 2 - 1
+            }
+        );
+        assert_eq!(fine.peaks[2], PeakValue { max: 0, min: 0 });
+        assert_eq!(
+            fine.peaks[3],
+            PeakValue {
+                max: i16::MAX,
+                min: i16::MAX
+            }
+        );
+        let coarse = &file.mipmaps[1];
+        assert_eq!(coarse.peak_count(2), 1);
+        assert_eq!(coarse.peaks[1].max, i16::MAX);
+        assert_eq!(coarse.peaks[1].min, -i16::MAX / 2 - 1);
+    }
+
+    #[test]
+    fn from_samples_ignores_incomplete_last_frame() {
+        // Given
+        let samples = [0.5, -0.5, 0.25, 1.0, 0.75];
+        // When
+        let file = PeakFile::from_samples(44100, 2, &samples, &[1]);
+        // Then
+        let mipmap = &file.mipmaps[0];
+        assert_eq!(mipmap.peak_count(2), 2);
+        assert!(mipmap.peaks.iter().all(|p| p.min <= p.max));
+        assert_eq!(mipmap.peaks[2].max, to_i16(0.25));
+        assert_eq!(mipmap.peaks[3].max, i16::MAX);
+    }
+
+    #[test]
+    fn roundtrip() {
+        // Given
+        let file = PeakFile {
+            format: PeakFileFormat::Rpkn,
+            channel_count: 1,
+            sample_rate: 48000,
+            source_modified_time: 1_600_000_000,
+            source_file_size: 12345,
+            mipmaps: vec![PeakMipmap {
+                division_factor: 400,
+                peaks: vec![
+                    PeakValue { max: 10, min: -20 },
+                    PeakValue { max: 5, min: 1 },
+                ],
+                spectral: vec![7, 8],
+            }],
+        };
+        // When
+        let bytes = file.to_bytes();
+        // Then
+        assert_eq!(&bytes[0..4], b"RPKN");
+        assert_eq!(
+            bytes.len(),
+            HEADER_SIZE + MIPMAP_HEADER_SIZE + 2 * 4 + 2 * 2
+        );
+        assert_eq!(PeakFile::parse(&bytes), Ok(file));
+    }
+
+    #[test]
+    fn parse_rejects_invalid_files() {
+        // Given
+        let file = PeakFile::from_samples(44100, 1, &[0.1, 0.2, 0.3], &[1]);
+        let bytes = file.to_bytes();
+        // When
+        let unknown_magic = PeakFile::parse(b"RIFF\x01\x00");
+        let truncated = PeakFile::parse(&bytes[..bytes.len() - 1]);
+        // Then
+        assert_eq!(unknown_magic.unwrap_err().message(), "unknown magic");
+        let error = truncated.unwrap_err();
+        assert_eq!(error.message(), "unexpected end of file");
+        assert_eq!(error.offset(), bytes.len() - 2);
+    }
+
+    #[test]
+    fn best_mipmap() {
+        // Given
+        let file = PeakFile::from_samples(44100, 1, &[0.0; 1024], &[16, 256]);
+        // When
+        let mipmap = file.best_mipmap_for(200.0).unwrap();
+        // Then
+        assert_eq!(mipmap.division_factor, 256);
+    }
+}
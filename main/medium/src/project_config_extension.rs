@@ -0,0 +1,142 @@
+use crate::{decode_user_data, encode_user_data, BorrowedProjectStateContext, ReaperStr};
+use reaper_low::{firewall, raw};
+use ref_cast::RefCast;
+use std::fmt::{Debug, Formatter};
+use std::os::raw::c_char;
+
+/// Consumers need to implement this trait in order to store custom state in the project file
+/// (`.RPP`) and in undo points.
+///
+/// Use [`get_current_project_in_load_save()`] to find out which project is being processed.
+///
+/// See [`plugin_register_add_project_config()`].
+///
+/// [`get_current_project_in_load_save()`]: struct.Reaper.html#method.get_current_project_in_load_save
+/// [`plugin_register_add_project_config()`]: struct.ReaperSession.html#method.plugin_register_add_project_config
+pub trait ProjectConfigExtension {
+    /// Called for each top-level line of the project state which REAPER doesn't know itself.
+    ///
+    /// If the line belongs to this extension, consume it (for blocks such as `<MYEXT ...`, read
+    /// the remaining lines including the closing `>` via [`get_line()`]) and return `true`.
+    /// Otherwise return `false` so other extensions get the chance to process it.
+    ///
+    /// `is_undo` is `true` if an undo point is being restored.
+    ///
+    /// [`get_line()`]: struct.BorrowedProjectStateContext.html#method.get_line
+    fn process_extension_line(
+        &mut self,
+        line: &ReaperStr,
+        ctx: &BorrowedProjectStateContext,
+        is_undo: bool,
+    ) -> bool;
+
+    /// Called when the project state is saved.
+    ///
+    /// Write lines via [`add_line()`]. `is_undo` is `true` if an undo point is being created.
+    ///
+    /// [`add_line()`]: struct.BorrowedProjectStateContext.html#method.add_line
+    fn save_extension_config(&mut self, ctx: &BorrowedProjectStateContext, is_undo: bool);
+
+    /// Called before the project state is loaded, also when an undo point is restored or a new
+    /// project is created.
+    ///
+    /// Useful for resetting the state to its defaults because
+    /// [`process_extension_line()`](#tymethod.process_extension_line) won't be called at all if
+    /// the project doesn't contain any state of this extension.
+    fn begin_load_project_state(&mut self, is_undo: bool) {
+        let _ = is_undo;
+    }
+}
+
+extern "C" fn delegating_process_extension_line<T: ProjectConfigExtension>(
+    line: *const c_char,
+    ctx: *mut raw::ProjectStateContext,
+    is_undo: bool,
+    reg: *mut raw::project_config_extension_t,
+) -> bool {
+    firewall(|| {
+        if line.is_null() || ctx.is_null() || reg.is_null() {
+            return false;
+        }
+        let callback_struct: &mut T = decode_user_data(unsafe { (*reg).userData });
+        let line = unsafe { ReaperStr::from_ptr(line) };
+        let ctx = BorrowedProjectStateContext::ref_cast(unsafe { &*ctx });
+        callback_struct.process_extension_line(line, ctx, is_undo)
+    })
+    .unwrap_or(false)
+}
+
+extern "C" fn delegating_save_extension_config<T: ProjectConfigExtension>(
+    ctx: *mut raw::ProjectStateContext,
+    is_undo: bool,
+    reg: *mut raw::project_config_extension_t,
+) {
+    firewall(|| {
+        if ctx.is_null() || reg.is_null() {
+            return;
+        }
+        let callback_struct: &mut T = decode_user_data(unsafe { (*reg).userData });
+        let ctx = BorrowedProjectStateContext::ref_cast(unsafe { &*ctx });
+        callback_struct.save_extension_config(ctx, is_undo);
+    });
+}
+
+extern "C" fn delegating_begin_load_project_state<T: ProjectConfigExtension>(
+    is_undo: bool,
+    reg: *mut raw::project_config_extension_t,
+) {
+    firewall(|| {
+        if reg.is_null() {
+            return;
+        }
+        let callback_struct: &mut T = decode_user_data(unsafe { (*reg).userData });
+        callback_struct.begin_load_project_state(is_undo);
+    });
+}
+
+// Case 2: Internals exposed: yes | vtable: no
+// ===========================================
+//
+// We don't expose the user-defined data pointer. It's already exposed implicitly as `&mut self` in
+// the callback functions.
+pub(crate) struct OwnedProjectConfigExtension {
+    inner: raw::project_config_extension_t,
+    callback: Box<dyn ProjectConfigExtension>,
+}
+
+impl Debug for OwnedProjectConfigExtension {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // ProjectConfigExtension doesn't generally implement Debug.
+        f.debug_struct("OwnedProjectConfigExtension")
+            .field("inner", &self.inner)
+            .field("callback", &"<omitted>")
+            .finish()
+    }
+}
+
+impl OwnedProjectConfigExtension {
+    pub fn new<T>(callback: Box<T>) -> Self
+    where
+        T: ProjectConfigExtension + 'static,
+    {
+        Self {
+            inner: raw::project_config_extension_t {
+                ProcessExtensionLine: Some(delegating_process_extension_line::<T>),
+                SaveExtensionConfig: Some(delegating_save_extension_config::<T>),
+                BeginLoadProjectState: Some(delegating_begin_load_project_state::<T>),
+                userData: encode_user_data(&callback),
+            },
+            callback,
+        }
+    }
+
+    pub fn into_callback(self) -> Box<dyn ProjectConfigExtension> {
+        self.callback
+    }
+}
+
+impl AsRef<raw::project_config_extension_t> for OwnedProjectConfigExtension {
+    fn as_ref(&self) -> &raw::project_config_extension_t {
+        &self.inner
+    }
+}
@@ -91,8 +91,12 @@ pub type MediaItem = NonNull<raw::MediaItem>;
 pub type MediaItemTake = NonNull<raw::MediaItem_Take>;
 /// Pointer to an envelope on a track.
 pub type TrackEnvelope = NonNull<raw::TrackEnvelope>;
+/// Pointer to an audio accessor.
+pub type AudioAccessor = NonNull<raw::AudioAccessor>;
 /// Pointer to a window (window handle).
 pub type Hwnd = NonNull<raw::HWND__>;
+/// Pointer to a menu (menu handle).
+pub type Hmenu = NonNull<raw::HMENU__>;
 /// Pointer to a module/instance (module/instance handle).
 pub type Hinstance = NonNull<c_void>;
 
@@ -7,27 +7,31 @@ use reaper_low::{raw, register_plugin_destroy_hook};
 use crate::ProjectContext::CurrentProject;
 use crate::{
     require_non_null_panic, Accel, ActionValueChange, AddFxBehavior, AudioDeviceAttributeKey,
-    AutoSeekBehavior, AutomationMode, BookmarkId, BookmarkRef, Bpm, ChunkCacheHint, CommandId, Db,
-    DurationInSeconds, EditMode, EnvChunkName, FxAddByNameBehavior, FxChainVisibility, FxPresetRef,
-    FxShowInstruction, GangBehavior, GlobalAutomationModeOverride, HelpMode, Hidden, Hwnd,
-    InitialAction, InputMonitoringMode, KbdSectionInfo, MasterTrackBehavior, MeasureMode,
-    MediaItem, MediaItemTake, MediaTrack, MessageBoxResult, MessageBoxType, MidiImportBehavior,
-    MidiInput, MidiInputDeviceId, MidiOutput, MidiOutputDeviceId, NativeColor, NormalizedPlayRate,
-    NotificationBehavior, OwnedPcmSource, OwnedReaperPitchShift, OwnedReaperResample, PanMode,
-    ParamId, PcmSource, PitchShiftMode, PitchShiftSubMode, PlaybackSpeedFactor, PluginContext,
-    PositionInBeats, PositionInQuarterNotes, PositionInSeconds, ProjectContext, ProjectRef,
-    PromptForActionResult, ReaProject, ReaperFunctionError, ReaperFunctionResult,
-    ReaperNormalizedFxParamValue, ReaperPanLikeValue, ReaperPanValue, ReaperPointer, ReaperStr,
-    ReaperString, ReaperStringArg, ReaperVersion, ReaperVolumeValue, ReaperWidthValue,
-    RecordArmMode, RecordingInput, RequiredViewMode, ResampleMode, SectionContext, SectionId,
-    SendTarget, SoloMode, StuffMidiMessageTarget, TakeAttributeKey, TimeModeOverride,
-    TimeRangeType, TrackArea, TrackAttributeKey, TrackDefaultsBehavior, TrackEnvelope,
-    TrackFxChainType, TrackFxLocation, TrackLocation, TrackSendAttributeKey, TrackSendCategory,
-    TrackSendDirection, TrackSendRef, TransferBehavior, UiRefreshBehavior, UndoBehavior, UndoScope,
-    ValueChange, VolumeSliderValue, WindowContext,
+    AutoSeekBehavior, AutomationMode, BookmarkId, BookmarkKind, BookmarkRef, BorrowedPcmSource,
+    Bpm, ChunkCacheHint, CommandId, CreateBehavior, Db, DurationInSeconds, EditMode, EnvChunkName,
+    EnvelopePointSet, EnvelopePointShape, FxAddByNameBehavior, FxChainVisibility, FxPinDirection,
+    FxPinMask, FxPresetRef, FxShowInstruction, GangBehavior, GlobalAutomationModeOverride,
+    HelpMode, Hidden, Hwnd, Hz, InitialAction, InputMonitoringMode, ItemAttributeKey,
+    KbdSectionInfo, MasterTrackBehavior, MeasureMode, MediaItem, MediaItemTake, MediaTrack,
+    MessageBoxResult, MessageBoxType, MidiCcShape, MidiImportBehavior, MidiInput,
+    MidiInputDeviceId, MidiOutput, MidiOutputDeviceId, MidiTextSysexEventType, NativeColor,
+    NormalizedPlayRate, NotificationBehavior, OwnedAudioAccessor, OwnedPcmSource,
+    OwnedReaperPitchShift, OwnedReaperResample, PanMode, ParamId, PcmSource, PitchShiftMode,
+    PitchShiftSubMode, PlaybackSpeedFactor, PluginContext, PositionInBeats, PositionInPpq,
+    PositionInQuarterNotes, PositionInSeconds, ProjectContext, ProjectInfoKey,
+    ProjectInfoStringKey, ProjectRef, PromptForActionResult, ReaProject, ReaperFunctionError,
+    ReaperFunctionResult, ReaperNormalizedFxParamValue, ReaperPanLikeValue, ReaperPanValue,
+    ReaperPointer, ReaperStr, ReaperString, ReaperStringArg, ReaperVersion, ReaperVolumeValue,
+    ReaperWidthValue, RecordArmMode, RecordingInput, ReorderTracksBehavior, RequiredViewMode,
+    ResampleMode, SaveProjectOption, SectionContext, SectionId, SendTarget, SoloMode, SortBehavior,
+    StuffMidiMessageTarget, TakeAttributeKey, TakeFxShowInstruction, TempoMarkerPosition,
+    TimeModeOverride, TimeRangeType, TrackArea, TrackAttributeKey, TrackDefaultsBehavior,
+    TrackEnvelope, TrackFxChainType, TrackFxLocation, TrackLocation, TrackSendAttributeKey,
+    TrackSendCategory, TrackSendDirection, TrackSendRef, TransferBehavior, UiRefreshBehavior,
+    UndoBehavior, UndoScope, ValueChange, VolumeSliderValue, WindowContext,
 };
 
-use helgoboss_midi::ShortMessage;
+use helgoboss_midi::{Channel, KeyNumber, ShortMessage, U7};
 use reaper_low::raw::GUID;
 
 use crate::util::{
@@ -280,2904 +284,6512 @@ impl<UsageScope> Reaper<UsageScope> {
         }
     }
 
-    /// Returns the track at the given index.
+    /// Opens the given project file in the current project tab.
     ///
     /// # Panics
     ///
-    /// Panics if the given project is not valid anymore.
+    /// Panics if the given path is not valid UTF-8.
+    pub fn main_open_project(&self, path: &Path, behavior: OpenProjectBehavior)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let path_str = path.to_str().expect("path is not valid UTF-8");
+        let mut name = String::new();
+        if !behavior.prompt {
+            name.push_str("noprompt:");
+        }
+        if behavior.open_as_template {
+            name.push_str("template:");
+        }
+        name.push_str(path_str);
+        let name = ReaperString::from_string(name);
+        unsafe {
+            self.low.Main_openProject(name.as_ptr());
+        }
+    }
+
+    /// Saves the given project.
     ///
-    /// # Example
+    /// If `force_save_as_dialog` is `true` or the project has never been saved, REAPER shows
+    /// the "Save as" dialog.
     ///
-    /// ```no_run
-    /// # let session = reaper_medium::ReaperSession::default();
-    /// use reaper_medium::ProjectContext::CurrentProject;
+    /// # Panics
     ///
-    /// let track = session.reaper().get_track(CurrentProject, 3).ok_or("No such track")?;
-    /// # Ok::<_, Box<dyn std::error::Error>>(())
-    /// ```
-    pub fn get_track(&self, project: ProjectContext, track_index: u32) -> Option<MediaTrack>
+    /// Panics if the given project is not valid anymore.
+    pub fn main_save_project(&self, project: ProjectContext, force_save_as_dialog: bool)
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.get_track_unchecked(project, track_index) }
+        unsafe { self.main_save_project_unchecked(project, force_save_as_dialog) }
     }
 
-    /// Like [`get_track()`] but doesn't check if project is valid.
+    /// Like [`main_save_project()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`get_track()`]: #method.get_track
-    pub unsafe fn get_track_unchecked(
+    /// [`main_save_project()`]: #method.main_save_project
+    pub unsafe fn main_save_project_unchecked(
         &self,
         project: ProjectContext,
-        track_index: u32,
-    ) -> Option<MediaTrack>
-    where
+        force_save_as_dialog: bool,
+    ) where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.GetTrack(project.to_raw(), track_index as i32);
-        NonNull::new(ptr)
+        self.low
+            .Main_SaveProject(project.to_raw(), force_save_as_dialog);
     }
 
-    /// Returns the item at the given index.
+    /// Saves the given project to the given file without showing any dialog.
+    ///
+    /// If no options are given, the project is saved as a whole and the given file becomes the
+    /// project's file.
     ///
     /// # Panics
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn get_media_item(&self, project: ProjectContext, item_index: u32) -> Option<MediaItem>
-    where
+    /// Panics if the given project is not valid anymore or if the given path is not valid UTF-8.
+    pub fn main_save_project_ex(
+        &self,
+        project: ProjectContext,
+        file: &Path,
+        options: BitFlags<SaveProjectOption>,
+    ) where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.get_media_item_unchecked(project, item_index) }
+        unsafe { self.main_save_project_ex_unchecked(project, file, options) }
     }
 
-    /// Like [`get_media_item()`] but doesn't check if project is valid.
+    /// Like [`main_save_project_ex()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`get_media_item()`]: #method.get_media_item
-    pub unsafe fn get_media_item_unchecked(
+    /// [`main_save_project_ex()`]: #method.main_save_project_ex
+    pub unsafe fn main_save_project_ex_unchecked(
         &self,
         project: ProjectContext,
-        item_index: u32,
-    ) -> Option<MediaItem>
-    where
+        file: &Path,
+        options: BitFlags<SaveProjectOption>,
+    ) where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.GetMediaItem(project.to_raw(), item_index as i32);
-        NonNull::new(ptr)
+        let file_str = file.to_str().expect("path is not valid UTF-8");
+        let file = ReaperString::from_str(file_str);
+        self.low
+            .Main_SaveProjectEx(project.to_raw(), file.as_ptr(), options.bits() as _);
     }
 
-    /// Checks if the given pointer is still valid.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// # let session = reaper_medium::ReaperSession::default();
-    /// use reaper_medium::ProjectContext::CurrentProject;
+    /// Switches to the project tab of the given project.
     ///
-    /// let track = session.reaper().get_track(CurrentProject, 0).ok_or("No track")?;
-    /// let track_is_valid = session.reaper().validate_ptr_2(CurrentProject, track);
-    /// assert!(track_is_valid);
-    /// # Ok::<_, Box<dyn std::error::Error>>(())
-    /// ```
+    /// # Panics
     ///
-    /// Returns `true` if the pointer is a valid object of the correct type in the given project.
-    /// The project is ignored if the pointer itself is a project.
-    pub fn validate_ptr_2<'a>(
-        &self,
-        project: ProjectContext,
-        pointer: impl Into<ReaperPointer<'a>>,
-    ) -> bool
+    /// Panics if the given project is not valid anymore.
+    pub fn select_project_instance(&self, project: ReaProject)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let pointer = pointer.into();
-        unsafe {
-            self.low.ValidatePtr2(
-                project.to_raw(),
-                pointer.ptr_as_void(),
-                pointer.key_into_raw().as_ptr(),
-            )
-        }
+        self.require_valid_project(ProjectContext::Proj(project));
+        unsafe { self.select_project_instance_unchecked(project) }
     }
 
-    /// Checks if the given pointer is still valid.
+    /// Like [`select_project_instance()`] but doesn't check if project is valid.
     ///
-    /// Returns `true` if the pointer is a valid object of the correct type in the current project.
-    pub fn validate_ptr<'a>(&self, pointer: impl Into<ReaperPointer<'a>>) -> bool
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`select_project_instance()`]: #method.select_project_instance
+    pub unsafe fn select_project_instance_unchecked(&self, project: ReaProject)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let pointer = pointer.into();
-        unsafe {
-            self.low
-                .ValidatePtr(pointer.ptr_as_void(), pointer.key_into_raw().as_ptr())
-        }
+        self.require_main_thread();
+        self.low.SelectProjectInstance(project.as_ptr());
     }
 
-    /// Redraws the arrange view and ruler.
-    pub fn update_timeline(&self)
+    /// Returns the file name (without path) of the given project.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the name you want. Returns an
+    /// empty string if the project has not been saved yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore or if the given buffer size is 0.
+    pub fn get_project_name(&self, project: ProjectContext, buffer_size: u32) -> ReaperString
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
-        self.low.UpdateTimeline();
+        self.require_valid_project(project);
+        unsafe { self.get_project_name_unchecked(project, buffer_size) }
     }
 
-    /// Redraws the arrange view.
-    pub fn update_arrange(&self)
+    /// Like [`get_project_name()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_project_name()`]: #method.get_project_name
+    pub unsafe fn get_project_name_unchecked(
+        &self,
+        project: ProjectContext,
+        buffer_size: u32,
+    ) -> ReaperString
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.UpdateArrange();
+        assert!(buffer_size > 0);
+        let (name, _) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low.GetProjectName(project.to_raw(), buffer, max_size)
+        });
+        name
     }
 
-    /// Updates the track list after a minor change.
-    pub fn track_list_adjust_windows_minor(&self)
+    /// Gets a numeric project info value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_set_project_info_get(&self, project: ProjectContext, key: ProjectInfoKey) -> f64
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
-        self.low.TrackList_AdjustWindows(true);
+        self.require_valid_project(project);
+        unsafe { self.get_set_project_info_get_unchecked(project, key) }
     }
 
-    /// Updates the track list after a major change.
-    pub fn track_list_adjust_windows_major(&self)
+    /// Like [`get_set_project_info_get()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_set_project_info_get()`]: #method.get_set_project_info_get
+    pub unsafe fn get_set_project_info_get_unchecked(
+        &self,
+        project: ProjectContext,
+        key: ProjectInfoKey,
+    ) -> f64
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.TrackList_AdjustWindows(false);
+        self.low
+            .GetSetProjectInfo(project.to_raw(), key.into_raw().as_ptr(), 0.0, false)
     }
 
-    /// Shows a message to the user in the ReaScript console.
+    /// Sets a numeric project info value.
     ///
-    /// This is also useful for debugging. Send "\n" for newline and "" to clear the console.
-    pub fn show_console_msg<'a>(&self, message: impl Into<ReaperStringArg<'a>>)
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_set_project_info_set(&self, project: ProjectContext, key: ProjectInfoKey, value: f64)
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
-        unsafe { self.low.ShowConsoleMsg(message.into().as_ptr()) }
+        self.require_valid_project(project);
+        unsafe { self.get_set_project_info_set_unchecked(project, key, value) }
     }
 
-    /// Gets or sets a track attribute.
-    ///
-    /// Returns the current value if `new_value` is `null_mut()`.
-    ///
-    /// It's recommended to use one of the convenience functions instead. They all start with
-    /// `get_set_media_track_info_` and are more type-safe.
+    /// Like [`get_set_project_info_set()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track or invalid new value.
-    pub unsafe fn get_set_media_track_info(
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_set_project_info_set()`]: #method.get_set_project_info_set
+    pub unsafe fn get_set_project_info_set_unchecked(
         &self,
-        track: MediaTrack,
-        attribute_key: TrackAttributeKey,
-        new_value: *mut c_void,
-    ) -> *mut c_void
-    where
+        project: ProjectContext,
+        key: ProjectInfoKey,
+        value: f64,
+    ) where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
         self.low
-            .GetSetMediaTrackInfo(track.as_ptr(), attribute_key.into_raw().as_ptr(), new_value)
+            .GetSetProjectInfo(project.to_raw(), key.into_raw().as_ptr(), value, true);
     }
 
-    /// Gets or sets a take attribute.
+    /// Gets a project info value as string.
     ///
-    /// Returns the current value if `new_value` is `null_mut()`.
+    /// With `buffer_size` you can tell REAPER how many bytes of the value you want.
     ///
-    /// It's recommended to use one of the convenience functions instead. They all start with
-    /// `get_set_media_item_take_info_` and are more type-safe.
+    /// # Errors
     ///
-    /// # Safety
+    /// Returns an error if the key is invalid.
     ///
-    /// REAPER can crash if you pass an invalid take or invalid new value.
-    pub unsafe fn get_set_media_item_take_info(
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore or if the given buffer size is 0.
+    pub fn get_set_project_info_string_get(
         &self,
-        take: MediaItemTake,
-        attribute_key: TakeAttributeKey,
-        new_value: *mut c_void,
-    ) -> *mut c_void
+        project: ProjectContext,
+        key: ProjectInfoStringKey,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
-        self.low.GetSetMediaItemTakeInfo(
-            take.as_ptr(),
-            attribute_key.into_raw().as_ptr(),
-            new_value,
-        )
+        self.require_valid_project(project);
+        unsafe { self.get_set_project_info_string_get_unchecked(project, key, buffer_size) }
     }
 
-    /// Sets a take attribute as numerical value.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if an invalid (e.g. non-numerical) track attribute key is passed.
+    /// Like [`get_set_project_info_string_get()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn set_media_item_take_info_value(
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_set_project_info_string_get()`]: #method.get_set_project_info_string_get
+    pub unsafe fn get_set_project_info_string_get_unchecked(
         &self,
-        take: MediaItemTake,
-        attribute_key: TakeAttributeKey,
-        new_value: f64,
-    ) -> ReaperFunctionResult<()>
+        project: ProjectContext,
+        key: ProjectInfoStringKey,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let successful = self.low.SetMediaItemTakeInfo_Value(
-            take.as_ptr(),
-            attribute_key.into_raw().as_ptr(),
-            new_value,
-        );
+        assert!(buffer_size > 0);
+        let (value, successful) = with_string_buffer(buffer_size, |buffer, _| {
+            self.low.GetSetProjectInfo_String(
+                project.to_raw(),
+                key.into_raw().as_ptr(),
+                buffer,
+                false,
+            )
+        });
         if !successful {
             return Err(ReaperFunctionError::new(
-                "couldn't set take attribute (maybe attribute key is invalid)",
+                "couldn't get project info (maybe key is invalid)",
             ));
         }
-        Ok(())
+        Ok(value)
     }
 
-    /// Convenience function which sets the take's source (`P_SOURCE`).
+    /// Sets a project info value as string.
     ///
-    /// Returns the previous source in case the take had a source assigned.
+    /// # Errors
     ///
-    /// # Safety
+    /// Returns an error if the key is invalid or read-only.
     ///
-    /// REAPER can crash if you pass an invalid take.
-    pub unsafe fn get_set_media_item_take_info_set_source(
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_set_project_info_string_set<'a>(
         &self,
-        take: MediaItemTake,
-        source: OwnedPcmSource,
-    ) -> Option<OwnedPcmSource>
+        project: ProjectContext,
+        key: ProjectInfoStringKey,
+        value: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
-        let previous_source_ptr =
-            self.get_set_media_item_take_info(take, TakeAttributeKey::Source, null_mut())
-                as *mut raw::PCM_source;
-        let new_source_ptr = source.leak().as_ptr();
-        self.get_set_media_item_take_info(take, TakeAttributeKey::Source, new_source_ptr as _);
-        NonNull::new(previous_source_ptr).map(|raw| OwnedPcmSource::from_raw(raw))
+        self.require_valid_project(project);
+        unsafe { self.get_set_project_info_string_set_unchecked(project, key, value) }
     }
 
-    /// Convenience function which returns the given track's parent track (`P_PARTRACK`).
+    /// Like [`get_set_project_info_string_set()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_par_track(
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_set_project_info_string_set()`]: #method.get_set_project_info_string_set
+    pub unsafe fn get_set_project_info_string_set_unchecked<'a>(
         &self,
-        track: MediaTrack,
-    ) -> Option<MediaTrack>
+        project: ProjectContext,
+        key: ProjectInfoStringKey,
+        value: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::ParTrack, null_mut())
-            as *mut raw::MediaTrack;
-        NonNull::new(ptr)
+        let successful = self.low.GetSetProjectInfo_String(
+            project.to_raw(),
+            key.into_raw().as_ptr(),
+            value.into().as_ptr() as *mut c_char,
+            true,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set project info (maybe key is invalid or read-only)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Convenience function which returns the given track's parent project (`P_PROJECT`).
+    /// Returns the track at the given index within the tracks which are rendered for the given
+    /// region when using the region render matrix.
     ///
-    /// In REAPER < 5.95 this returns `None`.
+    /// The returned track might be the master track. Returns `None` if there are no more tracks.
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_project(
+    /// Panics if the given project is not valid anymore.
+    pub fn enum_region_render_matrix(
         &self,
-        track: MediaTrack,
-    ) -> Option<ReaProject>
+        project: ProjectContext,
+        region_id: BookmarkId,
+        render_track_index: u32,
+    ) -> Option<MediaTrack>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Project, null_mut())
-            as *mut raw::ReaProject;
-        NonNull::new(ptr)
+        self.require_valid_project(project);
+        unsafe { self.enum_region_render_matrix_unchecked(project, region_id, render_track_index) }
     }
 
-    /// Convenience function which grants temporary access to the given track's name (`P_NAME`).
-    ///
-    /// Returns `None` if the given track is the master track.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// # use reaper_medium::ProjectContext::CurrentProject;
-    /// let session = reaper_medium::ReaperSession::default();
-    ///
-    /// let track = session.reaper().get_track(CurrentProject, 0).ok_or("no track")?;
-    /// let track_name = unsafe {
-    ///     session.reaper().get_set_media_track_info_get_name(
-    ///         track,
-    ///         |name| name.to_owned()
-    ///     )
-    /// };
-    /// let track_name = match &track_name {
-    ///     None => "Master track",
-    ///     Some(name) => name.to_str()
-    /// };
-    /// session.reaper().show_console_msg(format!("Track name is {}", track_name));
-    /// # Ok::<_, Box<dyn std::error::Error>>(())
-    /// ```
+    /// Like [`enum_region_render_matrix()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_name<R>(
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`enum_region_render_matrix()`]: #method.enum_region_render_matrix
+    pub unsafe fn enum_region_render_matrix_unchecked(
         &self,
-        track: MediaTrack,
-        use_name: impl FnOnce(&ReaperStr) -> R,
-    ) -> Option<R>
+        project: ProjectContext,
+        region_id: BookmarkId,
+        render_track_index: u32,
+    ) -> Option<MediaTrack>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Name, null_mut());
-        create_passing_c_str(ptr as *const c_char).map(use_name)
+        let ptr = self.low.EnumRegionRenderMatrix(
+            project.to_raw(),
+            region_id.to_raw(),
+            render_track_index as _,
+        );
+        NonNull::new(ptr)
     }
 
-    /// Convenience function which sets the track's name (`P_NAME`).
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// # use reaper_medium::ProjectContext::CurrentProject;
-    /// let session = reaper_medium::ReaperSession::default();
-    ///
-    /// let track = session.reaper().get_track(CurrentProject, 0).ok_or("no track")?;
-    /// unsafe {
-    ///     session.reaper().get_set_media_track_info_set_name(track, "Guitar");
-    /// }
-    /// # Ok::<_, Box<dyn std::error::Error>>(())
-    /// ```
+    /// Adds the given track to or removes it from the tracks which are rendered for the given
+    /// region when using the region render matrix.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_set_name<'a>(
+    /// REAPER can crash if you pass an invalid project or track.
+    pub unsafe fn set_region_render_matrix(
         &self,
+        project: ProjectContext,
+        region_id: BookmarkId,
         track: MediaTrack,
-        message: impl Into<ReaperStringArg<'a>>,
+        add: bool,
     ) where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.get_set_media_track_info(track, TrackAttributeKey::Name, message.into().as_ptr() as _);
+        self.low.SetRegionRenderMatrix(
+            project.to_raw(),
+            region_id.to_raw(),
+            track.as_ptr(),
+            if add { 1 } else { -1 },
+        );
     }
 
-    /// Convenience function which returns the given track's input monitoring mode (`I_RECMON`).
+    /// Returns the track at the given index.
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_rec_mon(
-        &self,
-        track: MediaTrack,
-    ) -> InputMonitoringMode
+    /// Panics if the given project is not valid anymore.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # let session = reaper_medium::ReaperSession::default();
+    /// use reaper_medium::ProjectContext::CurrentProject;
+    ///
+    /// let track = session.reaper().get_track(CurrentProject, 3).ok_or("No such track")?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get_track(&self, project: ProjectContext, track_index: u32) -> Option<MediaTrack>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::RecMon, null_mut());
-        let irecmon = deref_as::<i32>(ptr).expect("I_RECMON pointer is null");
-        InputMonitoringMode::from_raw(irecmon)
+        self.require_valid_project(project);
+        unsafe { self.get_track_unchecked(project, track_index) }
     }
 
-    /// Convenience function which returns the given track's solo mode (`I_SOLO`).
+    /// Like [`get_track()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_solo(&self, track: MediaTrack) -> SoloMode
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_track()`]: #method.get_track
+    pub unsafe fn get_track_unchecked(
+        &self,
+        project: ProjectContext,
+        track_index: u32,
+    ) -> Option<MediaTrack>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Solo, null_mut());
-        let isolo = deref_as::<i32>(ptr).expect("I_SOLO pointer is null");
-        SoloMode::from_raw(isolo)
+        let ptr = self.low.GetTrack(project.to_raw(), track_index as i32);
+        NonNull::new(ptr)
     }
 
-    /// Convenience function which sets the track's solo state (`I_SOLO`).
+    /// Returns the item at the given index.
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_set_solo(&self, track: MediaTrack, mode: SoloMode)
+    /// Panics if the given project is not valid anymore.
+    pub fn get_media_item(&self, project: ProjectContext, item_index: u32) -> Option<MediaItem>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let value = mode.to_raw();
-        self.get_set_media_track_info(track, TrackAttributeKey::Solo, &value as *const _ as _);
+        self.require_valid_project(project);
+        unsafe { self.get_media_item_unchecked(project, item_index) }
     }
 
-    /// Convenience function which sets whether the track is shown in the mixer (`B_SHOWINMIXER`).
-    ///
-    /// Do not use on master track.
+    /// Like [`get_media_item()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_set_show_in_mixer(&self, track: MediaTrack, show: bool)
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_media_item()`]: #method.get_media_item
+    pub unsafe fn get_media_item_unchecked(
+        &self,
+        project: ProjectContext,
+        item_index: u32,
+    ) -> Option<MediaItem>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.get_set_media_track_info(
-            track,
-            TrackAttributeKey::ShowInMixer,
-            &show as *const _ as _,
-        );
+        let ptr = self.low.GetMediaItem(project.to_raw(), item_index as i32);
+        NonNull::new(ptr)
     }
 
-    /// Convenience function which sets whether the track is shown in the arrange view (`B_SHOWINTCP`).
+    /// Checks if the given pointer is still valid.
     ///
-    /// Do not use on master track.
+    /// # Example
     ///
-    /// # Safety
+    /// ```no_run
+    /// # let session = reaper_medium::ReaperSession::default();
+    /// use reaper_medium::ProjectContext::CurrentProject;
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_set_show_in_tcp(&self, track: MediaTrack, show: bool)
+    /// let track = session.reaper().get_track(CurrentProject, 0).ok_or("No track")?;
+    /// let track_is_valid = session.reaper().validate_ptr_2(CurrentProject, track);
+    /// assert!(track_is_valid);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// Returns `true` if the pointer is a valid object of the correct type in the given project.
+    /// The project is ignored if the pointer itself is a project.
+    pub fn validate_ptr_2<'a>(
+        &self,
+        project: ProjectContext,
+        pointer: impl Into<ReaperPointer<'a>>,
+    ) -> bool
     where
-        UsageScope: MainThreadOnly,
+        UsageScope: AnyThread,
     {
-        self.require_main_thread();
-        self.get_set_media_track_info(track, TrackAttributeKey::ShowInTcp, &show as *const _ as _);
+        let pointer = pointer.into();
+        unsafe {
+            self.low.ValidatePtr2(
+                project.to_raw(),
+                pointer.ptr_as_void(),
+                pointer.key_into_raw().as_ptr(),
+            )
+        }
     }
 
-    /// Convenience function which returns the given track's pan mode (I_PANMODE).
-    ///
-    /// Returns `None` if the track uses the project default.
-    ///
-    /// # Safety
+    /// Checks if the given pointer is still valid.
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_pan_mode(&self, track: MediaTrack) -> Option<PanMode>
+    /// Returns `true` if the pointer is a valid object of the correct type in the current project.
+    pub fn validate_ptr<'a>(&self, pointer: impl Into<ReaperPointer<'a>>) -> bool
     where
-        UsageScope: MainThreadOnly,
+        UsageScope: AnyThread,
     {
-        self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::PanMode, null_mut());
-        let ipanmode = deref_as::<i32>(ptr).expect("I_PANMODE pointer is null");
-        if ipanmode == -1 {
-            return None;
+        let pointer = pointer.into();
+        unsafe {
+            self.low
+                .ValidatePtr(pointer.ptr_as_void(), pointer.key_into_raw().as_ptr())
         }
-        Some(PanMode::from_raw(ipanmode))
     }
 
-    /// Convenience function which returns the given track's pan (D_PAN).
-    ///
-    /// # Safety
-    ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_pan(&self, track: MediaTrack) -> ReaperPanValue
+    /// Redraws the arrange view and ruler.
+    pub fn update_timeline(&self)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Pan, null_mut());
-        let pan = deref_as::<f64>(ptr).expect("I_PAN pointer is null");
-        ReaperPanValue::new(pan)
+        self.low.UpdateTimeline();
     }
 
-    /// Convenience function which returns the given track's dual-pan position 1 (D_DUALPANL).
-    ///
-    /// # Safety
-    ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_dual_pan_l(
-        &self,
-        track: MediaTrack,
-    ) -> ReaperPanValue
+    /// Redraws the arrange view.
+    pub fn update_arrange(&self)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::DualPanL, null_mut());
-        let pan = deref_as::<f64>(ptr).expect("D_DUALPANL pointer is null");
-        ReaperPanValue::new(pan)
+        self.low.UpdateArrange();
     }
 
-    /// Convenience function which returns the given track's dual-pan position 2 (D_DUALPANR).
-    ///
-    /// # Safety
-    ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_dual_pan_r(
-        &self,
-        track: MediaTrack,
-    ) -> ReaperPanValue
+    /// Updates the track list after a minor change.
+    pub fn track_list_adjust_windows_minor(&self)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::DualPanR, null_mut());
-        let pan = deref_as::<f64>(ptr).expect("D_DUALPANR pointer is null");
-        ReaperPanValue::new(pan)
+        self.low.TrackList_AdjustWindows(true);
     }
 
-    /// Convenience function which returns the given track's width (D_WIDTH).
-    ///
-    /// # Safety
-    ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_width(&self, track: MediaTrack) -> ReaperWidthValue
+    /// Updates the track list after a major change.
+    pub fn track_list_adjust_windows_major(&self)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Width, null_mut());
-        let width = deref_as::<f64>(ptr).expect("I_WIDTH pointer is null");
-        ReaperWidthValue::new(width)
+        self.low.TrackList_AdjustWindows(false);
     }
 
-    /// Convenience function which returns the given track's recording input (I_RECINPUT).
-    ///
-    /// # Safety
+    /// Shows a message to the user in the ReaScript console.
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_rec_input(
-        &self,
-        track: MediaTrack,
-    ) -> Option<RecordingInput>
+    /// This is also useful for debugging. Send "\n" for newline and "" to clear the console.
+    pub fn show_console_msg<'a>(&self, message: impl Into<ReaperStringArg<'a>>)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::RecInput, null_mut());
-        let rec_input_index = deref_as::<i32>(ptr).expect("rec_input_index pointer is null");
-        RecordingInput::from_raw(rec_input_index)
+        unsafe { self.low.ShowConsoleMsg(message.into().as_ptr()) }
     }
 
-    /// Convenience function which returns the type and location of the given track
-    /// (IP_TRACKNUMBER).
+    /// Adds a customizable menu.
     ///
-    /// # Safety
+    /// `menu_id` is a unique identifying string which is also passed to the custom menu hook.
+    /// `menu_name` is the name of a main menu. Pass `None` for context menus. `kbd_section_name`
+    /// is the name of the section registered by the extension or `None` for the main section.
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_track_number(
+    /// This should be called at plug-in load time, before REAPER builds its menus.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the menu couldn't be added.
+    pub fn add_customizable_menu<'a>(
         &self,
-        track: MediaTrack,
-    ) -> Option<TrackLocation>
+        menu_id: impl Into<ReaperStringArg<'a>>,
+        menu_name: Option<ReaperStringArg<'a>>,
+        kbd_section_name: Option<ReaperStringArg<'a>>,
+        add_to_main_menu: bool,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        use TrackLocation::*;
-        match self.get_set_media_track_info(track, TrackAttributeKey::TrackNumber, null_mut())
-            as i32
-        {
-            -1 => Some(MasterTrack),
-            0 => None,
-            n if n > 0 => Some(NormalTrack(n as u32 - 1)),
-            _ => unreachable!(),
+        let successful = unsafe {
+            self.low.AddCustomizableMenu(
+                menu_id.into().as_ptr(),
+                menu_name.as_ref().map(|n| n.as_ptr()).unwrap_or(null()),
+                kbd_section_name
+                    .as_ref()
+                    .map(|n| n.as_ptr())
+                    .unwrap_or(null()),
+                add_to_main_menu,
+            )
+        };
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't add customizable menu"));
         }
+        Ok(())
     }
 
-    /// Convenience function which returns the given track's GUID (GUID).
-    ///
-    /// # Safety
+    /// Adds the *Extensions* menu to the main menu bar.
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_set_media_track_info_get_guid(&self, track: MediaTrack) -> GUID
+    /// The menu ID passed to custom menu hooks is `"Main extensions"`.
+    pub fn add_extensions_main_menu(&self) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Guid, null_mut());
-        deref_as::<GUID>(ptr).expect("GUID pointer is null")
+        self.low.AddExtensionsMainMenu()
     }
 
-    /// Returns whether we are in the real-time audio thread.
+    /// Gets or sets a track attribute.
     ///
-    /// *Real-time* means somewhere between [`OnAudioBuffer`] calls, not in some worker or
-    /// anticipative FX thread.
+    /// Returns the current value if `new_value` is `null_mut()`.
     ///
-    /// [`OnAudioBuffer`]: trait.OnAudioBuffer.html#method.call
-    pub fn is_in_real_time_audio(&self) -> bool
-    where
-        UsageScope: AnyThread,
-    {
-        self.low.IsInRealTimeAudio() != 0
-    }
-
-    /// Returns whether audio is running at all.
-    pub fn audio_is_running(&self) -> bool
-    where
-        UsageScope: AnyThread,
-    {
-        self.low.Audio_IsRunning() != 0
-    }
-
-    /// Starts playing.
-    pub fn csurf_on_play(&self)
-    where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        self.low.CSurf_OnPlay();
-    }
-
-    /// Stops playing.
-    pub fn csurf_on_stop(&self)
+    /// It's recommended to use one of the convenience functions instead. They all start with
+    /// `get_set_media_track_info_` and are more type-safe.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track or invalid new value.
+    pub unsafe fn get_set_media_track_info(
+        &self,
+        track: MediaTrack,
+        attribute_key: TrackAttributeKey,
+        new_value: *mut c_void,
+    ) -> *mut c_void
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.CSurf_OnStop();
+        self.low
+            .GetSetMediaTrackInfo(track.as_ptr(), attribute_key.into_raw().as_ptr(), new_value)
     }
 
-    /// Pauses playing.
-    pub fn csurf_on_pause(&self)
+    /// Gets or sets a take attribute.
+    ///
+    /// Returns the current value if `new_value` is `null_mut()`.
+    ///
+    /// It's recommended to use one of the convenience functions instead. They all start with
+    /// `get_set_media_item_take_info_` and are more type-safe.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take or invalid new value.
+    pub unsafe fn get_set_media_item_take_info(
+        &self,
+        take: MediaItemTake,
+        attribute_key: TakeAttributeKey,
+        new_value: *mut c_void,
+    ) -> *mut c_void
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.CSurf_OnPause();
+        self.low.GetSetMediaItemTakeInfo(
+            take.as_ptr(),
+            attribute_key.into_raw().as_ptr(),
+            new_value,
+        )
     }
 
-    /// Starts recording.
-    pub fn csurf_on_record(&self)
+    /// Gets or sets an item attribute.
+    ///
+    /// Returns the current value if `new_value` is `null_mut()`.
+    ///
+    /// It's recommended to use one of the convenience functions instead. They all start with
+    /// `get_set_media_item_info_` and are more type-safe.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid item or invalid new value.
+    pub unsafe fn get_set_media_item_info(
+        &self,
+        item: MediaItem,
+        attribute_key: ItemAttributeKey,
+        new_value: *mut c_void,
+    ) -> *mut c_void
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.CSurf_OnRecord();
+        self.low
+            .GetSetMediaItemInfo(item.as_ptr(), attribute_key.into_raw().as_ptr(), new_value)
     }
 
-    /// Informs control surfaces that the repeat mode has changed.
-    ///
-    /// Doesn't actually change the repeat mode.
-    ///
-    /// # Safety
+    /// Sets a take attribute as numerical value.
     ///
-    /// REAPER can crash if you pass an invalid control surface.
+    /// # Errors
     ///
-    /// # Example
+    /// Returns an error if an invalid (e.g. non-numerical) track attribute key is passed.
     ///
-    /// ```no_run
-    /// # let session = reaper_medium::ReaperSession::default();
-    /// use reaper_medium::{NotificationBehavior::NotifyAll, ProjectContext::CurrentProject};
+    /// # Safety
     ///
-    /// let track = session.reaper().get_track(CurrentProject, 0).ok_or("no tracks")?;
-    /// unsafe {
-    ///     session.reaper().csurf_set_repeat_state(true, NotifyAll);
-    /// }
-    /// # Ok::<_, Box<dyn std::error::Error>>(())
-    /// ```
-    pub unsafe fn csurf_set_repeat_state(
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn set_media_item_take_info_value(
         &self,
-        repeat_state: bool,
-        notification_behavior: NotificationBehavior,
-    ) where
+        take: MediaItemTake,
+        attribute_key: TakeAttributeKey,
+        new_value: f64,
+    ) -> ReaperFunctionResult<()>
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .CSurf_SetRepeatState(repeat_state, notification_behavior.to_raw());
+        let successful = self.low.SetMediaItemTakeInfo_Value(
+            take.as_ptr(),
+            attribute_key.into_raw().as_ptr(),
+            new_value,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set take attribute (maybe attribute key is invalid)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Returns `true` if any track in the given project is soloed.
+    /// Convenience function which sets the take's source (`P_SOURCE`).
     ///
-    /// # Panics
+    /// Returns the previous source in case the take had a source assigned.
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn any_track_solo(&self, project: ProjectContext) -> bool
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn get_set_media_item_take_info_set_source(
+        &self,
+        take: MediaItemTake,
+        source: OwnedPcmSource,
+    ) -> Option<OwnedPcmSource>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_valid_project(project);
-        unsafe { self.any_track_solo_unchecked(project) }
+        self.require_main_thread();
+        let previous_source_ptr =
+            self.get_set_media_item_take_info(take, TakeAttributeKey::Source, null_mut())
+                as *mut raw::PCM_source;
+        let new_source_ptr = source.leak().as_ptr();
+        self.get_set_media_item_take_info(take, TakeAttributeKey::Source, new_source_ptr as _);
+        NonNull::new(previous_source_ptr).map(|raw| OwnedPcmSource::from_raw(raw))
     }
 
-    /// Like [`any_track_solo()`] but doesn't check if project is valid.
+    /// Convenience function which returns the index of the given take within its item
+    /// (`IP_TAKENUMBER`).
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`any_track_solo()`]: #method.any_track_solo
-    pub unsafe fn any_track_solo_unchecked(&self, project: ProjectContext) -> bool
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn get_set_media_item_take_info_get_take_number(&self, take: MediaItemTake) -> u32
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.AnyTrackSolo(project.to_raw())
+        // REAPER returns the take number directly instead of a pointer to it
+        self.get_set_media_item_take_info(take, TakeAttributeKey::TakeNumber, null_mut()) as usize
+            as u32
     }
 
-    /// Directly simulates a play button hit.
+    /// Convenience function which returns the given track's parent track (`P_PARTRACK`).
     ///
-    /// # Panics
+    /// # Safety
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn on_play_button_ex(&self, project: ProjectContext)
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_par_track(
+        &self,
+        track: MediaTrack,
+    ) -> Option<MediaTrack>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_valid_project(project);
-        unsafe { self.on_play_button_ex_unchecked(project) }
+        self.require_main_thread();
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::ParTrack, null_mut())
+            as *mut raw::MediaTrack;
+        NonNull::new(ptr)
     }
 
-    /// Like [`on_play_button_ex()`] but doesn't check if project is valid.
+    /// Convenience function which returns the given track's parent project (`P_PROJECT`).
     ///
-    /// # Safety
+    /// In REAPER < 5.95 this returns `None`.
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// # Safety
     ///
-    /// [`on_play_button_ex()`]: #method.on_play_button_ex
-    pub unsafe fn on_play_button_ex_unchecked(&self, project: ProjectContext)
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_project(
+        &self,
+        track: MediaTrack,
+    ) -> Option<ReaProject>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.OnPlayButtonEx(project.to_raw());
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Project, null_mut())
+            as *mut raw::ReaProject;
+        NonNull::new(ptr)
     }
 
-    /// Directly simulates a stop button hit.
+    /// Convenience function which grants temporary access to the given track's name (`P_NAME`).
     ///
-    /// # Panics
+    /// Returns `None` if the given track is the master track.
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn on_stop_button_ex(&self, project: ProjectContext)
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use reaper_medium::ProjectContext::CurrentProject;
+    /// let session = reaper_medium::ReaperSession::default();
+    ///
+    /// let track = session.reaper().get_track(CurrentProject, 0).ok_or("no track")?;
+    /// let track_name = unsafe {
+    ///     session.reaper().get_set_media_track_info_get_name(
+    ///         track,
+    ///         |name| name.to_owned()
+    ///     )
+    /// };
+    /// let track_name = match &track_name {
+    ///     None => "Master track",
+    ///     Some(name) => name.to_str()
+    /// };
+    /// session.reaper().show_console_msg(format!("Track name is {}", track_name));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_name<R>(
+        &self,
+        track: MediaTrack,
+        use_name: impl FnOnce(&ReaperStr) -> R,
+    ) -> Option<R>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.require_valid_project(project);
-        unsafe { self.on_stop_button_ex_unchecked(project) }
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Name, null_mut());
+        create_passing_c_str(ptr as *const c_char).map(use_name)
     }
 
-    /// Like [`on_stop_button_ex()`] but doesn't check if project is valid.
+    /// Convenience function which sets the track's name (`P_NAME`).
     ///
-    /// # Safety
+    /// # Example
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// ```no_run
+    /// # use reaper_medium::ProjectContext::CurrentProject;
+    /// let session = reaper_medium::ReaperSession::default();
     ///
-    /// [`on_stop_button_ex()`]: #method.on_stop_button_ex
-    pub unsafe fn on_stop_button_ex_unchecked(&self, project: ProjectContext)
-    where
+    /// let track = session.reaper().get_track(CurrentProject, 0).ok_or("no track")?;
+    /// unsafe {
+    ///     session.reaper().get_set_media_track_info_set_name(track, "Guitar");
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_set_name<'a>(
+        &self,
+        track: MediaTrack,
+        message: impl Into<ReaperStringArg<'a>>,
+    ) where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.OnStopButtonEx(project.to_raw());
+        self.get_set_media_track_info(track, TrackAttributeKey::Name, message.into().as_ptr() as _);
     }
 
-    /// Directly simulates a pause button hit.
+    /// Convenience function which returns the given track's input monitoring mode (`I_RECMON`).
     ///
-    /// # Panics
+    /// # Safety
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn on_pause_button_ex(&self, project: ProjectContext)
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_rec_mon(
+        &self,
+        track: MediaTrack,
+    ) -> InputMonitoringMode
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.require_valid_project(project);
-        unsafe { self.on_pause_button_ex_unchecked(project) }
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::RecMon, null_mut());
+        let irecmon = deref_as::<i32>(ptr).expect("I_RECMON pointer is null");
+        InputMonitoringMode::from_raw(irecmon)
     }
 
-    /// Like [`on_pause_button_ex()`] but doesn't check if project is valid.
+    /// Convenience function which returns the given track's solo mode (`I_SOLO`).
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`on_pause_button_ex()`]: #method.on_pause_button_ex
-    pub unsafe fn on_pause_button_ex_unchecked(&self, project: ProjectContext)
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_solo(&self, track: MediaTrack) -> SoloMode
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.OnPauseButtonEx(project.to_raw());
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Solo, null_mut());
+        let isolo = deref_as::<i32>(ptr).expect("I_SOLO pointer is null");
+        SoloMode::from_raw(isolo)
     }
 
-    /// Queries the current play state.
+    /// Convenience function which sets the track's solo state (`I_SOLO`).
     ///
-    /// # Panics
+    /// # Safety
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn get_play_state_ex(&self, project: ProjectContext) -> PlayState
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_set_solo(&self, track: MediaTrack, mode: SoloMode)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        self.require_valid_project(project);
-        unsafe { self.get_play_state_ex_unchecked(project) }
+        self.require_main_thread();
+        let value = mode.to_raw();
+        self.get_set_media_track_info(track, TrackAttributeKey::Solo, &value as *const _ as _);
     }
 
-    /// Like [`get_play_state_ex()`] but doesn't check if project is valid.
+    /// Convenience function which sets whether the track is shown in the mixer (`B_SHOWINMIXER`).
     ///
-    /// # Safety
+    /// Do not use on master track.
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// # Safety
     ///
-    /// [`get_play_state_ex()`]: #method.get_play_state_ex
-    pub unsafe fn get_play_state_ex_unchecked(&self, project: ProjectContext) -> PlayState
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_set_show_in_mixer(&self, track: MediaTrack, show: bool)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let result = self.low.GetPlayStateEx(project.to_raw()) as u32;
-        PlayState {
-            is_playing: result & 1 > 0,
-            is_paused: result & 2 > 0,
-            is_recording: result & 4 > 0,
-        }
+        self.require_main_thread();
+        self.get_set_media_track_info(
+            track,
+            TrackAttributeKey::ShowInMixer,
+            &show as *const _ as _,
+        );
     }
 
-    /// Queries the current repeat state.
+    /// Convenience function which sets whether the track is shown in the arrange view (`B_SHOWINTCP`).
     ///
-    /// # Panics
+    /// Do not use on master track.
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn get_set_repeat_ex_get(&self, project: ProjectContext) -> bool
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_set_show_in_tcp(&self, track: MediaTrack, show: bool)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.require_valid_project(project);
-        unsafe { self.get_set_repeat_ex_get_unchecked(project) }
+        self.get_set_media_track_info(track, TrackAttributeKey::ShowInTcp, &show as *const _ as _);
     }
 
-    /// Like [`get_set_repeat_ex_get()`] but doesn't check if project is valid.
+    /// Convenience function which returns the given track's pan mode (I_PANMODE).
     ///
-    /// # Safety
+    /// Returns `None` if the track uses the project default.
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// # Safety
     ///
-    /// [`get_set_repeat_ex_get()`]: #method.get_set_repeat_ex_get
-    pub unsafe fn get_set_repeat_ex_get_unchecked(&self, project: ProjectContext) -> bool
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_pan_mode(&self, track: MediaTrack) -> Option<PanMode>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.GetSetRepeatEx(project.to_raw(), -1) > 0
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::PanMode, null_mut());
+        let ipanmode = deref_as::<i32>(ptr).expect("I_PANMODE pointer is null");
+        if ipanmode == -1 {
+            return None;
+        }
+        Some(PanMode::from_raw(ipanmode))
     }
 
-    /// Sets the repeat state.
+    /// Convenience function which returns the given track's pan (D_PAN).
     ///
-    /// # Panics
+    /// # Safety
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn get_set_repeat_ex_set(&self, project: ProjectContext, repeat: bool)
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_pan(&self, track: MediaTrack) -> ReaperPanValue
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.require_valid_project(project);
-        unsafe {
-            self.get_set_repeat_ex_set_unchecked(project, repeat);
-        }
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Pan, null_mut());
+        let pan = deref_as::<f64>(ptr).expect("I_PAN pointer is null");
+        ReaperPanValue::new(pan)
     }
 
-    /// Like [`get_set_repeat_ex_set()`] but doesn't check if project is valid.
+    /// Convenience function which returns the given track's dual-pan position 1 (D_DUALPANL).
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`get_set_repeat_ex_set()`]: #method.get_set_repeat_ex_set
-    pub unsafe fn get_set_repeat_ex_set_unchecked(&self, project: ProjectContext, repeat: bool)
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_dual_pan_l(
+        &self,
+        track: MediaTrack,
+    ) -> ReaperPanValue
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .GetSetRepeatEx(project.to_raw(), if repeat { 1 } else { 0 });
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::DualPanL, null_mut());
+        let pan = deref_as::<f64>(ptr).expect("D_DUALPANL pointer is null");
+        ReaperPanValue::new(pan)
     }
 
-    /// Grants temporary access to the data of the given marker/region.
-    ///
-    /// The given index starts as 0 and counts both markers and regions.
+    /// Convenience function which returns the given track's dual-pan position 2 (D_DUALPANR).
     ///
-    /// # Panics
+    /// # Safety
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn enum_project_markers_3<R>(
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_dual_pan_r(
         &self,
-        project: ProjectContext,
-        index: u32,
-        // TODO-high Other functions should take an option, too! Otherwise we can't give back
-        // ownership  in case this didn't return anything! Same for all other continuation
-        // passing functions!
-        use_result: impl FnOnce(Option<EnumProjectMarkers3Result>) -> R,
-    ) -> R
+        track: MediaTrack,
+    ) -> ReaperPanValue
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_valid_project(project);
-        unsafe { self.enum_project_markers_3_unchecked(project, index, use_result) }
+        self.require_main_thread();
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::DualPanR, null_mut());
+        let pan = deref_as::<f64>(ptr).expect("D_DUALPANR pointer is null");
+        ReaperPanValue::new(pan)
     }
 
-    /// Like [`enum_project_markers_3()`] but doesn't check if project is valid.
+    /// Convenience function which returns the given track's width (D_WIDTH).
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_width(&self, track: MediaTrack) -> ReaperWidthValue
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Width, null_mut());
+        let width = deref_as::<f64>(ptr).expect("I_WIDTH pointer is null");
+        ReaperWidthValue::new(width)
+    }
+
+    /// Convenience function which returns the given track's recording input (I_RECINPUT).
     ///
-    /// [`enum_project_markers_3()`]: #method.enum_project_markers_3
-    pub unsafe fn enum_project_markers_3_unchecked<R>(
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_rec_input(
         &self,
-        project: ProjectContext,
-        index: u32,
-        use_result: impl FnOnce(Option<EnumProjectMarkers3Result>) -> R,
-    ) -> R
+        track: MediaTrack,
+    ) -> Option<RecordingInput>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let mut is_region = MaybeUninit::zeroed();
-        let mut pos = MaybeUninit::zeroed();
-        let mut region_end = MaybeUninit::zeroed();
-        let mut name = MaybeUninit::zeroed();
-        let mut id = MaybeUninit::zeroed();
-        let mut color = MaybeUninit::zeroed();
-        let successful = self.low.EnumProjectMarkers3(
-            project.to_raw(),
-            index as _,
-            is_region.as_mut_ptr(),
-            pos.as_mut_ptr(),
-            region_end.as_mut_ptr(),
-            name.as_mut_ptr(),
-            id.as_mut_ptr(),
-            color.as_mut_ptr(),
-        );
-        if successful == 0 {
-            return use_result(None);
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::RecInput, null_mut());
+        let rec_input_index = deref_as::<i32>(ptr).expect("rec_input_index pointer is null");
+        RecordingInput::from_raw(rec_input_index)
+    }
+
+    /// Convenience function which returns the type and location of the given track
+    /// (IP_TRACKNUMBER).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_track_number(
+        &self,
+        track: MediaTrack,
+    ) -> Option<TrackLocation>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        use TrackLocation::*;
+        match self.get_set_media_track_info(track, TrackAttributeKey::TrackNumber, null_mut())
+            as i32
+        {
+            -1 => Some(MasterTrack),
+            0 => None,
+            n if n > 0 => Some(NormalTrack(n as u32 - 1)),
+            _ => unreachable!(),
         }
-        let result = EnumProjectMarkers3Result {
-            position: PositionInSeconds::new(pos.assume_init()),
-            region_end_position: if is_region.assume_init() {
-                Some(PositionInSeconds::new(region_end.assume_init()))
-            } else {
-                None
-            },
-            name: create_passing_c_str(name.assume_init()).unwrap(),
-            id: BookmarkId(id.assume_init() as _),
-            color: NativeColor(color.assume_init() as _),
-        };
-        use_result(Some(result))
     }
 
-    /// Creates a PCM source from the given file name.
+    /// Convenience function which returns the given track's GUID (GUID).
     ///
-    /// # Errors
+    /// # Safety
     ///
-    /// Returns an error if the PCM source could not be created.
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_set_media_track_info_get_guid(&self, track: MediaTrack) -> GUID
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.get_set_media_track_info(track, TrackAttributeKey::Guid, null_mut());
+        deref_as::<GUID>(ptr).expect("GUID pointer is null")
+    }
+
+    /// Returns whether we are in the real-time audio thread.
     ///
-    /// # Panics
+    /// *Real-time* means somewhere between [`OnAudioBuffer`] calls, not in some worker or
+    /// anticipative FX thread.
     ///
-    /// Panics if the given file name is not valid UTF-8.
-    pub fn pcm_source_create_from_file_ex(
-        &self,
-        file_name: &Path,
-        midi_import_behavior: MidiImportBehavior,
-    ) -> ReaperFunctionResult<OwnedPcmSource>
+    /// [`OnAudioBuffer`]: trait.OnAudioBuffer.html#method.call
+    pub fn is_in_real_time_audio(&self) -> bool
     where
         UsageScope: AnyThread,
     {
-        let file_name_str = file_name.to_str().expect("file name is not valid UTF-8");
-        let file_name_reaper_string = ReaperString::from_str(file_name_str);
-        let ptr = unsafe {
-            self.low.PCM_Source_CreateFromFileEx(
-                file_name_reaper_string.as_ptr(),
-                match midi_import_behavior {
-                    MidiImportBehavior::UsePreference => false,
-                    MidiImportBehavior::ForceNoMidiImport => true,
-                },
-            )
-        };
-        NonNull::new(ptr)
-            .ok_or_else(|| ReaperFunctionError::new("couldn't create PCM source from file"))
-            .map(OwnedPcmSource)
+        self.low.IsInRealTimeAudio() != 0
     }
 
-    /// Creates a PCM source from a type identifier.
+    /// Returns whether audio is running at all.
+    pub fn audio_is_running(&self) -> bool
+    where
+        UsageScope: AnyThread,
+    {
+        self.low.Audio_IsRunning() != 0
+    }
+
+    /// Starts playing.
+    pub fn csurf_on_play(&self)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.CSurf_OnPlay();
+    }
+
+    /// Stops playing.
+    pub fn csurf_on_stop(&self)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.CSurf_OnStop();
+    }
+
+    /// Pauses playing.
+    pub fn csurf_on_pause(&self)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.CSurf_OnPause();
+    }
+
+    /// Starts recording.
+    pub fn csurf_on_record(&self)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.CSurf_OnRecord();
+    }
+
+    /// Informs control surfaces that the repeat mode has changed.
     ///
-    /// TODO-high Documentation/API ... unstable
+    /// Doesn't actually change the repeat mode.
     ///
-    /// Use this if you're going to load its state via LoadState/ProjectStateContext.
-    /// Valid types include "WAVE", "MIDI", or whatever plug-ins define as well.
+    /// # Safety
     ///
-    /// # Errors
+    /// REAPER can crash if you pass an invalid control surface.
     ///
-    /// Returns an error if the PCM source could not be created.
-    pub fn pcm_source_create_from_type<'a>(
+    /// # Example
+    ///
+    /// ```no_run
+    /// # let session = reaper_medium::ReaperSession::default();
+    /// use reaper_medium::{NotificationBehavior::NotifyAll, ProjectContext::CurrentProject};
+    ///
+    /// let track = session.reaper().get_track(CurrentProject, 0).ok_or("no tracks")?;
+    /// unsafe {
+    ///     session.reaper().csurf_set_repeat_state(true, NotifyAll);
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub unsafe fn csurf_set_repeat_state(
         &self,
-        source_type: impl Into<ReaperStringArg<'a>>,
-    ) -> ReaperFunctionResult<OwnedPcmSource>
-    where
+        repeat_state: bool,
+        notification_behavior: NotificationBehavior,
+    ) where
         UsageScope: MainThreadOnly,
     {
-        // TODO-medium Can maybe be relaxed.
         self.require_main_thread();
-        let ptr = unsafe {
-            self.low
-                .PCM_Source_CreateFromType(source_type.into().as_ptr())
-        };
-        NonNull::new(ptr)
-            .ok_or_else(|| ReaperFunctionError::new("couldn't create PCM source from type"))
-            .map(OwnedPcmSource)
+        self.low
+            .CSurf_SetRepeatState(repeat_state, notification_behavior.to_raw());
     }
 
-    /// Goes to the given marker.
+    /// Returns `true` if any track in the given project is soloed.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn go_to_marker(&self, project: ProjectContext, marker: BookmarkRef)
+    pub fn any_track_solo(&self, project: ProjectContext) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_valid_project(project);
-        unsafe {
-            self.go_to_marker_unchecked(project, marker);
-        }
+        unsafe { self.any_track_solo_unchecked(project) }
     }
 
-    /// Like [`go_to_marker()`] but doesn't check if project is valid.
+    /// Like [`any_track_solo()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
-    /// [`go_to_marker()`]: #method.go_to_marker
-    pub unsafe fn go_to_marker_unchecked(&self, project: ProjectContext, marker: BookmarkRef)
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`any_track_solo()`]: #method.any_track_solo
+    pub unsafe fn any_track_solo_unchecked(&self, project: ProjectContext) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.GoToMarker(
-            project.to_raw(),
-            marker.to_raw(),
-            marker.uses_timeline_order(),
-        );
+        self.low.AnyTrackSolo(project.to_raw())
     }
 
-    /// Seeks to the given region after the current one finishes playing (smooth seek).
+    /// Directly simulates a play button hit.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn go_to_region(&self, project: ProjectContext, region: BookmarkRef)
+    pub fn on_play_button_ex(&self, project: ProjectContext)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_valid_project(project);
-        unsafe {
-            self.go_to_region_unchecked(project, region);
-        }
+        unsafe { self.on_play_button_ex_unchecked(project) }
     }
 
-    /// Like [`go_to_region()`] but doesn't check if project is valid.
+    /// Like [`on_play_button_ex()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`go_to_region()`]: #method.go_to_region
-    pub unsafe fn go_to_region_unchecked(&self, project: ProjectContext, region: BookmarkRef)
+    /// [`on_play_button_ex()`]: #method.on_play_button_ex
+    pub unsafe fn on_play_button_ex_unchecked(&self, project: ProjectContext)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.GoToRegion(
-            project.to_raw(),
-            region.to_raw(),
-            region.uses_timeline_order(),
-        );
+        self.low.OnPlayButtonEx(project.to_raw());
     }
 
-    /// Converts the given time into beats.
+    /// Directly simulates a stop button hit.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn time_map_2_time_to_beats(
-        &self,
-        project: ProjectContext,
-        tpos: PositionInSeconds,
-    ) -> TimeMap2TimeToBeatsResult
+    pub fn on_stop_button_ex(&self, project: ProjectContext)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
+        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.time_map_2_time_to_beats_unchecked(project, tpos) }
+        unsafe { self.on_stop_button_ex_unchecked(project) }
     }
 
-    /// Like [`time_map_2_time_to_beats()`] but doesn't check if project is valid.
+    /// Like [`on_stop_button_ex()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`time_map_2_time_to_beats()`]: #method.time_map_2_time_to_beats
-    pub unsafe fn time_map_2_time_to_beats_unchecked(
-        &self,
-        project: ProjectContext,
-        tpos: PositionInSeconds,
-    ) -> TimeMap2TimeToBeatsResult
+    /// [`on_stop_button_ex()`]: #method.on_stop_button_ex
+    pub unsafe fn on_stop_button_ex_unchecked(&self, project: ProjectContext)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let mut measures = MaybeUninit::zeroed();
-        let mut measure_length = MaybeUninit::zeroed();
-        let mut full_beats = MaybeUninit::zeroed();
-        let mut common_denom = MaybeUninit::zeroed();
-        let beats_within_measure = self.low.TimeMap2_timeToBeats(
-            project.to_raw(),
-            tpos.get(),
-            measures.as_mut_ptr(),
-            measure_length.as_mut_ptr(),
-            full_beats.as_mut_ptr(),
-            common_denom.as_mut_ptr(),
-        );
-        TimeMap2TimeToBeatsResult {
-            full_beats: PositionInBeats::new(full_beats.assume_init()),
-            measure_index: measures.assume_init(),
-            beats_since_measure: PositionInBeats::new(beats_within_measure),
-            time_signature: TimeSignature {
-                numerator: NonZeroU32::new(measure_length.assume_init() as _).unwrap(),
-                denominator: NonZeroU32::new(common_denom.assume_init() as _).unwrap(),
-            },
-        }
+        self.require_main_thread();
+        self.low.OnStopButtonEx(project.to_raw());
     }
 
-    /// Returns information about the given measure.
+    /// Directly simulates a pause button hit.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn time_map_get_measure_info(
-        &self,
-        project: ProjectContext,
-        measure_index: i32,
-    ) -> TimeMapGetMeasureInfoResult
+    pub fn on_pause_button_ex(&self, project: ProjectContext)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
+        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.time_map_get_measure_info_unchecked(project, measure_index) }
+        unsafe { self.on_pause_button_ex_unchecked(project) }
     }
 
-    /// Like [`time_map_get_measure_info()`] but doesn't check if project is valid.
+    /// Like [`on_pause_button_ex()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`time_map_get_measure_info()`]: #method.time_map_get_measure_info
-    pub unsafe fn time_map_get_measure_info_unchecked(
-        &self,
-        project: ProjectContext,
-        measure_index: i32,
-    ) -> TimeMapGetMeasureInfoResult
+    /// [`on_pause_button_ex()`]: #method.on_pause_button_ex
+    pub unsafe fn on_pause_button_ex_unchecked(&self, project: ProjectContext)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let mut start_qn = MaybeUninit::zeroed();
-        let mut end_qn = MaybeUninit::zeroed();
-        let mut num = MaybeUninit::zeroed();
-        let mut denom = MaybeUninit::zeroed();
-        let mut bpm = MaybeUninit::zeroed();
-        let start_time = self.low.TimeMap_GetMeasureInfo(
-            project.to_raw(),
-            measure_index,
-            start_qn.as_mut_ptr(),
-            end_qn.as_mut_ptr(),
-            num.as_mut_ptr(),
-            denom.as_mut_ptr(),
-            bpm.as_mut_ptr(),
-        );
-        TimeMapGetMeasureInfoResult {
-            start_time: PositionInSeconds::new(start_time),
-            start_qn: PositionInQuarterNotes::new(start_qn.assume_init()),
-            end_qn: PositionInQuarterNotes::new(end_qn.assume_init()),
-            time_signature: TimeSignature {
-                numerator: NonZeroU32::new(num.assume_init() as _).unwrap(),
-                denominator: NonZeroU32::new(denom.assume_init() as _).unwrap(),
-            },
-            tempo: Bpm::new(bpm.assume_init()),
-        }
+        self.require_main_thread();
+        self.low.OnPauseButtonEx(project.to_raw());
     }
 
-    /// Converts the given beat position to time, optionally starting from a specific measure.
+    /// Queries the current play state.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn time_map_2_beats_to_time(
-        &self,
-        project: ProjectContext,
-        measure_mode: MeasureMode,
-        bpos: PositionInBeats,
-    ) -> PositionInSeconds
+    pub fn get_play_state_ex(&self, project: ProjectContext) -> PlayState
     where
         UsageScope: AnyThread,
     {
         self.require_valid_project(project);
-        unsafe { self.time_map_2_beats_to_time_unchecked(project, measure_mode, bpos) }
+        unsafe { self.get_play_state_ex_unchecked(project) }
     }
 
-    /// Like [`time_map_2_beats_to_time()`] but doesn't check if project is valid.
+    /// Like [`get_play_state_ex()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`time_map_2_beats_to_time()`]: #method.time_map_2_beats_to_time
-    pub unsafe fn time_map_2_beats_to_time_unchecked(
-        &self,
-        project: ProjectContext,
-        measure_mode: MeasureMode,
-        bpos: PositionInBeats,
-    ) -> PositionInSeconds
+    /// [`get_play_state_ex()`]: #method.get_play_state_ex
+    pub unsafe fn get_play_state_ex_unchecked(&self, project: ProjectContext) -> PlayState
     where
         UsageScope: AnyThread,
     {
-        use MeasureMode::*;
-        let tpos = self.low.TimeMap2_beatsToTime(
-            project.to_raw(),
-            bpos.0,
-            match measure_mode {
-                IgnoreMeasure => null(),
-                FromMeasureAtIndex(i) => &i as *const _,
-            },
-        );
-        PositionInSeconds::new(tpos)
+        let result = self.low.GetPlayStateEx(project.to_raw()) as u32;
+        PlayState {
+            is_playing: result & 1 > 0,
+            is_paused: result & 2 > 0,
+            is_recording: result & 4 > 0,
+        }
     }
 
-    /// Converts the given quarter-note position to time.
+    /// Queries the current repeat state.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn time_map_2_qn_to_time(
-        &self,
-        project: ProjectContext,
-        qn: PositionInQuarterNotes,
-    ) -> PositionInSeconds
+    pub fn get_set_repeat_ex_get(&self, project: ProjectContext) -> bool
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
+        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.time_map_2_qn_to_time_unchecked(project, qn) }
+        unsafe { self.get_set_repeat_ex_get_unchecked(project) }
     }
 
-    /// Like [`time_map_2_qn_to_time()`] but doesn't check if project is valid.
+    /// Like [`get_set_repeat_ex_get()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`time_map_2_qn_to_time()`]: #method.time_map_2_qn_to_time
-    pub unsafe fn time_map_2_qn_to_time_unchecked(
-        &self,
-        project: ProjectContext,
-        qn: PositionInQuarterNotes,
-    ) -> PositionInSeconds
+    /// [`get_set_repeat_ex_get()`]: #method.get_set_repeat_ex_get
+    pub unsafe fn get_set_repeat_ex_get_unchecked(&self, project: ProjectContext) -> bool
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let tpos = self.low.TimeMap2_QNToTime(project.to_raw(), qn.0);
-        PositionInSeconds::new(tpos)
+        self.require_main_thread();
+        self.low.GetSetRepeatEx(project.to_raw(), -1) > 0
     }
 
-    /// Converts the given time to a quarter-note position.
+    /// Sets the repeat state.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn time_map_2_time_to_qn(
-        &self,
-        project: ProjectContext,
-        tpos: PositionInSeconds,
-    ) -> PositionInQuarterNotes
+    pub fn get_set_repeat_ex_set(&self, project: ProjectContext, repeat: bool)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
+        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.time_map_2_time_to_qn_unchecked(project, tpos) }
+        unsafe {
+            self.get_set_repeat_ex_set_unchecked(project, repeat);
+        }
     }
 
-    /// Like [`time_map_2_time_to_qn()`] but doesn't check if project is valid.
+    /// Like [`get_set_repeat_ex_set()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`time_map_2_time_to_qn()`]: #method.time_map_2_time_to_qn
-    pub unsafe fn time_map_2_time_to_qn_unchecked(
-        &self,
-        project: ProjectContext,
-        tpos: PositionInSeconds,
-    ) -> PositionInQuarterNotes
+    /// [`get_set_repeat_ex_set()`]: #method.get_set_repeat_ex_set
+    pub unsafe fn get_set_repeat_ex_set_unchecked(&self, project: ProjectContext, repeat: bool)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let qn = self.low.TimeMap2_timeToQN(project.to_raw(), tpos.0);
-        PositionInQuarterNotes::new(qn)
+        self.require_main_thread();
+        self.low
+            .GetSetRepeatEx(project.to_raw(), if repeat { 1 } else { 0 });
     }
 
-    /// Converts the given quarter-note position to time.
+    /// Grants temporary access to the data of the given marker/region.
     ///
-    /// Quarter notes are counted from the start of the project, regardless of any partial measures.
+    /// The given index starts as 0 and counts both markers and regions.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn time_map_2_qn_to_time_abs(
+    pub fn enum_project_markers_3<R>(
         &self,
         project: ProjectContext,
-        qn: PositionInQuarterNotes,
-    ) -> PositionInSeconds
+        index: u32,
+        // TODO-high Other functions should take an option, too! Otherwise we can't give back
+        // ownership  in case this didn't return anything! Same for all other continuation
+        // passing functions!
+        use_result: impl FnOnce(Option<EnumProjectMarkers3Result>) -> R,
+    ) -> R
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
         self.require_valid_project(project);
-        unsafe { self.time_map_2_qn_to_time_unchecked(project, qn) }
+        unsafe { self.enum_project_markers_3_unchecked(project, index, use_result) }
     }
 
-    /// Like [`time_map_2_qn_to_time_abs()`] but doesn't check if project is valid.
+    /// Like [`enum_project_markers_3()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`time_map_2_qn_to_time_abs()`]: #method.time_map_2_qn_to_time_abs
-    pub unsafe fn time_map_2_qn_to_time_abs_unchecked(
+    /// [`enum_project_markers_3()`]: #method.enum_project_markers_3
+    pub unsafe fn enum_project_markers_3_unchecked<R>(
         &self,
         project: ProjectContext,
-        qn: PositionInQuarterNotes,
-    ) -> PositionInSeconds
+        index: u32,
+        use_result: impl FnOnce(Option<EnumProjectMarkers3Result>) -> R,
+    ) -> R
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let tpos = self.low.TimeMap2_QNToTime(project.to_raw(), qn.0);
-        PositionInSeconds::new(tpos)
+        self.require_main_thread();
+        let mut is_region = MaybeUninit::zeroed();
+        let mut pos = MaybeUninit::zeroed();
+        let mut region_end = MaybeUninit::zeroed();
+        let mut name = MaybeUninit::zeroed();
+        let mut id = MaybeUninit::zeroed();
+        let mut color = MaybeUninit::zeroed();
+        let successful = self.low.EnumProjectMarkers3(
+            project.to_raw(),
+            index as _,
+            is_region.as_mut_ptr(),
+            pos.as_mut_ptr(),
+            region_end.as_mut_ptr(),
+            name.as_mut_ptr(),
+            id.as_mut_ptr(),
+            color.as_mut_ptr(),
+        );
+        if successful == 0 {
+            return use_result(None);
+        }
+        let result = EnumProjectMarkers3Result {
+            position: PositionInSeconds::new(pos.assume_init()),
+            region_end_position: if is_region.assume_init() {
+                Some(PositionInSeconds::new(region_end.assume_init()))
+            } else {
+                None
+            },
+            name: create_passing_c_str(name.assume_init()).unwrap(),
+            id: BookmarkId(id.assume_init() as _),
+            color: NativeColor(color.assume_init() as _),
+        };
+        use_result(Some(result))
     }
 
-    /// Converts the given time to a quarter-note position.
+    /// Creates a PCM source from the given file name.
     ///
-    /// Quarter notes are counted from the start of the project, regardless of any partial measures.
+    /// # Errors
+    ///
+    /// Returns an error if the PCM source could not be created.
     ///
     /// # Panics
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn time_map_2_time_to_qn_abs(
+    /// Panics if the given file name is not valid UTF-8.
+    pub fn pcm_source_create_from_file_ex(
         &self,
-        project: ProjectContext,
-        tpos: PositionInSeconds,
-    ) -> PositionInQuarterNotes
+        file_name: &Path,
+        midi_import_behavior: MidiImportBehavior,
+    ) -> ReaperFunctionResult<OwnedPcmSource>
     where
         UsageScope: AnyThread,
     {
-        self.require_valid_project(project);
-        unsafe { self.time_map_2_time_to_qn_abs_unchecked(project, tpos) }
+        let file_name_str = file_name.to_str().expect("file name is not valid UTF-8");
+        let file_name_reaper_string = ReaperString::from_str(file_name_str);
+        let ptr = unsafe {
+            self.low.PCM_Source_CreateFromFileEx(
+                file_name_reaper_string.as_ptr(),
+                match midi_import_behavior {
+                    MidiImportBehavior::UsePreference => false,
+                    MidiImportBehavior::ForceNoMidiImport => true,
+                },
+            )
+        };
+        NonNull::new(ptr)
+            .ok_or_else(|| ReaperFunctionError::new("couldn't create PCM source from file"))
+            .map(OwnedPcmSource)
     }
 
-    /// Like [`time_map_2_time_to_qn_abs()`] but doesn't check if project is valid.
+    /// Creates a PCM source from a type identifier.
     ///
-    /// # Safety
+    /// TODO-high Documentation/API ... unstable
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// Use this if you're going to load its state via LoadState/ProjectStateContext.
+    /// Valid types include "WAVE", "MIDI", or whatever plug-ins define as well.
     ///
-    /// [`time_map_2_time_to_qn_abs()`]: #method.time_map_2_time_to_qn_abs
-    pub unsafe fn time_map_2_time_to_qn_abs_unchecked(
+    /// # Errors
+    ///
+    /// Returns an error if the PCM source could not be created.
+    pub fn pcm_source_create_from_type<'a>(
         &self,
-        project: ProjectContext,
-        tpos: PositionInSeconds,
-    ) -> PositionInQuarterNotes
+        source_type: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<OwnedPcmSource>
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let qn = self.low.TimeMap2_timeToQN(project.to_raw(), tpos.0);
-        PositionInQuarterNotes::new(qn)
+        // TODO-medium Can maybe be relaxed.
+        self.require_main_thread();
+        let ptr = unsafe {
+            self.low
+                .PCM_Source_CreateFromType(source_type.into().as_ptr())
+        };
+        NonNull::new(ptr)
+            .ok_or_else(|| ReaperFunctionError::new("couldn't create PCM source from type"))
+            .map(OwnedPcmSource)
     }
 
-    /// Gets the arrange view start/end time for the given screen coordinates.
-    ///
-    /// Set both `screen_x_start` and `screen_x_end` to 0 to get the full arrange view's start/end
-    /// time.
+    /// Goes to the given marker.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn get_set_arrange_view_2_get(
-        &self,
-        project: ProjectContext,
-        screen_x_start: u32,
-        screen_x_end: u32,
-    ) -> GetSetArrangeView2Result
+    pub fn go_to_marker(&self, project: ProjectContext, marker: BookmarkRef)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_valid_project(project);
-        unsafe { self.get_set_arrange_view_2_get_unchecked(project, screen_x_start, screen_x_end) }
+        unsafe {
+            self.go_to_marker_unchecked(project, marker);
+        }
     }
 
-    /// Like [`get_set_arrange_view_2_get()`] but doesn't check if project is valid.
+    /// Like [`go_to_marker()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`get_set_arrange_view_2_get()`]: #method.get_set_arrange_view_2_get
-    pub unsafe fn get_set_arrange_view_2_get_unchecked(
-        &self,
-        project: ProjectContext,
-        screen_x_start: u32,
-        screen_x_end: u32,
-    ) -> GetSetArrangeView2Result
+    /// [`go_to_marker()`]: #method.go_to_marker
+    pub unsafe fn go_to_marker_unchecked(&self, project: ProjectContext, marker: BookmarkRef)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let mut start_time = MaybeUninit::zeroed();
-        let mut end_time = MaybeUninit::zeroed();
-        self.low.GetSet_ArrangeView2(
+        self.low.GoToMarker(
             project.to_raw(),
-            false,
-            screen_x_start as _,
-            screen_x_end as _,
-            start_time.as_mut_ptr(),
-            end_time.as_mut_ptr(),
+            marker.to_raw(),
+            marker.uses_timeline_order(),
         );
-        GetSetArrangeView2Result {
-            start_time: PositionInSeconds::new(start_time.assume_init()),
-            end_time: PositionInSeconds::new(end_time.assume_init()),
-        }
     }
 
-    /// Returns the effective tempo in BPM at the given position (i.e. 2x in /8 signatures).
+    /// Seeks to the given region after the current one finishes playing (smooth seek).
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn time_map_2_get_divided_bpm_at_time(
-        &self,
-        project: ProjectContext,
-        tpos: PositionInSeconds,
-    ) -> Bpm
+    pub fn go_to_region(&self, project: ProjectContext, region: BookmarkRef)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
         self.require_valid_project(project);
-        unsafe { self.time_map_2_get_divided_bpm_at_time_unchecked(project, tpos) }
+        unsafe {
+            self.go_to_region_unchecked(project, region);
+        }
     }
 
-    /// Like [`time_map_2_get_divided_bpm_at_time()`] but doesn't check if project is valid.
+    /// Like [`go_to_region()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`time_map_2_get_divided_bpm_at_time()`]: #method.time_map_2_get_divided_bpm_at_time
-    pub unsafe fn time_map_2_get_divided_bpm_at_time_unchecked(
-        &self,
-        project: ProjectContext,
-        tpos: PositionInSeconds,
-    ) -> Bpm
+    /// [`go_to_region()`]: #method.go_to_region
+    pub unsafe fn go_to_region_unchecked(&self, project: ProjectContext, region: BookmarkRef)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let bpm = self
-            .low
-            .TimeMap2_GetDividedBpmAtTime(project.to_raw(), tpos.get());
-        Bpm(bpm)
+        self.require_main_thread();
+        self.low.GoToRegion(
+            project.to_raw(),
+            region.to_raw(),
+            region.uses_timeline_order(),
+        );
     }
 
-    /// Returns the current position of the edit cursor.
+    /// Adds a marker or region (if `region_end_position` is given) and returns its ID.
+    ///
+    /// If `desired_id` is `None` or the ID is already taken, REAPER picks one. If `color` is
+    /// `None`, the default color is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn get_cursor_position_ex(&self, project: ProjectContext) -> PositionInSeconds
+    pub fn add_project_marker_2<'a>(
+        &self,
+        project: ProjectContext,
+        position: PositionInSeconds,
+        region_end_position: Option<PositionInSeconds>,
+        name: impl Into<ReaperStringArg<'a>>,
+        desired_id: Option<BookmarkId>,
+        color: Option<NativeColor>,
+    ) -> ReaperFunctionResult<BookmarkId>
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
         self.require_valid_project(project);
-        unsafe { self.get_cursor_position_ex_unchecked(project) }
+        unsafe {
+            self.add_project_marker_2_unchecked(
+                project,
+                position,
+                region_end_position,
+                name,
+                desired_id,
+                color,
+            )
+        }
     }
 
-    /// Like [`get_cursor_position_ex()`] but doesn't check if project is valid.
+    /// Like [`add_project_marker_2()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`get_cursor_position_ex()`]: #method.get_cursor_position_ex
-    pub unsafe fn get_cursor_position_ex_unchecked(
+    /// [`add_project_marker_2()`]: #method.add_project_marker_2
+    pub unsafe fn add_project_marker_2_unchecked<'a>(
         &self,
         project: ProjectContext,
-    ) -> PositionInSeconds
+        position: PositionInSeconds,
+        region_end_position: Option<PositionInSeconds>,
+        name: impl Into<ReaperStringArg<'a>>,
+        desired_id: Option<BookmarkId>,
+        color: Option<NativeColor>,
+    ) -> ReaperFunctionResult<BookmarkId>
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let res = self.low.GetCursorPositionEx(project.to_raw());
-        PositionInSeconds::new(res)
+        self.require_main_thread();
+        let id = self.low.AddProjectMarker2(
+            project.to_raw(),
+            region_end_position.is_some(),
+            position.get(),
+            region_end_position.map(|p| p.get()).unwrap_or_default(),
+            name.into().as_ptr(),
+            desired_id.map(|id| id.to_raw()).unwrap_or(-1),
+            bookmark_color_to_raw(color),
+        );
+        if id < 0 {
+            return Err(ReaperFunctionError::new("couldn't add marker or region"));
+        }
+        Ok(BookmarkId(id as _))
     }
 
-    /// Returns the latency-compensated actual-what-you-hear position.
+    /// Changes the marker or region (if `region_end_position` is given) with the given ID.
+    ///
+    /// An empty name clears the name. If `color` is `None`, the color is not changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there's no such marker or region.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn get_play_position_ex(&self, project: ProjectContext) -> PositionInSeconds
+    pub fn set_project_marker_4<'a>(
+        &self,
+        project: ProjectContext,
+        id: BookmarkId,
+        position: PositionInSeconds,
+        region_end_position: Option<PositionInSeconds>,
+        name: impl Into<ReaperStringArg<'a>>,
+        color: Option<NativeColor>,
+    ) -> ReaperFunctionResult<()>
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
         self.require_valid_project(project);
-        unsafe { self.get_play_position_ex_unchecked(project) }
+        unsafe {
+            self.set_project_marker_4_unchecked(
+                project,
+                id,
+                position,
+                region_end_position,
+                name,
+                color,
+            )
+        }
     }
 
-    /// Like [`get_play_position_ex()`] but doesn't check if project is valid.
+    /// Like [`set_project_marker_4()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`get_play_position_ex()`]: #method.get_play_position_ex
-    pub unsafe fn get_play_position_ex_unchecked(
+    /// [`set_project_marker_4()`]: #method.set_project_marker_4
+    pub unsafe fn set_project_marker_4_unchecked<'a>(
         &self,
         project: ProjectContext,
-    ) -> PositionInSeconds
+        id: BookmarkId,
+        position: PositionInSeconds,
+        region_end_position: Option<PositionInSeconds>,
+        name: impl Into<ReaperStringArg<'a>>,
+        color: Option<NativeColor>,
+    ) -> ReaperFunctionResult<()>
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let res = self.low.GetPlayPositionEx(project.to_raw());
-        PositionInSeconds::new(res)
+        self.require_main_thread();
+        let name = name.into();
+        let successful = self.low.SetProjectMarker4(
+            project.to_raw(),
+            id.to_raw(),
+            region_end_position.is_some(),
+            position.get(),
+            region_end_position.map(|p| p.get()).unwrap_or_default(),
+            name.as_ptr(),
+            bookmark_color_to_raw(color),
+            bookmark_name_flags(&name),
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't set marker or region"));
+        }
+        Ok(())
     }
 
-    /// Returns the position of the next audio block being processed.
+    /// Changes the marker or region at the given index.
+    ///
+    /// The given index starts as 0 and counts both markers and regions. Whether the bookmark is
+    /// treated as region depends on whether `region_end_position` is given. If `id` is `None`,
+    /// the ID is not changed. An empty name clears the name. If `color` is `None`, the color is
+    /// not changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (e.g. if the new ID is already taken by another
+    /// region).
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn get_play_position_2_ex(&self, project: ProjectContext) -> PositionInSeconds
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_project_marker_by_index_2<'a>(
+        &self,
+        project: ProjectContext,
+        index: u32,
+        position: PositionInSeconds,
+        region_end_position: Option<PositionInSeconds>,
+        id: Option<BookmarkId>,
+        name: impl Into<ReaperStringArg<'a>>,
+        color: Option<NativeColor>,
+    ) -> ReaperFunctionResult<()>
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
         self.require_valid_project(project);
-        unsafe { self.get_play_position_2_ex_unchecked(project) }
+        unsafe {
+            self.set_project_marker_by_index_2_unchecked(
+                project,
+                index,
+                position,
+                region_end_position,
+                id,
+                name,
+                color,
+            )
+        }
     }
 
-    /// Like [`get_play_position_2_ex()`] but doesn't check if project is valid.
+    /// Like [`set_project_marker_by_index_2()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`get_play_position_2_ex()`]: #method.get_play_position_2_ex
-    pub unsafe fn get_play_position_2_ex_unchecked(
+    /// [`set_project_marker_by_index_2()`]: #method.set_project_marker_by_index_2
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn set_project_marker_by_index_2_unchecked<'a>(
         &self,
         project: ProjectContext,
-    ) -> PositionInSeconds
+        index: u32,
+        position: PositionInSeconds,
+        region_end_position: Option<PositionInSeconds>,
+        id: Option<BookmarkId>,
+        name: impl Into<ReaperStringArg<'a>>,
+        color: Option<NativeColor>,
+    ) -> ReaperFunctionResult<()>
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let res = self.low.GetPlayPosition2Ex(project.to_raw());
-        PositionInSeconds::new(res)
+        self.require_main_thread();
+        let name = name.into();
+        let successful = self.low.SetProjectMarkerByIndex2(
+            project.to_raw(),
+            index as _,
+            region_end_position.is_some(),
+            position.get(),
+            region_end_position.map(|p| p.get()).unwrap_or_default(),
+            id.map(|id| id.to_raw()).unwrap_or(-1),
+            name.as_ptr(),
+            bookmark_color_to_raw(color),
+            bookmark_name_flags(&name),
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't set marker or region"));
+        }
+        Ok(())
     }
 
-    /// Returns the number of markers and regions in the given project.
+    /// Deletes the marker or region with the given ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there's no such marker or region.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn count_project_markers(&self, project: ProjectContext) -> CountProjectMarkersResult
+    pub fn delete_project_marker(
+        &self,
+        project: ProjectContext,
+        id: BookmarkId,
+        kind: BookmarkKind,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.count_project_markers_unchecked(project) }
+        unsafe { self.delete_project_marker_unchecked(project, id, kind) }
     }
 
-    /// Like [`count_project_markers()`] but doesn't check if project is valid.
+    /// Like [`delete_project_marker()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`count_project_markers()`]: #method.count_project_markers
-    pub unsafe fn count_project_markers_unchecked(
+    /// [`delete_project_marker()`]: #method.delete_project_marker
+    pub unsafe fn delete_project_marker_unchecked(
         &self,
         project: ProjectContext,
-    ) -> CountProjectMarkersResult
+        id: BookmarkId,
+        kind: BookmarkKind,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let mut num_markers = MaybeUninit::zeroed();
-        let mut num_regions = MaybeUninit::zeroed();
-        let total_count = self.low.CountProjectMarkers(
+        let successful = self.low.DeleteProjectMarker(
             project.to_raw(),
-            num_markers.as_mut_ptr(),
-            num_regions.as_mut_ptr(),
+            id.to_raw(),
+            kind == BookmarkKind::Region,
         );
-        CountProjectMarkersResult {
-            total_count: total_count as _,
-            marker_count: num_markers.assume_init() as _,
-            region_count: num_regions.assume_init() as _,
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't delete marker or region"));
         }
+        Ok(())
     }
 
-    /// Gets the last project marker before the given time and/or the project region that includes
-    /// the given time.
+    /// Deletes the marker or region at the given index.
+    ///
+    /// The given index starts as 0 and counts both markers and regions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there's no marker or region at the given index.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn get_last_marker_and_cur_region(
+    pub fn delete_project_marker_by_index(
         &self,
         project: ProjectContext,
-        time: PositionInSeconds,
-    ) -> GetLastMarkerAndCurRegionResult
+        index: u32,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.get_last_marker_and_cur_region_unchecked(project, time) }
+        unsafe { self.delete_project_marker_by_index_unchecked(project, index) }
     }
 
-    /// Like [`get_last_marker_and_cur_region()`] but doesn't check if project is valid.
+    /// Like [`delete_project_marker_by_index()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`get_last_marker_and_cur_region()`]: #method.get_last_marker_and_cur_region
-    pub unsafe fn get_last_marker_and_cur_region_unchecked(
+    /// [`delete_project_marker_by_index()`]: #method.delete_project_marker_by_index
+    pub unsafe fn delete_project_marker_by_index_unchecked(
         &self,
         project: ProjectContext,
-        time: PositionInSeconds,
-    ) -> GetLastMarkerAndCurRegionResult
+        index: u32,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let mut marker_idx = MaybeUninit::zeroed();
-        let mut region_idx = MaybeUninit::zeroed();
-        self.low.GetLastMarkerAndCurRegion(
+        let successful = self
+            .low
+            .DeleteProjectMarkerByIndex(project.to_raw(), index as _);
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't delete marker or region"));
+        }
+        Ok(())
+    }
+
+    /// Converts the given time into beats.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn time_map_2_time_to_beats(
+        &self,
+        project: ProjectContext,
+        tpos: PositionInSeconds,
+    ) -> TimeMap2TimeToBeatsResult
+    where
+        UsageScope: AnyThread,
+    {
+        self.require_valid_project(project);
+        unsafe { self.time_map_2_time_to_beats_unchecked(project, tpos) }
+    }
+
+    /// Like [`time_map_2_time_to_beats()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`time_map_2_time_to_beats()`]: #method.time_map_2_time_to_beats
+    pub unsafe fn time_map_2_time_to_beats_unchecked(
+        &self,
+        project: ProjectContext,
+        tpos: PositionInSeconds,
+    ) -> TimeMap2TimeToBeatsResult
+    where
+        UsageScope: AnyThread,
+    {
+        let mut measures = MaybeUninit::zeroed();
+        let mut measure_length = MaybeUninit::zeroed();
+        let mut full_beats = MaybeUninit::zeroed();
+        let mut common_denom = MaybeUninit::zeroed();
+        let beats_within_measure = self.low.TimeMap2_timeToBeats(
             project.to_raw(),
-            time.get(),
-            marker_idx.as_mut_ptr(),
-            region_idx.as_mut_ptr(),
+            tpos.get(),
+            measures.as_mut_ptr(),
+            measure_length.as_mut_ptr(),
+            full_beats.as_mut_ptr(),
+            common_denom.as_mut_ptr(),
         );
-        GetLastMarkerAndCurRegionResult {
-            marker_index: make_some_if_not_negative(marker_idx.assume_init()),
-            region_index: make_some_if_not_negative(region_idx.assume_init()),
+        TimeMap2TimeToBeatsResult {
+            full_beats: PositionInBeats::new(full_beats.assume_init()),
+            measure_index: measures.assume_init(),
+            beats_since_measure: PositionInBeats::new(beats_within_measure),
+            time_signature: TimeSignature {
+                numerator: NonZeroU32::new(measure_length.assume_init() as _).unwrap(),
+                denominator: NonZeroU32::new(common_denom.assume_init() as _).unwrap(),
+            },
+        }
+    }
+
+    /// Returns information about the given measure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn time_map_get_measure_info(
+        &self,
+        project: ProjectContext,
+        measure_index: i32,
+    ) -> TimeMapGetMeasureInfoResult
+    where
+        UsageScope: AnyThread,
+    {
+        self.require_valid_project(project);
+        unsafe { self.time_map_get_measure_info_unchecked(project, measure_index) }
+    }
+
+    /// Like [`time_map_get_measure_info()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`time_map_get_measure_info()`]: #method.time_map_get_measure_info
+    pub unsafe fn time_map_get_measure_info_unchecked(
+        &self,
+        project: ProjectContext,
+        measure_index: i32,
+    ) -> TimeMapGetMeasureInfoResult
+    where
+        UsageScope: AnyThread,
+    {
+        let mut start_qn = MaybeUninit::zeroed();
+        let mut end_qn = MaybeUninit::zeroed();
+        let mut num = MaybeUninit::zeroed();
+        let mut denom = MaybeUninit::zeroed();
+        let mut bpm = MaybeUninit::zeroed();
+        let start_time = self.low.TimeMap_GetMeasureInfo(
+            project.to_raw(),
+            measure_index,
+            start_qn.as_mut_ptr(),
+            end_qn.as_mut_ptr(),
+            num.as_mut_ptr(),
+            denom.as_mut_ptr(),
+            bpm.as_mut_ptr(),
+        );
+        TimeMapGetMeasureInfoResult {
+            start_time: PositionInSeconds::new(start_time),
+            start_qn: PositionInQuarterNotes::new(start_qn.assume_init()),
+            end_qn: PositionInQuarterNotes::new(end_qn.assume_init()),
+            time_signature: TimeSignature {
+                numerator: NonZeroU32::new(num.assume_init() as _).unwrap(),
+                denominator: NonZeroU32::new(denom.assume_init() as _).unwrap(),
+            },
+            tempo: Bpm::new(bpm.assume_init()),
+        }
+    }
+
+    /// Converts the given beat position to time, optionally starting from a specific measure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn time_map_2_beats_to_time(
+        &self,
+        project: ProjectContext,
+        measure_mode: MeasureMode,
+        bpos: PositionInBeats,
+    ) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        self.require_valid_project(project);
+        unsafe { self.time_map_2_beats_to_time_unchecked(project, measure_mode, bpos) }
+    }
+
+    /// Like [`time_map_2_beats_to_time()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`time_map_2_beats_to_time()`]: #method.time_map_2_beats_to_time
+    pub unsafe fn time_map_2_beats_to_time_unchecked(
+        &self,
+        project: ProjectContext,
+        measure_mode: MeasureMode,
+        bpos: PositionInBeats,
+    ) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        use MeasureMode::*;
+        let tpos = self.low.TimeMap2_beatsToTime(
+            project.to_raw(),
+            bpos.0,
+            match measure_mode {
+                IgnoreMeasure => null(),
+                FromMeasureAtIndex(i) => &i as *const _,
+            },
+        );
+        PositionInSeconds::new(tpos)
+    }
+
+    /// Converts the given quarter-note position to time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn time_map_2_qn_to_time(
+        &self,
+        project: ProjectContext,
+        qn: PositionInQuarterNotes,
+    ) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        self.require_valid_project(project);
+        unsafe { self.time_map_2_qn_to_time_unchecked(project, qn) }
+    }
+
+    /// Like [`time_map_2_qn_to_time()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`time_map_2_qn_to_time()`]: #method.time_map_2_qn_to_time
+    pub unsafe fn time_map_2_qn_to_time_unchecked(
+        &self,
+        project: ProjectContext,
+        qn: PositionInQuarterNotes,
+    ) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        let tpos = self.low.TimeMap2_QNToTime(project.to_raw(), qn.0);
+        PositionInSeconds::new(tpos)
+    }
+
+    /// Converts the given time to a quarter-note position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn time_map_2_time_to_qn(
+        &self,
+        project: ProjectContext,
+        tpos: PositionInSeconds,
+    ) -> PositionInQuarterNotes
+    where
+        UsageScope: AnyThread,
+    {
+        self.require_valid_project(project);
+        unsafe { self.time_map_2_time_to_qn_unchecked(project, tpos) }
+    }
+
+    /// Like [`time_map_2_time_to_qn()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`time_map_2_time_to_qn()`]: #method.time_map_2_time_to_qn
+    pub unsafe fn time_map_2_time_to_qn_unchecked(
+        &self,
+        project: ProjectContext,
+        tpos: PositionInSeconds,
+    ) -> PositionInQuarterNotes
+    where
+        UsageScope: AnyThread,
+    {
+        let qn = self.low.TimeMap2_timeToQN(project.to_raw(), tpos.0);
+        PositionInQuarterNotes::new(qn)
+    }
+
+    /// Converts the given quarter-note position to time.
+    ///
+    /// Quarter notes are counted from the start of the project, regardless of any partial measures.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn time_map_2_qn_to_time_abs(
+        &self,
+        project: ProjectContext,
+        qn: PositionInQuarterNotes,
+    ) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        self.require_valid_project(project);
+        unsafe { self.time_map_2_qn_to_time_unchecked(project, qn) }
+    }
+
+    /// Like [`time_map_2_qn_to_time_abs()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`time_map_2_qn_to_time_abs()`]: #method.time_map_2_qn_to_time_abs
+    pub unsafe fn time_map_2_qn_to_time_abs_unchecked(
+        &self,
+        project: ProjectContext,
+        qn: PositionInQuarterNotes,
+    ) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        let tpos = self.low.TimeMap2_QNToTime(project.to_raw(), qn.0);
+        PositionInSeconds::new(tpos)
+    }
+
+    /// Converts the given time to a quarter-note position.
+    ///
+    /// Quarter notes are counted from the start of the project, regardless of any partial measures.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn time_map_2_time_to_qn_abs(
+        &self,
+        project: ProjectContext,
+        tpos: PositionInSeconds,
+    ) -> PositionInQuarterNotes
+    where
+        UsageScope: AnyThread,
+    {
+        self.require_valid_project(project);
+        unsafe { self.time_map_2_time_to_qn_abs_unchecked(project, tpos) }
+    }
+
+    /// Like [`time_map_2_time_to_qn_abs()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`time_map_2_time_to_qn_abs()`]: #method.time_map_2_time_to_qn_abs
+    pub unsafe fn time_map_2_time_to_qn_abs_unchecked(
+        &self,
+        project: ProjectContext,
+        tpos: PositionInSeconds,
+    ) -> PositionInQuarterNotes
+    where
+        UsageScope: AnyThread,
+    {
+        let qn = self.low.TimeMap2_timeToQN(project.to_raw(), tpos.0);
+        PositionInQuarterNotes::new(qn)
+    }
+
+    /// Gets the arrange view start/end time for the given screen coordinates.
+    ///
+    /// Set both `screen_x_start` and `screen_x_end` to 0 to get the full arrange view's start/end
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_set_arrange_view_2_get(
+        &self,
+        project: ProjectContext,
+        screen_x_start: u32,
+        screen_x_end: u32,
+    ) -> GetSetArrangeView2Result
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.get_set_arrange_view_2_get_unchecked(project, screen_x_start, screen_x_end) }
+    }
+
+    /// Like [`get_set_arrange_view_2_get()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_set_arrange_view_2_get()`]: #method.get_set_arrange_view_2_get
+    pub unsafe fn get_set_arrange_view_2_get_unchecked(
+        &self,
+        project: ProjectContext,
+        screen_x_start: u32,
+        screen_x_end: u32,
+    ) -> GetSetArrangeView2Result
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut start_time = MaybeUninit::zeroed();
+        let mut end_time = MaybeUninit::zeroed();
+        self.low.GetSet_ArrangeView2(
+            project.to_raw(),
+            false,
+            screen_x_start as _,
+            screen_x_end as _,
+            start_time.as_mut_ptr(),
+            end_time.as_mut_ptr(),
+        );
+        GetSetArrangeView2Result {
+            start_time: PositionInSeconds::new(start_time.assume_init()),
+            end_time: PositionInSeconds::new(end_time.assume_init()),
+        }
+    }
+
+    /// Returns the effective tempo in BPM at the given position (i.e. 2x in /8 signatures).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn time_map_2_get_divided_bpm_at_time(
+        &self,
+        project: ProjectContext,
+        tpos: PositionInSeconds,
+    ) -> Bpm
+    where
+        UsageScope: AnyThread,
+    {
+        self.require_valid_project(project);
+        unsafe { self.time_map_2_get_divided_bpm_at_time_unchecked(project, tpos) }
+    }
+
+    /// Like [`time_map_2_get_divided_bpm_at_time()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`time_map_2_get_divided_bpm_at_time()`]: #method.time_map_2_get_divided_bpm_at_time
+    pub unsafe fn time_map_2_get_divided_bpm_at_time_unchecked(
+        &self,
+        project: ProjectContext,
+        tpos: PositionInSeconds,
+    ) -> Bpm
+    where
+        UsageScope: AnyThread,
+    {
+        let bpm = self
+            .low
+            .TimeMap2_GetDividedBpmAtTime(project.to_raw(), tpos.get());
+        Bpm(bpm)
+    }
+
+    /// Returns the number of tempo/time signature markers in the given project.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn count_tempo_time_sig_markers(&self, project: ProjectContext) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.count_tempo_time_sig_markers_unchecked(project) }
+    }
+
+    /// Like [`count_tempo_time_sig_markers()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`count_tempo_time_sig_markers()`]: #method.count_tempo_time_sig_markers
+    pub unsafe fn count_tempo_time_sig_markers_unchecked(&self, project: ProjectContext) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.CountTempoTimeSigMarkers(project.to_raw()) as u32
+    }
+
+    /// Returns the tempo/time signature marker at the given index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there's no marker at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_tempo_time_sig_marker(
+        &self,
+        project: ProjectContext,
+        index: u32,
+    ) -> ReaperFunctionResult<TempoTimeSigMarker>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.get_tempo_time_sig_marker_unchecked(project, index) }
+    }
+
+    /// Like [`get_tempo_time_sig_marker()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_tempo_time_sig_marker()`]: #method.get_tempo_time_sig_marker
+    pub unsafe fn get_tempo_time_sig_marker_unchecked(
+        &self,
+        project: ProjectContext,
+        index: u32,
+    ) -> ReaperFunctionResult<TempoTimeSigMarker>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut time_pos = MaybeUninit::zeroed();
+        let mut measure_pos = MaybeUninit::zeroed();
+        let mut beat_pos = MaybeUninit::zeroed();
+        let mut bpm = MaybeUninit::zeroed();
+        let mut num = MaybeUninit::zeroed();
+        let mut denom = MaybeUninit::zeroed();
+        let mut linear_tempo = MaybeUninit::zeroed();
+        let successful = self.low.GetTempoTimeSigMarker(
+            project.to_raw(),
+            index as _,
+            time_pos.as_mut_ptr(),
+            measure_pos.as_mut_ptr(),
+            beat_pos.as_mut_ptr(),
+            bpm.as_mut_ptr(),
+            num.as_mut_ptr(),
+            denom.as_mut_ptr(),
+            linear_tempo.as_mut_ptr(),
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get tempo/time signature marker",
+            ));
+        }
+        let time_signature = match (
+            NonZeroU32::new(num.assume_init() as _),
+            NonZeroU32::new(denom.assume_init() as _),
+        ) {
+            (Some(numerator), Some(denominator)) => Some(TimeSignature {
+                numerator,
+                denominator,
+            }),
+            _ => None,
+        };
+        let marker = TempoTimeSigMarker {
+            position: PositionInSeconds::new(time_pos.assume_init()),
+            measure_index: measure_pos.assume_init(),
+            beats_since_measure: PositionInBeats::new(beat_pos.assume_init()),
+            tempo: Bpm(bpm.assume_init()),
+            time_signature,
+            linear_tempo: linear_tempo.assume_init(),
+        };
+        Ok(marker)
+    }
+
+    /// Changes the tempo/time signature marker at the given index.
+    ///
+    /// If `time_signature` is `None`, the marker doesn't change the time signature. If
+    /// `linear_tempo` is `true`, the tempo changes gradually until the next marker. Call
+    /// [`update_timeline()`] when done.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (e.g. if there's no marker at the given index).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    ///
+    /// [`update_timeline()`]: #method.update_timeline
+    pub fn set_tempo_time_sig_marker(
+        &self,
+        project: ProjectContext,
+        index: u32,
+        position: TempoMarkerPosition,
+        tempo: Bpm,
+        time_signature: Option<TimeSignature>,
+        linear_tempo: bool,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe {
+            self.set_tempo_time_sig_marker_unchecked(
+                project,
+                index,
+                position,
+                tempo,
+                time_signature,
+                linear_tempo,
+            )
+        }
+    }
+
+    /// Like [`set_tempo_time_sig_marker()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`set_tempo_time_sig_marker()`]: #method.set_tempo_time_sig_marker
+    pub unsafe fn set_tempo_time_sig_marker_unchecked(
+        &self,
+        project: ProjectContext,
+        index: u32,
+        position: TempoMarkerPosition,
+        tempo: Bpm,
+        time_signature: Option<TimeSignature>,
+        linear_tempo: bool,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        use TempoMarkerPosition::*;
+        let (time_pos, measure_pos, beat_pos) = match position {
+            Time(pos) => (pos.get(), -1, -1.0),
+            Musical {
+                measure_index,
+                beats_since_measure,
+            } => (-1.0, measure_index, beats_since_measure.get()),
+        };
+        let (num, denom) = time_signature_to_raw(time_signature);
+        let successful = self.low.SetTempoTimeSigMarker(
+            project.to_raw(),
+            index as _,
+            time_pos,
+            measure_pos,
+            beat_pos,
+            tempo.get(),
+            num,
+            denom,
+            linear_tempo,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set tempo/time signature marker",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Adds a tempo/time signature marker at the given position.
+    ///
+    /// If `time_signature` is `None`, the marker doesn't change the time signature. If
+    /// `linear_tempo` is `true`, the tempo changes gradually until the next marker.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn add_tempo_time_sig_marker(
+        &self,
+        project: ProjectContext,
+        position: PositionInSeconds,
+        tempo: Bpm,
+        time_signature: Option<TimeSignature>,
+        linear_tempo: bool,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe {
+            self.add_tempo_time_sig_marker_unchecked(
+                project,
+                position,
+                tempo,
+                time_signature,
+                linear_tempo,
+            )
+        }
+    }
+
+    /// Like [`add_tempo_time_sig_marker()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`add_tempo_time_sig_marker()`]: #method.add_tempo_time_sig_marker
+    pub unsafe fn add_tempo_time_sig_marker_unchecked(
+        &self,
+        project: ProjectContext,
+        position: PositionInSeconds,
+        tempo: Bpm,
+        time_signature: Option<TimeSignature>,
+        linear_tempo: bool,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (num, denom) = time_signature_to_raw(time_signature);
+        let successful = self.low.AddTempoTimeSigMarker(
+            project.to_raw(),
+            position.get(),
+            tempo.get(),
+            num,
+            denom,
+            linear_tempo,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't add tempo/time signature marker",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Deletes the tempo/time signature marker at the given index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there's no marker at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn delete_tempo_time_sig_marker(
+        &self,
+        project: ProjectContext,
+        index: u32,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.delete_tempo_time_sig_marker_unchecked(project, index) }
+    }
+
+    /// Like [`delete_tempo_time_sig_marker()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`delete_tempo_time_sig_marker()`]: #method.delete_tempo_time_sig_marker
+    pub unsafe fn delete_tempo_time_sig_marker_unchecked(
+        &self,
+        project: ProjectContext,
+        index: u32,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self
+            .low
+            .DeleteTempoTimeSigMarker(project.to_raw(), index as _);
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't delete tempo/time signature marker",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the current position of the edit cursor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_cursor_position_ex(&self, project: ProjectContext) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        self.require_valid_project(project);
+        unsafe { self.get_cursor_position_ex_unchecked(project) }
+    }
+
+    /// Like [`get_cursor_position_ex()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_cursor_position_ex()`]: #method.get_cursor_position_ex
+    pub unsafe fn get_cursor_position_ex_unchecked(
+        &self,
+        project: ProjectContext,
+    ) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        let res = self.low.GetCursorPositionEx(project.to_raw());
+        PositionInSeconds::new(res)
+    }
+
+    /// Returns the latency-compensated actual-what-you-hear position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_play_position_ex(&self, project: ProjectContext) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        self.require_valid_project(project);
+        unsafe { self.get_play_position_ex_unchecked(project) }
+    }
+
+    /// Like [`get_play_position_ex()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_play_position_ex()`]: #method.get_play_position_ex
+    pub unsafe fn get_play_position_ex_unchecked(
+        &self,
+        project: ProjectContext,
+    ) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        let res = self.low.GetPlayPositionEx(project.to_raw());
+        PositionInSeconds::new(res)
+    }
+
+    /// Returns the position of the next audio block being processed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_play_position_2_ex(&self, project: ProjectContext) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        self.require_valid_project(project);
+        unsafe { self.get_play_position_2_ex_unchecked(project) }
+    }
+
+    /// Like [`get_play_position_2_ex()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_play_position_2_ex()`]: #method.get_play_position_2_ex
+    pub unsafe fn get_play_position_2_ex_unchecked(
+        &self,
+        project: ProjectContext,
+    ) -> PositionInSeconds
+    where
+        UsageScope: AnyThread,
+    {
+        let res = self.low.GetPlayPosition2Ex(project.to_raw());
+        PositionInSeconds::new(res)
+    }
+
+    /// Returns the number of markers and regions in the given project.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn count_project_markers(&self, project: ProjectContext) -> CountProjectMarkersResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.require_valid_project(project);
+        unsafe { self.count_project_markers_unchecked(project) }
+    }
+
+    /// Like [`count_project_markers()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`count_project_markers()`]: #method.count_project_markers
+    pub unsafe fn count_project_markers_unchecked(
+        &self,
+        project: ProjectContext,
+    ) -> CountProjectMarkersResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut num_markers = MaybeUninit::zeroed();
+        let mut num_regions = MaybeUninit::zeroed();
+        let total_count = self.low.CountProjectMarkers(
+            project.to_raw(),
+            num_markers.as_mut_ptr(),
+            num_regions.as_mut_ptr(),
+        );
+        CountProjectMarkersResult {
+            total_count: total_count as _,
+            marker_count: num_markers.assume_init() as _,
+            region_count: num_regions.assume_init() as _,
+        }
+    }
+
+    /// Gets the last project marker before the given time and/or the project region that includes
+    /// the given time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_last_marker_and_cur_region(
+        &self,
+        project: ProjectContext,
+        time: PositionInSeconds,
+    ) -> GetLastMarkerAndCurRegionResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.require_valid_project(project);
+        unsafe { self.get_last_marker_and_cur_region_unchecked(project, time) }
+    }
+
+    /// Like [`get_last_marker_and_cur_region()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_last_marker_and_cur_region()`]: #method.get_last_marker_and_cur_region
+    pub unsafe fn get_last_marker_and_cur_region_unchecked(
+        &self,
+        project: ProjectContext,
+        time: PositionInSeconds,
+    ) -> GetLastMarkerAndCurRegionResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut marker_idx = MaybeUninit::zeroed();
+        let mut region_idx = MaybeUninit::zeroed();
+        self.low.GetLastMarkerAndCurRegion(
+            project.to_raw(),
+            time.get(),
+            marker_idx.as_mut_ptr(),
+            region_idx.as_mut_ptr(),
+        );
+        GetLastMarkerAndCurRegionResult {
+            marker_index: make_some_if_not_negative(marker_idx.assume_init()),
+            region_index: make_some_if_not_negative(region_idx.assume_init()),
+        }
+    }
+
+    /// Performs an action belonging to the main section.
+    ///
+    /// To perform non-native actions (ReaScripts, custom or extension plugin actions) safely, see
+    /// [`named_command_lookup()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    ///
+    /// [`named_command_lookup()`]: #method.named_command_lookup
+    pub fn main_on_command_ex(&self, command: CommandId, flag: i32, project: ProjectContext)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.require_valid_project(project);
+        unsafe { self.main_on_command_ex_unchecked(command, flag, project) }
+    }
+
+    /// Like [`main_on_command_ex()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`main_on_command_ex()`]: #method.main_on_command_ex
+    pub unsafe fn main_on_command_ex_unchecked(
+        &self,
+        command_id: CommandId,
+        flag: i32,
+        project: ProjectContext,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .Main_OnCommandEx(command_id.to_raw(), flag, project.to_raw());
+    }
+
+    /// Sends an action command to the last focused MIDI editor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no MIDI editor is open or if the view mode does not match the input.
+    pub fn midi_editor_last_focused_on_command(
+        &self,
+        command_id: CommandId,
+        required_view_mode: RequiredViewMode,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self.low.MIDIEditor_LastFocused_OnCommand(
+            command_id.to_raw(),
+            required_view_mode == RequiredViewMode::ListView,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "no MIDI editor with this view mode open",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Informs control surfaces that the given track's mute state has changed.
+    ///
+    /// Doesn't actually change the mute state.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track or an invalid control surface.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # let session = reaper_medium::ReaperSession::default();
+    /// use reaper_medium::{NotificationBehavior::NotifyAll, ProjectContext::CurrentProject};
+    ///
+    /// let track = session.reaper().get_track(CurrentProject, 0).ok_or("no tracks")?;
+    /// unsafe {
+    ///     session.reaper().csurf_set_surface_mute(track, true, NotifyAll);
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub unsafe fn csurf_set_surface_mute(
+        &self,
+        track: MediaTrack,
+        mute: bool,
+        notification_behavior: NotificationBehavior,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .CSurf_SetSurfaceMute(track.as_ptr(), mute, notification_behavior.to_raw());
+    }
+
+    /// Informs control surfaces that the given track's solo state has changed.
+    ///
+    /// Doesn't actually change the solo state.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track or an invalid control surface.
+    pub unsafe fn csurf_set_surface_solo(
+        &self,
+        track: MediaTrack,
+        solo: bool,
+        notification_behavior: NotificationBehavior,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .CSurf_SetSurfaceSolo(track.as_ptr(), solo, notification_behavior.to_raw());
+    }
+
+    /// Generates a random GUID.
+    pub fn gen_guid(&self) -> GUID
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        // We zero this just for being safe
+        let mut guid = MaybeUninit::zeroed();
+        unsafe {
+            self.low.genGuid(guid.as_mut_ptr());
+        }
+        unsafe { guid.assume_init() }
+    }
+
+    /// Grants temporary access to the section with the given ID.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # let session = reaper_medium::ReaperSession::default();
+    /// use reaper_medium::SectionId;
+    ///
+    /// let action_count =
+    ///     session.reaper().section_from_unique_id(SectionId::new(1), |s| s.action_list_cnt());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    //
+    // In order to not need unsafe, we take the closure. For normal medium-level API usage, this is
+    // the safe way to go.
+    pub fn section_from_unique_id<R>(
+        &self,
+        section_id: SectionId,
+        use_section: impl FnOnce(&KbdSectionInfo) -> R,
+    ) -> Option<R>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.low.SectionFromUniqueID(section_id.to_raw());
+        if ptr.is_null() {
+            return None;
+        }
+        NonNull::new(ptr).map(|nnp| use_section(&KbdSectionInfo(nnp)))
+    }
+
+    /// Like [`section_from_unique_id()`] but returns the section.
+    ///
+    /// # Safety
+    ///
+    /// The lifetime of the returned section is unbounded.
+    ///
+    /// [`section_from_unique_id()`]: #method.section_from_unique_id
+    // The closure-taking function might be too restrictive in some cases, e.g. it wouldn't let us
+    // return an iterator (which is of course lazily evaluated). Also, in some cases we might know
+    // that a section is always valid, e.g. if it's the main section. A higher-level API could
+    // use this for such edge cases. If not the main section, a higher-level API
+    // should check if the section still exists (via section index) before each usage.
+    pub unsafe fn section_from_unique_id_unchecked(
+        &self,
+        section_id: SectionId,
+    ) -> Option<KbdSectionInfo>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.low.SectionFromUniqueID(section_id.to_raw());
+        NonNull::new(ptr).map(KbdSectionInfo)
+    }
+
+    /// Performs an action belonging to the main section.
+    ///
+    /// Unlike [`main_on_command_ex()`], this function also allows to control actions learned with
+    /// MIDI/OSC.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project or window.
+    ///
+    /// [`main_on_command_ex()`]: #method.main_on_command_ex
+    // Kept return value type i32 because I have no idea what the return value is about.
+    pub unsafe fn kbd_on_main_action_ex(
+        &self,
+        command_id: CommandId,
+        value_change: ActionValueChange,
+        window: WindowContext,
+        project: ProjectContext,
+    ) -> i32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (val, valhw, relmode) = value_change.to_raw();
+        self.low.KBD_OnMainActionEx(
+            command_id.to_raw(),
+            val,
+            valhw,
+            relmode,
+            window.to_raw(),
+            project.to_raw(),
+        )
+    }
+
+    /// Opens an action picker window for prompting the user to select an action.
+    pub fn prompt_for_action_create(&self, initial: InitialAction, section_id: SectionId)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .PromptForAction(1, initial.to_raw(), section_id.to_raw());
+    }
+
+    /// Polls an action picker session which has been previously created via
+    /// [`prompt_for_action_create()`].
+    ///
+    /// [`prompt_for_action_create()`]: #method.prompt_for_action_create
+    pub fn prompt_for_action_poll(&self, section_id: SectionId) -> PromptForActionResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let result = self.low.PromptForAction(0, 0, section_id.to_raw());
+        PromptForActionResult::from_raw(result)
+    }
+
+    /// Finishes an action picker session which has been previously created via
+    /// [`prompt_for_action_create()`].
+    ///
+    /// [`prompt_for_action_create()`]: #method.prompt_for_action_create
+    pub fn prompt_for_action_finish(&self, section_id: SectionId)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.PromptForAction(-1, 0, section_id.to_raw());
+    }
+
+    /// Returns the REAPER main window handle.
+    pub fn get_main_hwnd(&self) -> Hwnd
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        require_non_null_panic(self.low.GetMainHwnd())
+    }
+
+    /// Returns the focused MIDI editor window.
+    pub fn midi_editor_get_active(&self) -> Option<Hwnd>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        NonNull::new(self.low.MIDIEditor_GetActive())
+    }
+
+    /// Looks up the command ID for a named command.
+    ///
+    /// Named commands can be registered by extensions (e.g. `_SWS_ABOUT`), ReaScripts
+    /// (e.g. `_113088d11ae641c193a2b7ede3041ad5`) or custom actions.
+    pub fn named_command_lookup<'a>(
+        &self,
+        command_name: impl Into<ReaperStringArg<'a>>,
+    ) -> Option<CommandId>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let raw_id = unsafe { self.low.NamedCommandLookup(command_name.into().as_ptr()) as u32 };
+        if raw_id == 0 {
+            return None;
+        }
+        Some(CommandId(raw_id))
+    }
+
+    /// Returns a project configuration variable descriptor to be used with
+    /// [`project_config_var_addr`]
+    ///
+    /// [`project_config_var_addr`]: #method.project_config_var_addr
+    pub fn project_config_var_get_offs<'a>(
+        &self,
+        name: impl Into<ReaperStringArg<'a>>,
+    ) -> Option<ProjectConfigVarGetOffsResult>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut size = MaybeUninit::zeroed();
+        let offset = unsafe {
+            self.low
+                .projectconfig_var_getoffs(name.into().as_ptr(), size.as_mut_ptr())
+        };
+        if offset < 0 {
+            return None;
+        }
+        let result = ProjectConfigVarGetOffsResult {
+            offset: offset as _,
+            size: unsafe { size.assume_init() } as _,
+        };
+        Some(result)
+    }
+
+    /// Returns the project configuration object at the given address.
+    pub fn project_config_var_addr(
+        &self,
+        project: ProjectContext,
+        index: u32,
+    ) -> Option<NonNull<c_void>>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.project_config_var_addr_unchecked(project, index) }
+    }
+
+    /// Like [`project_config_var_addr()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`project_config_var_addr()`]: #method.project_config_var_addr
+    pub unsafe fn project_config_var_addr_unchecked(
+        &self,
+        project: ProjectContext,
+        index: u32,
+    ) -> Option<NonNull<c_void>>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self
+            .low
+            .projectconfig_var_addr(project.to_raw(), index as _);
+        NonNull::new(ptr)
+    }
+
+    /// Returns the REAPER preference with the given name.
+    pub fn get_config_var<'a>(
+        &self,
+        name: impl Into<ReaperStringArg<'a>>,
+    ) -> Option<GetConfigVarResult>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut size = MaybeUninit::zeroed();
+        let ptr = unsafe {
+            self.low
+                .get_config_var(name.into().as_ptr(), size.as_mut_ptr())
+        };
+        let res = GetConfigVarResult {
+            size: unsafe { size.assume_init() as u32 },
+            value: NonNull::new(ptr)?,
+        };
+        Some(res)
+    }
+
+    /// Clears the ReaScript console.
+    pub fn clear_console(&self)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.ClearConsole();
+    }
+
+    /// Returns the number of tracks in the given project.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn count_tracks(&self, project: ProjectContext) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.count_tracks_unchecked(project) }
+    }
+
+    /// Like [`count_tracks()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`count_tracks()`]: #method.count_tracks
+    pub unsafe fn count_tracks_unchecked(&self, project: ProjectContext) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.CountTracks(project.to_raw()) as u32
+    }
+
+    /// Returns the number of items in the given project.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn count_media_items(&self, project: ProjectContext) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.count_media_items_unchecked(project) }
+    }
+
+    /// Like [`count_media_items()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`count_media_items()`]: #method.count_media_items
+    pub unsafe fn count_media_items_unchecked(&self, project: ProjectContext) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.CountMediaItems(project.to_raw()) as u32
+    }
+
+    /// Returns the length of the given project.
+    ///
+    /// The length is the maximum of end of media item, markers, end of regions and tempo map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_project_length(&self, project: ProjectContext) -> DurationInSeconds
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.get_project_length_unchecked(project) }
+    }
+
+    /// Like [`get_project_length()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_project_length()`]: #method.get_project_length
+    pub unsafe fn get_project_length_unchecked(&self, project: ProjectContext) -> DurationInSeconds
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let res = self.low.GetProjectLength(project.to_raw());
+        DurationInSeconds::new(res)
+    }
+
+    /// Sets the position of the edit cursor and optionally moves the view and/or seeks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn set_edit_curs_pos_2(
+        &self,
+        project: ProjectContext,
+        time: PositionInSeconds,
+        options: SetEditCurPosOptions,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe {
+            self.set_edit_curs_pos_2_unchecked(project, time, options);
+        }
+    }
+
+    /// Like [`set_edit_curs_pos_2()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`set_edit_curs_pos_2()`]: #method.set_edit_curs_pos_2
+    pub unsafe fn set_edit_curs_pos_2_unchecked(
+        &self,
+        project: ProjectContext,
+        time: PositionInSeconds,
+        options: SetEditCurPosOptions,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.SetEditCurPos2(
+            project.to_raw(),
+            time.get(),
+            options.move_view,
+            options.seek_play,
+        );
+    }
+
+    /// Returns the loop point or time selection time range that's currently set in the given
+    /// project.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_set_loop_time_range_2_get(
+        &self,
+        project: ProjectContext,
+        time_range_type: TimeRangeType,
+    ) -> Option<GetLoopTimeRange2Result>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.get_set_loop_time_range_2_get_unchecked(project, time_range_type) }
+    }
+
+    /// Like [`get_set_loop_time_range_2_get()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_set_loop_time_range_2_get()`]: #method.get_set_loop_time_range_2_get
+    pub unsafe fn get_set_loop_time_range_2_get_unchecked(
+        &self,
+        project: ProjectContext,
+        time_range_type: TimeRangeType,
+    ) -> Option<GetLoopTimeRange2Result>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut start = MaybeUninit::zeroed();
+        let mut end = MaybeUninit::zeroed();
+        use TimeRangeType::*;
+        self.low.GetSet_LoopTimeRange2(
+            project.to_raw(),
+            false,
+            match time_range_type {
+                LoopPoints => true,
+                TimeSelection => false,
+            },
+            start.as_mut_ptr(),
+            end.as_mut_ptr(),
+            false,
+        );
+        let (start, end) = (start.assume_init(), end.assume_init());
+        if start == 0.0 && end == 0.0 {
+            return None;
+        }
+        let res = GetLoopTimeRange2Result {
+            start: PositionInSeconds::new(start),
+            end: PositionInSeconds::new(end),
+        };
+        Some(res)
+    }
+
+    /// Sets the loop point or time selection time range for the given project.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn get_set_loop_time_range_2_set(
+        &self,
+        project: ProjectContext,
+        time_range_type: TimeRangeType,
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+        auto_seek_behavior: AutoSeekBehavior,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe {
+            self.get_set_loop_time_range_2_set_unchecked(
+                project,
+                time_range_type,
+                start,
+                end,
+                auto_seek_behavior,
+            );
+        }
+    }
+
+    /// Like [`get_set_loop_time_range_2_set()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_set_loop_time_range_2_set()`]: #method.get_set_loop_time_range_2_set
+    pub unsafe fn get_set_loop_time_range_2_set_unchecked(
+        &self,
+        project: ProjectContext,
+        time_range_type: TimeRangeType,
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+        auto_seek_behavior: AutoSeekBehavior,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        use AutoSeekBehavior::*;
+        use TimeRangeType::*;
+        self.low.GetSet_LoopTimeRange2(
+            project.to_raw(),
+            true,
+            match time_range_type {
+                LoopPoints => true,
+                TimeSelection => false,
+            },
+            &mut start.get() as _,
+            &mut end.get() as _,
+            match auto_seek_behavior {
+                DenyAutoSeek => false,
+                AllowAutoSeek => true,
+            },
+        );
+    }
+
+    /// Creates a new track at the given index.
+    pub fn insert_track_at_index(&self, index: u32, defaults_behavior: TrackDefaultsBehavior)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.InsertTrackAtIndex(
+            index as i32,
+            defaults_behavior == TrackDefaultsBehavior::AddDefaultEnvAndFx,
+        );
+    }
+
+    /// Resets all MIDI devices.
+    pub fn midi_reinit(&self)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.midi_reinit();
+    }
+
+    /// Returns the maximum number of MIDI input devices (usually 63).
+    pub fn get_max_midi_inputs(&self) -> u32
+    where
+        UsageScope: AnyThread,
+    {
+        self.low.GetMaxMidiInputs() as u32
+    }
+
+    /// Returns the maximum number of MIDI output devices (usually 64).
+    pub fn get_max_midi_outputs(&self) -> u32
+    where
+        UsageScope: AnyThread,
+    {
+        self.low.GetMaxMidiOutputs() as u32
+    }
+
+    /// Returns information about the given MIDI input device.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the device name you want.
+    /// If you are not interested in the device name at all, pass 0.
+    pub fn get_midi_input_name(
+        &self,
+        device_id: MidiInputDeviceId,
+        buffer_size: u32,
+    ) -> GetMidiDevNameResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        if buffer_size == 0 {
+            let is_present =
+                unsafe { self.low.GetMIDIInputName(device_id.to_raw(), null_mut(), 0) };
+            GetMidiDevNameResult {
+                is_present,
+                name: None,
+            }
+        } else {
+            let (name, is_present) = with_string_buffer(buffer_size, |buffer, max_size| unsafe {
+                self.low
+                    .GetMIDIInputName(device_id.to_raw(), buffer, max_size)
+            });
+            if name.is_empty() {
+                return GetMidiDevNameResult {
+                    is_present,
+                    name: None,
+                };
+            }
+            GetMidiDevNameResult {
+                is_present,
+                name: Some(name),
+            }
+        }
+    }
+
+    /// Returns information about the given MIDI output device.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the device name you want.
+    /// If you are not interested in the device name at all, pass 0.
+    pub fn get_midi_output_name(
+        &self,
+        device_id: MidiOutputDeviceId,
+        buffer_size: u32,
+    ) -> GetMidiDevNameResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        if buffer_size == 0 {
+            let is_present = unsafe {
+                self.low
+                    .GetMIDIOutputName(device_id.to_raw(), null_mut(), 0)
+            };
+            GetMidiDevNameResult {
+                is_present,
+                name: None,
+            }
+        } else {
+            let (name, is_present) = with_string_buffer(buffer_size, |buffer, max_size| unsafe {
+                self.low
+                    .GetMIDIOutputName(device_id.to_raw(), buffer, max_size)
+            });
+            if name.is_empty() {
+                return GetMidiDevNameResult {
+                    is_present,
+                    name: None,
+                };
+            }
+            GetMidiDevNameResult {
+                is_present,
+                name: Some(name),
+            }
+        }
+    }
+
+    /// Returns a new pitch shift API instance.
+    ///
+    /// Version must be [raw::REAPER_PITCHSHIFT_API_VER].
+    pub fn reaper_get_pitch_shift_api(&self, version: i32) -> Option<OwnedReaperPitchShift>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let raw = self.low.ReaperGetPitchShiftAPI(version);
+        NonNull::new(raw).map(|ptr| unsafe { OwnedReaperPitchShift::from_raw(ptr) })
+    }
+
+    /// Returns information about the given pitch shift mode.
+    ///
+    /// Start querying modes at 0. Returns `None` when no more modes possible.
+    pub fn enum_pitch_shift_modes(
+        &self,
+        mode: PitchShiftMode,
+    ) -> Option<EnumPitchShiftModesResult<'static>>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut name = MaybeUninit::zeroed();
+        let exists = unsafe {
+            self.low
+                .EnumPitchShiftModes(mode.to_raw(), name.as_mut_ptr())
+        };
+        if !exists {
+            return None;
+        }
+        let name = unsafe { name.assume_init() };
+        let res = if name.is_null() {
+            EnumPitchShiftModesResult::Unsupported
+        } else {
+            EnumPitchShiftModesResult::Supported {
+                name: unsafe { create_passing_c_str(name).unwrap() },
+            }
+        };
+        Some(res)
+    }
+
+    /// Grants temporary access to the name of the given pitch shift sub mode.
+    ///
+    /// Start querying modes at 0. Returns `None` when no more sub modes possible.
+    pub fn enum_pitch_shift_sub_modes<R>(
+        &self,
+        mode: PitchShiftMode,
+        sub_mode: PitchShiftSubMode,
+        use_name: impl FnOnce(Option<&ReaperStr>) -> R,
+    ) -> R
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let name = self
+            .low
+            .EnumPitchShiftSubModes(mode.to_raw(), sub_mode.to_raw());
+        if name.is_null() {
+            return use_name(None);
+        }
+        let name = unsafe { create_passing_c_str(name).unwrap() };
+        use_name(Some(name))
+    }
+
+    /// Grants temporary access to the name and ident of the installed FX at the given index.
+    ///
+    /// Start querying at 0. Passes `None` when there are no more installed FX.
+    pub fn enum_installed_fx<R>(
+        &self,
+        index: u32,
+        use_fx: impl FnOnce(Option<EnumInstalledFxResult>) -> R,
+    ) -> R
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut name = MaybeUninit::zeroed();
+        let mut ident = MaybeUninit::zeroed();
+        let exists = unsafe {
+            self.low
+                .EnumInstalledFX(index as i32, name.as_mut_ptr(), ident.as_mut_ptr())
+        };
+        if !exists {
+            return use_fx(None);
+        }
+        let (name, ident) = unsafe {
+            (
+                create_passing_c_str(name.assume_init()),
+                create_passing_c_str(ident.assume_init()),
+            )
+        };
+        match (name, ident) {
+            (Some(name), Some(ident)) => use_fx(Some(EnumInstalledFxResult { name, ident })),
+            _ => use_fx(None),
+        }
+    }
+
+    /// Creates an audio accessor for reading the audio of the given take.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if REAPER couldn't create the accessor.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn create_take_audio_accessor(
+        &self,
+        take: MediaItemTake,
+    ) -> ReaperFunctionResult<OwnedAudioAccessor>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.low.CreateTakeAudioAccessor(take.as_ptr());
+        self.own_audio_accessor(ptr)
+    }
+
+    /// Creates an audio accessor for reading the audio of the given track (post-FX).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if REAPER couldn't create the accessor.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn create_track_audio_accessor(
+        &self,
+        track: MediaTrack,
+    ) -> ReaperFunctionResult<OwnedAudioAccessor>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.low.CreateTrackAudioAccessor(track.as_ptr());
+        self.own_audio_accessor(ptr)
+    }
+
+    fn own_audio_accessor(
+        &self,
+        ptr: *mut raw::AudioAccessor,
+    ) -> ReaperFunctionResult<OwnedAudioAccessor> {
+        let accessor = NonNull::new(ptr)
+            .ok_or_else(|| ReaperFunctionError::new("couldn't create accessor"))?;
+        let destroy = self
+            .low
+            .pointers()
+            .DestroyAudioAccessor
+            .expect("DestroyAudioAccessor not available");
+        Ok(unsafe { OwnedAudioAccessor::from_raw(accessor, destroy) })
+    }
+
+    /// Returns the start time of the audio that can be returned from the given accessor.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if the take or track of the given accessor doesn't exist anymore.
+    pub unsafe fn get_audio_accessor_start_time(
+        &self,
+        accessor: &OwnedAudioAccessor,
+    ) -> PositionInSeconds
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let time = self
+            .low
+            .GetAudioAccessorStartTime(accessor.as_ptr().as_ptr());
+        PositionInSeconds::new(time)
+    }
+
+    /// Returns the end time of the audio that can be returned from the given accessor.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if the take or track of the given accessor doesn't exist anymore.
+    pub unsafe fn get_audio_accessor_end_time(
+        &self,
+        accessor: &OwnedAudioAccessor,
+    ) -> PositionInSeconds
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let time = self.low.GetAudioAccessorEndTime(accessor.as_ptr().as_ptr());
+        PositionInSeconds::new(time)
+    }
+
+    /// Returns whether the underlying samples (track or media item take) have changed since the
+    /// accessor has been created or updated.
+    ///
+    /// Doesn't update the accessor. Use [`audio_accessor_update()`] for that.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if the take or track of the given accessor doesn't exist anymore.
+    ///
+    /// [`audio_accessor_update()`]: #method.audio_accessor_update
+    pub unsafe fn audio_accessor_state_changed(&self, accessor: &OwnedAudioAccessor) -> bool
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .AudioAccessorStateChanged(accessor.as_ptr().as_ptr())
+    }
+
+    /// Makes the given accessor reflect the current state of its track or take.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if the take or track of the given accessor doesn't exist anymore.
+    pub unsafe fn audio_accessor_update(&self, accessor: &OwnedAudioAccessor)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.AudioAccessorUpdate(accessor.as_ptr().as_ptr());
+    }
+
+    /// Reads interleaved samples from the given accessor into the given buffer.
+    ///
+    /// The buffer must have room for `samples_per_channel * channel_count` samples. Returns
+    /// `false` if there's no audio at the requested position (the buffer is filled with silence
+    /// in that case).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if REAPER couldn't read the samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sample rate is not a whole number, if the channel count is 0 or if the
+    /// buffer is too small.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if the take or track of the given accessor doesn't exist anymore.
+    pub unsafe fn get_audio_accessor_samples(
+        &self,
+        accessor: &OwnedAudioAccessor,
+        sample_rate: Hz,
+        channel_count: u32,
+        start_time: PositionInSeconds,
+        samples_per_channel: u32,
+        buffer: &mut [f64],
+    ) -> ReaperFunctionResult<bool>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert_eq!(
+            sample_rate.get().fract(),
+            0.0,
+            "sample rate must be a whole number"
+        );
+        assert!(channel_count > 0, "channel count must be greater than 0");
+        assert!(
+            buffer.len() >= (samples_per_channel * channel_count) as usize,
+            "buffer too small"
+        );
+        let result = self.low.GetAudioAccessorSamples(
+            accessor.as_ptr().as_ptr(),
+            sample_rate.get() as _,
+            channel_count as _,
+            start_time.get(),
+            samples_per_channel as _,
+            buffer.as_mut_ptr(),
+        );
+        match result {
+            1 => Ok(true),
+            0 => Ok(false),
+            _ => Err(ReaperFunctionError::new(
+                "couldn't get audio accessor samples",
+            )),
+        }
+    }
+
+    /// Returns a new resample instance.
+    pub fn resampler_create(&self) -> OwnedReaperResample
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let raw = self.low.Resampler_Create();
+        let ptr = NonNull::new(raw).expect("REAPER didn't return a resample instance");
+        unsafe { OwnedReaperResample::from_raw(ptr) }
+    }
+
+    /// Returns the name of the given resample mode.
+    ///
+    /// Start querying modes at 0. Returns `None` when no more sub modes possible.
+    pub fn resample_enum_modes(&self, mode: ResampleMode) -> Option<&'static ReaperStr>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let name = self.low.Resample_EnumModes(mode.to_raw());
+        if name.is_null() {
+            return None;
+        }
+        let name = unsafe { create_passing_c_str(name).unwrap() };
+        Some(name)
+    }
+
+    // Return type Option or Result can't be easily chosen here because if instantiate is 0, it
+    // should be Option, if it's -1 or > 0, it should be Result. So we just keep the i32. That's
+    // also one reason why we just publish the convenience functions.
+    unsafe fn track_fx_add_by_name<'a>(
+        &self,
+        track: MediaTrack,
+        fx_name: impl Into<ReaperStringArg<'a>>,
+        fx_chain_type: TrackFxChainType,
+        behavior: FxAddByNameBehavior,
+    ) -> i32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.TrackFX_AddByName(
+            track.as_ptr(),
+            fx_name.into().as_ptr(),
+            fx_chain_type == TrackFxChainType::InputFxChain,
+            behavior.to_raw(),
+        )
+    }
+
+    /// Returns the index of the first FX instance in a track or monitoring FX chain.
+    ///
+    /// The FX name can have a prefix to further specify its type: `VST3:` | `VST2:` | `VST:` |
+    /// `AU:` | `JS:` | `DX:`
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_add_by_name_query<'a>(
+        &self,
+        track: MediaTrack,
+        fx_name: impl Into<ReaperStringArg<'a>>,
+        fx_chain_type: TrackFxChainType,
+    ) -> Option<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        match self.track_fx_add_by_name(track, fx_name, fx_chain_type, FxAddByNameBehavior::Query) {
+            -1 => None,
+            idx if idx >= 0 => Some(idx as u32),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the parameter index corresponding to the given identifier.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_param_from_ident(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        ident: ParamId,
+    ) -> Option<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        match self.low.TrackFX_GetParamFromIdent(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            ident.into_raw().as_ptr(),
+        ) {
+            -1 => None,
+            idx if idx >= 0 => Some(idx as u32),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Adds an instance of an FX to a track or monitoring FX chain.
+    ///
+    /// See [`track_fx_add_by_name_query()`] for possible FX name prefixes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX couldn't be added (e.g. if no such FX is installed).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    ///
+    /// [`track_fx_add_by_name_query()`]: #method.track_fx_add_by_name_query
+    pub unsafe fn track_fx_add_by_name_add<'a>(
+        &self,
+        track: MediaTrack,
+        fx_name: impl Into<ReaperStringArg<'a>>,
+        fx_chain_type: TrackFxChainType,
+        behavior: AddFxBehavior,
+    ) -> ReaperFunctionResult<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        match self.track_fx_add_by_name(track, fx_name, fx_chain_type, behavior.into()) {
+            -1 => Err(ReaperFunctionError::new("FX couldn't be added")),
+            idx if idx >= 0 => Ok(idx as u32),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns whether the given track FX is enabled.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_enabled(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+    ) -> bool
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .TrackFX_GetEnabled(track.as_ptr(), fx_location.to_raw())
+    }
+
+    /// Returns whether the given track FX is offline.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_offline(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+    ) -> bool
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .TrackFX_GetOffline(track.as_ptr(), fx_location.to_raw())
+    }
+
+    /// Returns the name of the given FX.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the FX name you want.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_fx_name(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low
+                .TrackFX_GetFXName(track.as_ptr(), fx_location.to_raw(), buffer, max_size)
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get FX name (probably FX doesn't exist)",
+            ));
+        }
+        Ok(name)
+    }
+
+    /// Returns the name of the given track send or hardware output send.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the send name you want.
+    ///
+    /// When choosing the send index, keep in mind that the hardware output sends (if any) come
+    /// first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the track send doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_track_send_name(
+        &self,
+        track: MediaTrack,
+        send_index: u32,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low
+                .GetTrackSendName(track.as_ptr(), send_index as i32, buffer, max_size)
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get send name (probably send doesn't exist)",
+            ));
+        }
+        Ok(name)
+    }
+
+    /// Returns the name of the given track receive.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the receive name you want.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the track send doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_track_receive_name(
+        &self,
+        track: MediaTrack,
+        receive_index: u32,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low
+                .GetTrackReceiveName(track.as_ptr(), receive_index as i32, buffer, max_size)
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get receive name (probably receive doesn't exist)",
+            ));
+        }
+        Ok(name)
+    }
+
+    /// Returns the index of the first track FX that is a virtual instrument.
+    ///
+    /// Doesn't look in the input FX chain.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_instrument(&self, track: MediaTrack) -> Option<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let index = self.low.TrackFX_GetInstrument(track.as_ptr());
+        if index == -1 {
+            return None;
+        }
+        Some(index as u32)
+    }
+
+    /// Enables or disables a track FX.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_set_enabled(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        enabled: bool,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .TrackFX_SetEnabled(track.as_ptr(), fx_location.to_raw(), enabled);
+    }
+
+    /// Sets the given track FX offline or online.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_set_offline(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        offline: bool,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .TrackFX_SetOffline(track.as_ptr(), fx_location.to_raw(), offline);
+    }
+
+    /// Returns the number of parameters of given track FX.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_num_params(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+    ) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .TrackFX_GetNumParams(track.as_ptr(), fx_location.to_raw()) as u32
+    }
+
+    /// Returns the audio device input/output latency in samples.
+    pub fn get_input_output_latency(&self) -> GetInputOutputLatencyResult
+    where
+        UsageScope: AnyThread,
+    {
+        let mut input_latency = MaybeUninit::uninit();
+        let mut output_latency = MaybeUninit::uninit();
+        unsafe {
+            self.low
+                .GetInputOutputLatency(input_latency.as_mut_ptr(), output_latency.as_mut_ptr())
+        };
+        GetInputOutputLatencyResult {
+            input_latency: unsafe { input_latency.assume_init() } as u32,
+            output_latency: unsafe { output_latency.assume_init() } as u32,
+        }
+    }
+
+    /// Returns the current project if it's just being loaded or saved.
+    ///
+    /// This is usually only used from within a [`ProjectConfigExtension`].
+    ///
+    /// [`ProjectConfigExtension`]: trait.ProjectConfigExtension.html
+    pub fn get_current_project_in_load_save(&self) -> Option<ReaProject>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.low.GetCurrentProjectInLoadSave();
+        NonNull::new(ptr)
+    }
+
+    /// Returns the name of the given track FX parameter.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the parameter name you want.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX or parameter doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_param_name(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_index: u32,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low.TrackFX_GetParamName(
+                track.as_ptr(),
+                fx_location.to_raw(),
+                param_index as i32,
+                buffer,
+                max_size,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get FX parameter name (probably FX or parameter doesn't exist)",
+            ));
+        }
+        Ok(name)
+    }
+
+    /// Returns the current value of the given track FX parameter formatted as string.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the parameter value string you
+    /// want.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX or parameter doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_formatted_param_value(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_index: u32,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low.TrackFX_GetFormattedParamValue(
+                track.as_ptr(),
+                fx_location.to_raw(),
+                param_index as i32,
+                buffer,
+                max_size,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't format current FX parameter value (probably FX or parameter doesn't exist)",
+            ));
+        }
+        Ok(name)
+    }
+
+    /// Returns the given value formatted as string according to the given track FX parameter.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the parameter value string you
+    /// want.
+    ///
+    /// This only works with FX that supports Cockos VST extensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX or parameter doesn't exist. Also errors if the FX doesn't support
+    /// formatting arbitrary parameter values *and* the given value is not equal to the current
+    /// one. If the given value is equal to the current one, it's just like calling
+    /// [`track_fx_get_formatted_param_value`].
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    ///
+    /// [`track_fx_get_formatted_param_value`]: #method.track_fx_get_formatted_param_value
+    pub unsafe fn track_fx_format_param_value_normalized(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_index: u32,
+        param_value: ReaperNormalizedFxParamValue,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low.TrackFX_FormatParamValueNormalized(
+                track.as_ptr(),
+                fx_location.to_raw(),
+                param_index as i32,
+                param_value.get(),
+                buffer,
+                max_size,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't format FX parameter value (FX maybe doesn't support Cockos extensions or FX or parameter doesn't exist)",
+            ));
+        }
+        Ok(name)
+    }
+
+    /// Sets the value of the given track FX parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX or parameter doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// - REAPER can crash if you pass an invalid track.
+    /// - Calling this from any other thread than the main thread causes undefined behavior!
+    /// - However, there's one exception: Calling it in a real-time thread directly "from the track"
+    ///   which is currently processing is okay, and only for REAPER >= v6.52+dev0323. Previous
+    ///   REAPER versions will send control surface change notifications, in the wrong thread.
+    ///   Newer versions don't send any notifications when this function is called in real-time.
+    pub unsafe fn track_fx_set_param_normalized(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_index: u32,
+        param_value: ReaperNormalizedFxParamValue,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: AnyThread,
+    {
+        let successful = self.low.TrackFX_SetParamNormalized(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            param_index as i32,
+            param_value.get(),
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set FX parameter value (probably FX or parameter doesn't exist)",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Notifies REAPER that we are done changing parameter values
+    ///
+    /// This is important for automation mode _Touch_.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX or parameter doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// - REAPER can crash if you pass an invalid track.
+    /// - Calling this from any other thread than the main thread causes undefined behavior!
+    /// - However, there's one exception: Calling it in a real-time thread directly "from the track"
+    ///   which is currently processing is okay, and only for REAPER >= v6.52+dev0323. Previous
+    ///   REAPER versions will send control surface change notifications, in the wrong thread.
+    ///   Newer versions don't send any notifications when this function is called in real-time.
+    pub unsafe fn track_fx_end_param_edit(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_index: u32,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: AnyThread,
+    {
+        let successful =
+            self.low
+                .TrackFX_EndParamEdit(track.as_ptr(), fx_location.to_raw(), param_index as i32);
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't end FX parameter edit (probably FX or parameter doesn't exist)",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns information about the (last) focused FX window.
+    ///
+    /// Returns `Some` if an FX window has focus or was the last focused one and is still open.
+    /// Returns `None` if no FX window has focus.
+    pub fn get_focused_fx(&self) -> Option<GetFocusedFxResult>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut tracknumber = MaybeUninit::uninit();
+        let mut itemnumber = MaybeUninit::uninit();
+        let mut fxnumber = MaybeUninit::uninit();
+        let result = unsafe {
+            self.low.GetFocusedFX(
+                tracknumber.as_mut_ptr(),
+                itemnumber.as_mut_ptr(),
+                fxnumber.as_mut_ptr(),
+            )
+        };
+        let tracknumber = unsafe { tracknumber.assume_init() as u32 };
+        let fxnumber = unsafe { fxnumber.assume_init() };
+        use GetFocusedFxResult::*;
+        match result {
+            0 => None,
+            1 => Some(TrackFx {
+                track_location: convert_tracknumber_to_track_location(tracknumber),
+                fx_location: TrackFxLocation::from_raw(fxnumber),
+            }),
+            2 => {
+                // TODO-low Add test
+                let fxnumber = fxnumber as u32;
+                Some(TakeFx {
+                    // Master track can't contain items
+                    track_index: tracknumber - 1,
+                    // Although the parameter is called itemnumber, it's zero-based (mentioned in
+                    // official doc and checked)
+                    item_index: unsafe { itemnumber.assume_init() as u32 },
+                    take_index: (fxnumber >> 16) & 0xFFFF,
+                    fx_index: fxnumber & 0xFFFF,
+                })
+            }
+            x => Some(Unknown(Hidden(x))),
+        }
+    }
+
+    /// Returns information about the last touched FX parameter.
+    ///
+    /// Returns `Some` if an FX parameter has been touched already and that FX is still existing.
+    /// Returns `None` otherwise.
+    pub fn get_last_touched_fx(&self) -> Option<GetLastTouchedFxResult>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut tracknumber = MaybeUninit::uninit();
+        let mut fxnumber = MaybeUninit::uninit();
+        let mut paramnumber = MaybeUninit::uninit();
+        let is_valid = unsafe {
+            self.low.GetLastTouchedFX(
+                tracknumber.as_mut_ptr(),
+                fxnumber.as_mut_ptr(),
+                paramnumber.as_mut_ptr(),
+            )
+        };
+        if !is_valid {
+            return None;
+        }
+        let tracknumber = unsafe { tracknumber.assume_init() as u32 };
+        let tracknumber_high_word = (tracknumber >> 16) & 0xFFFF;
+        let fxnumber = unsafe { fxnumber.assume_init() };
+        let paramnumber = unsafe { paramnumber.assume_init() as u32 };
+        use GetLastTouchedFxResult::*;
+        if tracknumber_high_word == 0 {
+            Some(TrackFx {
+                track_location: convert_tracknumber_to_track_location(tracknumber),
+                fx_location: TrackFxLocation::from_raw(fxnumber),
+                // Although the parameter is called paramnumber, it's zero-based (checked)
+                param_index: paramnumber,
+            })
+        } else {
+            // TODO-low Add test
+            let fxnumber = fxnumber as u32;
+            Some(TakeFx {
+                // Master track can't contain items
+                track_index: (tracknumber & 0xFFFF) - 1,
+                item_index: tracknumber_high_word - 1,
+                take_index: (fxnumber >> 16) & 0xFFFF,
+                fx_index: fxnumber & 0xFFFF,
+                // Although the parameter is called paramnumber, it's zero-based (checked)
+                param_index: paramnumber,
+            })
+        }
+    }
+
+    /// Copies, moves or reorders FX.
+    ///
+    /// Reorders if source and destination track are the same.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_copy_to_track(
+        &self,
+        source: (MediaTrack, TrackFxLocation),
+        destination: (MediaTrack, TrackFxLocation),
+        transfer_behavior: TransferBehavior,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.TrackFX_CopyToTrack(
+            source.0.as_ptr(),
+            source.1.to_raw(),
+            destination.0.as_ptr(),
+            destination.1.to_raw(),
+            transfer_behavior == TransferBehavior::Move,
+        );
+    }
+
+    /// Removes the given FX from the track FX chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_delete(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let succesful = self
+            .low
+            .TrackFX_Delete(track.as_ptr(), fx_location.to_raw());
+        if !succesful {
+            return Err(ReaperFunctionError::new(
+                "couldn't delete FX (probably FX doesn't exist)",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns information about the given FX parameter's step sizes.
+    ///
+    /// Returns `None` if the FX parameter doesn't report step sizes or if the FX or parameter
+    /// doesn't exist (there's no way to distinguish with just this function).
+    ///
+    /// # Safety
+    ///
+    /// - REAPER can crash if you pass an invalid track.
+    /// - Calling this from any other thread than the main thread causes undefined behavior!
+    /// - However, there's one exception: Calling it in a real-time thread directly "from the track"
+    ///   which is currently processing should be okay.
+    //
+    // Option makes more sense than Result here because this function is at the same time the
+    // correct function to be used to determine *if* a parameter reports step sizes. So
+    // "parameter doesn't report step sizes" is a valid result.
+    pub unsafe fn track_fx_get_parameter_step_sizes(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_index: u32,
+    ) -> Option<GetParameterStepSizesResult>
+    where
+        UsageScope: AnyThread,
+    {
+        // It's important to zero these variables (could also do that without MaybeUninit) because
+        // if REAPER returns true, that doesn't always mean that it initialized all of the variables
+        // correctly. Learned this the hard way with some super random results coming up.
+        let mut step = MaybeUninit::zeroed();
+        let mut small_step = MaybeUninit::zeroed();
+        let mut large_step = MaybeUninit::zeroed();
+        let mut is_toggle = MaybeUninit::zeroed();
+        let successful = self.low.TrackFX_GetParameterStepSizes(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            param_index as i32,
+            step.as_mut_ptr(),
+            small_step.as_mut_ptr(),
+            large_step.as_mut_ptr(),
+            is_toggle.as_mut_ptr(),
+        );
+        if !successful {
+            return None;
+        }
+        let is_toggle = is_toggle.assume_init();
+        if is_toggle {
+            Some(GetParameterStepSizesResult::Toggle)
+        } else {
+            Some(GetParameterStepSizesResult::Normal {
+                normal_step: step.assume_init(),
+                small_step: make_some_if_greater_than_zero(small_step.assume_init()),
+                large_step: make_some_if_greater_than_zero(large_step.assume_init()),
+            })
+        }
+    }
+
+    /// Returns the current value and min/mid/max values of the given track FX.
+    ///
+    /// # Safety
+    ///
+    /// - REAPER can crash if you pass an invalid track.
+    /// - Calling this from any other thread than the main thread causes undefined behavior!
+    /// - However, there's one exception: Calling it in a real-time thread directly "from the track"
+    ///   which is currently processing should be okay.
+    pub unsafe fn track_fx_get_param_ex(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_index: u32,
+    ) -> GetParamExResult
+    where
+        UsageScope: AnyThread,
+    {
+        let mut min_val = MaybeUninit::uninit();
+        let mut max_val = MaybeUninit::uninit();
+        let mut mid_val = MaybeUninit::uninit();
+        let value = self.low.TrackFX_GetParamEx(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            param_index as i32,
+            min_val.as_mut_ptr(),
+            max_val.as_mut_ptr(),
+            mid_val.as_mut_ptr(),
+        );
+        GetParamExResult {
+            current_value: value,
+            min_value: min_val.assume_init(),
+            mid_value: mid_val.assume_init(),
+            max_value: max_val.assume_init(),
+        }
+    }
+
+    /// Gets a plug-in specific named configuration value.
+    ///
+    /// With `buffer_size` you can tell REAPER and the FX how many bytes of the value you want.
+    ///
+    /// Named parameters are a vendor-specific VST extension from Cockos (see
+    /// <http://reaper.fm/sdk/vst/vst_ext.php>).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given FX doesn't have this named parameter or doesn't support named
+    /// parameters.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_named_config_parm<'a>(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_name: impl Into<ReaperStringArg<'a>>,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<Vec<u8>>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (buffer, successful) = with_buffer(buffer_size, |buffer, max_size| {
+            self.low.TrackFX_GetNamedConfigParm(
+                track.as_ptr(),
+                fx_location.to_raw(),
+                param_name.into().as_ptr(),
+                buffer,
+                max_size,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get named parameter value",
+            ));
+        }
+        Ok(buffer)
+    }
+
+    /// Like [`track_fx_get_named_config_parm`](Self::track_fx_get_named_config_parm)
+    /// but interpreting the result as a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given FX doesn't have this named parameter, doesn't support named
+    /// parameters or if the returned data doesn't resemble a proper string.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_named_config_parm_as_string<'a>(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_name: impl Into<ReaperStringArg<'a>>,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        let mut bytes =
+            self.track_fx_get_named_config_parm(track, fx_location, param_name, buffer_size)?;
+        if let Some(nul_byte_index) = bytes.iter().position(|b| *b == 0) {
+            // Crop end of vector so that it doesn't include the nul terminator anymore.
+            bytes.resize(nul_byte_index, 0);
+            Ok(ReaperString::new(CString::from_vec_unchecked(bytes)))
+        } else {
+            Err(ReaperFunctionError::new("result is not a string"))
+        }
+    }
+
+    /// Sets a plug-in specific named configuration value.
+    ///
+    /// Named parameters are a vendor-specific VST extension from Cockos (see
+    /// <http://reaper.fm/sdk/vst/vst_ext.php>).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given FX doesn't have this named parameter or doesn't support named
+    /// parameters.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_set_named_config_parm<'a>(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_name: impl Into<ReaperStringArg<'a>>,
+        buffer: &[u8],
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self.low.TrackFX_SetNamedConfigParm(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            param_name.into().as_ptr(),
+            buffer.as_ptr() as _,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set named parameter value",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the number of input and output pins of the given track FX.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_io_size(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+    ) -> ReaperFunctionResult<GetIoSizeResult>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut input_pin_count = MaybeUninit::zeroed();
+        let mut output_pin_count = MaybeUninit::zeroed();
+        let plugin_type = self.low.TrackFX_GetIOSize(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            input_pin_count.as_mut_ptr(),
+            output_pin_count.as_mut_ptr(),
+        );
+        if plugin_type < 0 {
+            return Err(ReaperFunctionError::new("couldn't get FX I/O size"));
+        }
+        Ok(GetIoSizeResult {
+            input_pin_count: input_pin_count.assume_init().max(0) as u32,
+            output_pin_count: output_pin_count.assume_init().max(0) as u32,
+        })
+    }
+
+    /// Returns the track channels to which the given pin of the given track FX is connected.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_pin_mappings(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        direction: FxPinDirection,
+        pin_index: u32,
+    ) -> FxPinMask
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut high32 = MaybeUninit::zeroed();
+        let low32 = self.low.TrackFX_GetPinMappings(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            direction.to_raw(),
+            pin_index as i32,
+            high32.as_mut_ptr(),
+        );
+        FxPinMask::from_raw(low32, high32.assume_init())
+    }
+
+    /// Connects the given pin of the given track FX to the given track channels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX or pin doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_set_pin_mappings(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        direction: FxPinDirection,
+        pin_index: u32,
+        mask: FxPinMask,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let (low32, high32) = mask.to_raw();
+        let successful = self.low.TrackFX_SetPinMappings(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            direction.to_raw(),
+            pin_index as i32,
+            low32,
+            high32,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't set FX pin mappings"));
+        }
+        Ok(())
+    }
+
+    /// Returns the number of FX instances in the given take's FX chain.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_count(&self, take: MediaItemTake) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.TakeFX_GetCount(take.as_ptr()) as u32
+    }
+
+    /// Returns the visibility state of the given take's FX chain.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_chain_visible(&self, take: MediaItemTake) -> FxChainVisibility
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let raw = self.low.TakeFX_GetChainVisible(take.as_ptr());
+        FxChainVisibility::from_raw(raw)
+    }
+
+    // See track_fx_add_by_name() for why this returns the raw index.
+    unsafe fn take_fx_add_by_name<'a>(
+        &self,
+        take: MediaItemTake,
+        fx_name: impl Into<ReaperStringArg<'a>>,
+        behavior: FxAddByNameBehavior,
+    ) -> i32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .TakeFX_AddByName(take.as_ptr(), fx_name.into().as_ptr(), behavior.to_raw())
+    }
+
+    /// Returns the index of the first FX instance in a take FX chain.
+    ///
+    /// See [`track_fx_add_by_name_query()`] for possible FX name prefixes.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`track_fx_add_by_name_query()`]: #method.track_fx_add_by_name_query
+    pub unsafe fn take_fx_add_by_name_query<'a>(
+        &self,
+        take: MediaItemTake,
+        fx_name: impl Into<ReaperStringArg<'a>>,
+    ) -> Option<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        match self.take_fx_add_by_name(take, fx_name, FxAddByNameBehavior::Query) {
+            -1 => None,
+            idx if idx >= 0 => Some(idx as u32),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Adds an instance of an FX to a take FX chain.
+    ///
+    /// See [`track_fx_add_by_name_query()`] for possible FX name prefixes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX couldn't be added (e.g. if no such FX is installed).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`track_fx_add_by_name_query()`]: #method.track_fx_add_by_name_query
+    pub unsafe fn take_fx_add_by_name_add<'a>(
+        &self,
+        take: MediaItemTake,
+        fx_name: impl Into<ReaperStringArg<'a>>,
+        behavior: AddFxBehavior,
+    ) -> ReaperFunctionResult<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        match self.take_fx_add_by_name(take, fx_name, behavior.into()) {
+            -1 => Err(ReaperFunctionError::new("FX couldn't be added")),
+            idx if idx >= 0 => Ok(idx as u32),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the name of the given take FX.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the FX name you want.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_fx_name(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low
+                .TakeFX_GetFXName(take.as_ptr(), fx_index as i32, buffer, max_size)
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get FX name (probably FX doesn't exist)",
+            ));
         }
+        Ok(name)
     }
 
-    /// Performs an action belonging to the main section.
+    /// Returns the GUID of the given take FX.
     ///
-    /// To perform non-native actions (ReaScripts, custom or extension plugin actions) safely, see
-    /// [`named_command_lookup()`].
+    /// # Errors
     ///
-    /// # Panics
+    /// Returns an error if the FX doesn't exist.
     ///
-    /// Panics if the given project is not valid anymore.
+    /// # Safety
     ///
-    /// [`named_command_lookup()`]: #method.named_command_lookup
-    pub fn main_on_command_ex(&self, command: CommandId, flag: i32, project: ProjectContext)
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_fx_guid(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+    ) -> ReaperFunctionResult<GUID>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.require_valid_project(project);
-        unsafe { self.main_on_command_ex_unchecked(command, flag, project) }
+        let ptr = self.low.TakeFX_GetFXGUID(take.as_ptr(), fx_index as i32);
+        deref(ptr).ok_or_else(|| {
+            ReaperFunctionError::new("couldn't get FX GUID (probably FX doesn't exist)")
+        })
     }
 
-    /// Like [`main_on_command_ex()`] but doesn't check if project is valid.
+    /// Returns whether the given take FX is enabled.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_enabled(&self, take: MediaItemTake, fx_index: u32) -> bool
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.TakeFX_GetEnabled(take.as_ptr(), fx_index as i32)
+    }
+
+    /// Enables or disables a take FX.
     ///
-    /// [`main_on_command_ex()`]: #method.main_on_command_ex
-    pub unsafe fn main_on_command_ex_unchecked(
-        &self,
-        command_id: CommandId,
-        flag: i32,
-        project: ProjectContext,
-    ) where
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_set_enabled(&self, take: MediaItemTake, fx_index: u32, enabled: bool)
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
         self.low
-            .Main_OnCommandEx(command_id.to_raw(), flag, project.to_raw());
+            .TakeFX_SetEnabled(take.as_ptr(), fx_index as i32, enabled);
     }
 
-    /// Sends an action command to the last focused MIDI editor.
+    /// Returns whether the given take FX is offline.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_offline(&self, take: MediaItemTake, fx_index: u32) -> bool
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.TakeFX_GetOffline(take.as_ptr(), fx_index as i32)
+    }
+
+    /// Sets the given take FX offline or online.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_set_offline(&self, take: MediaItemTake, fx_index: u32, offline: bool)
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low
+            .TakeFX_SetOffline(take.as_ptr(), fx_index as i32, offline);
+    }
+
+    /// Returns the number of parameters of the given take FX.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_num_params(&self, take: MediaItemTake, fx_index: u32) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.TakeFX_GetNumParams(take.as_ptr(), fx_index as i32) as u32
+    }
+
+    /// Returns the parameter index corresponding to the given identifier.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_param_from_ident(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+        ident: ParamId,
+    ) -> Option<u32>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        match self.low.TakeFX_GetParamFromIdent(
+            take.as_ptr(),
+            fx_index as i32,
+            ident.into_raw().as_ptr(),
+        ) {
+            -1 => None,
+            idx if idx >= 0 => Some(idx as u32),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the name of the given take FX parameter.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the parameter name you want.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
     ///
     /// # Errors
     ///
-    /// Returns an error if no MIDI editor is open or if the view mode does not match the input.
-    pub fn midi_editor_last_focused_on_command(
+    /// Returns an error if the FX or parameter doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_param_name(
         &self,
-        command_id: CommandId,
-        required_view_mode: RequiredViewMode,
-    ) -> ReaperFunctionResult<()>
+        take: MediaItemTake,
+        fx_index: u32,
+        param_index: u32,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let successful = self.low.MIDIEditor_LastFocused_OnCommand(
-            command_id.to_raw(),
-            required_view_mode == RequiredViewMode::ListView,
-        );
+        assert!(buffer_size > 0);
+        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low.TakeFX_GetParamName(
+                take.as_ptr(),
+                fx_index as i32,
+                param_index as i32,
+                buffer,
+                max_size,
+            )
+        });
         if !successful {
             return Err(ReaperFunctionError::new(
-                "no MIDI editor with this view mode open",
+                "couldn't get FX parameter name (probably FX or parameter doesn't exist)",
             ));
         }
-        Ok(())
+        Ok(name)
     }
 
-    /// Informs control surfaces that the given track's mute state has changed.
+    /// Returns the current value of the given take FX parameter formatted as string.
     ///
-    /// Doesn't actually change the mute state.
+    /// With `buffer_size` you can tell REAPER how many bytes of the parameter value string you
+    /// want.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX or parameter doesn't exist.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track or an invalid control surface.
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_formatted_param_value(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+        param_index: u32,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low.TakeFX_GetFormattedParamValue(
+                take.as_ptr(),
+                fx_index as i32,
+                param_index as i32,
+                buffer,
+                max_size,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't format current FX parameter value (probably FX or parameter doesn't exist)",
+            ));
+        }
+        Ok(name)
+    }
+
+    /// Returns the given value formatted as string according to the given take FX parameter.
     ///
-    /// # Example
+    /// With `buffer_size` you can tell REAPER how many bytes of the parameter value string you
+    /// want.
     ///
-    /// ```no_run
-    /// # let session = reaper_medium::ReaperSession::default();
-    /// use reaper_medium::{NotificationBehavior::NotifyAll, ProjectContext::CurrentProject};
+    /// This only works with FX that supports Cockos VST extensions.
     ///
-    /// let track = session.reaper().get_track(CurrentProject, 0).ok_or("no tracks")?;
-    /// unsafe {
-    ///     session.reaper().csurf_set_surface_mute(track, true, NotifyAll);
-    /// }
-    /// # Ok::<_, Box<dyn std::error::Error>>(())
-    /// ```
-    pub unsafe fn csurf_set_surface_mute(
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// See [`track_fx_format_param_value_normalized()`].
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`track_fx_format_param_value_normalized()`]: #method.track_fx_format_param_value_normalized
+    pub unsafe fn take_fx_format_param_value_normalized(
         &self,
-        track: MediaTrack,
-        mute: bool,
-        notification_behavior: NotificationBehavior,
-    ) where
+        take: MediaItemTake,
+        fx_index: u32,
+        param_index: u32,
+        param_value: ReaperNormalizedFxParamValue,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low.TakeFX_FormatParamValueNormalized(
+                take.as_ptr(),
+                fx_index as i32,
+                param_index as i32,
+                param_value.get(),
+                buffer,
+                max_size,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't format FX parameter value (FX maybe doesn't support Cockos extensions or FX or parameter doesn't exist)",
+            ));
+        }
+        Ok(name)
+    }
+
+    /// Returns the current value of the given take FX parameter in REAPER-normalized form.
+    ///
+    /// See [`track_fx_get_param_normalized()`] for how to interpret negative values.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`track_fx_get_param_normalized()`]: #method.track_fx_get_param_normalized
+    pub unsafe fn take_fx_get_param_normalized(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+        param_index: u32,
+    ) -> ReaperNormalizedFxParamValue
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .CSurf_SetSurfaceMute(track.as_ptr(), mute, notification_behavior.to_raw());
+        let raw_value =
+            self.low
+                .TakeFX_GetParamNormalized(take.as_ptr(), fx_index as i32, param_index as i32);
+        ReaperNormalizedFxParamValue::new(raw_value)
     }
 
-    /// Informs control surfaces that the given track's solo state has changed.
+    /// Sets the value of the given take FX parameter.
     ///
-    /// Doesn't actually change the solo state.
+    /// # Errors
+    ///
+    /// Returns an error if the FX or parameter doesn't exist.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track or an invalid control surface.
-    pub unsafe fn csurf_set_surface_solo(
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_set_param_normalized(
         &self,
-        track: MediaTrack,
-        solo: bool,
-        notification_behavior: NotificationBehavior,
-    ) where
+        take: MediaItemTake,
+        fx_index: u32,
+        param_index: u32,
+        param_value: ReaperNormalizedFxParamValue,
+    ) -> ReaperFunctionResult<()>
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .CSurf_SetSurfaceSolo(track.as_ptr(), solo, notification_behavior.to_raw());
+        let successful = self.low.TakeFX_SetParamNormalized(
+            take.as_ptr(),
+            fx_index as i32,
+            param_index as i32,
+            param_value.get(),
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set FX parameter value (probably FX or parameter doesn't exist)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Generates a random GUID.
-    pub fn gen_guid(&self) -> GUID
+    /// Notifies REAPER that we are done changing take FX parameter values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FX or parameter doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_end_param_edit(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+        param_index: u32,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        // We zero this just for being safe
-        let mut guid = MaybeUninit::zeroed();
-        unsafe {
-            self.low.genGuid(guid.as_mut_ptr());
+        let successful =
+            self.low
+                .TakeFX_EndParamEdit(take.as_ptr(), fx_index as i32, param_index as i32);
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't end FX parameter edit (probably FX or parameter doesn't exist)",
+            ));
         }
-        unsafe { guid.assume_init() }
+        Ok(())
     }
 
-    /// Grants temporary access to the section with the given ID.
+    /// Returns information about the given take FX parameter's step sizes.
     ///
-    /// # Example
+    /// See [`track_fx_get_parameter_step_sizes()`] for details.
     ///
-    /// ```no_run
-    /// # let session = reaper_medium::ReaperSession::default();
-    /// use reaper_medium::SectionId;
+    /// # Safety
     ///
-    /// let action_count =
-    ///     session.reaper().section_from_unique_id(SectionId::new(1), |s| s.action_list_cnt());
-    /// # Ok::<_, Box<dyn std::error::Error>>(())
-    /// ```
-    //
-    // In order to not need unsafe, we take the closure. For normal medium-level API usage, this is
-    // the safe way to go.
-    pub fn section_from_unique_id<R>(
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`track_fx_get_parameter_step_sizes()`]: #method.track_fx_get_parameter_step_sizes
+    pub unsafe fn take_fx_get_parameter_step_sizes(
         &self,
-        section_id: SectionId,
-        use_section: impl FnOnce(&KbdSectionInfo) -> R,
-    ) -> Option<R>
+        take: MediaItemTake,
+        fx_index: u32,
+        param_index: u32,
+    ) -> Option<GetParameterStepSizesResult>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.SectionFromUniqueID(section_id.to_raw());
-        if ptr.is_null() {
+        // Zeroed for the same reason as in track_fx_get_parameter_step_sizes()
+        let mut step = MaybeUninit::zeroed();
+        let mut small_step = MaybeUninit::zeroed();
+        let mut large_step = MaybeUninit::zeroed();
+        let mut is_toggle = MaybeUninit::zeroed();
+        let successful = self.low.TakeFX_GetParameterStepSizes(
+            take.as_ptr(),
+            fx_index as i32,
+            param_index as i32,
+            step.as_mut_ptr(),
+            small_step.as_mut_ptr(),
+            large_step.as_mut_ptr(),
+            is_toggle.as_mut_ptr(),
+        );
+        if !successful {
             return None;
         }
-        NonNull::new(ptr).map(|nnp| use_section(&KbdSectionInfo(nnp)))
+        let is_toggle = is_toggle.assume_init();
+        if is_toggle {
+            Some(GetParameterStepSizesResult::Toggle)
+        } else {
+            Some(GetParameterStepSizesResult::Normal {
+                normal_step: step.assume_init(),
+                small_step: make_some_if_greater_than_zero(small_step.assume_init()),
+                large_step: make_some_if_greater_than_zero(large_step.assume_init()),
+            })
+        }
     }
 
-    /// Like [`section_from_unique_id()`] but returns the section.
+    /// Returns the current value and min/mid/max values of the given take FX parameter.
     ///
     /// # Safety
     ///
-    /// The lifetime of the returned section is unbounded.
-    ///
-    /// [`section_from_unique_id()`]: #method.section_from_unique_id
-    // The closure-taking function might be too restrictive in some cases, e.g. it wouldn't let us
-    // return an iterator (which is of course lazily evaluated). Also, in some cases we might know
-    // that a section is always valid, e.g. if it's the main section. A higher-level API could
-    // use this for such edge cases. If not the main section, a higher-level API
-    // should check if the section still exists (via section index) before each usage.
-    pub unsafe fn section_from_unique_id_unchecked(
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_param_ex(
         &self,
-        section_id: SectionId,
-    ) -> Option<KbdSectionInfo>
+        take: MediaItemTake,
+        fx_index: u32,
+        param_index: u32,
+    ) -> GetParamExResult
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.SectionFromUniqueID(section_id.to_raw());
-        NonNull::new(ptr).map(KbdSectionInfo)
+        let mut min_val = MaybeUninit::uninit();
+        let mut max_val = MaybeUninit::uninit();
+        let mut mid_val = MaybeUninit::uninit();
+        let value = self.low.TakeFX_GetParamEx(
+            take.as_ptr(),
+            fx_index as i32,
+            param_index as i32,
+            min_val.as_mut_ptr(),
+            max_val.as_mut_ptr(),
+            mid_val.as_mut_ptr(),
+        );
+        GetParamExResult {
+            current_value: value,
+            min_value: min_val.assume_init(),
+            mid_value: mid_val.assume_init(),
+            max_value: max_val.assume_init(),
+        }
     }
 
-    /// Performs an action belonging to the main section.
+    /// Gets a plug-in specific named configuration value of the given take FX.
     ///
-    /// Unlike [`main_on_command_ex()`], this function also allows to control actions learned with
-    /// MIDI/OSC.
+    /// See [`track_fx_get_named_config_parm()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given FX doesn't have this named parameter or doesn't support named
+    /// parameters.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project or window.
+    /// REAPER can crash if you pass an invalid take.
     ///
-    /// [`main_on_command_ex()`]: #method.main_on_command_ex
-    // Kept return value type i32 because I have no idea what the return value is about.
-    pub unsafe fn kbd_on_main_action_ex(
+    /// [`track_fx_get_named_config_parm()`]: #method.track_fx_get_named_config_parm
+    pub unsafe fn take_fx_get_named_config_parm<'a>(
         &self,
-        command_id: CommandId,
-        value_change: ActionValueChange,
-        window: WindowContext,
-        project: ProjectContext,
-    ) -> i32
-    where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        let (val, valhw, relmode) = value_change.to_raw();
-        self.low.KBD_OnMainActionEx(
-            command_id.to_raw(),
-            val,
-            valhw,
-            relmode,
-            window.to_raw(),
-            project.to_raw(),
-        )
-    }
-
-    /// Opens an action picker window for prompting the user to select an action.
-    pub fn prompt_for_action_create(&self, initial: InitialAction, section_id: SectionId)
+        take: MediaItemTake,
+        fx_index: u32,
+        param_name: impl Into<ReaperStringArg<'a>>,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<Vec<u8>>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .PromptForAction(1, initial.to_raw(), section_id.to_raw());
+        let (buffer, successful) = with_buffer(buffer_size, |buffer, max_size| {
+            self.low.TakeFX_GetNamedConfigParm(
+                take.as_ptr(),
+                fx_index as i32,
+                param_name.into().as_ptr(),
+                buffer,
+                max_size,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get named parameter value",
+            ));
+        }
+        Ok(buffer)
     }
 
-    /// Polls an action picker session which has been previously created via
-    /// [`prompt_for_action_create()`].
+    /// Like [`take_fx_get_named_config_parm`](Self::take_fx_get_named_config_parm)
+    /// but interpreting the result as a string.
     ///
-    /// [`prompt_for_action_create()`]: #method.prompt_for_action_create
-    pub fn prompt_for_action_poll(&self, section_id: SectionId) -> PromptForActionResult
+    /// # Errors
+    ///
+    /// Returns an error if the given FX doesn't have this named parameter, doesn't support named
+    /// parameters or if the returned data doesn't resemble a proper string.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_named_config_parm_as_string<'a>(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+        param_name: impl Into<ReaperStringArg<'a>>,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
-        let result = self.low.PromptForAction(0, 0, section_id.to_raw());
-        PromptForActionResult::from_raw(result)
+        let mut bytes =
+            self.take_fx_get_named_config_parm(take, fx_index, param_name, buffer_size)?;
+        if let Some(nul_byte_index) = bytes.iter().position(|b| *b == 0) {
+            // Crop end of vector so that it doesn't include the nul terminator anymore.
+            bytes.resize(nul_byte_index, 0);
+            Ok(ReaperString::new(CString::from_vec_unchecked(bytes)))
+        } else {
+            Err(ReaperFunctionError::new("result is not a string"))
+        }
     }
 
-    /// Finishes an action picker session which has been previously created via
-    /// [`prompt_for_action_create()`].
+    /// Sets a plug-in specific named configuration value of the given take FX.
     ///
-    /// [`prompt_for_action_create()`]: #method.prompt_for_action_create
-    pub fn prompt_for_action_finish(&self, section_id: SectionId)
+    /// # Errors
+    ///
+    /// Returns an error if the given FX doesn't have this named parameter or doesn't support named
+    /// parameters.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_set_named_config_parm<'a>(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+        param_name: impl Into<ReaperStringArg<'a>>,
+        buffer: &[u8],
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.PromptForAction(-1, 0, section_id.to_raw());
+        let successful = self.low.TakeFX_SetNamedConfigParm(
+            take.as_ptr(),
+            fx_index as i32,
+            param_name.into().as_ptr(),
+            buffer.as_ptr() as _,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set named parameter value",
+            ));
+        }
+        Ok(())
     }
 
-    /// Returns the REAPER main window handle.
-    pub fn get_main_hwnd(&self) -> Hwnd
-    where
+    /// Copies, moves or reorders take FX.
+    ///
+    /// Reorders if source and destination take are the same.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_copy_to_take(
+        &self,
+        source: (MediaItemTake, u32),
+        destination: (MediaItemTake, u32),
+        transfer_behavior: TransferBehavior,
+    ) where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        require_non_null_panic(self.low.GetMainHwnd())
+        self.low.TakeFX_CopyToTake(
+            source.0.as_ptr(),
+            source.1 as i32,
+            destination.0.as_ptr(),
+            destination.1 as i32,
+            transfer_behavior == TransferBehavior::Move,
+        );
     }
 
-    /// Returns the focused MIDI editor window.
-    pub fn midi_editor_get_active(&self) -> Option<Hwnd>
-    where
+    /// Copies or moves a take FX to a track FX chain.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take or track.
+    pub unsafe fn take_fx_copy_to_track(
+        &self,
+        source: (MediaItemTake, u32),
+        destination: (MediaTrack, TrackFxLocation),
+        transfer_behavior: TransferBehavior,
+    ) where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        NonNull::new(self.low.MIDIEditor_GetActive())
+        self.low.TakeFX_CopyToTrack(
+            source.0.as_ptr(),
+            source.1 as i32,
+            destination.0.as_ptr(),
+            destination.1.to_raw(),
+            transfer_behavior == TransferBehavior::Move,
+        );
     }
 
-    /// Looks up the command ID for a named command.
+    /// Removes the given FX from the take FX chain.
     ///
-    /// Named commands can be registered by extensions (e.g. `_SWS_ABOUT`), ReaScripts
-    /// (e.g. `_113088d11ae641c193a2b7ede3041ad5`) or custom actions.
-    pub fn named_command_lookup<'a>(
+    /// # Errors
+    ///
+    /// Returns an error if the FX doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_delete(
         &self,
-        command_name: impl Into<ReaperStringArg<'a>>,
-    ) -> Option<CommandId>
+        take: MediaItemTake,
+        fx_index: u32,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let raw_id = unsafe { self.low.NamedCommandLookup(command_name.into().as_ptr()) as u32 };
-        if raw_id == 0 {
-            return None;
+        let successful = self.low.TakeFX_Delete(take.as_ptr(), fx_index as i32);
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't delete FX (probably FX doesn't exist)",
+            ));
         }
-        Some(CommandId(raw_id))
+        Ok(())
     }
 
-    /// Returns a project configuration variable descriptor to be used with
-    /// [`project_config_var_addr`]
+    /// Returns the envelope of the given take FX parameter.
     ///
-    /// [`project_config_var_addr`]: #method.project_config_var_addr
-    pub fn project_config_var_get_offs<'a>(
+    /// With `create_behavior` you can decide whether the envelope should be created if it doesn't
+    /// exist yet.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_envelope(
         &self,
-        name: impl Into<ReaperStringArg<'a>>,
-    ) -> Option<ProjectConfigVarGetOffsResult>
+        take: MediaItemTake,
+        fx_index: u32,
+        param_index: u32,
+        create_behavior: CreateBehavior,
+    ) -> Option<TrackEnvelope>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let mut size = MaybeUninit::zeroed();
-        let offset = unsafe {
-            self.low
-                .projectconfig_var_getoffs(name.into().as_ptr(), size.as_mut_ptr())
-        };
-        if offset < 0 {
-            return None;
-        }
-        let result = ProjectConfigVarGetOffsResult {
-            offset: offset as _,
-            size: unsafe { size.assume_init() } as _,
-        };
-        Some(result)
+        let ptr = self.low.TakeFX_GetEnvelope(
+            take.as_ptr(),
+            fx_index as i32,
+            param_index as i32,
+            create_behavior == CreateBehavior::Create,
+        );
+        NonNull::new(ptr)
     }
 
-    /// Returns the project configuration object at the given address.
-    pub fn project_config_var_addr(
-        &self,
-        project: ProjectContext,
-        index: u32,
-    ) -> Option<NonNull<c_void>>
+    /// Shows or hides a take FX user interface.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_show(&self, take: MediaItemTake, instruction: TakeFxShowInstruction)
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_valid_project(project);
-        unsafe { self.project_config_var_addr_unchecked(project, index) }
+        self.require_main_thread();
+        self.low.TakeFX_Show(
+            take.as_ptr(),
+            instruction.index_to_raw(),
+            instruction.instruction_to_raw(),
+        );
     }
 
-    /// Like [`project_config_var_addr()`] but doesn't check if project is valid.
+    /// Returns the floating window handle of the given take FX, if there is any.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`project_config_var_addr()`]: #method.project_config_var_addr
-    pub unsafe fn project_config_var_addr_unchecked(
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_floating_window(
         &self,
-        project: ProjectContext,
-        index: u32,
-    ) -> Option<NonNull<c_void>>
+        take: MediaItemTake,
+        fx_index: u32,
+    ) -> Option<Hwnd>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
         let ptr = self
             .low
-            .projectconfig_var_addr(project.to_raw(), index as _);
+            .TakeFX_GetFloatingWindow(take.as_ptr(), fx_index as i32);
         NonNull::new(ptr)
     }
 
-    /// Returns the REAPER preference with the given name.
-    pub fn get_config_var<'a>(
-        &self,
-        name: impl Into<ReaperStringArg<'a>>,
-    ) -> Option<GetConfigVarResult>
+    /// Returns whether the user interface of the given take FX is open.
+    ///
+    /// *Open* means either visible in the FX chain window or visible in a floating window.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_open(&self, take: MediaItemTake, fx_index: u32) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let mut size = MaybeUninit::zeroed();
-        let ptr = unsafe {
-            self.low
-                .get_config_var(name.into().as_ptr(), size.as_mut_ptr())
-        };
-        let res = GetConfigVarResult {
-            size: unsafe { size.assume_init() as u32 },
-            value: NonNull::new(ptr)?,
-        };
-        Some(res)
+        self.low.TakeFX_GetOpen(take.as_ptr(), fx_index as i32)
     }
 
-    /// Clears the ReaScript console.
-    pub fn clear_console(&self)
+    /// Returns the index of the currently selected take FX preset as well as the total preset
+    /// count.
+    ///
+    /// See [`track_fx_get_preset_index()`] for details.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`track_fx_get_preset_index()`]: #method.track_fx_get_preset_index
+    #[allow(clippy::if_same_then_else)]
+    pub unsafe fn take_fx_get_preset_index(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+    ) -> TrackFxGetPresetIndexResult
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.ClearConsole();
+        // We zero this just for being safe
+        let mut num_presets = MaybeUninit::zeroed();
+        let index = self.low.TakeFX_GetPresetIndex(
+            take.as_ptr(),
+            fx_index as i32,
+            num_presets.as_mut_ptr(),
+        );
+        let num_presets = num_presets.assume_init();
+        TrackFxGetPresetIndexResult {
+            index: if index == -1 {
+                None
+            } else if index == num_presets {
+                None
+            } else {
+                Some(index as u32)
+            },
+            count: num_presets as u32,
+        }
     }
 
-    /// Returns the number of tracks in the given project.
+    /// Selects a preset of the given take FX.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn count_tracks(&self, project: ProjectContext) -> u32
-    where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_valid_project(project);
-        unsafe { self.count_tracks_unchecked(project) }
-    }
-
-    /// Like [`count_tracks()`] but doesn't check if project is valid.
+    /// Returns an error e.g. if the FX doesn't exist.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`count_tracks()`]: #method.count_tracks
-    pub unsafe fn count_tracks_unchecked(&self, project: ProjectContext) -> u32
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_set_preset_by_index(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+        preset: FxPresetRef,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.CountTracks(project.to_raw()) as u32
+        let successful =
+            self.low
+                .TakeFX_SetPresetByIndex(take.as_ptr(), fx_index as i32, preset.to_raw());
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't select FX preset (maybe FX doesn't exist)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Returns the number of items in the given project.
+    /// Navigates within the presets of the given take FX.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn count_media_items(&self, project: ProjectContext) -> u32
+    /// Returns an error e.g. if the FX doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_navigate_presets(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+        increment: i32,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_valid_project(project);
-        unsafe { self.count_media_items_unchecked(project) }
+        self.require_main_thread();
+        let successful = self
+            .low
+            .TakeFX_NavigatePresets(take.as_ptr(), fx_index as i32, increment);
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't navigate FX presets (maybe FX doesn't exist)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Like [`count_media_items()`] but doesn't check if project is valid.
+    /// Returns information about the currently selected preset of the given take FX.
     ///
-    /// # Safety
+    /// With `buffer size` you can tell REAPER how many bytes of the preset name you want. If
+    /// you are not interested in the preset name at all, pass 0.
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// # Safety
     ///
-    /// [`count_media_items()`]: #method.count_media_items
-    pub unsafe fn count_media_items_unchecked(&self, project: ProjectContext) -> u32
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_preset(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+        buffer_size: u32,
+    ) -> TrackFxGetPresetResult
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.CountMediaItems(project.to_raw()) as u32
+        if buffer_size == 0 {
+            let state_matches_preset =
+                self.low
+                    .TakeFX_GetPreset(take.as_ptr(), fx_index as i32, null_mut(), 0);
+            return TrackFxGetPresetResult {
+                state_matches_preset,
+                name: None,
+            };
+        }
+        let (name, state_matches_preset) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low
+                .TakeFX_GetPreset(take.as_ptr(), fx_index as i32, buffer, max_size)
+        });
+        TrackFxGetPresetResult {
+            state_matches_preset,
+            name: if name.is_empty() { None } else { Some(name) },
+        }
     }
 
-    /// Returns the length of the given project.
+    /// Returns the number of input and output pins of the given take FX.
     ///
-    /// The length is the maximum of end of media item, markers, end of regions and tempo map.
+    /// # Errors
     ///
-    /// # Panics
+    /// Returns an error if the FX doesn't exist.
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn get_project_length(&self, project: ProjectContext) -> DurationInSeconds
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_io_size(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+    ) -> ReaperFunctionResult<GetIoSizeResult>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_valid_project(project);
-        unsafe { self.get_project_length_unchecked(project) }
+        self.require_main_thread();
+        let mut input_pin_count = MaybeUninit::zeroed();
+        let mut output_pin_count = MaybeUninit::zeroed();
+        let plugin_type = self.low.TakeFX_GetIOSize(
+            take.as_ptr(),
+            fx_index as i32,
+            input_pin_count.as_mut_ptr(),
+            output_pin_count.as_mut_ptr(),
+        );
+        if plugin_type < 0 {
+            return Err(ReaperFunctionError::new("couldn't get FX I/O size"));
+        }
+        Ok(GetIoSizeResult {
+            input_pin_count: input_pin_count.assume_init().max(0) as u32,
+            output_pin_count: output_pin_count.assume_init().max(0) as u32,
+        })
     }
 
-    /// Like [`get_project_length()`] but doesn't check if project is valid.
+    /// Returns the take channels to which the given pin of the given take FX is connected.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`get_project_length()`]: #method.get_project_length
-    pub unsafe fn get_project_length_unchecked(&self, project: ProjectContext) -> DurationInSeconds
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_get_pin_mappings(
+        &self,
+        take: MediaItemTake,
+        fx_index: u32,
+        direction: FxPinDirection,
+        pin_index: u32,
+    ) -> FxPinMask
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let res = self.low.GetProjectLength(project.to_raw());
-        DurationInSeconds::new(res)
+        let mut high32 = MaybeUninit::zeroed();
+        let low32 = self.low.TakeFX_GetPinMappings(
+            take.as_ptr(),
+            fx_index as i32,
+            direction.to_raw(),
+            pin_index as i32,
+            high32.as_mut_ptr(),
+        );
+        FxPinMask::from_raw(low32, high32.assume_init())
     }
 
-    /// Sets the position of the edit cursor and optionally moves the view and/or seeks.
+    /// Connects the given pin of the given take FX to the given take channels.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn set_edit_curs_pos_2(
-        &self,
-        project: ProjectContext,
-        time: PositionInSeconds,
-        options: SetEditCurPosOptions,
-    ) where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_valid_project(project);
-        unsafe {
-            self.set_edit_curs_pos_2_unchecked(project, time, options);
-        }
-    }
-
-    /// Like [`set_edit_curs_pos_2()`] but doesn't check if project is valid.
+    /// Returns an error if the FX or pin doesn't exist.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`set_edit_curs_pos_2()`]: #method.set_edit_curs_pos_2
-    pub unsafe fn set_edit_curs_pos_2_unchecked(
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_fx_set_pin_mappings(
         &self,
-        project: ProjectContext,
-        time: PositionInSeconds,
-        options: SetEditCurPosOptions,
-    ) where
+        take: MediaItemTake,
+        fx_index: u32,
+        direction: FxPinDirection,
+        pin_index: u32,
+        mask: FxPinMask,
+    ) -> ReaperFunctionResult<()>
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.SetEditCurPos2(
-            project.to_raw(),
-            time.get(),
-            options.move_view,
-            options.seek_play,
+        let (low32, high32) = mask.to_raw();
+        let successful = self.low.TakeFX_SetPinMappings(
+            take.as_ptr(),
+            fx_index as i32,
+            direction.to_raw(),
+            pin_index as i32,
+            low32,
+            high32,
         );
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't set FX pin mappings"));
+        }
+        Ok(())
     }
 
-    /// Returns the loop point or time selection time range that's currently set in the given
-    /// project.
+    /// Starts a new undo block.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn get_set_loop_time_range_2_get(
-        &self,
-        project: ProjectContext,
-        time_range_type: TimeRangeType,
-    ) -> Option<GetLoopTimeRange2Result>
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # let session = reaper_medium::ReaperSession::default();
+    /// use reaper_medium::{ProjectContext::CurrentProject, UndoScope::Scoped, ProjectPart::*};
+    ///
+    /// session.reaper().undo_begin_block_2(CurrentProject);
+    /// // ... modify something ...
+    /// session.reaper().undo_end_block_2(CurrentProject, "Modify something", Scoped(Items | Fx));
+    /// ```
+    pub fn undo_begin_block_2(&self, project: ProjectContext)
     where
         UsageScope: MainThreadOnly,
     {
+        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.get_set_loop_time_range_2_get_unchecked(project, time_range_type) }
+        unsafe { self.undo_begin_block_2_unchecked(project) };
     }
 
-    /// Like [`get_set_loop_time_range_2_get()`] but doesn't check if project is valid.
+    /// Like [`undo_begin_block_2()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`get_set_loop_time_range_2_get()`]: #method.get_set_loop_time_range_2_get
-    pub unsafe fn get_set_loop_time_range_2_get_unchecked(
-        &self,
-        project: ProjectContext,
-        time_range_type: TimeRangeType,
-    ) -> Option<GetLoopTimeRange2Result>
+    /// [`undo_begin_block_2()`]: #method.undo_begin_block_2
+    pub unsafe fn undo_begin_block_2_unchecked(&self, project: ProjectContext)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let mut start = MaybeUninit::zeroed();
-        let mut end = MaybeUninit::zeroed();
-        use TimeRangeType::*;
-        self.low.GetSet_LoopTimeRange2(
-            project.to_raw(),
-            false,
-            match time_range_type {
-                LoopPoints => true,
-                TimeSelection => false,
-            },
-            start.as_mut_ptr(),
-            end.as_mut_ptr(),
-            false,
-        );
-        let (start, end) = (start.assume_init(), end.assume_init());
-        if start == 0.0 && end == 0.0 {
-            return None;
-        }
-        let res = GetLoopTimeRange2Result {
-            start: PositionInSeconds::new(start),
-            end: PositionInSeconds::new(end),
-        };
-        Some(res)
+        self.low.Undo_BeginBlock2(project.to_raw());
     }
 
-    /// Sets the loop point or time selection time range for the given project.
+    /// Ends the current undo block.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn get_set_loop_time_range_2_set(
+    pub fn undo_end_block_2<'a>(
         &self,
         project: ProjectContext,
-        time_range_type: TimeRangeType,
-        start: PositionInSeconds,
-        end: PositionInSeconds,
-        auto_seek_behavior: AutoSeekBehavior,
+        description: impl Into<ReaperStringArg<'a>>,
+        scope: UndoScope,
     ) where
         UsageScope: MainThreadOnly,
     {
+        self.require_main_thread();
         self.require_valid_project(project);
         unsafe {
-            self.get_set_loop_time_range_2_set_unchecked(
-                project,
-                time_range_type,
-                start,
-                end,
-                auto_seek_behavior,
-            );
+            self.undo_end_block_2_unchecked(project, description, scope);
         }
     }
 
-    /// Like [`get_set_loop_time_range_2_set()`] but doesn't check if project is valid.
+    /// Like [`undo_end_block_2()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`get_set_loop_time_range_2_set()`]: #method.get_set_loop_time_range_2_set
-    pub unsafe fn get_set_loop_time_range_2_set_unchecked(
+    /// [`undo_end_block_2()`]: #method.undo_end_block_2
+    pub unsafe fn undo_end_block_2_unchecked<'a>(
         &self,
         project: ProjectContext,
-        time_range_type: TimeRangeType,
-        start: PositionInSeconds,
-        end: PositionInSeconds,
-        auto_seek_behavior: AutoSeekBehavior,
+        description: impl Into<ReaperStringArg<'a>>,
+        scope: UndoScope,
     ) where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        use AutoSeekBehavior::*;
-        use TimeRangeType::*;
-        self.low.GetSet_LoopTimeRange2(
+        self.low.Undo_EndBlock2(
             project.to_raw(),
-            true,
-            match time_range_type {
-                LoopPoints => true,
-                TimeSelection => false,
-            },
-            &mut start.get() as _,
-            &mut end.get() as _,
-            match auto_seek_behavior {
-                DenyAutoSeek => false,
-                AllowAutoSeek => true,
-            },
-        );
-    }
-
-    /// Creates a new track at the given index.
-    pub fn insert_track_at_index(&self, index: u32, defaults_behavior: TrackDefaultsBehavior)
-    where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        self.low.InsertTrackAtIndex(
-            index as i32,
-            defaults_behavior == TrackDefaultsBehavior::AddDefaultEnvAndFx,
+            description.into().as_ptr(),
+            scope.to_raw(),
         );
     }
 
-    /// Resets all MIDI devices.
-    pub fn midi_reinit(&self)
-    where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        self.low.midi_reinit();
-    }
-
-    /// Returns the maximum number of MIDI input devices (usually 63).
-    pub fn get_max_midi_inputs(&self) -> u32
-    where
-        UsageScope: AnyThread,
-    {
-        self.low.GetMaxMidiInputs() as u32
-    }
-
-    /// Returns the maximum number of MIDI output devices (usually 64).
-    pub fn get_max_midi_outputs(&self) -> u32
-    where
-        UsageScope: AnyThread,
-    {
-        self.low.GetMaxMidiOutputs() as u32
-    }
-
-    /// Returns information about the given MIDI input device.
+    /// Grants temporary access to the the description of the last undoable operation, if any.
     ///
-    /// With `buffer_size` you can tell REAPER how many bytes of the device name you want.
-    /// If you are not interested in the device name at all, pass 0.
-    pub fn get_midi_input_name(
-        &self,
-        device_id: MidiInputDeviceId,
-        buffer_size: u32,
-    ) -> GetMidiDevNameResult
-    where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        if buffer_size == 0 {
-            let is_present =
-                unsafe { self.low.GetMIDIInputName(device_id.to_raw(), null_mut(), 0) };
-            GetMidiDevNameResult {
-                is_present,
-                name: None,
-            }
-        } else {
-            let (name, is_present) = with_string_buffer(buffer_size, |buffer, max_size| unsafe {
-                self.low
-                    .GetMIDIInputName(device_id.to_raw(), buffer, max_size)
-            });
-            if name.is_empty() {
-                return GetMidiDevNameResult {
-                    is_present,
-                    name: None,
-                };
-            }
-            GetMidiDevNameResult {
-                is_present,
-                name: Some(name),
-            }
-        }
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn undo_can_undo_2<R>(
+        &self,
+        project: ProjectContext,
+        use_description: impl FnOnce(&ReaperStr) -> R,
+    ) -> Option<R>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.require_valid_project(project);
+        unsafe { self.undo_can_undo_2_unchecked(project, use_description) }
     }
 
-    /// Returns information about the given MIDI output device.
+    /// Like [`undo_can_undo_2()`] but doesn't check if project is valid.
     ///
-    /// With `buffer_size` you can tell REAPER how many bytes of the device name you want.
-    /// If you are not interested in the device name at all, pass 0.
-    pub fn get_midi_output_name(
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`undo_can_undo_2()`]: #method.undo_can_undo_2
+    pub unsafe fn undo_can_undo_2_unchecked<R>(
         &self,
-        device_id: MidiOutputDeviceId,
-        buffer_size: u32,
-    ) -> GetMidiDevNameResult
+        project: ProjectContext,
+        use_description: impl FnOnce(&ReaperStr) -> R,
+    ) -> Option<R>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        if buffer_size == 0 {
-            let is_present = unsafe {
-                self.low
-                    .GetMIDIOutputName(device_id.to_raw(), null_mut(), 0)
-            };
-            GetMidiDevNameResult {
-                is_present,
-                name: None,
-            }
-        } else {
-            let (name, is_present) = with_string_buffer(buffer_size, |buffer, max_size| unsafe {
-                self.low
-                    .GetMIDIOutputName(device_id.to_raw(), buffer, max_size)
-            });
-            if name.is_empty() {
-                return GetMidiDevNameResult {
-                    is_present,
-                    name: None,
-                };
-            }
-            GetMidiDevNameResult {
-                is_present,
-                name: Some(name),
-            }
-        }
+        let ptr = self.low.Undo_CanUndo2(project.to_raw());
+        create_passing_c_str(ptr).map(use_description)
     }
 
-    /// Returns a new pitch shift API instance.
+    /// Grants temporary access to the description of the next redoable operation, if any.
     ///
-    /// Version must be [raw::REAPER_PITCHSHIFT_API_VER].
-    pub fn reaper_get_pitch_shift_api(&self, version: i32) -> Option<OwnedReaperPitchShift>
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn undo_can_redo_2<R>(
+        &self,
+        project: ProjectContext,
+        use_description: impl FnOnce(&ReaperStr) -> R,
+    ) -> Option<R>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let raw = self.low.ReaperGetPitchShiftAPI(version);
-        NonNull::new(raw).map(|ptr| unsafe { OwnedReaperPitchShift::from_raw(ptr) })
+        self.require_valid_project(project);
+        unsafe { self.undo_can_redo_2_unchecked(project, use_description) }
     }
 
-    /// Returns information about the given pitch shift mode.
+    /// Like [`undo_can_redo_2()`] but doesn't check if project is valid.
     ///
-    /// Start querying modes at 0. Returns `None` when no more modes possible.
-    pub fn enum_pitch_shift_modes(
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`undo_can_redo_2()`]: #method.undo_can_redo_2
+    pub unsafe fn undo_can_redo_2_unchecked<R>(
         &self,
-        mode: PitchShiftMode,
-    ) -> Option<EnumPitchShiftModesResult<'static>>
+        project: ProjectContext,
+        use_description: impl FnOnce(&ReaperStr) -> R,
+    ) -> Option<R>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let mut name = MaybeUninit::zeroed();
-        let exists = unsafe {
-            self.low
-                .EnumPitchShiftModes(mode.to_raw(), name.as_mut_ptr())
-        };
-        if !exists {
-            return None;
-        }
-        let name = unsafe { name.assume_init() };
-        let res = if name.is_null() {
-            EnumPitchShiftModesResult::Unsupported
-        } else {
-            EnumPitchShiftModesResult::Supported {
-                name: unsafe { create_passing_c_str(name).unwrap() },
-            }
-        };
-        Some(res)
+        let ptr = self.low.Undo_CanRedo2(project.to_raw());
+        create_passing_c_str(ptr).map(use_description)
     }
 
-    /// Grants temporary access to the name of the given pitch shift sub mode.
+    /// Makes the last undoable operation undone.
     ///
-    /// Start querying modes at 0. Returns `None` when no more sub modes possible.
-    pub fn enum_pitch_shift_sub_modes<R>(
-        &self,
-        mode: PitchShiftMode,
-        sub_mode: PitchShiftSubMode,
-        use_name: impl FnOnce(Option<&ReaperStr>) -> R,
-    ) -> R
+    /// Returns `false` if there was nothing to be undone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn undo_do_undo_2(&self, project: ProjectContext) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let name = self
-            .low
-            .EnumPitchShiftSubModes(mode.to_raw(), sub_mode.to_raw());
-        if name.is_null() {
-            return use_name(None);
-        }
-        let name = unsafe { create_passing_c_str(name).unwrap() };
-        use_name(Some(name))
+        self.require_valid_project(project);
+        unsafe { self.undo_do_undo_2_unchecked(project) }
     }
 
-    /// Returns a new resample instance.
-    pub fn resampler_create(&self) -> OwnedReaperResample
+    /// Like [`undo_do_undo_2()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`undo_do_undo_2()`]: #method.undo_do_undo_2
+    pub unsafe fn undo_do_undo_2_unchecked(&self, project: ProjectContext) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let raw = self.low.Resampler_Create();
-        let ptr = NonNull::new(raw).expect("REAPER didn't return a resample instance");
-        unsafe { OwnedReaperResample::from_raw(ptr) }
+        self.low.Undo_DoUndo2(project.to_raw()) != 0
     }
 
-    /// Returns the name of the given resample mode.
+    /// Executes the next redoable action.
     ///
-    /// Start querying modes at 0. Returns `None` when no more sub modes possible.
-    pub fn resample_enum_modes(&self, mode: ResampleMode) -> Option<&'static ReaperStr>
+    /// Returns `false` if there was nothing to be redone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn undo_do_redo_2(&self, project: ProjectContext) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let name = self.low.Resample_EnumModes(mode.to_raw());
-        if name.is_null() {
-            return None;
-        }
-        let name = unsafe { create_passing_c_str(name).unwrap() };
-        Some(name)
+        self.require_valid_project(project);
+        unsafe { self.undo_do_redo_2_unchecked(project) }
     }
 
-    // Return type Option or Result can't be easily chosen here because if instantiate is 0, it
-    // should be Option, if it's -1 or > 0, it should be Result. So we just keep the i32. That's
-    // also one reason why we just publish the convenience functions.
-    unsafe fn track_fx_add_by_name<'a>(
-        &self,
-        track: MediaTrack,
-        fx_name: impl Into<ReaperStringArg<'a>>,
-        fx_chain_type: TrackFxChainType,
-        behavior: FxAddByNameBehavior,
-    ) -> i32
+    /// Like [`undo_do_redo_2()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`undo_do_redo_2()`]: #method.undo_do_redo_2
+    pub unsafe fn undo_do_redo_2_unchecked(&self, project: ProjectContext) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.TrackFX_AddByName(
-            track.as_ptr(),
-            fx_name.into().as_ptr(),
-            fx_chain_type == TrackFxChainType::InputFxChain,
-            behavior.to_raw(),
-        )
+        self.low.Undo_DoRedo2(project.to_raw()) != 0
     }
 
-    /// Returns the index of the first FX instance in a track or monitoring FX chain.
+    /// Marks the given project as dirty.
     ///
-    /// The FX name can have a prefix to further specify its type: `VST3:` | `VST2:` | `VST:` |
-    /// `AU:` | `JS:` | `DX:`
+    /// *Dirty* means the project needs to be saved. Only makes a difference if "Maximum undo
+    /// memory" is not 0 in REAPER's preferences (0 disables undo/prompt to save).
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_add_by_name_query<'a>(
-        &self,
-        track: MediaTrack,
-        fx_name: impl Into<ReaperStringArg<'a>>,
-        fx_chain_type: TrackFxChainType,
-    ) -> Option<u32>
+    /// Panics if the given project is not valid anymore.
+    pub fn mark_project_dirty(&self, project: ProjectContext)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        match self.track_fx_add_by_name(track, fx_name, fx_chain_type, FxAddByNameBehavior::Query) {
-            -1 => None,
-            idx if idx >= 0 => Some(idx as u32),
-            _ => unreachable!(),
+        self.require_valid_project(project);
+        unsafe {
+            self.mark_project_dirty_unchecked(project);
         }
     }
 
-    /// Returns the parameter index corresponding to the given identifier.
+    /// Like [`mark_project_dirty()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_param_from_ident(
-        &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-        ident: ParamId,
-    ) -> Option<u32>
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`mark_project_dirty()`]: #method.mark_project_dirty
+    pub unsafe fn mark_project_dirty_unchecked(&self, project: ProjectContext)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        match self.low.TrackFX_GetParamFromIdent(
-            track.as_ptr(),
-            fx_location.to_raw(),
-            ident.into_raw().as_ptr(),
-        ) {
-            -1 => None,
-            idx if idx >= 0 => Some(idx as u32),
-            _ => unreachable!(),
-        }
+        self.low.MarkProjectDirty(project.to_raw());
     }
 
-    /// Adds an instance of an FX to a track or monitoring FX chain.
+    /// Returns whether the given project is dirty.
     ///
-    /// See [`track_fx_add_by_name_query()`] for possible FX name prefixes.
+    /// Always returns `false` if "Maximum undo memory" is 0 in REAPER's preferences.
     ///
-    /// # Errors
+    /// Also see [`mark_project_dirty()`]
     ///
-    /// Returns an error if the FX couldn't be added (e.g. if no such FX is installed).
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    ///
+    /// [`mark_project_dirty()`]: #method.mark_project_dirty
+    pub fn is_project_dirty(&self, project: ProjectContext) -> bool
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.require_valid_project(project);
+        unsafe { self.is_project_dirty_unchecked(project) }
+    }
+
+    /// Like [`is_project_dirty()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
+    /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`track_fx_add_by_name_query()`]: #method.track_fx_add_by_name_query
-    pub unsafe fn track_fx_add_by_name_add<'a>(
-        &self,
-        track: MediaTrack,
-        fx_name: impl Into<ReaperStringArg<'a>>,
-        fx_chain_type: TrackFxChainType,
-        behavior: AddFxBehavior,
-    ) -> ReaperFunctionResult<u32>
+    /// [`is_project_dirty()`]: #method.is_project_dirty
+    pub unsafe fn is_project_dirty_unchecked(&self, project: ProjectContext) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        match self.track_fx_add_by_name(track, fx_name, fx_chain_type, behavior.into()) {
-            -1 => Err(ReaperFunctionError::new("FX couldn't be added")),
-            idx if idx >= 0 => Ok(idx as u32),
-            _ => unreachable!(),
-        }
+        self.low.IsProjectDirty(project.to_raw()) != 0
     }
 
-    /// Returns whether the given track FX is enabled.
-    ///
-    /// # Safety
+    /// Notifies all control surfaces that something in the track list has changed.
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_enabled(
-        &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-    ) -> bool
+    /// Behavior not confirmed.
+    pub fn track_list_update_all_external_surfaces(&self)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .TrackFX_GetEnabled(track.as_ptr(), fx_location.to_raw())
+        self.low.TrackList_UpdateAllExternalSurfaces();
     }
 
-    /// Returns whether the given track FX is offline.
+    /// Returns the version of the REAPER application in which this plug-in is currently running.
+    pub fn get_app_version(&self) -> ReaperVersion<'static>
+    where
+        UsageScope: AnyThread,
+    {
+        let ptr = self.low.GetAppVersion();
+        let version_str = unsafe { ReaperStr::from_ptr(ptr) };
+        ReaperVersion::new(version_str)
+    }
+
+    /// Returns the track automation mode, regardless of the global override.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_offline(
-        &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-    ) -> bool
+    pub unsafe fn get_track_automation_mode(&self, track: MediaTrack) -> AutomationMode
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .TrackFX_GetOffline(track.as_ptr(), fx_location.to_raw())
+        let result = self.low.GetTrackAutomationMode(track.as_ptr());
+        AutomationMode::from_raw(result)
     }
 
-    /// Returns the name of the given FX.
-    ///
-    /// With `buffer_size` you can tell REAPER how many bytes of the FX name you want.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the given buffer size is 0.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the FX doesn't exist.
+    /// Returns the custom color of the given track.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_fx_name(
-        &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-        buffer_size: u32,
-    ) -> ReaperFunctionResult<ReaperString>
+    pub unsafe fn get_track_color(&self, track: MediaTrack) -> Option<NativeColorResult>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        assert!(buffer_size > 0);
-        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
-            self.low
-                .TrackFX_GetFXName(track.as_ptr(), fx_location.to_raw(), buffer, max_size)
-        });
-        if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't get FX name (probably FX doesn't exist)",
-            ));
-        }
-        Ok(name)
+        let value = self.low.GetTrackColor(track.as_ptr());
+        NativeColorResult::from_track_color_value(value)
     }
 
-    /// Returns the name of the given track send or hardware output send.
-    ///
-    /// With `buffer_size` you can tell REAPER how many bytes of the send name you want.
-    ///
-    /// When choosing the send index, keep in mind that the hardware output sends (if any) come
-    /// first.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the given buffer size is 0.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the track send doesn't exist.
-    ///
-    /// # Safety
-    ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_track_send_name(
-        &self,
-        track: MediaTrack,
-        send_index: u32,
-        buffer_size: u32,
-    ) -> ReaperFunctionResult<ReaperString>
+    /// Extracts an RGB color from the given OS-dependent color.
+    pub fn color_from_native(&self, color: NativeColor) -> RgbColor
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        assert!(buffer_size > 0);
-        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
-            self.low
-                .GetTrackSendName(track.as_ptr(), send_index as i32, buffer, max_size)
-        });
-        if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't get send name (probably send doesn't exist)",
-            ));
+        let (mut r, mut g, mut b) = (
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+        );
+        unsafe {
+            self.low.ColorFromNative(
+                color.to_raw(),
+                r.as_mut_ptr(),
+                g.as_mut_ptr(),
+                b.as_mut_ptr(),
+            );
+        }
+        RgbColor {
+            r: unsafe { r.assume_init() as _ },
+            g: unsafe { g.assume_init() as _ },
+            b: unsafe { b.assume_init() as _ },
         }
-        Ok(name)
     }
 
-    /// Returns the name of the given track receive.
-    ///
-    /// With `buffer_size` you can tell REAPER how many bytes of the receive name you want.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the given buffer size is 0.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the track send doesn't exist.
-    ///
-    /// # Safety
-    ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_track_receive_name(
-        &self,
-        track: MediaTrack,
-        receive_index: u32,
-        buffer_size: u32,
-    ) -> ReaperFunctionResult<ReaperString>
+    /// Converts the given RGB color to an OS-dependent color.
+    pub fn color_to_native(&self, color: RgbColor) -> NativeColor
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        assert!(buffer_size > 0);
-        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
-            self.low
-                .GetTrackReceiveName(track.as_ptr(), receive_index as i32, buffer, max_size)
-        });
-        if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't get receive name (probably receive doesn't exist)",
-            ));
-        }
-        Ok(name)
+        let value = self
+            .low
+            .ColorToNative(color.r as _, color.g as _, color.b as _);
+        NativeColor::new(value as u32)
     }
 
-    /// Returns the index of the first track FX that is a virtual instrument.
-    ///
-    /// Doesn't look in the input FX chain.
-    ///
-    /// # Safety
+    /// Runs the system color chooser dialog.
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_instrument(&self, track: MediaTrack) -> Option<u32>
+    /// Returns `None` if the user cancels the dialog.
+    pub fn gr_select_color(&self, window: WindowContext) -> Option<NativeColor>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let index = self.low.TrackFX_GetInstrument(track.as_ptr());
-        if index == -1 {
+        let mut raw = MaybeUninit::uninit();
+        let picked = unsafe { self.low.GR_SelectColor(window.to_raw(), raw.as_mut_ptr()) };
+        if picked == 0 {
             return None;
         }
-        Some(index as u32)
+        Some(NativeColor::new(unsafe { raw.assume_init() } as _))
     }
 
-    /// Enables or disables a track FX.
+    /// Sets the track automation mode.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_set_enabled(
+    pub unsafe fn set_track_automation_mode(
         &self,
         track: MediaTrack,
-        fx_location: TrackFxLocation,
-        enabled: bool,
+        automation_mode: AutomationMode,
     ) where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
         self.low
-            .TrackFX_SetEnabled(track.as_ptr(), fx_location.to_raw(), enabled);
+            .SetTrackAutomationMode(track.as_ptr(), automation_mode.to_raw());
     }
 
-    /// Sets the given track FX offline or online.
+    /// Returns the global track automation override, if any.
+    pub fn get_global_automation_override(&self) -> Option<GlobalAutomationModeOverride>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        use GlobalAutomationModeOverride::*;
+        match self.low.GetGlobalAutomationOverride() {
+            -1 => None,
+            6 => Some(Bypass),
+            x => Some(Mode(AutomationMode::from_raw(x))),
+        }
+    }
+
+    /// Sets the global track automation override.
+    pub fn set_global_automation_override(
+        &self,
+        mode_override: Option<GlobalAutomationModeOverride>,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        use GlobalAutomationModeOverride::*;
+        let raw = match mode_override {
+            None => -1,
+            Some(Bypass) => 6,
+            Some(Mode(x)) => x.to_raw(),
+        };
+        self.low.SetGlobalAutomationOverride(raw);
+    }
+
+    /// Returns the track envelope for the given track and configuration chunk name.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_set_offline(
+    // TODO-low Test
+    pub unsafe fn get_track_envelope_by_chunk_name(
         &self,
         track: MediaTrack,
-        fx_location: TrackFxLocation,
-        offline: bool,
-    ) where
+        chunk_name: EnvChunkName,
+    ) -> Option<TrackEnvelope>
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .TrackFX_SetOffline(track.as_ptr(), fx_location.to_raw(), offline);
+        let ptr = self
+            .low
+            .GetTrackEnvelopeByChunkName(track.as_ptr(), chunk_name.into_raw().as_ptr());
+        NonNull::new(ptr)
     }
 
-    /// Returns the number of parameters of given track FX.
+    /// Returns the track envelope for the given track and envelope display name.
+    ///
+    /// For getting common envelopes (like volume or pan) using
+    /// [`get_track_envelope_by_chunk_name()`] is better because it provides more type safety.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_num_params(
+    ///
+    /// [`get_track_envelope_by_chunk_name()`]: #method.get_track_envelope_by_chunk_name
+    pub unsafe fn get_track_envelope_by_name<'a>(
         &self,
         track: MediaTrack,
-        fx_location: TrackFxLocation,
-    ) -> u32
+        env_name: impl Into<ReaperStringArg<'a>>,
+    ) -> Option<TrackEnvelope>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .TrackFX_GetNumParams(track.as_ptr(), fx_location.to_raw()) as u32
-    }
-
-    /// Returns the audio device input/output latency in samples.
-    pub fn get_input_output_latency(&self) -> GetInputOutputLatencyResult
-    where
-        UsageScope: AnyThread,
-    {
-        let mut input_latency = MaybeUninit::uninit();
-        let mut output_latency = MaybeUninit::uninit();
-        unsafe {
-            self.low
-                .GetInputOutputLatency(input_latency.as_mut_ptr(), output_latency.as_mut_ptr())
-        };
-        GetInputOutputLatencyResult {
-            input_latency: unsafe { input_latency.assume_init() } as u32,
-            output_latency: unsafe { output_latency.assume_init() } as u32,
-        }
+        let ptr = self
+            .low
+            .GetTrackEnvelopeByName(track.as_ptr(), env_name.into().as_ptr());
+        NonNull::new(ptr)
     }
 
-    /// Returns the current project if it's just being loaded or saved.
+    /// Returns the envelope of the given track FX parameter.
     ///
-    /// This is usually only used from `project_config_extension_t`.
-    // TODO-low `project_config_extension_t` is not yet ported
-    pub fn get_current_project_in_load_save(&self) -> Option<ReaProject>
+    /// With `create_behavior` you can decide whether the envelope should be created if it doesn't
+    /// exist yet.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_fx_envelope(
+        &self,
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_index: u32,
+        create_behavior: CreateBehavior,
+    ) -> Option<TrackEnvelope>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.GetCurrentProjectInLoadSave();
+        let ptr = self.low.GetFXEnvelope(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            param_index as i32,
+            create_behavior == CreateBehavior::Create,
+        );
         NonNull::new(ptr)
     }
 
-    /// Returns the name of the given track FX parameter.
+    /// Returns the display name of the given envelope.
     ///
-    /// With `buffer_size` you can tell REAPER how many bytes of the parameter name you want.
+    /// With `buffer_size` you can tell REAPER how many bytes of the name you want.
     ///
     /// # Panics
     ///
@@ -3185,16 +6797,14 @@ impl<UsageScope> Reaper<UsageScope> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the FX or parameter doesn't exist.
+    /// Returns an error if the name couldn't be determined.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_param_name(
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn get_envelope_name(
         &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-        param_index: u32,
+        envelope: TrackEnvelope,
         buffer_size: u32,
     ) -> ReaperFunctionResult<ReaperString>
     where
@@ -3203,2203 +6813,2635 @@ impl<UsageScope> Reaper<UsageScope> {
         self.require_main_thread();
         assert!(buffer_size > 0);
         let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
-            self.low.TrackFX_GetParamName(
-                track.as_ptr(),
-                fx_location.to_raw(),
-                param_index as i32,
-                buffer,
-                max_size,
-            )
+            self.low
+                .GetEnvelopeName(envelope.as_ptr(), buffer, max_size)
         });
         if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't get FX parameter name (probably FX or parameter doesn't exist)",
-            ));
+            return Err(ReaperFunctionError::new("couldn't get envelope name"));
         }
         Ok(name)
     }
 
-    /// Returns the current value of the given track FX parameter formatted as string.
+    /// Returns the number of points in the given envelope.
     ///
-    /// With `buffer_size` you can tell REAPER how many bytes of the parameter value string you
-    /// want.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the given buffer size is 0.
-    ///
-    /// # Errors
+    /// # Safety
     ///
-    /// Returns an error if the FX or parameter doesn't exist.
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn count_envelope_points(&self, envelope: TrackEnvelope) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.count_envelope_points_ex(envelope, EnvelopePointSet::Underlying)
+    }
+
+    /// Returns the number of points in the given point set of the given envelope.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_formatted_param_value(
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn count_envelope_points_ex(
         &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-        param_index: u32,
-        buffer_size: u32,
-    ) -> ReaperFunctionResult<ReaperString>
+        envelope: TrackEnvelope,
+        point_set: EnvelopePointSet,
+    ) -> u32
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        assert!(buffer_size > 0);
-        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
-            self.low.TrackFX_GetFormattedParamValue(
-                track.as_ptr(),
-                fx_location.to_raw(),
-                param_index as i32,
-                buffer,
-                max_size,
-            )
-        });
-        if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't format current FX parameter value (probably FX or parameter doesn't exist)",
-            ));
-        }
-        Ok(name)
+        self.low
+            .CountEnvelopePointsEx(envelope.as_ptr(), point_set.to_raw()) as u32
     }
 
-    /// Returns the given value formatted as string according to the given track FX parameter.
+    /// Returns the attributes of the given envelope point.
     ///
-    /// With `buffer_size` you can tell REAPER how many bytes of the parameter value string you
-    /// want.
+    /// Point values are raw values. They are not scaled according to the envelope scaling mode.
     ///
-    /// This only works with FX that supports Cockos VST extensions.
+    /// # Errors
     ///
-    /// # Panics
+    /// Returns an error if the point doesn't exist.
     ///
-    /// Panics if the given buffer size is 0.
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn get_envelope_point(
+        &self,
+        envelope: TrackEnvelope,
+        point_index: u32,
+    ) -> ReaperFunctionResult<EnvelopePoint>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.get_envelope_point_ex(envelope, EnvelopePointSet::Underlying, point_index)
+    }
+
+    /// Like [`get_envelope_point()`] but lets you choose the point set (e.g. an automation item).
     ///
     /// # Errors
     ///
-    /// Returns an error if the FX or parameter doesn't exist. Also errors if the FX doesn't support
-    /// formatting arbitrary parameter values *and* the given value is not equal to the current
-    /// one. If the given value is equal to the current one, it's just like calling
-    /// [`track_fx_get_formatted_param_value`].
+    /// Returns an error if the point doesn't exist.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
+    /// REAPER can crash if you pass an invalid envelope.
     ///
-    /// [`track_fx_get_formatted_param_value`]: #method.track_fx_get_formatted_param_value
-    pub unsafe fn track_fx_format_param_value_normalized(
+    /// [`get_envelope_point()`]: #method.get_envelope_point
+    pub unsafe fn get_envelope_point_ex(
         &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-        param_index: u32,
-        param_value: ReaperNormalizedFxParamValue,
-        buffer_size: u32,
-    ) -> ReaperFunctionResult<ReaperString>
+        envelope: TrackEnvelope,
+        point_set: EnvelopePointSet,
+        point_index: u32,
+    ) -> ReaperFunctionResult<EnvelopePoint>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        assert!(buffer_size > 0);
-        let (name, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
-            self.low.TrackFX_FormatParamValueNormalized(
-                track.as_ptr(),
-                fx_location.to_raw(),
-                param_index as i32,
-                param_value.get(),
-                buffer,
-                max_size,
-            )
-        });
+        let mut time = MaybeUninit::zeroed();
+        let mut value = MaybeUninit::zeroed();
+        let mut shape = MaybeUninit::zeroed();
+        let mut tension = MaybeUninit::zeroed();
+        let mut selected = MaybeUninit::zeroed();
+        let successful = self.low.GetEnvelopePointEx(
+            envelope.as_ptr(),
+            point_set.to_raw(),
+            point_index as i32,
+            time.as_mut_ptr(),
+            value.as_mut_ptr(),
+            shape.as_mut_ptr(),
+            tension.as_mut_ptr(),
+            selected.as_mut_ptr(),
+        );
         if !successful {
             return Err(ReaperFunctionError::new(
-                "couldn't format FX parameter value (FX maybe doesn't support Cockos extensions or FX or parameter doesn't exist)",
+                "couldn't get envelope point (probably point doesn't exist)",
             ));
         }
-        Ok(name)
+        let point = EnvelopePoint {
+            time: PositionInSeconds::new(time.assume_init()),
+            value: value.assume_init(),
+            shape: EnvelopePointShape::from_raw(shape.assume_init()),
+            tension: tension.assume_init(),
+            selected: selected.assume_init(),
+        };
+        Ok(point)
     }
 
-    /// Sets the value of the given track FX parameter.
+    /// Inserts a point into the given envelope.
+    ///
+    /// If you insert multiple points at once, pass [`SortBehavior::DontSort`] and call
+    /// [`envelope_sort_points()`] when done.
     ///
     /// # Errors
     ///
-    /// Returns an error if the FX or parameter doesn't exist.
+    /// Returns an error if not successful.
     ///
     /// # Safety
     ///
-    /// - REAPER can crash if you pass an invalid track.
-    /// - Calling this from any other thread than the main thread causes undefined behavior!
-    /// - However, there's one exception: Calling it in a real-time thread directly "from the track"
-    ///   which is currently processing is okay, and only for REAPER >= v6.52+dev0323. Previous
-    ///   REAPER versions will send control surface change notifications, in the wrong thread.
-    ///   Newer versions don't send any notifications when this function is called in real-time.
-    pub unsafe fn track_fx_set_param_normalized(
+    /// REAPER can crash if you pass an invalid envelope.
+    ///
+    /// [`SortBehavior::DontSort`]: enum.SortBehavior.html#variant.DontSort
+    /// [`envelope_sort_points()`]: #method.envelope_sort_points
+    pub unsafe fn insert_envelope_point(
         &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-        param_index: u32,
-        param_value: ReaperNormalizedFxParamValue,
+        envelope: TrackEnvelope,
+        point: EnvelopePoint,
+        sort_behavior: SortBehavior,
     ) -> ReaperFunctionResult<()>
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let successful = self.low.TrackFX_SetParamNormalized(
-            track.as_ptr(),
-            fx_location.to_raw(),
-            param_index as i32,
-            param_value.get(),
-        );
-        if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't set FX parameter value (probably FX or parameter doesn't exist)",
-            ));
-        }
-        Ok(())
+        self.insert_envelope_point_ex(envelope, EnvelopePointSet::Underlying, point, sort_behavior)
     }
 
-    /// Notifies REAPER that we are done changing parameter values
-    ///
-    /// This is important for automation mode _Touch_.
+    /// Like [`insert_envelope_point()`] but lets you choose the point set (e.g. an automation
+    /// item).
     ///
     /// # Errors
     ///
-    /// Returns an error if the FX or parameter doesn't exist.
+    /// Returns an error if not successful.
     ///
     /// # Safety
     ///
-    /// - REAPER can crash if you pass an invalid track.
-    /// - Calling this from any other thread than the main thread causes undefined behavior!
-    /// - However, there's one exception: Calling it in a real-time thread directly "from the track"
-    ///   which is currently processing is okay, and only for REAPER >= v6.52+dev0323. Previous
-    ///   REAPER versions will send control surface change notifications, in the wrong thread.
-    ///   Newer versions don't send any notifications when this function is called in real-time.
-    pub unsafe fn track_fx_end_param_edit(
+    /// REAPER can crash if you pass an invalid envelope.
+    ///
+    /// [`insert_envelope_point()`]: #method.insert_envelope_point
+    pub unsafe fn insert_envelope_point_ex(
         &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-        param_index: u32,
+        envelope: TrackEnvelope,
+        point_set: EnvelopePointSet,
+        point: EnvelopePoint,
+        sort_behavior: SortBehavior,
     ) -> ReaperFunctionResult<()>
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let successful =
-            self.low
-                .TrackFX_EndParamEdit(track.as_ptr(), fx_location.to_raw(), param_index as i32);
+        self.require_main_thread();
+        let mut no_sort = sort_behavior == SortBehavior::DontSort;
+        let successful = self.low.InsertEnvelopePointEx(
+            envelope.as_ptr(),
+            point_set.to_raw(),
+            point.time.get(),
+            point.value,
+            point.shape.to_raw(),
+            point.tension,
+            point.selected,
+            &mut no_sort,
+        );
         if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't end FX parameter edit (probably FX or parameter doesn't exist)",
-            ));
+            return Err(ReaperFunctionError::new("couldn't insert envelope point"));
         }
         Ok(())
     }
 
-    /// Returns information about the (last) focused FX window.
+    /// Overwrites the attributes of the given envelope point.
     ///
-    /// Returns `Some` if an FX window has focus or was the last focused one and is still open.
-    /// Returns `None` if no FX window has focus.
-    pub fn get_focused_fx(&self) -> Option<GetFocusedFxResult>
+    /// If you change multiple points at once, pass [`SortBehavior::DontSort`] and call
+    /// [`envelope_sort_points()`] when done.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (e.g. if the point doesn't exist).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid envelope.
+    ///
+    /// [`SortBehavior::DontSort`]: enum.SortBehavior.html#variant.DontSort
+    /// [`envelope_sort_points()`]: #method.envelope_sort_points
+    pub unsafe fn set_envelope_point(
+        &self,
+        envelope: TrackEnvelope,
+        point_index: u32,
+        point: EnvelopePoint,
+        sort_behavior: SortBehavior,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
-        let mut tracknumber = MaybeUninit::uninit();
-        let mut itemnumber = MaybeUninit::uninit();
-        let mut fxnumber = MaybeUninit::uninit();
-        let result = unsafe {
-            self.low.GetFocusedFX(
-                tracknumber.as_mut_ptr(),
-                itemnumber.as_mut_ptr(),
-                fxnumber.as_mut_ptr(),
-            )
-        };
-        let tracknumber = unsafe { tracknumber.assume_init() as u32 };
-        let fxnumber = unsafe { fxnumber.assume_init() };
-        use GetFocusedFxResult::*;
-        match result {
-            0 => None,
-            1 => Some(TrackFx {
-                track_location: convert_tracknumber_to_track_location(tracknumber),
-                fx_location: TrackFxLocation::from_raw(fxnumber),
-            }),
-            2 => {
-                // TODO-low Add test
-                let fxnumber = fxnumber as u32;
-                Some(TakeFx {
-                    // Master track can't contain items
-                    track_index: tracknumber - 1,
-                    // Although the parameter is called itemnumber, it's zero-based (mentioned in
-                    // official doc and checked)
-                    item_index: unsafe { itemnumber.assume_init() as u32 },
-                    take_index: (fxnumber >> 16) & 0xFFFF,
-                    fx_index: fxnumber & 0xFFFF,
-                })
-            }
-            x => Some(Unknown(Hidden(x))),
-        }
+        self.set_envelope_point_ex(
+            envelope,
+            EnvelopePointSet::Underlying,
+            point_index,
+            point,
+            sort_behavior,
+        )
     }
 
-    /// Returns information about the last touched FX parameter.
+    /// Like [`set_envelope_point()`] but lets you choose the point set (e.g. an automation item).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (e.g. if the point doesn't exist).
     ///
-    /// Returns `Some` if an FX parameter has been touched already and that FX is still existing.
-    /// Returns `None` otherwise.
-    pub fn get_last_touched_fx(&self) -> Option<GetLastTouchedFxResult>
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid envelope.
+    ///
+    /// [`set_envelope_point()`]: #method.set_envelope_point
+    pub unsafe fn set_envelope_point_ex(
+        &self,
+        envelope: TrackEnvelope,
+        point_set: EnvelopePointSet,
+        point_index: u32,
+        point: EnvelopePoint,
+        sort_behavior: SortBehavior,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let mut tracknumber = MaybeUninit::uninit();
-        let mut fxnumber = MaybeUninit::uninit();
-        let mut paramnumber = MaybeUninit::uninit();
-        let is_valid = unsafe {
-            self.low.GetLastTouchedFX(
-                tracknumber.as_mut_ptr(),
-                fxnumber.as_mut_ptr(),
-                paramnumber.as_mut_ptr(),
-            )
-        };
-        if !is_valid {
-            return None;
-        }
-        let tracknumber = unsafe { tracknumber.assume_init() as u32 };
-        let tracknumber_high_word = (tracknumber >> 16) & 0xFFFF;
-        let fxnumber = unsafe { fxnumber.assume_init() };
-        let paramnumber = unsafe { paramnumber.assume_init() as u32 };
-        use GetLastTouchedFxResult::*;
-        if tracknumber_high_word == 0 {
-            Some(TrackFx {
-                track_location: convert_tracknumber_to_track_location(tracknumber),
-                fx_location: TrackFxLocation::from_raw(fxnumber),
-                // Although the parameter is called paramnumber, it's zero-based (checked)
-                param_index: paramnumber,
-            })
-        } else {
-            // TODO-low Add test
-            let fxnumber = fxnumber as u32;
-            Some(TakeFx {
-                // Master track can't contain items
-                track_index: (tracknumber & 0xFFFF) - 1,
-                item_index: tracknumber_high_word - 1,
-                take_index: (fxnumber >> 16) & 0xFFFF,
-                fx_index: fxnumber & 0xFFFF,
-                // Although the parameter is called paramnumber, it's zero-based (checked)
-                param_index: paramnumber,
-            })
+        let mut time = point.time.get();
+        let mut value = point.value;
+        let mut shape = point.shape.to_raw();
+        let mut tension = point.tension;
+        let mut selected = point.selected;
+        let mut no_sort = sort_behavior == SortBehavior::DontSort;
+        let successful = self.low.SetEnvelopePointEx(
+            envelope.as_ptr(),
+            point_set.to_raw(),
+            point_index as i32,
+            &mut time,
+            &mut value,
+            &mut shape,
+            &mut tension,
+            &mut selected,
+            &mut no_sort,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set envelope point (probably point doesn't exist)",
+            ));
         }
+        Ok(())
     }
 
-    /// Copies, moves or reorders FX.
+    /// Deletes all points of the given envelope which are within the given time range.
     ///
-    /// Reorders if source and destination track are the same.
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_copy_to_track(
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn delete_envelope_point_range(
         &self,
-        source: (MediaTrack, TrackFxLocation),
-        destination: (MediaTrack, TrackFxLocation),
-        transfer_behavior: TransferBehavior,
-    ) where
+        envelope: TrackEnvelope,
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+    ) -> ReaperFunctionResult<()>
+    where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
-        self.low.TrackFX_CopyToTrack(
-            source.0.as_ptr(),
-            source.1.to_raw(),
-            destination.0.as_ptr(),
-            destination.1.to_raw(),
-            transfer_behavior == TransferBehavior::Move,
-        );
+        self.delete_envelope_point_range_ex(envelope, EnvelopePointSet::Underlying, start, end)
     }
 
-    /// Removes the given FX from the track FX chain.
+    /// Like [`delete_envelope_point_range()`] but lets you choose the point set (e.g. an
+    /// automation item).
     ///
     /// # Errors
     ///
-    /// Returns an error if the FX doesn't exist.
+    /// Returns an error if not successful.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_delete(
+    /// REAPER can crash if you pass an invalid envelope.
+    ///
+    /// [`delete_envelope_point_range()`]: #method.delete_envelope_point_range
+    pub unsafe fn delete_envelope_point_range_ex(
         &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
+        envelope: TrackEnvelope,
+        point_set: EnvelopePointSet,
+        start: PositionInSeconds,
+        end: PositionInSeconds,
     ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let succesful = self
-            .low
-            .TrackFX_Delete(track.as_ptr(), fx_location.to_raw());
-        if !succesful {
+        let successful = self.low.DeleteEnvelopePointRangeEx(
+            envelope.as_ptr(),
+            point_set.to_raw(),
+            start.get(),
+            end.get(),
+        );
+        if !successful {
             return Err(ReaperFunctionError::new(
-                "couldn't delete FX (probably FX doesn't exist)",
+                "couldn't delete envelope point range",
             ));
         }
         Ok(())
     }
 
-    /// Returns information about the given FX parameter's step sizes.
+    /// Sorts the points of the given envelope by time.
     ///
-    /// Returns `None` if the FX parameter doesn't report step sizes or if the FX or parameter
-    /// doesn't exist (there's no way to distinguish with just this function).
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
     ///
     /// # Safety
     ///
-    /// - REAPER can crash if you pass an invalid track.
-    /// - Calling this from any other thread than the main thread causes undefined behavior!
-    /// - However, there's one exception: Calling it in a real-time thread directly "from the track"
-    ///   which is currently processing should be okay.
-    //
-    // Option makes more sense than Result here because this function is at the same time the
-    // correct function to be used to determine *if* a parameter reports step sizes. So
-    // "parameter doesn't report step sizes" is a valid result.
-    pub unsafe fn track_fx_get_parameter_step_sizes(
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn envelope_sort_points(&self, envelope: TrackEnvelope) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.envelope_sort_points_ex(envelope, EnvelopePointSet::Underlying)
+    }
+
+    /// Like [`envelope_sort_points()`] but lets you choose the point set (e.g. an automation
+    /// item).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid envelope.
+    ///
+    /// [`envelope_sort_points()`]: #method.envelope_sort_points
+    pub unsafe fn envelope_sort_points_ex(
         &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-        param_index: u32,
-    ) -> Option<GetParameterStepSizesResult>
+        envelope: TrackEnvelope,
+        point_set: EnvelopePointSet,
+    ) -> ReaperFunctionResult<()>
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        // It's important to zero these variables (could also do that without MaybeUninit) because
-        // if REAPER returns true, that doesn't always mean that it initialized all of the variables
-        // correctly. Learned this the hard way with some super random results coming up.
-        let mut step = MaybeUninit::zeroed();
-        let mut small_step = MaybeUninit::zeroed();
-        let mut large_step = MaybeUninit::zeroed();
-        let mut is_toggle = MaybeUninit::zeroed();
-        let successful = self.low.TrackFX_GetParameterStepSizes(
-            track.as_ptr(),
-            fx_location.to_raw(),
-            param_index as i32,
-            step.as_mut_ptr(),
-            small_step.as_mut_ptr(),
-            large_step.as_mut_ptr(),
-            is_toggle.as_mut_ptr(),
-        );
+        self.require_main_thread();
+        let successful = self
+            .low
+            .Envelope_SortPointsEx(envelope.as_ptr(), point_set.to_raw());
         if !successful {
-            return None;
-        }
-        let is_toggle = is_toggle.assume_init();
-        if is_toggle {
-            Some(GetParameterStepSizesResult::Toggle)
-        } else {
-            Some(GetParameterStepSizesResult::Normal {
-                normal_step: step.assume_init(),
-                small_step: make_some_if_greater_than_zero(small_step.assume_init()),
-                large_step: make_some_if_greater_than_zero(large_step.assume_init()),
-            })
+            return Err(ReaperFunctionError::new("couldn't sort envelope points"));
         }
+        Ok(())
     }
 
-    /// Returns the current value and min/mid/max values of the given track FX.
+    /// Returns the effective value of the given envelope at the given time position.
+    ///
+    /// `samples_requested` is how long the caller expects until the next call (often the block
+    /// size). The result tells for how many samples beyond the given position the returned values
+    /// are valid.
     ///
     /// # Safety
     ///
-    /// - REAPER can crash if you pass an invalid track.
-    /// - Calling this from any other thread than the main thread causes undefined behavior!
-    /// - However, there's one exception: Calling it in a real-time thread directly "from the track"
-    ///   which is currently processing should be okay.
-    pub unsafe fn track_fx_get_param_ex(
+    /// REAPER can crash if you pass an invalid envelope.
+    pub unsafe fn envelope_evaluate(
         &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-        param_index: u32,
-    ) -> GetParamExResult
+        envelope: TrackEnvelope,
+        time: PositionInSeconds,
+        sample_rate: Hz,
+        samples_requested: u32,
+    ) -> EnvelopeEvaluateResult
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let mut min_val = MaybeUninit::uninit();
-        let mut max_val = MaybeUninit::uninit();
-        let mut mid_val = MaybeUninit::uninit();
-        let value = self.low.TrackFX_GetParamEx(
-            track.as_ptr(),
-            fx_location.to_raw(),
-            param_index as i32,
-            min_val.as_mut_ptr(),
-            max_val.as_mut_ptr(),
-            mid_val.as_mut_ptr(),
+        self.require_main_thread();
+        let mut value = MaybeUninit::zeroed();
+        let mut first_derivative = MaybeUninit::zeroed();
+        let mut second_derivative = MaybeUninit::zeroed();
+        let mut third_derivative = MaybeUninit::zeroed();
+        let valid_sample_count = self.low.Envelope_Evaluate(
+            envelope.as_ptr(),
+            time.get(),
+            sample_rate.get(),
+            samples_requested as i32,
+            value.as_mut_ptr(),
+            first_derivative.as_mut_ptr(),
+            second_derivative.as_mut_ptr(),
+            third_derivative.as_mut_ptr(),
         );
-        GetParamExResult {
-            current_value: value,
-            min_value: min_val.assume_init(),
-            mid_value: mid_val.assume_init(),
-            max_value: max_val.assume_init(),
+        EnvelopeEvaluateResult {
+            value: value.assume_init(),
+            first_derivative: first_derivative.assume_init(),
+            second_derivative: second_derivative.assume_init(),
+            third_derivative: third_derivative.assume_init(),
+            valid_sample_count: valid_sample_count.max(0) as u32,
         }
     }
 
-    /// Gets a plug-in specific named configuration value.
-    ///
-    /// With `buffer_size` you can tell REAPER and the FX how many bytes of the value you want.
-    ///
-    /// Named parameters are a vendor-specific VST extension from Cockos (see
-    /// <http://reaper.fm/sdk/vst/vst_ext.php>).
+    /// Returns the current peak volume for the given track channel.
     ///
-    /// # Errors
+    /// # Safety
     ///
-    /// Returns an error if the given FX doesn't have this named parameter or doesn't support named
-    /// parameters.
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_get_peak_info(&self, track: MediaTrack, channel: u32) -> ReaperVolumeValue
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let result = self.low.Track_GetPeakInfo(track.as_ptr(), channel as _);
+        ReaperVolumeValue::new(result)
+    }
+
+    /// Gets a track attribute as numerical value.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_named_config_parm<'a>(
+    pub unsafe fn get_media_track_info_value(
         &self,
         track: MediaTrack,
-        fx_location: TrackFxLocation,
-        param_name: impl Into<ReaperStringArg<'a>>,
-        buffer_size: u32,
-    ) -> ReaperFunctionResult<Vec<u8>>
+        attribute_key: TrackAttributeKey,
+    ) -> f64
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let (buffer, successful) = with_buffer(buffer_size, |buffer, max_size| {
-            self.low.TrackFX_GetNamedConfigParm(
-                track.as_ptr(),
-                fx_location.to_raw(),
-                param_name.into().as_ptr(),
-                buffer,
-                max_size,
-            )
-        });
-        if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't get named parameter value",
-            ));
-        }
-        Ok(buffer)
+        self.low
+            .GetMediaTrackInfo_Value(track.as_ptr(), attribute_key.into_raw().as_ptr())
     }
 
-    /// Like [`track_fx_get_named_config_parm`](Self::track_fx_get_named_config_parm)
-    /// but interpreting the result as a string.
+    /// Gets a track track send, hardware output send or track receive attribute as numerical value.
     ///
-    /// # Errors
+    /// # Safety
     ///
-    /// Returns an error if the given FX doesn't have this named parameter, doesn't support named
-    /// parameters or if the returned data doesn't resemble a proper string.
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_track_send_info_value(
+        &self,
+        track: MediaTrack,
+        category: TrackSendCategory,
+        send_index: u32,
+        attribute_key: TrackSendAttributeKey,
+    ) -> f64
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.GetTrackSendInfo_Value(
+            track.as_ptr(),
+            category.to_raw(),
+            send_index as i32,
+            attribute_key.into_raw().as_ptr(),
+        )
+    }
+
+    /// Counts the number of items in the given track.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_named_config_parm_as_string<'a>(
-        &self,
-        track: MediaTrack,
-        fx_location: TrackFxLocation,
-        param_name: impl Into<ReaperStringArg<'a>>,
-        buffer_size: u32,
-    ) -> ReaperFunctionResult<ReaperString>
+    pub unsafe fn count_track_media_items(&self, track: MediaTrack) -> u32
     where
         UsageScope: MainThreadOnly,
     {
-        let mut bytes =
-            self.track_fx_get_named_config_parm(track, fx_location, param_name, buffer_size)?;
-        if let Some(nul_byte_index) = bytes.iter().position(|b| *b == 0) {
-            // Crop end of vector so that it doesn't include the nul terminator anymore.
-            bytes.resize(nul_byte_index, 0);
-            Ok(ReaperString::new(CString::from_vec_unchecked(bytes)))
-        } else {
-            Err(ReaperFunctionError::new("result is not a string"))
-        }
+        self.require_main_thread();
+        self.low.CountTrackMediaItems(track.as_ptr()) as u32
     }
 
-    /// Sets a plug-in specific named configuration value.
-    ///
-    /// Named parameters are a vendor-specific VST extension from Cockos (see
-    /// <http://reaper.fm/sdk/vst/vst_ext.php>).
+    /// Counts the number of FX parameter knobs displayed on the track control panel.
     ///
-    /// # Errors
+    /// # Safety
     ///
-    /// Returns an error if the given FX doesn't have this named parameter or doesn't support named
-    /// parameters.
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn count_tcp_fx_parms(&self, project: ProjectContext, track: MediaTrack) -> u32
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.CountTCPFXParms(project.to_raw(), track.as_ptr()) as u32
+    }
+
+    /// Returns information about a specific FX parameter knob displayed on the track control panel.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_set_named_config_parm<'a>(
+    pub unsafe fn get_tcp_fx_parm(
         &self,
+        project: ProjectContext,
         track: MediaTrack,
-        fx_location: TrackFxLocation,
-        param_name: impl Into<ReaperStringArg<'a>>,
-        buffer: &[u8],
-    ) -> ReaperFunctionResult<()>
+        index: u32,
+    ) -> ReaperFunctionResult<GetTcpFxParmResult>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let successful = self.low.TrackFX_SetNamedConfigParm(
+        let mut fx_index = MaybeUninit::uninit();
+        let mut param_index = MaybeUninit::uninit();
+        let successful = self.low.GetTCPFXParm(
+            project.to_raw(),
             track.as_ptr(),
-            fx_location.to_raw(),
-            param_name.into().as_ptr(),
-            buffer.as_ptr() as _,
+            index as _,
+            fx_index.as_mut_ptr(),
+            param_index.as_mut_ptr(),
         );
         if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't set named parameter value",
-            ));
+            return Err(ReaperFunctionError::new("couldn't get TCP FX param info"));
         }
-        Ok(())
+        let fx_index = fx_index.assume_init();
+        let result = GetTcpFxParmResult {
+            fx_location: TrackFxLocation::from_raw(fx_index),
+            param_index: param_index.assume_init() as u32,
+        };
+        Ok(result)
     }
 
-    /// Starts a new undo block.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the given project is not valid anymore.
-    ///
-    /// # Example
+    /// Returns the media item on the given track at the given index.
     ///
-    /// ```no_run
-    /// # let session = reaper_medium::ReaperSession::default();
-    /// use reaper_medium::{ProjectContext::CurrentProject, UndoScope::Scoped, ProjectPart::*};
+    /// # Safety
     ///
-    /// session.reaper().undo_begin_block_2(CurrentProject);
-    /// // ... modify something ...
-    /// session.reaper().undo_end_block_2(CurrentProject, "Modify something", Scoped(Items | Fx));
-    /// ```
-    pub fn undo_begin_block_2(&self, project: ProjectContext)
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_track_media_item(&self, track: MediaTrack, item_idx: u32) -> Option<MediaItem>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.require_valid_project(project);
-        unsafe { self.undo_begin_block_2_unchecked(project) };
+        let ptr = self.low.GetTrackMediaItem(track.as_ptr(), item_idx as _);
+        NonNull::new(ptr)
     }
 
-    /// Like [`undo_begin_block_2()`] but doesn't check if project is valid.
+    /// Gets the number of FX instances on the given track's normal FX chain.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`undo_begin_block_2()`]: #method.undo_begin_block_2
-    pub unsafe fn undo_begin_block_2_unchecked(&self, project: ProjectContext)
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_count(&self, track: MediaTrack) -> u32
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.Undo_BeginBlock2(project.to_raw());
+        self.low.TrackFX_GetCount(track.as_ptr()) as u32
     }
 
-    /// Ends the current undo block.
-    ///
-    /// # Panics
+    /// Gets the number of FX instances on the given track's input FX chain.
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn undo_end_block_2<'a>(
-        &self,
-        project: ProjectContext,
-        description: impl Into<ReaperStringArg<'a>>,
-        scope: UndoScope,
-    ) where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        self.require_valid_project(project);
-        unsafe {
-            self.undo_end_block_2_unchecked(project, description, scope);
-        }
-    }
-
-    /// Like [`undo_end_block_2()`] but doesn't check if project is valid.
+    /// On the master track, this refers to the monitoring FX chain.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`undo_end_block_2()`]: #method.undo_end_block_2
-    pub unsafe fn undo_end_block_2_unchecked<'a>(
-        &self,
-        project: ProjectContext,
-        description: impl Into<ReaperStringArg<'a>>,
-        scope: UndoScope,
-    ) where
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_rec_count(&self, track: MediaTrack) -> u32
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.Undo_EndBlock2(
-            project.to_raw(),
-            description.into().as_ptr(),
-            scope.to_raw(),
-        );
+        self.low.TrackFX_GetRecCount(track.as_ptr()) as u32
     }
 
-    /// Grants temporary access to the the description of the last undoable operation, if any.
+    /// Returns the GUID of the given track FX.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn undo_can_undo_2<R>(
+    /// Returns an error if the FX doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn track_fx_get_fx_guid(
         &self,
-        project: ProjectContext,
-        use_description: impl FnOnce(&ReaperStr) -> R,
-    ) -> Option<R>
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+    ) -> ReaperFunctionResult<GUID>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.require_valid_project(project);
-        unsafe { self.undo_can_undo_2_unchecked(project, use_description) }
+        let ptr = self
+            .low
+            .TrackFX_GetFXGUID(track.as_ptr(), fx_location.to_raw());
+        deref(ptr).ok_or_else(|| {
+            ReaperFunctionError::new("couldn't get FX GUID (probably FX doesn't exist)")
+        })
     }
 
-    /// Like [`undo_can_undo_2()`] but doesn't check if project is valid.
+    /// Returns the current value of the given track FX in REAPER-normalized form.
     ///
+    /// If the returned value is lower than zero, it can mean two things. Either there was an error,
+    /// e.g. the FX or parameter doesn't exist, or the parameter can take exotic values. There's no
+    /// way to distinguish between both cases. See [`ReaperNormalizedFxParamValue`] for details.
+    ///  
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// - REAPER can crash if you pass an invalid track.
+    /// - Calling this from any other thread than the main thread causes undefined behavior!
+    /// - However, there's one exception: Calling it in a real-time thread directly "from the track"
+    ///   which is currently processing should be okay.
     ///
-    /// [`undo_can_undo_2()`]: #method.undo_can_undo_2
-    pub unsafe fn undo_can_undo_2_unchecked<R>(
+    /// [`ReaperNormalizedFxParamValue`]: struct.ReaperNormalizedFxParamValue.html
+    pub unsafe fn track_fx_get_param_normalized(
         &self,
-        project: ProjectContext,
-        use_description: impl FnOnce(&ReaperStr) -> R,
-    ) -> Option<R>
+        track: MediaTrack,
+        fx_location: TrackFxLocation,
+        param_index: u32,
+    ) -> ReaperNormalizedFxParamValue
     where
-        UsageScope: MainThreadOnly,
+        UsageScope: AnyThread,
     {
-        self.require_main_thread();
-        let ptr = self.low.Undo_CanUndo2(project.to_raw());
-        create_passing_c_str(ptr).map(use_description)
+        let raw_value = self.low.TrackFX_GetParamNormalized(
+            track.as_ptr(),
+            fx_location.to_raw(),
+            param_index as i32,
+        );
+        ReaperNormalizedFxParamValue::new(raw_value)
     }
 
-    /// Grants temporary access to the description of the next redoable operation, if any.
+    /// Returns the master track of the given project.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn undo_can_redo_2<R>(
-        &self,
-        project: ProjectContext,
-        use_description: impl FnOnce(&ReaperStr) -> R,
-    ) -> Option<R>
+    pub fn get_master_track(&self, project: ProjectContext) -> MediaTrack
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.undo_can_redo_2_unchecked(project, use_description) }
+        unsafe { self.get_master_track_unchecked(project) }
     }
 
-    /// Like [`undo_can_redo_2()`] but doesn't check if project is valid.
+    /// Like [`get_master_track()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`undo_can_redo_2()`]: #method.undo_can_redo_2
-    pub unsafe fn undo_can_redo_2_unchecked<R>(
-        &self,
-        project: ProjectContext,
-        use_description: impl FnOnce(&ReaperStr) -> R,
-    ) -> Option<R>
+    /// [`get_master_track()`]: #method.get_master_track
+    pub unsafe fn get_master_track_unchecked(&self, project: ProjectContext) -> MediaTrack
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.Undo_CanRedo2(project.to_raw());
-        create_passing_c_str(ptr).map(use_description)
+        let ptr = self.low.GetMasterTrack(project.to_raw());
+        require_non_null_panic(ptr)
     }
 
-    /// Makes the last undoable operation undone.
+    /// Converts the given GUID to a string (including braces).
+    pub fn guid_to_string(&self, guid: &GUID) -> ReaperString
+    where
+        UsageScope: AnyThread,
+    {
+        let (guid_string, _) = with_string_buffer(64, |buffer, _| unsafe {
+            self.low.guidToString(guid as *const GUID, buffer)
+        });
+        guid_string
+    }
+
+    /// Converts the given accelerator key to a human-readable name.
+    pub fn kbd_format_key_name(&self, accel: Accel) -> ReaperString
+    where
+        UsageScope: AnyThread,
+    {
+        let (key_string, _) = with_string_buffer(64, |buffer, _| unsafe {
+            let mut accel = accel.to_raw();
+            self.low.kbd_formatKeyName(&mut accel as *mut _, buffer)
+        });
+        key_string
+    }
+
+    /// Returns the project recording path.
     ///
-    /// Returns `false` if there was nothing to be undone.
+    /// With `buffer_size` you can tell REAPER how many bytes of the resulting path you want.
     ///
     /// # Panics
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn undo_do_undo_2(&self, project: ProjectContext) -> bool
+    /// Panics if the given buffer size is 0.
+    pub fn get_project_path_ex(&self, project: ProjectContext, buffer_size: u32) -> PathBuf
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.undo_do_undo_2_unchecked(project) }
+        unsafe { self.get_project_path_ex_unchecked(project, buffer_size) }
     }
 
-    /// Like [`undo_do_undo_2()`] but doesn't check if project is valid.
+    /// Like [`get_project_path_ex()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`undo_do_undo_2()`]: #method.undo_do_undo_2
-    pub unsafe fn undo_do_undo_2_unchecked(&self, project: ProjectContext) -> bool
+    /// [`get_project_path_ex()`]: #method.get_project_path_ex
+    pub unsafe fn get_project_path_ex_unchecked(
+        &self,
+        project: ProjectContext,
+        buffer_size: u32,
+    ) -> PathBuf
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.Undo_DoUndo2(project.to_raw()) != 0
+        let (reaper_string, _) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low
+                .GetProjectPathEx(project.to_raw(), buffer, max_size)
+        });
+        let owned_string = reaper_string.into_string();
+        PathBuf::from(owned_string)
     }
 
-    /// Executes the next redoable action.
-    ///
-    /// Returns `false` if there was nothing to be redone.
+    /// Returns the master tempo of the current project.
+    pub fn master_get_tempo(&self) -> Bpm
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        Bpm(self.low.Master_GetTempo())
+    }
+
+    /// Sets the current tempo of the given project.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn undo_do_redo_2(&self, project: ProjectContext) -> bool
+    pub fn set_current_bpm(&self, project: ProjectContext, tempo: Bpm, undo_behavior: UndoBehavior)
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.undo_do_redo_2_unchecked(project) }
+        unsafe {
+            self.set_current_bpm_unchecked(project, tempo, undo_behavior);
+        }
     }
 
-    /// Like [`undo_do_redo_2()`] but doesn't check if project is valid.
+    /// Like [`set_current_bpm()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`undo_do_redo_2()`]: #method.undo_do_redo_2
-    pub unsafe fn undo_do_redo_2_unchecked(&self, project: ProjectContext) -> bool
-    where
+    /// [`set_current_bpm()`]: #method.set_current_bpm
+    pub unsafe fn set_current_bpm_unchecked(
+        &self,
+        project: ProjectContext,
+        tempo: Bpm,
+        undo_behavior: UndoBehavior,
+    ) where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.Undo_DoRedo2(project.to_raw()) != 0
+        self.low.SetCurrentBPM(
+            project.to_raw(),
+            tempo.get(),
+            undo_behavior == UndoBehavior::AddUndoPoint,
+        );
     }
 
-    /// Marks the given project as dirty.
-    ///
-    /// *Dirty* means the project needs to be saved. Only makes a difference if "Maximum undo
-    /// memory" is not 0 in REAPER's preferences (0 disables undo/prompt to save).
-    ///
-    /// # Panics
-    ///
-    /// Panics if the given project is not valid anymore.
-    pub fn mark_project_dirty(&self, project: ProjectContext)
+    /// Converts the given playback speed factor to a normalized play rate.
+    pub fn master_normalize_play_rate_normalize(
+        &self,
+        value: PlaybackSpeedFactor,
+    ) -> NormalizedPlayRate
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.require_valid_project(project);
-        unsafe {
-            self.mark_project_dirty_unchecked(project);
-        }
+        let result = self.low.Master_NormalizePlayRate(value.get(), false);
+        NormalizedPlayRate::new(result)
     }
 
-    /// Like [`mark_project_dirty()`] but doesn't check if project is valid.
-    ///
-    /// # Safety
-    ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`mark_project_dirty()`]: #method.mark_project_dirty
-    pub unsafe fn mark_project_dirty_unchecked(&self, project: ProjectContext)
+    /// Converts the given normalized play rate to a playback speed factor.
+    pub fn master_normalize_play_rate_denormalize(
+        &self,
+        value: NormalizedPlayRate,
+    ) -> PlaybackSpeedFactor
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.MarkProjectDirty(project.to_raw());
+        let result = self.low.Master_NormalizePlayRate(value.get(), true);
+        PlaybackSpeedFactor::new(result)
     }
 
-    /// Returns whether the given project is dirty.
-    ///
-    /// Always returns `false` if "Maximum undo memory" is 0 in REAPER's preferences.
-    ///
-    /// Also see [`mark_project_dirty()`]
+    /// Returns the master play rate of the given project.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    ///
-    /// [`mark_project_dirty()`]: #method.mark_project_dirty
-    pub fn is_project_dirty(&self, project: ProjectContext) -> bool
+    pub fn master_get_play_rate(&self, project: ProjectContext) -> PlaybackSpeedFactor
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.is_project_dirty_unchecked(project) }
+        unsafe { self.master_get_play_rate_unchecked(project) }
     }
 
-    /// Like [`is_project_dirty()`] but doesn't check if project is valid.
+    /// Like [`master_get_play_rate()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`is_project_dirty()`]: #method.is_project_dirty
-    pub unsafe fn is_project_dirty_unchecked(&self, project: ProjectContext) -> bool
+    /// [`master_get_play_rate()`]: #method.master_get_play_rate
+    pub unsafe fn master_get_play_rate_unchecked(
+        &self,
+        project: ProjectContext,
+    ) -> PlaybackSpeedFactor
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.IsProjectDirty(project.to_raw()) != 0
+        let raw = self.low.Master_GetPlayRate(project.to_raw());
+        PlaybackSpeedFactor(raw)
     }
 
-    /// Notifies all control surfaces that something in the track list has changed.
+    /// Sets the master play rate of the current project.
+    pub fn csurf_on_play_rate_change(&self, play_rate: PlaybackSpeedFactor) {
+        self.low.CSurf_OnPlayRateChange(play_rate.get());
+    }
+
+    /// Shows a message box to the user.
     ///
-    /// Behavior not confirmed.
-    pub fn track_list_update_all_external_surfaces(&self)
+    /// Blocks the main thread.
+    pub fn show_message_box<'a>(
+        &self,
+        message: impl Into<ReaperStringArg<'a>>,
+        title: impl Into<ReaperStringArg<'a>>,
+        r#type: MessageBoxType,
+    ) -> MessageBoxResult
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.TrackList_UpdateAllExternalSurfaces();
+        let result = unsafe {
+            self.low.ShowMessageBox(
+                message.into().as_ptr(),
+                title.into().as_ptr(),
+                r#type.to_raw(),
+            )
+        };
+        MessageBoxResult::from_raw(result)
     }
 
-    /// Returns the version of the REAPER application in which this plug-in is currently running.
-    pub fn get_app_version(&self) -> ReaperVersion<'static>
+    /// Displays a text close to the transport bar.
+    pub fn help_set<'a>(&self, message: impl Into<ReaperStringArg<'a>>, mode: HelpMode)
     where
-        UsageScope: AnyThread,
+        UsageScope: MainThreadOnly,
     {
-        let ptr = self.low.GetAppVersion();
-        let version_str = unsafe { ReaperStr::from_ptr(ptr) };
-        ReaperVersion::new(version_str)
+        self.require_main_thread();
+        unsafe { self.low.Help_Set(message.into().as_ptr(), mode.to_raw()) };
     }
 
-    /// Returns the track automation mode, regardless of the global override.
+    /// Parses the given string as GUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given string is not a valid GUID string.
+    pub fn string_to_guid<'a>(
+        &self,
+        guid_string: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<GUID>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut guid = MaybeUninit::uninit();
+        unsafe {
+            self.low
+                .stringToGuid(guid_string.into().as_ptr(), guid.as_mut_ptr());
+        }
+        let guid = unsafe { guid.assume_init() };
+        if guid == ZERO_GUID {
+            return Err(ReaperFunctionError::new("GUID string is invalid"));
+        }
+        Ok(guid)
+    }
+
+    /// Sets the input monitoring mode of the given track.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_track_automation_mode(&self, track: MediaTrack) -> AutomationMode
+    pub unsafe fn csurf_on_input_monitoring_change_ex(
+        &self,
+        track: MediaTrack,
+        mode: InputMonitoringMode,
+        gang_behavior: GangBehavior,
+    ) -> i32
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let result = self.low.GetTrackAutomationMode(track.as_ptr());
-        AutomationMode::from_raw(result)
+        self.low.CSurf_OnInputMonitorChangeEx(
+            track.as_ptr(),
+            mode.to_raw(),
+            gang_behavior == GangBehavior::AllowGang,
+        )
     }
 
-    /// Returns the custom color of the given track.
+    /// Scrolls the mixer so that the given track is the leftmost visible track.
+    ///
+    /// Returns the leftmost visible track after scrolling, which may be different from the given
+    /// track if there are not enough tracks to its right. Not exactly sure what it's supposed to
+    /// mean if this returns `None`, but it happens at times.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_track_color(&self, track: MediaTrack) -> Option<NativeColorResult>
+    pub unsafe fn set_mixer_scroll(&self, track: MediaTrack) -> Option<MediaTrack>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let value = self.low.GetTrackColor(track.as_ptr());
-        NativeColorResult::from_track_color_value(value)
+        let ptr = self.low.SetMixerScroll(track.as_ptr());
+        NonNull::new(ptr)
     }
 
-    /// Extracts an RGB color from the given OS-dependent color.
-    pub fn color_from_native(&self, color: NativeColor) -> RgbColor
+    /// Creates a new media item.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn add_media_item_to_track(
+        &self,
+        track: MediaTrack,
+    ) -> ReaperFunctionResult<MediaItem>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let (mut r, mut g, mut b) = (
-            MaybeUninit::uninit(),
-            MaybeUninit::uninit(),
-            MaybeUninit::uninit(),
-        );
-        unsafe {
-            self.low.ColorFromNative(
-                color.to_raw(),
-                r.as_mut_ptr(),
-                g.as_mut_ptr(),
-                b.as_mut_ptr(),
-            );
-        }
-        RgbColor {
-            r: unsafe { r.assume_init() as _ },
-            g: unsafe { g.assume_init() as _ },
-            b: unsafe { b.assume_init() as _ },
-        }
+        let ptr = self.low.AddMediaItemToTrack(track.as_ptr());
+        NonNull::new(ptr).ok_or(ReaperFunctionError::new("couldn't add item to track"))
+    }
+
+    /// Creates a new media item containing an empty MIDI take.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the item couldn't be created.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn create_new_midi_item_in_proj(
+        &self,
+        track: MediaTrack,
+        start: PositionInSeconds,
+        end: PositionInSeconds,
+    ) -> ReaperFunctionResult<MediaItem>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self
+            .low
+            .CreateNewMIDIItemInProj(track.as_ptr(), start.get(), end.get(), null());
+        NonNull::new(ptr).ok_or(ReaperFunctionError::new("couldn't create MIDI item"))
     }
 
-    /// Runs the system color chooser dialog.
+    /// Deletes the given media item.
     ///
-    /// Returns `None` if the user cancels the dialog.
-    pub fn gr_select_color(&self, window: WindowContext) -> Option<NativeColor>
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track or item.
+    pub unsafe fn delete_track_media_item(
+        &self,
+        track: MediaTrack,
+        item: MediaItem,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let mut raw = MaybeUninit::uninit();
-        let picked = unsafe { self.low.GR_SelectColor(window.to_raw(), raw.as_mut_ptr()) };
-        if picked == 0 {
-            return None;
+        let successful = self.low.DeleteTrackMediaItem(track.as_ptr(), item.as_ptr());
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "deletion of media item not successful",
+            ));
         }
-        Some(NativeColor::new(unsafe { raw.assume_init() } as _))
+        Ok(())
     }
 
-    /// Sets the track automation mode.
+    /// Creates a new take in an item.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn set_track_automation_mode(
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn add_take_to_media_item(
         &self,
-        track: MediaTrack,
-        automation_mode: AutomationMode,
-    ) where
+        item: MediaItem,
+    ) -> ReaperFunctionResult<MediaItemTake>
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .SetTrackAutomationMode(track.as_ptr(), automation_mode.to_raw());
+        let ptr = self.low.AddTakeToMediaItem(item.as_ptr());
+        NonNull::new(ptr).ok_or(ReaperFunctionError::new("couldn't add take to item"))
     }
 
-    /// Returns the global track automation override, if any.
-    pub fn get_global_automation_override(&self) -> Option<GlobalAutomationModeOverride>
+    /// Sets the position of the given item.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn set_media_item_position(
+        &self,
+        item: MediaItem,
+        pos: PositionInSeconds,
+        refresh_behavior: UiRefreshBehavior,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        use GlobalAutomationModeOverride::*;
-        match self.low.GetGlobalAutomationOverride() {
-            -1 => None,
-            6 => Some(Bypass),
-            x => Some(Mode(AutomationMode::from_raw(x))),
+        let successful = self.low.SetMediaItemPosition(
+            item.as_ptr(),
+            pos.get(),
+            refresh_behavior == UiRefreshBehavior::Refresh,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't set item position"));
         }
+        Ok(())
     }
 
-    /// Sets the global track automation override.
-    pub fn set_global_automation_override(
+    /// Sets the length of the given item.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn set_media_item_length(
         &self,
-        mode_override: Option<GlobalAutomationModeOverride>,
-    ) where
+        item: MediaItem,
+        length: DurationInSeconds,
+        refresh_behavior: UiRefreshBehavior,
+    ) -> ReaperFunctionResult<()>
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        use GlobalAutomationModeOverride::*;
-        let raw = match mode_override {
-            None => -1,
-            Some(Bypass) => 6,
-            Some(Mode(x)) => x.to_raw(),
-        };
-        self.low.SetGlobalAutomationOverride(raw);
+        let successful = self.low.SetMediaItemLength(
+            item.as_ptr(),
+            length.get(),
+            refresh_behavior == UiRefreshBehavior::Refresh,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't set item length"));
+        }
+        Ok(())
     }
 
-    /// Returns the track envelope for the given track and configuration chunk name.
+    /// Selects or unselects the given media item.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    // TODO-low Test
-    pub unsafe fn get_track_envelope_by_chunk_name(
-        &self,
-        track: MediaTrack,
-        chunk_name: EnvChunkName,
-    ) -> Option<TrackEnvelope>
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn set_media_item_selected(&self, item: MediaItem, selected: bool)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self
-            .low
-            .GetTrackEnvelopeByChunkName(track.as_ptr(), chunk_name.into_raw().as_ptr());
-        NonNull::new(ptr)
+        self.low.SetMediaItemSelected(item.as_ptr(), selected);
     }
 
-    /// Returns the track envelope for the given track and envelope display name.
-    ///
-    /// For getting common envelopes (like volume or pan) using
-    /// [`get_track_envelope_by_chunk_name()`] is better because it provides more type safety.
+    /// Gets an item attribute as numerical value.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    ///
-    /// [`get_track_envelope_by_chunk_name()`]: #method.get_track_envelope_by_chunk_name
-    pub unsafe fn get_track_envelope_by_name<'a>(
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn get_media_item_info_value(
         &self,
-        track: MediaTrack,
-        env_name: impl Into<ReaperStringArg<'a>>,
-    ) -> Option<TrackEnvelope>
+        item: MediaItem,
+        attribute_key: ItemAttributeKey,
+    ) -> f64
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self
-            .low
-            .GetTrackEnvelopeByName(track.as_ptr(), env_name.into().as_ptr());
-        NonNull::new(ptr)
+        self.low
+            .GetMediaItemInfo_Value(item.as_ptr(), attribute_key.into_raw().as_ptr())
     }
 
-    /// Returns the current peak volume for the given track channel.
+    /// Sets an item attribute as numerical value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an invalid (e.g. non-numerical) item attribute key is passed.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_get_peak_info(&self, track: MediaTrack, channel: u32) -> ReaperVolumeValue
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn set_media_item_info_value(
+        &self,
+        item: MediaItem,
+        attribute_key: ItemAttributeKey,
+        new_value: f64,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let result = self.low.Track_GetPeakInfo(track.as_ptr(), channel as _);
-        ReaperVolumeValue::new(result)
+        let successful = self.low.SetMediaItemInfo_Value(
+            item.as_ptr(),
+            attribute_key.into_raw().as_ptr(),
+            new_value,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set item attribute (maybe attribute key is invalid)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Gets a track attribute as numerical value.
+    /// Gets an item attribute as string (e.g. `P_NOTES`, `GUID` or `P_EXT:xyz`).
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the string you want.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the attribute key is invalid or if the attribute doesn't exist.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_media_track_info_value(
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn get_set_media_item_info_string_get(
         &self,
-        track: MediaTrack,
-        attribute_key: TrackAttributeKey,
-    ) -> f64
+        item: MediaItem,
+        attribute_key: ItemAttributeKey,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<ReaperString>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .GetMediaTrackInfo_Value(track.as_ptr(), attribute_key.into_raw().as_ptr())
+        assert!(buffer_size > 0);
+        let (value, successful) = with_string_buffer(buffer_size, |buffer, _| {
+            self.low.GetSetMediaItemInfo_String(
+                item.as_ptr(),
+                attribute_key.into_raw().as_ptr(),
+                buffer,
+                false,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get item attribute (maybe attribute key is invalid)",
+            ));
+        }
+        Ok(value)
     }
 
-    /// Gets a track track send, hardware output send or track receive attribute as numerical value.
+    /// Sets an item attribute as string (e.g. `P_NOTES`, `GUID` or `P_EXT:xyz`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an invalid attribute key is passed.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_track_send_info_value(
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn get_set_media_item_info_string_set<'a>(
         &self,
-        track: MediaTrack,
-        category: TrackSendCategory,
-        send_index: u32,
-        attribute_key: TrackSendAttributeKey,
-    ) -> f64
+        item: MediaItem,
+        attribute_key: ItemAttributeKey,
+        value: impl Into<ReaperStringArg<'a>>,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.GetTrackSendInfo_Value(
-            track.as_ptr(),
-            category.to_raw(),
-            send_index as i32,
+        let successful = self.low.GetSetMediaItemInfo_String(
+            item.as_ptr(),
             attribute_key.into_raw().as_ptr(),
-        )
+            value.into().as_ptr() as *mut c_char,
+            true,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set item attribute (maybe attribute key is invalid)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Counts the number of items in the given track.
+    /// Convenience function which returns the given item's parent track (`P_TRACK`).
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn count_track_media_items(&self, track: MediaTrack) -> u32
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn get_set_media_item_info_get_track(&self, item: MediaItem) -> Option<MediaTrack>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.CountTrackMediaItems(track.as_ptr()) as u32
+        let ptr = self.get_set_media_item_info(item, ItemAttributeKey::Track, null_mut())
+            as *mut raw::MediaTrack;
+        NonNull::new(ptr)
     }
 
-    /// Counts the number of FX parameter knobs displayed on the track control panel.
+    /// Sets a track attribute as numerical value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an invalid (e.g. non-numerical) track attribute key is passed.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn count_tcp_fx_parms(&self, project: ProjectContext, track: MediaTrack) -> u32
+    pub unsafe fn set_media_track_info_value(
+        &self,
+        track: MediaTrack,
+        attribute_key: TrackAttributeKey,
+        new_value: f64,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.CountTCPFXParms(project.to_raw(), track.as_ptr()) as u32
+        let successful = self.low.SetMediaTrackInfo_Value(
+            track.as_ptr(),
+            attribute_key.into_raw().as_ptr(),
+            new_value,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set track attribute (maybe attribute key is invalid)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Returns information about a specific FX parameter knob displayed on the track control panel.
+    /// Sets a track track send, hardware output send or track receive attribute as numerical value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an invalid (e.g. non-numerical) attribute key is passed.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_tcp_fx_parm(
+    pub unsafe fn set_track_send_info_value(
         &self,
-        project: ProjectContext,
         track: MediaTrack,
-        index: u32,
-    ) -> ReaperFunctionResult<GetTcpFxParmResult>
+        category: TrackSendCategory,
+        send_index: u32,
+        attribute_key: TrackSendAttributeKey,
+        new_value: f64,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let mut fx_index = MaybeUninit::uninit();
-        let mut param_index = MaybeUninit::uninit();
-        let successful = self.low.GetTCPFXParm(
-            project.to_raw(),
+        let successful = self.low.SetTrackSendInfo_Value(
             track.as_ptr(),
-            index as _,
-            fx_index.as_mut_ptr(),
-            param_index.as_mut_ptr(),
+            category.to_raw(),
+            send_index as i32,
+            attribute_key.into_raw().as_ptr(),
+            new_value,
         );
         if !successful {
-            return Err(ReaperFunctionError::new("couldn't get TCP FX param info"));
+            return Err(ReaperFunctionError::new(
+                "couldn't set track send attribute (maybe attribute key is invalid)",
+            ));
         }
-        let fx_index = fx_index.assume_init();
-        let result = GetTcpFxParmResult {
-            fx_location: TrackFxLocation::from_raw(fx_index),
-            param_index: param_index.assume_init() as u32,
-        };
-        Ok(result)
+        Ok(())
     }
 
-    /// Returns the media item on the given track at the given index.
+    /// Stuffs a 3-byte MIDI message into a queue or send it to an external MIDI hardware.
     ///
-    /// # Safety
+    /// # Example
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_track_media_item(&self, track: MediaTrack, item_idx: u32) -> Option<MediaItem>
+    /// ```no_run
+    /// # let session = reaper_medium::ReaperSession::default();
+    /// use helgoboss_midi::test_util::note_on;
+    /// use reaper_medium::StuffMidiMessageTarget::VirtualMidiKeyboardQueue;
+    ///
+    /// session.reaper().stuff_midi_message(VirtualMidiKeyboardQueue, note_on(0, 64, 100));
+    /// ```
+    pub fn stuff_midi_message(&self, target: StuffMidiMessageTarget, message: impl ShortMessage)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.GetTrackMediaItem(track.as_ptr(), item_idx as _);
-        NonNull::new(ptr)
+        let bytes = message.to_bytes();
+        self.low.StuffMIDIMessage(
+            target.to_raw(),
+            bytes.0.into(),
+            bytes.1.into(),
+            bytes.2.into(),
+        );
     }
 
-    /// Gets the number of FX instances on the given track's normal FX chain.
+    /// Converts a decibel value into a volume slider value.
+    pub fn db2slider(&self, value: Db) -> VolumeSliderValue
+    where
+        UsageScope: AnyThread,
+    {
+        VolumeSliderValue(self.low.DB2SLIDER(value.get()))
+    }
+
+    /// Converts a volume slider value into a decibel value.
+    pub fn slider2db(&self, value: VolumeSliderValue) -> Db
+    where
+        UsageScope: AnyThread,
+    {
+        Db(self.low.SLIDER2DB(value.get()))
+    }
+
+    /// Returns the given track's volume and incomplete pan. Also returns the correct value during
+    /// the process of writing an automation envelope.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (unclear when this happens).
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_count(&self, track: MediaTrack) -> u32
+    pub unsafe fn get_track_ui_vol_pan(
+        &self,
+        track: MediaTrack,
+    ) -> ReaperFunctionResult<VolumeAndPan>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.TrackFX_GetCount(track.as_ptr()) as u32
+        // We zero them just for being safe.
+        let mut volume = MaybeUninit::zeroed();
+        let mut pan = MaybeUninit::zeroed();
+        let successful =
+            self.low
+                .GetTrackUIVolPan(track.as_ptr(), volume.as_mut_ptr(), pan.as_mut_ptr());
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get track volume and pan",
+            ));
+        }
+        Ok(VolumeAndPan {
+            volume: ReaperVolumeValue::new(volume.assume_init()),
+            pan: ReaperPanValue::new(pan.assume_init()),
+        })
     }
 
-    /// Gets the number of FX instances on the given track's input FX chain.
+    /// Returns the given track's mute state. Also returns the correct value during the process of
+    /// writing an automation envelope.
     ///
-    /// On the master track, this refers to the monitoring FX chain.
+    /// # Errors
+    ///
+    /// Returns an error if not successful (unclear when this happens).
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_rec_count(&self, track: MediaTrack) -> u32
+    pub unsafe fn get_track_ui_mute(&self, track: MediaTrack) -> ReaperFunctionResult<bool>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.TrackFX_GetRecCount(track.as_ptr()) as u32
+        // We zero them just for being safe.
+        let mut mute = MaybeUninit::zeroed();
+        let successful = self.low.GetTrackUIMute(track.as_ptr(), mute.as_mut_ptr());
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't get track mute"));
+        }
+        Ok(mute.assume_init())
     }
 
-    /// Returns the GUID of the given track FX.
+    /// Returns the given track's complete pan. Also returns the correct value during the process of
+    /// writing an automation envelope.
     ///
     /// # Errors
     ///
-    /// Returns an error if the FX doesn't exist.
+    /// Returns an error if not successful (unclear when this happens).
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn track_fx_get_fx_guid(
+    pub unsafe fn get_track_ui_pan(
         &self,
         track: MediaTrack,
-        fx_location: TrackFxLocation,
-    ) -> ReaperFunctionResult<GUID>
+    ) -> ReaperFunctionResult<GetTrackUiPanResult>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self
-            .low
-            .TrackFX_GetFXGUID(track.as_ptr(), fx_location.to_raw());
-        deref(ptr).ok_or_else(|| {
-            ReaperFunctionError::new("couldn't get FX GUID (probably FX doesn't exist)")
-        })
+        // We zero them just for being safe.
+        let mut pan_1 = MaybeUninit::zeroed();
+        let mut pan_2 = MaybeUninit::zeroed();
+        let mut pan_mode = MaybeUninit::zeroed();
+        let successful = self.low.GetTrackUIPan(
+            track.as_ptr(),
+            pan_1.as_mut_ptr(),
+            pan_2.as_mut_ptr(),
+            pan_mode.as_mut_ptr(),
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't get track pan"));
+        }
+        let pan_mode = PanMode::from_raw(pan_mode.assume_init());
+        let res = GetTrackUiPanResult {
+            pan_mode,
+            pan_1: ReaperPanLikeValue(pan_1.assume_init()),
+            pan_2: ReaperPanLikeValue(pan_2.assume_init()),
+        };
+        Ok(res)
     }
 
-    /// Returns the current value of the given track FX in REAPER-normalized form.
+    /// Informs control surfaces that the given track's volume has changed.
     ///
-    /// If the returned value is lower than zero, it can mean two things. Either there was an error,
-    /// e.g. the FX or parameter doesn't exist, or the parameter can take exotic values. There's no
-    /// way to distinguish between both cases. See [`ReaperNormalizedFxParamValue`] for details.
-    ///  
-    /// # Safety
+    /// Doesn't actually change the volume.
     ///
-    /// - REAPER can crash if you pass an invalid track.
-    /// - Calling this from any other thread than the main thread causes undefined behavior!
-    /// - However, there's one exception: Calling it in a real-time thread directly "from the track"
-    ///   which is currently processing should be okay.
+    /// # Safety
     ///
-    /// [`ReaperNormalizedFxParamValue`]: struct.ReaperNormalizedFxParamValue.html
-    pub unsafe fn track_fx_get_param_normalized(
+    /// REAPER can crash if you pass an invalid track or an invalid control surface.
+    pub unsafe fn csurf_set_surface_volume(
         &self,
         track: MediaTrack,
-        fx_location: TrackFxLocation,
-        param_index: u32,
-    ) -> ReaperNormalizedFxParamValue
-    where
-        UsageScope: AnyThread,
+        volume: ReaperVolumeValue,
+        notification_behavior: NotificationBehavior,
+    ) where
+        UsageScope: MainThreadOnly,
     {
-        let raw_value = self.low.TrackFX_GetParamNormalized(
+        self.require_main_thread();
+        self.low.CSurf_SetSurfaceVolume(
             track.as_ptr(),
-            fx_location.to_raw(),
-            param_index as i32,
+            volume.get(),
+            notification_behavior.to_raw(),
         );
-        ReaperNormalizedFxParamValue::new(raw_value)
     }
 
-    /// Returns the master track of the given project.
-    ///
-    /// # Panics
+    /// Sets the given track's volume, also supports relative changes and gang.
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn get_master_track(&self, project: ProjectContext) -> MediaTrack
-    where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        self.require_valid_project(project);
-        unsafe { self.get_master_track_unchecked(project) }
-    }
-
-    /// Like [`get_master_track()`] but doesn't check if project is valid.
+    /// Returns the value that has actually been set. I think this only deviates if 0.0 is sent.
+    /// Then it returns a slightly higher value - the one which actually corresponds to -150 dB.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
-    ///
-    /// [`get_master_track()`]: #method.get_master_track
-    pub unsafe fn get_master_track_unchecked(&self, project: ProjectContext) -> MediaTrack
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn csurf_on_volume_change_ex(
+        &self,
+        track: MediaTrack,
+        value_change: ValueChange<ReaperVolumeValue>,
+        gang_behavior: GangBehavior,
+    ) -> ReaperVolumeValue
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.GetMasterTrack(project.to_raw());
-        require_non_null_panic(ptr)
-    }
-
-    /// Converts the given GUID to a string (including braces).
-    pub fn guid_to_string(&self, guid: &GUID) -> ReaperString
-    where
-        UsageScope: AnyThread,
-    {
-        let (guid_string, _) = with_string_buffer(64, |buffer, _| unsafe {
-            self.low.guidToString(guid as *const GUID, buffer)
-        });
-        guid_string
-    }
-
-    /// Converts the given accelerator key to a human-readable name.
-    pub fn kbd_format_key_name(&self, accel: Accel) -> ReaperString
-    where
-        UsageScope: AnyThread,
-    {
-        let (key_string, _) = with_string_buffer(64, |buffer, _| unsafe {
-            let mut accel = accel.to_raw();
-            self.low.kbd_formatKeyName(&mut accel as *mut _, buffer)
-        });
-        key_string
+        let raw = self.low.CSurf_OnVolumeChangeEx(
+            track.as_ptr(),
+            value_change.value(),
+            value_change.is_relative(),
+            gang_behavior == GangBehavior::AllowGang,
+        );
+        ReaperVolumeValue::new(raw)
     }
 
-    /// Returns the project recording path.
+    /// Informs control surfaces that the given track's pan has been changed.
     ///
-    /// With `buffer_size` you can tell REAPER how many bytes of the resulting path you want.
+    /// Doesn't actually change the pan.
     ///
-    /// # Panics
+    /// # Safety
     ///
-    /// Panics if the given buffer size is 0.
-    pub fn get_project_path_ex(&self, project: ProjectContext, buffer_size: u32) -> PathBuf
-    where
+    /// REAPER can crash if you pass an invalid track or an invalid control surface.
+    pub unsafe fn csurf_set_surface_pan(
+        &self,
+        track: MediaTrack,
+        pan: ReaperPanValue,
+        notification_behavior: NotificationBehavior,
+    ) where
         UsageScope: MainThreadOnly,
     {
-        self.require_valid_project(project);
-        unsafe { self.get_project_path_ex_unchecked(project, buffer_size) }
+        self.require_main_thread();
+        self.low
+            .CSurf_SetSurfacePan(track.as_ptr(), pan.get(), notification_behavior.to_raw());
     }
 
-    /// Like [`get_project_path_ex()`] but doesn't check if project is valid.
+    /// Sets the given track's pan. Also supports relative changes and gang.
     ///
-    /// # Safety
+    /// Returns the value that has actually been set.
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// # Safety
     ///
-    /// [`get_project_path_ex()`]: #method.get_project_path_ex
-    pub unsafe fn get_project_path_ex_unchecked(
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn csurf_on_pan_change_ex(
         &self,
-        project: ProjectContext,
-        buffer_size: u32,
-    ) -> PathBuf
+        track: MediaTrack,
+        value_change: ValueChange<ReaperPanValue>,
+        gang_behavior: GangBehavior,
+    ) -> ReaperPanValue
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let (reaper_string, _) = with_string_buffer(buffer_size, |buffer, max_size| {
-            self.low
-                .GetProjectPathEx(project.to_raw(), buffer, max_size)
-        });
-        let owned_string = reaper_string.into_string();
-        PathBuf::from(owned_string)
+        let raw = self.low.CSurf_OnPanChangeEx(
+            track.as_ptr(),
+            value_change.value(),
+            value_change.is_relative(),
+            gang_behavior == GangBehavior::AllowGang,
+        );
+        ReaperPanValue::new(raw)
     }
 
-    /// Returns the master tempo of the current project.
-    pub fn master_get_tempo(&self) -> Bpm
+    /// Sets the given track's width. Also supports relative changes and gang.
+    ///
+    /// Returns the value that has actually been set.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn csurf_on_width_change_ex(
+        &self,
+        track: MediaTrack,
+        value_change: ValueChange<ReaperWidthValue>,
+        gang_behavior: GangBehavior,
+    ) -> ReaperWidthValue
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        Bpm(self.low.Master_GetTempo())
+        let raw = self.low.CSurf_OnWidthChangeEx(
+            track.as_ptr(),
+            value_change.value(),
+            value_change.is_relative(),
+            gang_behavior == GangBehavior::AllowGang,
+        );
+        ReaperWidthValue::new(raw)
     }
 
-    /// Sets the current tempo of the given project.
+    /// Counts the number of selected tracks in the given project.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn set_current_bpm(&self, project: ProjectContext, tempo: Bpm, undo_behavior: UndoBehavior)
+    pub fn count_selected_tracks_2(
+        &self,
+        project: ProjectContext,
+        master_track_behavior: MasterTrackBehavior,
+    ) -> u32
     where
         UsageScope: MainThreadOnly,
     {
+        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe {
-            self.set_current_bpm_unchecked(project, tempo, undo_behavior);
-        }
+        unsafe { self.count_selected_tracks_2_unchecked(project, master_track_behavior) }
     }
 
-    /// Like [`set_current_bpm()`] but doesn't check if project is valid.
+    /// Like [`count_selected_tracks_2()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`set_current_bpm()`]: #method.set_current_bpm
-    pub unsafe fn set_current_bpm_unchecked(
+    /// [`count_selected_tracks_2()`]: #method.count_selected_tracks_2
+    pub unsafe fn count_selected_tracks_2_unchecked(
         &self,
         project: ProjectContext,
-        tempo: Bpm,
-        undo_behavior: UndoBehavior,
-    ) where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        self.low.SetCurrentBPM(
-            project.to_raw(),
-            tempo.get(),
-            undo_behavior == UndoBehavior::AddUndoPoint,
-        );
-    }
-
-    /// Converts the given playback speed factor to a normalized play rate.
-    pub fn master_normalize_play_rate_normalize(
-        &self,
-        value: PlaybackSpeedFactor,
-    ) -> NormalizedPlayRate
+        master_track_behavior: MasterTrackBehavior,
+    ) -> u32
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let result = self.low.Master_NormalizePlayRate(value.get(), false);
-        NormalizedPlayRate::new(result)
+        self.low.CountSelectedTracks2(
+            project.to_raw(),
+            master_track_behavior == MasterTrackBehavior::IncludeMasterTrack,
+        ) as u32
     }
 
-    /// Converts the given normalized play rate to a playback speed factor.
-    pub fn master_normalize_play_rate_denormalize(
-        &self,
-        value: NormalizedPlayRate,
-    ) -> PlaybackSpeedFactor
+    /// Selects or deselects the given track.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn set_track_selected(&self, track: MediaTrack, is_selected: bool)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let result = self.low.Master_NormalizePlayRate(value.get(), true);
-        PlaybackSpeedFactor::new(result)
+        self.low.SetTrackSelected(track.as_ptr(), is_selected);
     }
 
-    /// Returns the master play rate of the given project.
+    /// Returns a selected track from the given project.
     ///
     /// # Panics
     ///
     /// Panics if the given project is not valid anymore.
-    pub fn master_get_play_rate(&self, project: ProjectContext) -> PlaybackSpeedFactor
+    pub fn get_selected_track_2(
+        &self,
+        project: ProjectContext,
+        selected_track_index: u32,
+        master_track_behavior: MasterTrackBehavior,
+    ) -> Option<MediaTrack>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
         self.require_valid_project(project);
-        unsafe { self.master_get_play_rate_unchecked(project) }
+        unsafe {
+            self.get_selected_track_2_unchecked(
+                project,
+                selected_track_index,
+                master_track_behavior,
+            )
+        }
     }
 
-    /// Like [`master_get_play_rate()`] but doesn't check if project is valid.
+    /// Like [`get_selected_track_2()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid project.
     ///
-    /// [`master_get_play_rate()`]: #method.master_get_play_rate
-    pub unsafe fn master_get_play_rate_unchecked(
+    /// [`get_selected_track_2()`]: #method.get_selected_track_2
+    pub unsafe fn get_selected_track_2_unchecked(
         &self,
         project: ProjectContext,
-    ) -> PlaybackSpeedFactor
-    where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        let raw = self.low.Master_GetPlayRate(project.to_raw());
-        PlaybackSpeedFactor(raw)
-    }
-
-    /// Sets the master play rate of the current project.
-    pub fn csurf_on_play_rate_change(&self, play_rate: PlaybackSpeedFactor) {
-        self.low.CSurf_OnPlayRateChange(play_rate.get());
-    }
-
-    /// Shows a message box to the user.
-    ///
-    /// Blocks the main thread.
-    pub fn show_message_box<'a>(
-        &self,
-        message: impl Into<ReaperStringArg<'a>>,
-        title: impl Into<ReaperStringArg<'a>>,
-        r#type: MessageBoxType,
-    ) -> MessageBoxResult
-    where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        let result = unsafe {
-            self.low.ShowMessageBox(
-                message.into().as_ptr(),
-                title.into().as_ptr(),
-                r#type.to_raw(),
-            )
-        };
-        MessageBoxResult::from_raw(result)
-    }
-
-    /// Displays a text close to the transport bar.
-    pub fn help_set<'a>(&self, message: impl Into<ReaperStringArg<'a>>, mode: HelpMode)
+        selected_track_index: u32,
+        master_track_behavior: MasterTrackBehavior,
+    ) -> Option<MediaTrack>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        unsafe { self.low.Help_Set(message.into().as_ptr(), mode.to_raw()) };
+        let ptr = self.low.GetSelectedTrack2(
+            project.to_raw(),
+            selected_track_index as i32,
+            master_track_behavior == MasterTrackBehavior::IncludeMasterTrack,
+        );
+        NonNull::new(ptr)
     }
 
-    /// Parses the given string as GUID.
+    /// Returns a selected item from the given project.
     ///
-    /// # Errors
+    /// # Panics
     ///
-    /// Returns an error if the given string is not a valid GUID string.
-    pub fn string_to_guid<'a>(
+    /// Panics if the given project is not valid anymore.
+    pub fn get_selected_media_item(
         &self,
-        guid_string: impl Into<ReaperStringArg<'a>>,
-    ) -> ReaperFunctionResult<GUID>
+        project: ProjectContext,
+        selected_item_index: u32,
+    ) -> Option<MediaItem>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_main_thread();
-        let mut guid = MaybeUninit::uninit();
-        unsafe {
-            self.low
-                .stringToGuid(guid_string.into().as_ptr(), guid.as_mut_ptr());
-        }
-        let guid = unsafe { guid.assume_init() };
-        if guid == ZERO_GUID {
-            return Err(ReaperFunctionError::new("GUID string is invalid"));
-        }
-        Ok(guid)
+        self.require_valid_project(project);
+        unsafe { self.get_selected_media_item_unchecked(project, selected_item_index) }
     }
 
-    /// Sets the input monitoring mode of the given track.
+    /// Like [`get_selected_media_item()`] but doesn't check if project is valid.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn csurf_on_input_monitoring_change_ex(
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_selected_media_item()`]: #method.get_selected_media_item
+    pub unsafe fn get_selected_media_item_unchecked(
         &self,
-        track: MediaTrack,
-        mode: InputMonitoringMode,
-        gang_behavior: GangBehavior,
-    ) -> i32
+        project: ProjectContext,
+        selected_item_index: u32,
+    ) -> Option<MediaItem>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.CSurf_OnInputMonitorChangeEx(
-            track.as_ptr(),
-            mode.to_raw(),
-            gang_behavior == GangBehavior::AllowGang,
-        )
+        let ptr = self
+            .low
+            .GetSelectedMediaItem(project.to_raw(), selected_item_index as i32);
+        NonNull::new(ptr)
     }
 
-    /// Scrolls the mixer so that the given track is the leftmost visible track.
-    ///
-    /// Returns the leftmost visible track after scrolling, which may be different from the given
-    /// track if there are not enough tracks to its right. Not exactly sure what it's supposed to
-    /// mean if this returns `None`, but it happens at times.
+    /// Returns the media source of the given media item take.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn set_mixer_scroll(&self, track: MediaTrack) -> Option<MediaTrack>
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn get_media_item_take_source(&self, take: MediaItemTake) -> Option<PcmSource>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.SetMixerScroll(track.as_ptr());
+        let ptr = self.low.GetMediaItemTake_Source(take.as_ptr());
         NonNull::new(ptr)
     }
 
-    /// Creates a new media item.
+    /// Returns the media item which contains the given take.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn add_media_item_to_track(
-        &self,
-        track: MediaTrack,
-    ) -> ReaperFunctionResult<MediaItem>
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn get_media_item_take_item(&self, take: MediaItemTake) -> Option<MediaItem>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.AddMediaItemToTrack(track.as_ptr());
-        NonNull::new(ptr).ok_or(ReaperFunctionError::new("couldn't add item to track"))
+        let ptr = self.low.GetMediaItemTake_Item(take.as_ptr());
+        NonNull::new(ptr)
     }
 
-    /// Deletes the given media item.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if not successful.
+    /// Returns the track which contains the given take.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track or item.
-    pub unsafe fn delete_track_media_item(
-        &self,
-        track: MediaTrack,
-        item: MediaItem,
-    ) -> ReaperFunctionResult<()>
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn get_media_item_take_track(&self, take: MediaItemTake) -> Option<MediaTrack>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let successful = self.low.DeleteTrackMediaItem(track.as_ptr(), item.as_ptr());
-        if !successful {
-            return Err(ReaperFunctionError::new(
-                "deletion of media item not successful",
-            ));
-        }
-        Ok(())
+        let ptr = self.low.GetMediaItemTake_Track(take.as_ptr());
+        NonNull::new(ptr)
     }
 
-    /// Creates a new take in an item.
+    /// Reads peaks of the given source into the given buffer.
     ///
-    /// # Safety
+    /// The buffer is filled with the maximum values of all channels (interleaved), followed by
+    /// the minimum values and, if `want_spectral_info` is `true`, the spectral information. So it
+    /// must have room for `channel_count * samples_per_channel` values times 2 (or 3 if spectral
+    /// information is requested).
     ///
-    /// REAPER can crash if you pass an invalid item.
-    pub unsafe fn add_take_to_media_item(
+    /// # Panics
+    ///
+    /// Panics if the channel count is 0 or if the buffer is too small.
+    #[allow(clippy::too_many_arguments)]
+    pub fn pcm_source_get_peaks(
         &self,
-        item: MediaItem,
-    ) -> ReaperFunctionResult<MediaItemTake>
+        source: &BorrowedPcmSource,
+        peak_rate: Hz,
+        start_time: PositionInSeconds,
+        channel_count: u32,
+        samples_per_channel: u32,
+        want_spectral_info: bool,
+        buffer: &mut [f64],
+    ) -> GetPeaksResult
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.AddTakeToMediaItem(item.as_ptr());
-        NonNull::new(ptr).ok_or(ReaperFunctionError::new("couldn't add take to item"))
+        check_peaks_buffer(
+            channel_count,
+            samples_per_channel,
+            want_spectral_info,
+            buffer,
+        );
+        let result = unsafe {
+            self.low.PCM_Source_GetPeaks(
+                source.as_ptr().as_ptr(),
+                peak_rate.get(),
+                start_time.get(),
+                channel_count as _,
+                samples_per_channel as _,
+                extra_type(want_spectral_info),
+                buffer.as_mut_ptr(),
+            )
+        };
+        GetPeaksResult::from_raw(result)
     }
 
-    /// Sets the position of the given item.
+    /// Reads peaks of the given take into the given buffer.
     ///
-    /// # Errors
+    /// See [`pcm_source_get_peaks()`] for the buffer layout. In contrast to it, the start time
+    /// is relative to the project (taking the item position, take offset and play rate into
+    /// account).
     ///
-    /// Returns an error if not successful.
+    /// # Panics
+    ///
+    /// Panics if the channel count is 0 or if the buffer is too small.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid item.
-    pub unsafe fn set_media_item_position(
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`pcm_source_get_peaks()`]: #method.pcm_source_get_peaks
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn get_media_item_take_peaks(
         &self,
-        item: MediaItem,
-        pos: PositionInSeconds,
-        refresh_behavior: UiRefreshBehavior,
-    ) -> ReaperFunctionResult<()>
+        take: MediaItemTake,
+        peak_rate: Hz,
+        start_time: PositionInSeconds,
+        channel_count: u32,
+        samples_per_channel: u32,
+        want_spectral_info: bool,
+        buffer: &mut [f64],
+    ) -> GetPeaksResult
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let successful = self.low.SetMediaItemPosition(
-            item.as_ptr(),
-            pos.get(),
-            refresh_behavior == UiRefreshBehavior::Refresh,
+        check_peaks_buffer(
+            channel_count,
+            samples_per_channel,
+            want_spectral_info,
+            buffer,
         );
-        if !successful {
-            return Err(ReaperFunctionError::new("couldn't set item position"));
-        }
-        Ok(())
+        let result = self.low.GetMediaItemTake_Peaks(
+            take.as_ptr(),
+            peak_rate.get(),
+            start_time.get(),
+            channel_count as _,
+            samples_per_channel as _,
+            extra_type(want_spectral_info),
+            buffer.as_mut_ptr(),
+        );
+        GetPeaksResult::from_raw(result)
     }
 
-    /// Sets the length of the given item.
-    ///
-    /// # Errors
+    /// Unstable!!!
     ///
-    /// Returns an error if not successful.
+    /// Returns the project which contains this item.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid item.
-    pub unsafe fn set_media_item_length(
-        &self,
-        item: MediaItem,
-        length: DurationInSeconds,
-        refresh_behavior: UiRefreshBehavior,
-    ) -> ReaperFunctionResult<()>
+    // TODO-high-unstable Can this EVER be None?
+    pub unsafe fn get_item_project_context(&self, item: MediaItem) -> Option<ReaProject>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let successful = self.low.SetMediaItemLength(
-            item.as_ptr(),
-            length.get(),
-            refresh_behavior == UiRefreshBehavior::Refresh,
-        );
-        if !successful {
-            return Err(ReaperFunctionError::new("couldn't set item length"));
-        }
-        Ok(())
+        let ptr = self.low.GetItemProjectContext(item.as_ptr());
+        NonNull::new(ptr)
     }
 
-    /// Selects or unselects the given media item.
+    /// Returns the active take in this item.
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid item.
-    pub unsafe fn set_media_item_selected(&self, item: MediaItem, selected: bool)
+    pub unsafe fn get_active_take(&self, item: MediaItem) -> Option<MediaItemTake>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.SetMediaItemSelected(item.as_ptr(), selected);
+        let ptr = self.low.GetActiveTake(item.as_ptr());
+        NonNull::new(ptr)
     }
 
-    /// Sets a track attribute as numerical value.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if an invalid (e.g. non-numerical) track attribute key is passed.
+    /// Returns the take that is currently being edited in the given MIDI editor.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn set_media_track_info_value(
+    /// REAPER can crash if you pass an invalid window.
+    pub unsafe fn midi_editor_get_take(
         &self,
-        track: MediaTrack,
-        attribute_key: TrackAttributeKey,
-        new_value: f64,
-    ) -> ReaperFunctionResult<()>
+        midi_editor: Hwnd,
+    ) -> ReaperFunctionResult<MediaItemTake>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let successful = self.low.SetMediaTrackInfo_Value(
-            track.as_ptr(),
-            attribute_key.into_raw().as_ptr(),
-            new_value,
-        );
-        if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't set track attribute (maybe attribute key is invalid)",
-            ));
-        }
-        Ok(())
+        let ptr = self.low.MIDIEditor_GetTake(midi_editor.as_ptr());
+        NonNull::new(ptr).ok_or(ReaperFunctionError::new("couldn't get MIDI editor take"))
     }
 
-    /// Sets a track track send, hardware output send or track receive attribute as numerical value.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if an invalid (e.g. non-numerical) attribute key is passed.
+    /// Returns whether the given take contains MIDI.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn set_track_send_info_value(
-        &self,
-        track: MediaTrack,
-        category: TrackSendCategory,
-        send_index: u32,
-        attribute_key: TrackSendAttributeKey,
-        new_value: f64,
-    ) -> ReaperFunctionResult<()>
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn take_is_midi(&self, take: MediaItemTake) -> bool
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let successful = self.low.SetTrackSendInfo_Value(
-            track.as_ptr(),
-            category.to_raw(),
-            send_index as i32,
-            attribute_key.into_raw().as_ptr(),
-            new_value,
-        );
-        if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't set track send attribute (maybe attribute key is invalid)",
-            ));
-        }
-        Ok(())
+        self.low.TakeIsMIDI(take.as_ptr())
     }
 
-    /// Stuffs a 3-byte MIDI message into a queue or send it to an external MIDI hardware.
-    ///
-    /// # Example
+    /// Converts the given project time to a MIDI tick position within the given take.
     ///
-    /// ```no_run
-    /// # let session = reaper_medium::ReaperSession::default();
-    /// use helgoboss_midi::test_util::note_on;
-    /// use reaper_medium::StuffMidiMessageTarget::VirtualMidiKeyboardQueue;
+    /// # Safety
     ///
-    /// session.reaper().stuff_midi_message(VirtualMidiKeyboardQueue, note_on(0, 64, 100));
-    /// ```
-    pub fn stuff_midi_message(&self, target: StuffMidiMessageTarget, message: impl ShortMessage)
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_get_ppq_pos_from_proj_time(
+        &self,
+        take: MediaItemTake,
+        time: PositionInSeconds,
+    ) -> PositionInPpq
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let bytes = message.to_bytes();
-        self.low.StuffMIDIMessage(
-            target.to_raw(),
-            bytes.0.into(),
-            bytes.1.into(),
-            bytes.2.into(),
-        );
-    }
-
-    /// Converts a decibel value into a volume slider value.
-    pub fn db2slider(&self, value: Db) -> VolumeSliderValue
-    where
-        UsageScope: AnyThread,
-    {
-        VolumeSliderValue(self.low.DB2SLIDER(value.get()))
-    }
-
-    /// Converts a volume slider value into a decibel value.
-    pub fn slider2db(&self, value: VolumeSliderValue) -> Db
-    where
-        UsageScope: AnyThread,
-    {
-        Db(self.low.SLIDER2DB(value.get()))
+        let ppq = self
+            .low
+            .MIDI_GetPPQPosFromProjTime(take.as_ptr(), time.get());
+        PositionInPpq::new(ppq)
     }
 
-    /// Returns the given track's volume and incomplete pan. Also returns the correct value during
-    /// the process of writing an automation envelope.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if not successful (unclear when this happens).
+    /// Converts the given MIDI tick position within the given take to a project time.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_track_ui_vol_pan(
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_get_proj_time_from_ppq_pos(
         &self,
-        track: MediaTrack,
-    ) -> ReaperFunctionResult<VolumeAndPan>
+        take: MediaItemTake,
+        ppq_pos: PositionInPpq,
+    ) -> PositionInSeconds
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        // We zero them just for being safe.
-        let mut volume = MaybeUninit::zeroed();
-        let mut pan = MaybeUninit::zeroed();
-        let successful =
-            self.low
-                .GetTrackUIVolPan(track.as_ptr(), volume.as_mut_ptr(), pan.as_mut_ptr());
-        if !successful {
-            return Err(ReaperFunctionError::new(
-                "couldn't get track volume and pan",
-            ));
-        }
-        Ok(VolumeAndPan {
-            volume: ReaperVolumeValue::new(volume.assume_init()),
-            pan: ReaperPanValue::new(pan.assume_init()),
-        })
+        let time = self
+            .low
+            .MIDI_GetProjTimeFromPPQPos(take.as_ptr(), ppq_pos.get());
+        PositionInSeconds::new(time)
     }
 
-    /// Returns the given track's mute state. Also returns the correct value during the process of
-    /// writing an automation envelope.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if not successful (unclear when this happens).
+    /// Returns the number of notes, CC events and text/sysex events in the given take.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_track_ui_mute(&self, track: MediaTrack) -> ReaperFunctionResult<bool>
-    where
-        UsageScope: MainThreadOnly,
-    {
-        self.require_main_thread();
-        // We zero them just for being safe.
-        let mut mute = MaybeUninit::zeroed();
-        let successful = self.low.GetTrackUIMute(track.as_ptr(), mute.as_mut_ptr());
-        if !successful {
-            return Err(ReaperFunctionError::new("couldn't get track mute"));
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_count_evts(&self, take: MediaItemTake) -> MidiCountEvtsResult
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let mut note_count = MaybeUninit::zeroed();
+        let mut cc_count = MaybeUninit::zeroed();
+        let mut text_sysex_count = MaybeUninit::zeroed();
+        self.low.MIDI_CountEvts(
+            take.as_ptr(),
+            note_count.as_mut_ptr(),
+            cc_count.as_mut_ptr(),
+            text_sysex_count.as_mut_ptr(),
+        );
+        MidiCountEvtsResult {
+            note_count: note_count.assume_init() as u32,
+            cc_count: cc_count.assume_init() as u32,
+            text_sysex_count: text_sysex_count.assume_init() as u32,
         }
-        Ok(mute.assume_init())
     }
 
-    /// Returns the given track's complete pan. Also returns the correct value during the process of
-    /// writing an automation envelope.
+    /// Returns the properties of the given MIDI note.
     ///
     /// # Errors
     ///
-    /// Returns an error if not successful (unclear when this happens).
+    /// Returns an error if the note doesn't exist.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn get_track_ui_pan(
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_get_note(
         &self,
-        track: MediaTrack,
-    ) -> ReaperFunctionResult<GetTrackUiPanResult>
+        take: MediaItemTake,
+        note_index: u32,
+    ) -> ReaperFunctionResult<MidiNote>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        // We zero them just for being safe.
-        let mut pan_1 = MaybeUninit::zeroed();
-        let mut pan_2 = MaybeUninit::zeroed();
-        let mut pan_mode = MaybeUninit::zeroed();
-        let successful = self.low.GetTrackUIPan(
-            track.as_ptr(),
-            pan_1.as_mut_ptr(),
-            pan_2.as_mut_ptr(),
-            pan_mode.as_mut_ptr(),
+        let mut selected = MaybeUninit::zeroed();
+        let mut muted = MaybeUninit::zeroed();
+        let mut start = MaybeUninit::zeroed();
+        let mut end = MaybeUninit::zeroed();
+        let mut channel = MaybeUninit::zeroed();
+        let mut key_number = MaybeUninit::zeroed();
+        let mut velocity = MaybeUninit::zeroed();
+        let successful = self.low.MIDI_GetNote(
+            take.as_ptr(),
+            note_index as i32,
+            selected.as_mut_ptr(),
+            muted.as_mut_ptr(),
+            start.as_mut_ptr(),
+            end.as_mut_ptr(),
+            channel.as_mut_ptr(),
+            key_number.as_mut_ptr(),
+            velocity.as_mut_ptr(),
         );
         if !successful {
-            return Err(ReaperFunctionError::new("couldn't get track pan"));
+            return Err(ReaperFunctionError::new(
+                "couldn't get MIDI note (probably note doesn't exist)",
+            ));
         }
-        let pan_mode = PanMode::from_raw(pan_mode.assume_init());
-        let res = GetTrackUiPanResult {
-            pan_mode,
-            pan_1: ReaperPanLikeValue(pan_1.assume_init()),
-            pan_2: ReaperPanLikeValue(pan_2.assume_init()),
+        let note = MidiNote {
+            selected: selected.assume_init(),
+            muted: muted.assume_init(),
+            start: PositionInPpq::new(start.assume_init()),
+            end: PositionInPpq::new(end.assume_init()),
+            channel: Channel::new(channel.assume_init() as u8),
+            key_number: KeyNumber::new(key_number.assume_init() as u8),
+            velocity: U7::new(velocity.assume_init() as u8),
         };
-        Ok(res)
+        Ok(note)
     }
 
-    /// Informs control surfaces that the given track's volume has changed.
+    /// Inserts a MIDI note into the given take.
     ///
-    /// Doesn't actually change the volume.
+    /// If you insert multiple notes at once, pass [`SortBehavior::DontSort`] and call
+    /// [`midi_sort()`] when done.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track or an invalid control surface.
-    pub unsafe fn csurf_set_surface_volume(
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`SortBehavior::DontSort`]: enum.SortBehavior.html#variant.DontSort
+    /// [`midi_sort()`]: #method.midi_sort
+    pub unsafe fn midi_insert_note(
         &self,
-        track: MediaTrack,
-        volume: ReaperVolumeValue,
-        notification_behavior: NotificationBehavior,
-    ) where
+        take: MediaItemTake,
+        note: MidiNote,
+        sort_behavior: SortBehavior,
+    ) -> ReaperFunctionResult<()>
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.CSurf_SetSurfaceVolume(
-            track.as_ptr(),
-            volume.get(),
-            notification_behavior.to_raw(),
+        let no_sort = sort_behavior == SortBehavior::DontSort;
+        let successful = self.low.MIDI_InsertNote(
+            take.as_ptr(),
+            note.selected,
+            note.muted,
+            note.start.get(),
+            note.end.get(),
+            note.channel.get() as i32,
+            note.key_number.get() as i32,
+            note.velocity.get() as i32,
+            &no_sort,
         );
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't insert MIDI note"));
+        }
+        Ok(())
     }
 
-    /// Sets the given track's volume, also supports relative changes and gang.
+    /// Overwrites the properties of the given MIDI note.
     ///
-    /// Returns the value that has actually been set. I think this only deviates if 0.0 is sent.
-    /// Then it returns a slightly higher value - the one which actually corresponds to -150 dB.
+    /// If you change multiple notes at once, pass [`SortBehavior::DontSort`] and call
+    /// [`midi_sort()`] when done. Moving the start positions of many notes at once is done more
+    /// safely by deleting and re-inserting them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (e.g. if the note doesn't exist).
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn csurf_on_volume_change_ex(
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`SortBehavior::DontSort`]: enum.SortBehavior.html#variant.DontSort
+    /// [`midi_sort()`]: #method.midi_sort
+    pub unsafe fn midi_set_note(
         &self,
-        track: MediaTrack,
-        value_change: ValueChange<ReaperVolumeValue>,
-        gang_behavior: GangBehavior,
-    ) -> ReaperVolumeValue
+        take: MediaItemTake,
+        note_index: u32,
+        note: MidiNote,
+        sort_behavior: SortBehavior,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let raw = self.low.CSurf_OnVolumeChangeEx(
-            track.as_ptr(),
-            value_change.value(),
-            value_change.is_relative(),
-            gang_behavior == GangBehavior::AllowGang,
+        let start = note.start.get();
+        let end = note.end.get();
+        let channel = note.channel.get() as i32;
+        let key_number = note.key_number.get() as i32;
+        let velocity = note.velocity.get() as i32;
+        let no_sort = sort_behavior == SortBehavior::DontSort;
+        let successful = self.low.MIDI_SetNote(
+            take.as_ptr(),
+            note_index as i32,
+            &note.selected,
+            &note.muted,
+            &start,
+            &end,
+            &channel,
+            &key_number,
+            &velocity,
+            &no_sort,
         );
-        ReaperVolumeValue::new(raw)
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set MIDI note (probably note doesn't exist)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Informs control surfaces that the given track's pan has been changed.
+    /// Deletes the given MIDI note.
     ///
-    /// Doesn't actually change the pan.
+    /// # Errors
+    ///
+    /// Returns an error if not successful (e.g. if the note doesn't exist).
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track or an invalid control surface.
-    pub unsafe fn csurf_set_surface_pan(
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_delete_note(
         &self,
-        track: MediaTrack,
-        pan: ReaperPanValue,
-        notification_behavior: NotificationBehavior,
-    ) where
+        take: MediaItemTake,
+        note_index: u32,
+    ) -> ReaperFunctionResult<()>
+    where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low
-            .CSurf_SetSurfacePan(track.as_ptr(), pan.get(), notification_behavior.to_raw());
+        let successful = self.low.MIDI_DeleteNote(take.as_ptr(), note_index as i32);
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't delete MIDI note (probably note doesn't exist)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Sets the given track's pan. Also supports relative changes and gang.
+    /// Returns the properties of the given MIDI CC event.
     ///
-    /// Returns the value that has actually been set.
+    /// # Errors
+    ///
+    /// Returns an error if the CC event doesn't exist.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn csurf_on_pan_change_ex(
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_get_cc(
         &self,
-        track: MediaTrack,
-        value_change: ValueChange<ReaperPanValue>,
-        gang_behavior: GangBehavior,
-    ) -> ReaperPanValue
+        take: MediaItemTake,
+        cc_index: u32,
+    ) -> ReaperFunctionResult<MidiCc>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let raw = self.low.CSurf_OnPanChangeEx(
-            track.as_ptr(),
-            value_change.value(),
-            value_change.is_relative(),
-            gang_behavior == GangBehavior::AllowGang,
+        let mut selected = MaybeUninit::zeroed();
+        let mut muted = MaybeUninit::zeroed();
+        let mut position = MaybeUninit::zeroed();
+        let mut channel_message = MaybeUninit::zeroed();
+        let mut channel = MaybeUninit::zeroed();
+        let mut data_byte_1 = MaybeUninit::zeroed();
+        let mut data_byte_2 = MaybeUninit::zeroed();
+        let successful = self.low.MIDI_GetCC(
+            take.as_ptr(),
+            cc_index as i32,
+            selected.as_mut_ptr(),
+            muted.as_mut_ptr(),
+            position.as_mut_ptr(),
+            channel_message.as_mut_ptr(),
+            channel.as_mut_ptr(),
+            data_byte_1.as_mut_ptr(),
+            data_byte_2.as_mut_ptr(),
         );
-        ReaperPanValue::new(raw)
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get MIDI CC event (probably event doesn't exist)",
+            ));
+        }
+        let cc = MidiCc {
+            selected: selected.assume_init(),
+            muted: muted.assume_init(),
+            position: PositionInPpq::new(position.assume_init()),
+            channel_message: channel_message.assume_init() as u8,
+            channel: Channel::new(channel.assume_init() as u8),
+            data_byte_1: U7::new(data_byte_1.assume_init() as u8),
+            data_byte_2: U7::new(data_byte_2.assume_init() as u8),
+        };
+        Ok(cc)
     }
 
-    /// Sets the given track's width. Also supports relative changes and gang.
+    /// Inserts a MIDI CC event into the given take.
     ///
-    /// Returns the value that has actually been set.
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn csurf_on_width_change_ex(
-        &self,
-        track: MediaTrack,
-        value_change: ValueChange<ReaperWidthValue>,
-        gang_behavior: GangBehavior,
-    ) -> ReaperWidthValue
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_insert_cc(&self, take: MediaItemTake, cc: MidiCc) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let raw = self.low.CSurf_OnWidthChangeEx(
-            track.as_ptr(),
-            value_change.value(),
-            value_change.is_relative(),
-            gang_behavior == GangBehavior::AllowGang,
+        let successful = self.low.MIDI_InsertCC(
+            take.as_ptr(),
+            cc.selected,
+            cc.muted,
+            cc.position.get(),
+            cc.channel_message as i32,
+            cc.channel.get() as i32,
+            cc.data_byte_1.get() as i32,
+            cc.data_byte_2.get() as i32,
         );
-        ReaperWidthValue::new(raw)
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't insert MIDI CC event"));
+        }
+        Ok(())
     }
 
-    /// Counts the number of selected tracks in the given project.
+    /// Overwrites the properties of the given MIDI CC event.
     ///
-    /// # Panics
+    /// If you change multiple events at once, pass [`SortBehavior::DontSort`] and call
+    /// [`midi_sort()`] when done.
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn count_selected_tracks_2(
+    /// # Errors
+    ///
+    /// Returns an error if not successful (e.g. if the CC event doesn't exist).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`SortBehavior::DontSort`]: enum.SortBehavior.html#variant.DontSort
+    /// [`midi_sort()`]: #method.midi_sort
+    pub unsafe fn midi_set_cc(
         &self,
-        project: ProjectContext,
-        master_track_behavior: MasterTrackBehavior,
-    ) -> u32
+        take: MediaItemTake,
+        cc_index: u32,
+        cc: MidiCc,
+        sort_behavior: SortBehavior,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.require_valid_project(project);
-        unsafe { self.count_selected_tracks_2_unchecked(project, master_track_behavior) }
+        let position = cc.position.get();
+        let channel_message = cc.channel_message as i32;
+        let channel = cc.channel.get() as i32;
+        let data_byte_1 = cc.data_byte_1.get() as i32;
+        let data_byte_2 = cc.data_byte_2.get() as i32;
+        let no_sort = sort_behavior == SortBehavior::DontSort;
+        let successful = self.low.MIDI_SetCC(
+            take.as_ptr(),
+            cc_index as i32,
+            &cc.selected,
+            &cc.muted,
+            &position,
+            &channel_message,
+            &channel,
+            &data_byte_1,
+            &data_byte_2,
+            &no_sort,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set MIDI CC event (probably event doesn't exist)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Like [`count_selected_tracks_2()`] but doesn't check if project is valid.
+    /// Deletes the given MIDI CC event.
     ///
-    /// # Safety
+    /// # Errors
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// Returns an error if not successful (e.g. if the CC event doesn't exist).
     ///
-    /// [`count_selected_tracks_2()`]: #method.count_selected_tracks_2
-    pub unsafe fn count_selected_tracks_2_unchecked(
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_delete_cc(
         &self,
-        project: ProjectContext,
-        master_track_behavior: MasterTrackBehavior,
-    ) -> u32
+        take: MediaItemTake,
+        cc_index: u32,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.CountSelectedTracks2(
-            project.to_raw(),
-            master_track_behavior == MasterTrackBehavior::IncludeMasterTrack,
-        ) as u32
+        let successful = self.low.MIDI_DeleteCC(take.as_ptr(), cc_index as i32);
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't delete MIDI CC event (probably event doesn't exist)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Selects or deselects the given track.
+    /// Returns the shape and Bézier tension of the given MIDI CC event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the CC event doesn't exist.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn set_track_selected(&self, track: MediaTrack, is_selected: bool)
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_get_cc_shape(
+        &self,
+        take: MediaItemTake,
+        cc_index: u32,
+    ) -> ReaperFunctionResult<MidiGetCcShapeResult>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        self.low.SetTrackSelected(track.as_ptr(), is_selected);
+        let mut shape = MaybeUninit::zeroed();
+        let mut bezier_tension = MaybeUninit::zeroed();
+        let successful = self.low.MIDI_GetCCShape(
+            take.as_ptr(),
+            cc_index as i32,
+            shape.as_mut_ptr(),
+            bezier_tension.as_mut_ptr(),
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get MIDI CC shape (probably event doesn't exist)",
+            ));
+        }
+        let result = MidiGetCcShapeResult {
+            shape: MidiCcShape::from_raw(shape.assume_init()),
+            bezier_tension: bezier_tension.assume_init(),
+        };
+        Ok(result)
     }
 
-    /// Returns a selected track from the given project.
+    /// Sets the shape and Bézier tension of the given MIDI CC event.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn get_selected_track_2(
+    /// Returns an error if not successful (e.g. if the CC event doesn't exist).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_set_cc_shape(
         &self,
-        project: ProjectContext,
-        selected_track_index: u32,
-        master_track_behavior: MasterTrackBehavior,
-    ) -> Option<MediaTrack>
+        take: MediaItemTake,
+        cc_index: u32,
+        shape: MidiCcShape,
+        bezier_tension: f64,
+        sort_behavior: SortBehavior,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_valid_project(project);
-        unsafe {
-            self.get_selected_track_2_unchecked(
-                project,
-                selected_track_index,
-                master_track_behavior,
-            )
+        self.require_main_thread();
+        let no_sort = sort_behavior == SortBehavior::DontSort;
+        let successful = self.low.MIDI_SetCCShape(
+            take.as_ptr(),
+            cc_index as i32,
+            shape.to_raw(),
+            bezier_tension,
+            &no_sort,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set MIDI CC shape (probably event doesn't exist)",
+            ));
         }
+        Ok(())
     }
 
-    /// Like [`get_selected_track_2()`] but doesn't check if project is valid.
+    /// Returns the properties of the given MIDI text or sysex event.
     ///
-    /// # Safety
+    /// With `buffer_size` you can tell REAPER how many bytes of the message you want. Sysex
+    /// messages don't include the bounding F0 and F7 bytes.
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// # Errors
     ///
-    /// [`get_selected_track_2()`]: #method.get_selected_track_2
-    pub unsafe fn get_selected_track_2_unchecked(
+    /// Returns an error if the event doesn't exist.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_get_text_sysex_evt(
         &self,
-        project: ProjectContext,
-        selected_track_index: u32,
-        master_track_behavior: MasterTrackBehavior,
-    ) -> Option<MediaTrack>
+        take: MediaItemTake,
+        event_index: u32,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<MidiTextSysexEvent>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.GetSelectedTrack2(
-            project.to_raw(),
-            selected_track_index as i32,
-            master_track_behavior == MasterTrackBehavior::IncludeMasterTrack,
-        );
-        NonNull::new(ptr)
+        let mut selected = MaybeUninit::zeroed();
+        let mut muted = MaybeUninit::zeroed();
+        let mut position = MaybeUninit::zeroed();
+        let mut event_type = MaybeUninit::zeroed();
+        let mut message_size = buffer_size as i32;
+        let (mut message, successful) = with_buffer(buffer_size, |buffer, _| {
+            self.low.MIDI_GetTextSysexEvt(
+                take.as_ptr(),
+                event_index as i32,
+                selected.as_mut_ptr(),
+                muted.as_mut_ptr(),
+                position.as_mut_ptr(),
+                event_type.as_mut_ptr(),
+                buffer,
+                &mut message_size,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't get MIDI text/sysex event (probably event doesn't exist)",
+            ));
+        }
+        message.truncate(message_size.max(0) as usize);
+        let event = MidiTextSysexEvent {
+            selected: selected.assume_init(),
+            muted: muted.assume_init(),
+            position: PositionInPpq::new(position.assume_init()),
+            event_type: MidiTextSysexEventType::from_raw(event_type.assume_init()),
+            message,
+        };
+        Ok(event)
     }
 
-    /// Returns a selected item from the given project.
+    /// Inserts a MIDI text or sysex event into the given take.
     ///
-    /// # Panics
+    /// Sysex messages must not include the bounding F0 and F7 bytes.
     ///
-    /// Panics if the given project is not valid anymore.
-    pub fn get_selected_media_item(
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid take.
+    pub unsafe fn midi_insert_text_sysex_evt(
         &self,
-        project: ProjectContext,
-        selected_item_index: u32,
-    ) -> Option<MediaItem>
+        take: MediaItemTake,
+        selected: bool,
+        muted: bool,
+        position: PositionInPpq,
+        event_type: MidiTextSysexEventType,
+        message: &[u8],
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
-        self.require_valid_project(project);
-        unsafe { self.get_selected_media_item_unchecked(project, selected_item_index) }
+        self.require_main_thread();
+        let successful = self.low.MIDI_InsertTextSysexEvt(
+            take.as_ptr(),
+            selected,
+            muted,
+            position.get(),
+            event_type.to_raw(),
+            message.as_ptr() as *const c_char,
+            message.len() as i32,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't insert MIDI text/sysex event",
+            ));
+        }
+        Ok(())
     }
 
-    /// Like [`get_selected_media_item()`] but doesn't check if project is valid.
+    /// Overwrites the properties of the given MIDI text or sysex event.
+    ///
+    /// If you change multiple events at once, pass [`SortBehavior::DontSort`] and call
+    /// [`midi_sort()`] when done.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (e.g. if the event doesn't exist).
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid project.
+    /// REAPER can crash if you pass an invalid take.
     ///
-    /// [`get_selected_media_item()`]: #method.get_selected_media_item
-    pub unsafe fn get_selected_media_item_unchecked(
+    /// [`SortBehavior::DontSort`]: enum.SortBehavior.html#variant.DontSort
+    /// [`midi_sort()`]: #method.midi_sort
+    pub unsafe fn midi_set_text_sysex_evt(
         &self,
-        project: ProjectContext,
-        selected_item_index: u32,
-    ) -> Option<MediaItem>
+        take: MediaItemTake,
+        event_index: u32,
+        event: &MidiTextSysexEvent,
+        sort_behavior: SortBehavior,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self
-            .low
-            .GetSelectedMediaItem(project.to_raw(), selected_item_index as i32);
-        NonNull::new(ptr)
+        let position = event.position.get();
+        let event_type = event.event_type.to_raw();
+        let no_sort = sort_behavior == SortBehavior::DontSort;
+        let successful = self.low.MIDI_SetTextSysexEvt(
+            take.as_ptr(),
+            event_index as i32,
+            &event.selected,
+            &event.muted,
+            &position,
+            &event_type,
+            event.message.as_ptr() as *const c_char,
+            event.message.len() as i32,
+            &no_sort,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set MIDI text/sysex event (probably event doesn't exist)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Returns the media source of the given media item take.
+    /// Deletes the given MIDI text or sysex event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (e.g. if the event doesn't exist).
     ///
     /// # Safety
     ///
     /// REAPER can crash if you pass an invalid take.
-    pub unsafe fn get_media_item_take_source(&self, take: MediaItemTake) -> Option<PcmSource>
+    pub unsafe fn midi_delete_text_sysex_evt(
+        &self,
+        take: MediaItemTake,
+        event_index: u32,
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.GetMediaItemTake_Source(take.as_ptr());
-        NonNull::new(ptr)
+        let successful = self
+            .low
+            .MIDI_DeleteTextSysexEvt(take.as_ptr(), event_index as i32);
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't delete MIDI text/sysex event (probably event doesn't exist)",
+            ));
+        }
+        Ok(())
     }
 
-    /// Unstable!!!
+    /// Sorts the MIDI events of the given take.
     ///
-    /// Returns the project which contains this item.
+    /// Call this after changing multiple events with [`SortBehavior::DontSort`].
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid item.
-    // TODO-high-unstable Can this EVER be None?
-    pub unsafe fn get_item_project_context(&self, item: MediaItem) -> Option<ReaProject>
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`SortBehavior::DontSort`]: enum.SortBehavior.html#variant.DontSort
+    pub unsafe fn midi_sort(&self, take: MediaItemTake)
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.GetItemProjectContext(item.as_ptr());
-        NonNull::new(ptr)
+        self.low.MIDI_Sort(take.as_ptr());
     }
 
-    /// Returns the active take in this item.
+    /// Returns all MIDI events of the given take as packed buffer.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes you want. Use
+    /// [`decode_packed_midi_events()`] to turn the buffer into typed events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (e.g. if the buffer is too small).
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid item.
-    pub unsafe fn get_active_take(&self, item: MediaItem) -> Option<MediaItemTake>
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`decode_packed_midi_events()`]: fn.decode_packed_midi_events.html
+    pub unsafe fn midi_get_all_evts(
+        &self,
+        take: MediaItemTake,
+        buffer_size: u32,
+    ) -> ReaperFunctionResult<Vec<u8>>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.GetActiveTake(item.as_ptr());
-        NonNull::new(ptr)
+        assert!(buffer_size > 0);
+        let mut size = buffer_size as i32;
+        let (mut buffer, successful) = with_buffer(buffer_size, |buffer, _| {
+            self.low.MIDI_GetAllEvts(take.as_ptr(), buffer, &mut size)
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't get MIDI events"));
+        }
+        buffer.truncate(size.max(0) as usize);
+        Ok(buffer)
     }
 
-    /// Returns the take that is currently being edited in the given MIDI editor.
+    /// Replaces all MIDI events of the given take with the ones in the given packed buffer.
+    ///
+    /// Use [`encode_packed_midi_events()`] to create the buffer from typed events.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful.
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid window.
-    pub unsafe fn midi_editor_get_take(
+    /// REAPER can crash if you pass an invalid take.
+    ///
+    /// [`encode_packed_midi_events()`]: fn.encode_packed_midi_events.html
+    pub unsafe fn midi_set_all_evts(
         &self,
-        midi_editor: Hwnd,
-    ) -> ReaperFunctionResult<MediaItemTake>
+        take: MediaItemTake,
+        buffer: &[u8],
+    ) -> ReaperFunctionResult<()>
     where
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let ptr = self.low.MIDIEditor_GetTake(midi_editor.as_ptr());
-        NonNull::new(ptr).ok_or(ReaperFunctionError::new("couldn't get MIDI editor take"))
+        let successful = self.low.MIDI_SetAllEvts(
+            take.as_ptr(),
+            buffer.as_ptr() as *const c_char,
+            buffer.len() as i32,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't set MIDI events"));
+        }
+        Ok(())
     }
 
     /// Selects exactly one track and deselects all others.
@@ -5421,6 +9463,34 @@ impl<UsageScope> Reaper<UsageScope> {
         self.low.SetOnlyTrackSelected(ptr);
     }
 
+    /// Moves all selected tracks of the current project so that they end up directly above the
+    /// track at the given index.
+    ///
+    /// Pass the track count as index to move the tracks to the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no tracks are selected.
+    pub fn reorder_selected_tracks(
+        &self,
+        before_track_index: u32,
+        behavior: ReorderTracksBehavior,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self
+            .low
+            .ReorderSelectedTracks(before_track_index as i32, behavior.to_raw());
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't reorder tracks (no tracks selected)",
+            ));
+        }
+        Ok(())
+    }
+
     /// Deletes the given track.
     ///
     /// # Safety
@@ -5476,6 +9546,33 @@ impl<UsageScope> Reaper<UsageScope> {
         )
     }
 
+    /// Convenience function which returns the envelope (`P_ENV`) of the given track send, hardware
+    /// output send or track receive.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn get_track_send_info_env(
+        &self,
+        track: MediaTrack,
+        category: TrackSendCategory,
+        send_index: u32,
+        env_chunk_name: EnvChunkName,
+    ) -> Option<TrackEnvelope>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = self.get_set_track_send_info(
+            track,
+            category,
+            send_index,
+            TrackSendAttributeKey::Env(env_chunk_name),
+            null_mut(),
+        ) as *mut raw::TrackEnvelope;
+        NonNull::new(ptr)
+    }
+
     /// Convenience function which returns the destination track (`P_SRCTRACK`) of the given track
     /// send or track receive.
     ///
@@ -5584,6 +9681,46 @@ impl<UsageScope> Reaper<UsageScope> {
         Ok(chunk_content)
     }
 
+    /// Returns the RPPXML state of the given item.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the chunk you want.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given buffer size is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (unclear when this happens).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn get_item_state_chunk(
+        &self,
+        item: MediaItem,
+        buffer_size: u32,
+        cache_hint: ChunkCacheHint,
+    ) -> ReaperFunctionResult<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let (chunk_content, successful) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low.GetItemStateChunk(
+                item.as_ptr(),
+                buffer,
+                max_size,
+                cache_hint == ChunkCacheHint::UndoMode,
+            )
+        });
+        if !successful {
+            return Err(ReaperFunctionError::new("couldn't get item chunk"));
+        }
+        Ok(chunk_content)
+    }
+
     /// Prompts the user for string values.
     ///
     /// If a caption begins with `*`, for example `*password`, the edit field will not display the
@@ -5769,7 +9906,39 @@ impl<UsageScope> Reaper<UsageScope> {
         )
     }
 
-    /// Sets the RPPXML state of the given track.
+    /// Sets the RPPXML state of the given track.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not successful (for example if the given chunk is not accepted).
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid track.
+    pub unsafe fn set_track_state_chunk<'a>(
+        &self,
+        track: MediaTrack,
+        chunk: impl Into<ReaperStringArg<'a>>,
+        cache_hint: ChunkCacheHint,
+    ) -> ReaperFunctionResult<()>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let successful = self.low.SetTrackStateChunk(
+            track.as_ptr(),
+            chunk.into().as_ptr(),
+            cache_hint == ChunkCacheHint::UndoMode,
+        );
+        if !successful {
+            return Err(ReaperFunctionError::new(
+                "couldn't set track chunk (maybe chunk was invalid)",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Sets the RPPXML state of the given item.
     ///
     /// # Errors
     ///
@@ -5777,10 +9946,10 @@ impl<UsageScope> Reaper<UsageScope> {
     ///
     /// # Safety
     ///
-    /// REAPER can crash if you pass an invalid track.
-    pub unsafe fn set_track_state_chunk<'a>(
+    /// REAPER can crash if you pass an invalid item.
+    pub unsafe fn set_item_state_chunk<'a>(
         &self,
-        track: MediaTrack,
+        item: MediaItem,
         chunk: impl Into<ReaperStringArg<'a>>,
         cache_hint: ChunkCacheHint,
     ) -> ReaperFunctionResult<()>
@@ -5788,14 +9957,14 @@ impl<UsageScope> Reaper<UsageScope> {
         UsageScope: MainThreadOnly,
     {
         self.require_main_thread();
-        let successful = self.low.SetTrackStateChunk(
-            track.as_ptr(),
+        let successful = self.low.SetItemStateChunk(
+            item.as_ptr(),
             chunk.into().as_ptr(),
             cache_hint == ChunkCacheHint::UndoMode,
         );
         if !successful {
             return Err(ReaperFunctionError::new(
-                "couldn't set track chunk (maybe chunk was invalid)",
+                "couldn't set item chunk (maybe chunk was invalid)",
             ));
         }
         Ok(())
@@ -6012,6 +10181,291 @@ impl<UsageScope> Reaper<UsageScope> {
         use_resource_path(path)
     }
 
+    /// Sets a global extension state value.
+    ///
+    /// If `persist` is `true`, the value is saved to `reaper-extstate.ini` and restored the next
+    /// time REAPER starts. The value must not contain line breaks.
+    pub fn set_ext_state<'a>(
+        &self,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+        value: impl Into<ReaperStringArg<'a>>,
+        persist: bool,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        unsafe {
+            self.low.SetExtState(
+                section.into().as_ptr(),
+                key.into().as_ptr(),
+                value.into().as_ptr(),
+                persist,
+            );
+        }
+    }
+
+    /// Grants temporary access to a global extension state value.
+    ///
+    /// Passes `None` if there's no value for the given key (REAPER doesn't distinguish between
+    /// a missing and an empty value here, use [`has_ext_state()`] for that).
+    ///
+    /// [`has_ext_state()`]: #method.has_ext_state
+    pub fn get_ext_state<'a, R>(
+        &self,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+        use_value: impl FnOnce(Option<&ReaperStr>) -> R,
+    ) -> R
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        let ptr = unsafe {
+            self.low
+                .GetExtState(section.into().as_ptr(), key.into().as_ptr())
+        };
+        let value = unsafe { create_passing_c_str(ptr) };
+        use_value(value.filter(|v| !v.as_c_str().to_bytes().is_empty()))
+    }
+
+    /// Returns whether there's a global extension state value for the given key.
+    pub fn has_ext_state<'a>(
+        &self,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+    ) -> bool
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        unsafe {
+            self.low
+                .HasExtState(section.into().as_ptr(), key.into().as_ptr())
+        }
+    }
+
+    /// Deletes a global extension state value.
+    ///
+    /// If `persist` is `true`, the value is also removed from `reaper-extstate.ini`.
+    pub fn delete_ext_state<'a>(
+        &self,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+        persist: bool,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        unsafe {
+            self.low
+                .DeleteExtState(section.into().as_ptr(), key.into().as_ptr(), persist);
+        }
+    }
+
+    /// Sets an extension state value which is saved with the given project.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn set_proj_ext_state<'a>(
+        &self,
+        project: ProjectContext,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+        value: impl Into<ReaperStringArg<'a>>,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.set_proj_ext_state_unchecked(project, section, key, value) }
+    }
+
+    /// Like [`set_proj_ext_state()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`set_proj_ext_state()`]: #method.set_proj_ext_state
+    pub unsafe fn set_proj_ext_state_unchecked<'a>(
+        &self,
+        project: ProjectContext,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+        value: impl Into<ReaperStringArg<'a>>,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.SetProjExtState(
+            project.to_raw(),
+            section.into().as_ptr(),
+            key.into().as_ptr(),
+            value.into().as_ptr(),
+        );
+    }
+
+    /// Deletes a project extension state value.
+    ///
+    /// If `key` is `None`, all values of the given section are deleted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore.
+    pub fn delete_proj_ext_state<'a>(
+        &self,
+        project: ProjectContext,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: Option<ReaperStringArg<'a>>,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.delete_proj_ext_state_unchecked(project, section, key) }
+    }
+
+    /// Like [`delete_proj_ext_state()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`delete_proj_ext_state()`]: #method.delete_proj_ext_state
+    pub unsafe fn delete_proj_ext_state_unchecked<'a>(
+        &self,
+        project: ProjectContext,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: Option<ReaperStringArg<'a>>,
+    ) where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        self.low.SetProjExtState(
+            project.to_raw(),
+            section.into().as_ptr(),
+            key.as_ref().map(|k| k.as_ptr()).unwrap_or(null()),
+            null(),
+        );
+    }
+
+    /// Returns a project extension state value.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the value you want. Returns `None`
+    /// if there's no value for the given key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore or if the given buffer size is 0.
+    pub fn get_proj_ext_state<'a>(
+        &self,
+        project: ProjectContext,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+        buffer_size: u32,
+    ) -> Option<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.get_proj_ext_state_unchecked(project, section, key, buffer_size) }
+    }
+
+    /// Like [`get_proj_ext_state()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`get_proj_ext_state()`]: #method.get_proj_ext_state
+    pub unsafe fn get_proj_ext_state_unchecked<'a>(
+        &self,
+        project: ProjectContext,
+        section: impl Into<ReaperStringArg<'a>>,
+        key: impl Into<ReaperStringArg<'a>>,
+        buffer_size: u32,
+    ) -> Option<ReaperString>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let (value, length) = with_string_buffer(buffer_size, |buffer, max_size| {
+            self.low.GetProjExtState(
+                project.to_raw(),
+                section.into().as_ptr(),
+                key.into().as_ptr(),
+                buffer,
+                max_size,
+            )
+        });
+        if length <= 0 {
+            return None;
+        }
+        Some(value)
+    }
+
+    /// Returns the key and value of the project extension state entry at the given index.
+    ///
+    /// With `buffer_size` you can tell REAPER how many bytes of the key and value you want.
+    /// Returns `None` if there's no entry at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given project is not valid anymore or if the given buffer size is 0.
+    pub fn enum_proj_ext_state<'a>(
+        &self,
+        project: ProjectContext,
+        section: impl Into<ReaperStringArg<'a>>,
+        index: u32,
+        buffer_size: u32,
+    ) -> Option<EnumProjExtStateResult>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_valid_project(project);
+        unsafe { self.enum_proj_ext_state_unchecked(project, section, index, buffer_size) }
+    }
+
+    /// Like [`enum_proj_ext_state()`] but doesn't check if project is valid.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid project.
+    ///
+    /// [`enum_proj_ext_state()`]: #method.enum_proj_ext_state
+    pub unsafe fn enum_proj_ext_state_unchecked<'a>(
+        &self,
+        project: ProjectContext,
+        section: impl Into<ReaperStringArg<'a>>,
+        index: u32,
+        buffer_size: u32,
+    ) -> Option<EnumProjExtStateResult>
+    where
+        UsageScope: MainThreadOnly,
+    {
+        self.require_main_thread();
+        assert!(buffer_size > 0);
+        let section = section.into();
+        let (key, (value, successful)) = with_string_buffer(buffer_size, |key_buffer, max_size| {
+            with_string_buffer(buffer_size, |value_buffer, _| {
+                self.low.EnumProjExtState(
+                    project.to_raw(),
+                    section.as_ptr(),
+                    index as _,
+                    key_buffer,
+                    max_size,
+                    value_buffer,
+                    max_size,
+                )
+            })
+        });
+        if !successful {
+            return None;
+        }
+        Some(EnumProjExtStateResult { key, value })
+    }
+
     /// Grants temporary access to the name of the given take.
     ///
     /// # Error
@@ -6780,6 +11234,103 @@ pub struct GetParamExResult {
     pub max_value: f64,
 }
 
+/// Number of audio pins of an FX.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GetIoSizeResult {
+    /// Number of input pins.
+    pub input_pin_count: u32,
+    /// Number of output pins.
+    pub output_pin_count: u32,
+}
+
+/// Attributes of an envelope point.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct EnvelopePoint {
+    /// Position of the point.
+    pub time: PositionInSeconds,
+    /// Raw value (not scaled according to the envelope scaling mode).
+    pub value: f64,
+    /// Shape of the curve to the next point.
+    pub shape: EnvelopePointShape,
+    /// Tension of the curve (-1.0 to 1.0), only relevant for Bézier shape.
+    pub tension: f64,
+    /// Whether the point is selected.
+    pub selected: bool,
+}
+
+/// The effective value of an envelope at a certain position and how it's going to change.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct EnvelopeEvaluateResult {
+    /// Effective envelope value.
+    pub value: f64,
+    /// Change in value per sample.
+    pub first_derivative: f64,
+    /// Second derivative.
+    pub second_derivative: f64,
+    /// Third derivative.
+    pub third_derivative: f64,
+    /// Number of samples beyond the given position for which the returned values are valid.
+    pub valid_sample_count: u32,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MidiCountEvtsResult {
+    /// Number of notes.
+    pub note_count: u32,
+    /// Number of CC events.
+    pub cc_count: u32,
+    /// Number of text and sysex events.
+    pub text_sysex_count: u32,
+}
+
+/// Properties of a MIDI note within a take.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MidiNote {
+    pub selected: bool,
+    pub muted: bool,
+    /// Position of the note-on event.
+    pub start: PositionInPpq,
+    /// Position of the note-off event.
+    pub end: PositionInPpq,
+    pub channel: Channel,
+    pub key_number: KeyNumber,
+    pub velocity: U7,
+}
+
+/// Properties of a MIDI CC event within a take.
+///
+/// Despite the name, this also covers other channel messages such as program changes or pitch
+/// bend.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MidiCc {
+    pub selected: bool,
+    pub muted: bool,
+    pub position: PositionInPpq,
+    /// Status byte without channel, e.g. `0xB0` for control change or `0xE0` for pitch bend.
+    pub channel_message: u8,
+    pub channel: Channel,
+    pub data_byte_1: U7,
+    pub data_byte_2: U7,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MidiGetCcShapeResult {
+    pub shape: MidiCcShape,
+    /// Bézier tension (-1.0 to 1.0), only relevant for Bézier shape.
+    pub bezier_tension: f64,
+}
+
+/// Properties of a MIDI text or sysex event within a take.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MidiTextSysexEvent {
+    pub selected: bool,
+    pub muted: bool,
+    pub position: PositionInPpq,
+    pub event_type: MidiTextSysexEventType,
+    /// Message bytes (sysex without the bounding F0 and F7 bytes).
+    pub message: Vec<u8>,
+}
+
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub struct EnumProjectsResult {
     /// Project pointer.
@@ -6807,6 +11358,18 @@ pub enum EnumPitchShiftModesResult<'a> {
     },
 }
 
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct EnumInstalledFxResult<'a> {
+    /// Name including type prefix and vendor, e.g. "VST: ReaEQ (Cockos)".
+    ///
+    /// This can be passed to [`track_fx_add_by_name_add()`].
+    ///
+    /// [`track_fx_add_by_name_add()`]: struct.Reaper.html#method.track_fx_add_by_name_add
+    pub name: &'a ReaperStr,
+    /// Identifier of the plug-in (e.g. file name of a VST2 plug-in).
+    pub ident: &'a ReaperStr,
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct GetInputOutputLatencyResult {
     pub input_latency: u32,
@@ -6918,6 +11481,59 @@ pub struct TimeMapGetMeasureInfoResult {
     pub tempo: Bpm,
 }
 
+/// A tempo/time signature marker.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TempoTimeSigMarker {
+    /// Position of the marker in seconds.
+    pub position: PositionInSeconds,
+    /// Index of the measure in which the marker is located.
+    pub measure_index: i32,
+    /// Position of the marker in beats within that measure.
+    pub beats_since_measure: PositionInBeats,
+    /// Tempo starting at this marker.
+    pub tempo: Bpm,
+    /// New time signature or `None` if this marker doesn't change the time signature.
+    pub time_signature: Option<TimeSignature>,
+    /// Whether the tempo changes gradually until the next marker.
+    pub linear_tempo: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct EnumProjExtStateResult {
+    pub key: ReaperString,
+    pub value: ReaperString,
+}
+
+/// Defines how to open a project file.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct OpenProjectBehavior {
+    /// Whether to prompt the user to save unsaved changes of the current project first.
+    pub prompt: bool,
+    /// Whether to load the project file as template.
+    pub open_as_template: bool,
+}
+
+/// The result of reading peaks.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GetPeaksResult {
+    /// Number of peaks per channel which have actually been written.
+    pub sample_count: u32,
+    /// Output mode (0 → peaks, 1 → waveform, 2 → MIDI note, ...).
+    pub output_mode: u32,
+    /// Whether spectral information has been written.
+    pub has_spectral_info: bool,
+}
+
+impl GetPeaksResult {
+    fn from_raw(raw: i32) -> GetPeaksResult {
+        GetPeaksResult {
+            sample_count: (raw & 0xfffff) as u32,
+            output_mode: ((raw >> 20) & 0xf) as u32,
+            has_spectral_info: raw & 0x1000000 != 0,
+        }
+    }
+}
+
 /// Time signature.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct TimeSignature {
@@ -7108,3 +11724,47 @@ mod private {
     impl Sealed for MainThreadScope {}
     impl Sealed for RealTimeAudioThreadScope {}
 }
+
+/// REAPER expects the "custom color" flag to be set for marker/region colors, 0 means default.
+fn bookmark_color_to_raw(color: Option<NativeColor>) -> i32 {
+    color.map(|c| c.to_raw() | 0x1000000).unwrap_or(0)
+}
+
+/// Makes REAPER clear the name if the given name is empty (instead of leaving it unchanged).
+fn bookmark_name_flags(name: &ReaperStringArg) -> i32 {
+    if name.as_reaper_str().as_c_str().to_bytes().is_empty() {
+        1
+    } else {
+        0
+    }
+}
+
+/// REAPER expects 0 as numerator and denominator if the time signature shouldn't change.
+fn time_signature_to_raw(time_signature: Option<TimeSignature>) -> (i32, i32) {
+    match time_signature {
+        None => (0, 0),
+        Some(s) => (s.numerator.get() as _, s.denominator.get() as _),
+    }
+}
+
+fn check_peaks_buffer(
+    channel_count: u32,
+    samples_per_channel: u32,
+    want_spectral_info: bool,
+    buffer: &[f64],
+) {
+    assert!(channel_count > 0, "channel count must be greater than 0");
+    let block_count = if want_spectral_info { 3 } else { 2 };
+    assert!(
+        buffer.len() >= (channel_count * samples_per_channel * block_count) as usize,
+        "buffer too small"
+    );
+}
+
+fn extra_type(want_spectral_info: bool) -> i32 {
+    if want_spectral_info {
+        b's' as i32
+    } else {
+        0
+    }
+}
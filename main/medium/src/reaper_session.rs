@@ -9,14 +9,16 @@ use crate::keeper::{Keeper, SharedKeeper};
 
 use crate::{
     concat_reaper_strs, delegating_hook_command, delegating_hook_command_2,
-    delegating_hook_post_command, delegating_hook_post_command_2, delegating_toggle_action,
-    AcceleratorPosition, BufferingBehavior, CommandId, ControlSurface, ControlSurfaceAdapter,
-    HookCommand, HookCommand2, HookPostCommand, HookPostCommand2, MainThreadScope,
-    MeasureAlignment, OnAudioBuffer, OwnedAcceleratorRegister, OwnedAudioHookRegister,
-    OwnedGaccelRegister, OwnedPreviewRegister, PluginRegistration, ProjectContext,
-    RealTimeAudioThreadScope, Reaper, ReaperFunctionError, ReaperFunctionResult, ReaperMutex,
-    ReaperString, ReaperStringArg, RegistrationHandle, RegistrationObject, ToggleAction,
-    TranslateAccel,
+    delegating_hook_custom_menu, delegating_hook_post_command, delegating_hook_post_command_2,
+    delegating_toggle_action, remember_reaper_version, AcceleratorPosition, BufferingBehavior,
+    CommandId, ControlSurface, ControlSurfaceAdapter, CustomActionHandle, HookCommand,
+    HookCommand2, HookCustomMenu, HookPostCommand, HookPostCommand2, MainThreadScope,
+    MeasureAlignment, OnAudioBuffer, OwnedAcceleratorRegister, OwnedActionHelpRegister,
+    OwnedAudioHookRegister, OwnedCsurfRegister, OwnedCustomActionRegister, OwnedGaccelRegister,
+    OwnedPreviewRegister, OwnedProjectConfigExtension, PluginRegistration, ProjectConfigExtension,
+    ProjectContext, RealTimeAudioThreadScope, Reaper, ReaperFunctionError, ReaperFunctionResult,
+    ReaperMutex, ReaperString, ReaperStringArg, RegistrationHandle, RegistrationObject,
+    ToggleAction, TranslateAccel,
 };
 use reaper_low::raw::audio_hook_register_t;
 
@@ -65,8 +67,16 @@ pub struct ReaperSession {
     reaper: Reaper<MainThreadScope>,
     /// Provides a safe place in memory for registered actions.
     gaccel_registers: Keeper<OwnedGaccelRegister, raw::gaccel_register_t>,
+    /// Provides a safe place in memory for registered actions of arbitrary sections.
+    custom_action_registers: Keeper<OwnedCustomActionRegister, raw::custom_action_register_t>,
+    /// Provides a safe place in memory for registered action help texts.
+    action_help_registers: Keeper<OwnedActionHelpRegister, raw::action_help_t>,
+    /// Provides a safe place in memory for registered control surface types.
+    csurf_registers: Keeper<OwnedCsurfRegister, raw::reaper_csurf_reg_t>,
     /// Provides a safe place in memory for accelerator registers.
     accelerator_registers: Keeper<OwnedAcceleratorRegister, raw::accelerator_register_t>,
+    /// Provides a safe place in memory for project config extensions.
+    project_config_extensions: Keeper<OwnedProjectConfigExtension, raw::project_config_extension_t>,
     /// Provides a safe place in memory for currently playing preview registers.
     preview_registers: SharedKeeper<ReaperMutex<OwnedPreviewRegister>, raw::preview_register_t>,
     /// Provides a safe place in memory for command names used in command ID registrations.
@@ -111,7 +121,11 @@ impl ReaperSession {
         ReaperSession {
             reaper: Reaper::new(low),
             gaccel_registers: Default::default(),
+            custom_action_registers: Default::default(),
+            action_help_registers: Default::default(),
+            csurf_registers: Default::default(),
             accelerator_registers: Default::default(),
+            project_config_extensions: Default::default(),
             preview_registers: Default::default(),
             command_names: Default::default(),
             api_defs: Default::default(),
@@ -162,7 +176,7 @@ impl ReaperSession {
     /// the safe convenience functions instead. They all start with `plugin_register_add_`.
     ///
     /// The meaning of the return value depends very much on the actual thing being registered. In
-    /// most cases it just returns 1. In any case, if it's not 0, *reaper-rs* translates this into
+    /// most cases it just returns 1. In any case, if it's 0, *reaper-rs* translates this into
     /// an error.
     ///
     /// Also see [`plugin_register_remove()`].
@@ -184,13 +198,16 @@ impl ReaperSession {
         object: RegistrationObject,
     ) -> ReaperFunctionResult<i32> {
         let reg = object.into_raw();
-        let key_ptr = reg.key.as_ptr();
-        let value = reg.value;
-        self.plugin_registrations.insert(reg);
-        let result = self.reaper.low().plugin_register(key_ptr, value);
+        let result = self
+            .reaper
+            .low()
+            .plugin_register(reg.key.as_ptr(), reg.value);
         if result == 0 {
             return Err(ReaperFunctionError::new("couldn't register thing"));
         }
+        // Only remember successful registrations. Otherwise we would unregister a pointer on drop
+        // which the caller might have freed already.
+        self.plugin_registrations.insert(reg);
         Ok(result)
     }
 
@@ -343,6 +360,43 @@ impl ReaperSession {
         }
     }
 
+    /// Registers a custom menu hook.
+    ///
+    /// REAPER calls custom menu hooks whenever a customizable menu (see
+    /// [`add_customizable_menu()`]) or the *Extensions* main menu (see
+    /// [`add_extensions_main_menu()`]) is initialized or about to be shown. Use [`SwellMenu`] to
+    /// add items to the menu.
+    ///
+    /// See [`plugin_register_add_hook_command()`](#method.plugin_register_add_hook_command) for an
+    /// example.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed.
+    ///
+    /// [`add_customizable_menu()`]: struct.Reaper.html#method.add_customizable_menu
+    /// [`add_extensions_main_menu()`]: struct.Reaper.html#method.add_extensions_main_menu
+    /// [`SwellMenu`]: struct.SwellMenu.html
+    pub fn plugin_register_add_hook_custom_menu<T: HookCustomMenu>(
+        &mut self,
+    ) -> ReaperFunctionResult<()> {
+        unsafe {
+            self.plugin_register_add(RegistrationObject::HookCustomMenu(
+                delegating_hook_custom_menu::<T>,
+            ))?
+        };
+        Ok(())
+    }
+
+    /// Unregisters a custom menu hook.
+    pub fn plugin_register_remove_hook_custom_menu<T: HookCustomMenu>(&mut self) {
+        unsafe {
+            self.plugin_register_remove(RegistrationObject::HookCustomMenu(
+                delegating_hook_custom_menu::<T>,
+            ));
+        }
+    }
+
     /// Registers a hook post command.
     ///
     /// REAPER calls hook post commands whenever a normal action of the main section has been
@@ -537,6 +591,62 @@ impl ReaperSession {
         Ok(handle)
     }
 
+    /// Registers an action into an arbitrary section, e.g. the MIDI editor.
+    ///
+    /// In contrast to [`plugin_register_add_gaccel()`], REAPER assigns the command ID itself. It's
+    /// available via the returned handle, which you can also use to unregister the action via
+    /// [`plugin_register_remove_custom_action()`]. The code to be executed when the action runs
+    /// must be registered via [`plugin_register_add_hook_command_2()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed, e.g. because the command name is not unique.
+    ///
+    /// [`plugin_register_add_gaccel()`]: #method.plugin_register_add_gaccel
+    /// [`plugin_register_remove_custom_action()`]: #method.plugin_register_remove_custom_action
+    /// [`plugin_register_add_hook_command_2()`]: #method.plugin_register_add_hook_command_2
+    pub fn plugin_register_add_custom_action(
+        &mut self,
+        register: OwnedCustomActionRegister,
+    ) -> ReaperFunctionResult<CustomActionHandle> {
+        let handle = self.custom_action_registers.keep(register);
+        let result = unsafe { self.plugin_register_add(RegistrationObject::CustomAction(handle)) };
+        match result {
+            Ok(raw_id) => Ok(CustomActionHandle::new(CommandId(raw_id as _), handle)),
+            Err(e) => {
+                // REAPER doesn't know the register, so it's safe to free it
+                self.custom_action_registers.release(handle);
+                Err(e)
+            }
+        }
+    }
+
+    /// Registers help text for an action.
+    ///
+    /// REAPER shows it in the action list. The action is identified by its description.
+    ///
+    /// This function returns a handle which you can use to unregister the help text at any time
+    /// via [`plugin_register_remove_action_help()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed.
+    ///
+    /// [`plugin_register_remove_action_help()`]: #method.plugin_register_remove_action_help
+    pub fn plugin_register_add_action_help(
+        &mut self,
+        register: OwnedActionHelpRegister,
+    ) -> ReaperFunctionResult<NonNull<raw::action_help_t>> {
+        let handle = self.action_help_registers.keep(register);
+        if let Err(e) = unsafe { self.plugin_register_add(RegistrationObject::ActionHelp(handle)) }
+        {
+            // REAPER doesn't know the register, so it's safe to free it
+            self.action_help_registers.release(handle);
+            return Err(e);
+        }
+        Ok(handle)
+    }
+
     pub fn plugin_register_add_accelerator_register<T>(
         &mut self,
         callback: Box<T>,
@@ -564,6 +674,38 @@ impl ReaperSession {
         Ok(handle)
     }
 
+    /// Registers callbacks for storing custom state in the project file and in undo points.
+    ///
+    /// This function returns a handle which you can use to unregister the extension at any time
+    /// via [`plugin_register_remove_project_config()`]. Unregistering hands back ownership of the
+    /// callback struct.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed.
+    ///
+    /// [`plugin_register_remove_project_config()`]: #method.plugin_register_remove_project_config
+    pub fn plugin_register_add_project_config<T>(
+        &mut self,
+        callback: Box<T>,
+    ) -> ReaperFunctionResult<RegistrationHandle<T>>
+    where
+        T: ProjectConfigExtension + 'static,
+    {
+        let callback_thin_ptr: NonNull<T> = callback.as_ref().into();
+        let extension = OwnedProjectConfigExtension::new(callback);
+        let reaper_ptr = self.project_config_extensions.keep(extension);
+        if let Err(e) =
+            unsafe { self.plugin_register_add(RegistrationObject::ProjectConfig(reaper_ptr)) }
+        {
+            // REAPER doesn't know the extension, so it's safe to free it (including the callback)
+            self.project_config_extensions.release(reaper_ptr);
+            return Err(e);
+        }
+        let handle = RegistrationHandle::new(callback_thin_ptr, reaper_ptr.cast());
+        Ok(handle)
+    }
+
     /// Plays a preview register.
     ///
     /// # Errors
@@ -802,6 +944,30 @@ impl ReaperSession {
         unsafe { self.plugin_register_remove(RegistrationObject::Gaccel(handle)) };
     }
 
+    /// Unregisters the help text of an action.
+    pub fn plugin_register_remove_action_help(&mut self, handle: NonNull<raw::action_help_t>) {
+        unsafe { self.plugin_register_remove(RegistrationObject::ActionHelp(handle)) };
+        self.action_help_registers.release(handle);
+    }
+
+    /// Returns the help text descriptor which is registered with the given handle.
+    ///
+    /// Returns `None` if it has been unregistered already.
+    pub fn action_help_register(
+        &self,
+        handle: NonNull<raw::action_help_t>,
+    ) -> Option<&OwnedActionHelpRegister> {
+        self.action_help_registers.get(handle)
+    }
+
+    /// Unregisters an action of an arbitrary section.
+    pub fn plugin_register_remove_custom_action(&mut self, handle: CustomActionHandle) {
+        unsafe {
+            self.plugin_register_remove(RegistrationObject::CustomAction(handle.register()));
+        }
+        self.custom_action_registers.release(handle.register());
+    }
+
     pub fn plugin_register_remove_accelerator<T>(
         &mut self,
         handle: RegistrationHandle<T>,
@@ -829,6 +995,25 @@ impl ReaperSession {
         Some(callback)
     }
 
+    /// Unregisters project config extension callbacks.
+    ///
+    /// Hands ownership of the callback struct back to the consumer.
+    pub fn plugin_register_remove_project_config<T>(
+        &mut self,
+        handle: RegistrationHandle<T>,
+    ) -> Option<Box<T>>
+    where
+        T: ProjectConfigExtension,
+    {
+        let reaper_ptr = handle.reaper_ptr().cast();
+        unsafe { self.plugin_register_remove(RegistrationObject::ProjectConfig(reaper_ptr)) };
+        let extension = self.project_config_extensions.release(reaper_ptr)?;
+        // See plugin_register_remove_accelerator() for why we leak the trait object here.
+        Box::leak(extension.into_callback());
+        let callback = unsafe { handle.restore_original() };
+        Some(callback)
+    }
+
     /// Registers a hidden control surface.
     ///
     /// This is very useful for being notified by REAPER about all kinds of events in the main
@@ -888,6 +1073,44 @@ impl ReaperSession {
         Ok(handle)
     }
 
+    /// Registers a control surface type which users can select in *Preferences →
+    /// Control/OSC/web*.
+    ///
+    /// In contrast to [`plugin_register_add_csurf_inst()`], REAPER instantiates the control
+    /// surfaces itself (via [`ControlSurfaceType::create()`]), one for each instance configured
+    /// by the user. REAPER also owns and destroys these instances.
+    ///
+    /// This function returns a handle which you can use to unregister the type at any time via
+    /// [`plugin_register_remove_csurf()`]. This doesn't destroy existing instances, so registering
+    /// control surface types only makes sense in extension plug-ins, which live as long as REAPER.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registration failed.
+    ///
+    /// [`plugin_register_add_csurf_inst()`]: #method.plugin_register_add_csurf_inst
+    /// [`ControlSurfaceType::create()`]: trait.ControlSurfaceType.html#tymethod.create
+    /// [`plugin_register_remove_csurf()`]: #method.plugin_register_remove_csurf
+    pub fn plugin_register_add_csurf(
+        &mut self,
+        register: OwnedCsurfRegister,
+    ) -> ReaperFunctionResult<NonNull<raw::reaper_csurf_reg_t>> {
+        let handle = self.csurf_registers.keep(register);
+        if let Err(e) = unsafe { self.plugin_register_add(RegistrationObject::Csurf(handle)) } {
+            // REAPER doesn't know the register, so it's safe to free it
+            self.csurf_registers.release(handle);
+            return Err(e);
+        }
+        remember_reaper_version(self.reaper.get_app_version());
+        Ok(handle)
+    }
+
+    /// Unregisters a control surface type.
+    pub fn plugin_register_remove_csurf(&mut self, handle: NonNull<raw::reaper_csurf_reg_t>) {
+        unsafe { self.plugin_register_remove(RegistrationObject::Csurf(handle)) };
+        self.csurf_registers.release(handle);
+    }
+
     /// Unregisters a hidden control surface and hands ownership back to you.
     ///
     /// If the control surface is not registered, this function just returns `None`.
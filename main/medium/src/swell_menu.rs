@@ -0,0 +1,164 @@
+use crate::{CommandId, Hmenu, ReaperFunctionError, ReaperFunctionResult, ReaperStringArg};
+use reaper_low::{raw, Swell};
+use std::ptr::NonNull;
+
+/// Check and gray state of a menu item.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct MenuItemState {
+    /// Whether a check mark is shown next to the item.
+    pub checked: bool,
+    /// Whether the item is grayed out and can't be clicked.
+    pub grayed: bool,
+}
+
+impl MenuItemState {
+    fn to_raw(self) -> u32 {
+        let mut state = raw::MF_ENABLED;
+        if self.checked {
+            state |= raw::MF_CHECKED;
+        }
+        if self.grayed {
+            state |= raw::MF_GRAYED | raw::MF_DISABLED;
+        }
+        state
+    }
+}
+
+/// A menu to which items can be added.
+///
+/// This is a safe wrapper around SWELL's menu functions, mainly useful for populating menus
+/// within a [`HookCustomMenu`] callback. Items are always appended at the end of the menu.
+///
+/// [`HookCustomMenu`]: trait.HookCustomMenu.html
+#[derive(Copy, Clone, Debug)]
+pub struct SwellMenu<'a> {
+    swell: &'a Swell,
+    menu: Hmenu,
+}
+
+impl<'a> SwellMenu<'a> {
+    /// Wraps the given menu handle.
+    ///
+    /// # Safety
+    ///
+    /// REAPER can crash if you pass an invalid menu handle or if the menu is destroyed while
+    /// this wrapper is still in use.
+    pub unsafe fn new(swell: &'a Swell, menu: Hmenu) -> SwellMenu<'a> {
+        SwellMenu { swell, menu }
+    }
+
+    /// Returns the wrapped menu handle.
+    pub fn menu(&self) -> Hmenu {
+        self.menu
+    }
+
+    /// Returns the number of items in this menu (not counting items of submenus).
+    pub fn item_count(&self) -> u32 {
+        unsafe { self.swell.GetMenuItemCount(self.menu.as_ptr()) as u32 }
+    }
+
+    /// Adds an item which invokes the action with the given command ID when clicked.
+    pub fn add_action<'b>(&self, command_id: CommandId, label: impl Into<ReaperStringArg<'b>>) {
+        self.add_action_with_state(command_id, label, MenuItemState::default());
+    }
+
+    /// Adds an item which invokes the action with the given command ID when clicked and which
+    /// has the given initial check and gray state.
+    pub fn add_action_with_state<'b>(
+        &self,
+        command_id: CommandId,
+        label: impl Into<ReaperStringArg<'b>>,
+        state: MenuItemState,
+    ) {
+        let label = label.into();
+        let mut mi = raw::MENUITEMINFO {
+            fMask: raw::MIIM_TYPE | raw::MIIM_DATA | raw::MIIM_ID | raw::MIIM_STATE,
+            fType: raw::MF_STRING,
+            fState: state.to_raw(),
+            wID: command_id.get(),
+            dwTypeData: label.as_ptr() as _,
+            ..Default::default()
+        };
+        self.append(&mut mi);
+    }
+
+    /// Adds a separator.
+    pub fn add_separator(&self) {
+        let mut mi = raw::MENUITEMINFO {
+            fMask: raw::MIIM_TYPE,
+            fType: raw::MF_SEPARATOR,
+            ..Default::default()
+        };
+        self.append(&mut mi);
+    }
+
+    /// Adds a submenu and lets the given function populate it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the submenu couldn't be created.
+    pub fn add_submenu<'b>(
+        &self,
+        label: impl Into<ReaperStringArg<'b>>,
+        populate: impl FnOnce(SwellMenu),
+    ) -> ReaperFunctionResult<()> {
+        let submenu = NonNull::new(self.swell.CreatePopupMenu())
+            .ok_or_else(|| ReaperFunctionError::new("couldn't create submenu"))?;
+        populate(SwellMenu {
+            swell: self.swell,
+            menu: submenu,
+        });
+        let label = label.into();
+        // The parent menu takes ownership of the submenu.
+        let mut mi = raw::MENUITEMINFO {
+            fMask: raw::MIIM_TYPE | raw::MIIM_DATA | raw::MIIM_SUBMENU,
+            fType: raw::MF_STRING,
+            hSubMenu: submenu.as_ptr(),
+            dwTypeData: label.as_ptr() as _,
+            ..Default::default()
+        };
+        self.append(&mut mi);
+        Ok(())
+    }
+
+    /// Updates the check and gray state of the item bound to the given command ID.
+    ///
+    /// Items in submenus are found as well. Typically called when the menu is about to be shown.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this menu doesn't contain an item bound to the given command ID.
+    pub fn set_action_state(
+        &self,
+        command_id: CommandId,
+        state: MenuItemState,
+    ) -> ReaperFunctionResult<()> {
+        let mut mi = raw::MENUITEMINFO {
+            fMask: raw::MIIM_STATE,
+            fState: state.to_raw(),
+            ..Default::default()
+        };
+        let successful = unsafe {
+            self.swell.SetMenuItemInfo(
+                self.menu.as_ptr(),
+                command_id.get() as _,
+                0,
+                &mut mi as *mut _,
+            )
+        };
+        if successful == 0 {
+            return Err(ReaperFunctionError::new(
+                "menu doesn't contain an item for this command",
+            ));
+        }
+        Ok(())
+    }
+
+    fn append(&self, mi: &mut raw::MENUITEMINFO) {
+        mi.cbSize = std::mem::size_of::<raw::MENUITEMINFO>() as _;
+        unsafe {
+            self.swell
+                .InsertMenuItem(self.menu.as_ptr(), self.item_count() as _, 1, mi as *mut _);
+        }
+    }
+}
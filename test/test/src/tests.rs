@@ -3,13 +3,18 @@ use approx::*;
 
 use std::iter;
 use std::ops::Deref;
+use std::path::Path;
 
 use c_str_macro::c_str;
+use enumflags2::BitFlags;
 
 use reaper_high::{
-    get_media_track_guid, toggleable, ActionCharacter, ActionKind, FxChain, FxInfo,
-    FxParameterCharacter, Guid, Pan, PlayRate, Reaper, SendPartnerType, Tempo, Track,
-    TrackRoutePartner, Volume, Width,
+    get_media_track_guid, toggleable, ActionCharacter, ActionKind, AudioBlockSpec, BookmarkType,
+    ExtStateStore, FxChain, FxInfo, FxParameterCharacter, FxParameterLinkSource, Guid,
+    InstalledFxKind, Item, LfoShape, MixerChange, MixerRecallOptions, MixerSnapshot, MoveMode,
+    OwnedSource, Pan, PlayRate, ProjectConfigStore, Reaper, RenderBounds, RenderSampleRate,
+    RenderSettings, RenderSource, SendPartnerType, Tempo, Track, TrackRoutePartner,
+    TrackTemplateOptions, Volume, Width,
 };
 use rxrust::prelude::*;
 
@@ -22,18 +27,26 @@ use helgoboss_midi::{RawShortMessage, ShortMessageFactory};
 
 use reaper_medium::ProjectContext::CurrentProject;
 use reaper_medium::{
-    reaper_str, AutoSeekBehavior, AutomationMode, Bpm, CommandId, Db, DurationInSeconds, EditMode,
-    EnumPitchShiftModesResult, FxPresetRef, GangBehavior, GetParamExResult, InputMonitoringMode,
-    MasterTrackBehavior, MidiInputDeviceId, MidiOutputDeviceId, NormalizedPlayRate, PitchShiftMode,
-    PlaybackSpeedFactor, PositionInSeconds, ReaperNormalizedFxParamValue, ReaperPanValue,
-    ReaperVersion, ReaperVolumeValue, ReaperWidthValue, RecordingInput, SoloMode,
-    StuffMidiMessageTarget, TrackFxGetPresetIndexResult, TrackLocation, UndoBehavior, ValueChange,
+    reaper_str, AutoSeekBehavior, AutomationMode, BorrowedProjectStateContext, Bpm, CommandId,
+    ControlSurface, ControlSurfaceDeviceError, ControlSurfaceType, Db, DurationInSeconds, EditMode,
+    EnumPitchShiftModesResult, EnvChunkName, EnvelopePoint, EnvelopePointShape, FadeShape,
+    FolderCompactState, FxPinDirection, FxPinMask, FxPresetRef, GangBehavior, GetParamExResult,
+    Hmenu, HookCustomMenu, Hz, InputMonitoringMode, MasterTrackBehavior, MenuHookFlag,
+    MenuItemState, MidiCc, MidiImportBehavior, MidiInputDeviceId, MidiNote, MidiOutputDeviceId,
+    NormalizedPlayRate, OpenProjectBehavior, OwnedCsurfRegister, PeakFile, PitchShiftMode,
+    PlaybackSpeedFactor, PositionInPpq, PositionInSeconds, ProjectConfigExtension,
+    ReaperNormalizedFxParamValue, ReaperPanValue, ReaperStr, ReaperVersion, ReaperVolumeValue,
+    ReaperWidthValue, RecordingInput, RgbColor, SectionId, SoloMode, SortBehavior,
+    StuffMidiMessageTarget, SwellMenu, TrackFxGetPresetIndexResult, TrackLocation,
+    UiRefreshBehavior, UndoBehavior, ValueChange,
 };
 
-use reaper_low::{raw, Swell};
+use reaper_low::{delete_cpp_control_surface, raw, Swell};
 use reaper_rx::ActionRxProvider;
-use std::os::raw::{c_int, c_void};
-use std::ptr::null_mut;
+use std::cell::Cell;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr::{null_mut, NonNull};
 use std::rc::Rc;
 
 const EPSILON: f64 = 0.000_000_1;
@@ -121,6 +134,13 @@ pub fn create_test_steps() -> impl Iterator<Item = TestStep> {
         generate_guid(),
         main_section_functions(),
         register_and_unregister_action(),
+        register_and_unregister_action_with_help(),
+        register_and_unregister_action_in_section(),
+        build_menu(),
+        register_and_unregister_custom_menu_hook(),
+        register_and_unregister_control_surface_type(),
+        register_and_unregister_project_config_extension(),
+        restore_project_config_on_undo(),
         register_and_unregister_toggle_action(),
     ]
     .into_iter();
@@ -140,6 +160,25 @@ pub fn create_test_steps() -> impl Iterator<Item = TestStep> {
         get_project_tempo(),
         set_project_tempo(),
         swell(),
+        track_envelope_points(),
+        project_markers_and_regions(),
+        tempo_markers(),
+        ext_state(),
+        save_open_and_close_project(),
+        set_project_title(),
+        render_settings(),
+        read_track_audio_blocks(),
+        read_peaks(),
+        take_fx_chain(),
+        item_properties(),
+        edit_midi_take_events(),
+        fx_containers(),
+        fx_pin_matrix(),
+        fx_parameter_modulation(),
+        installed_fxs(),
+        track_folder_hierarchy(),
+        track_templates(),
+        mixer_snapshot(),
     ]
     .into_iter();
     let output_fx_steps = create_fx_steps("Output FX chain", || {
@@ -155,6 +194,829 @@ pub fn create_test_steps() -> impl Iterator<Item = TestStep> {
         .chain(steps_b)
 }
 
+fn mixer_snapshot() -> TestStep {
+    step(AllVersions, "Mixer snapshot", |_, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let track = project.add_track()?;
+        let other_track = project.add_track()?;
+        let send = track.add_send_to(&other_track);
+        let fx = track
+            .normal_fx_chain()
+            .add_fx_by_original_name("ReaEQ (Cockos)")
+            .ok_or("No FX added")?;
+        let snapshot = MixerSnapshot::capture(project)?;
+        // When
+        track.set_volume(
+            Volume::try_from_soft_normalized_value(0.2)?,
+            GangBehavior::DenyGang,
+        );
+        track.mute(GangBehavior::DenyGang);
+        send.set_volume(
+            Volume::try_from_soft_normalized_value(0.3)?,
+            EditMode::NormalTweak,
+        )?;
+        fx.disable();
+        let changed_snapshot = MixerSnapshot::capture(project)?;
+        let changes = snapshot.diff(&changed_snapshot);
+        snapshot.recall(
+            project,
+            MixerRecallOptions {
+                only_sends: true,
+                only_selected_tracks: false,
+            },
+        )?;
+        let after_send_recall = MixerSnapshot::capture(project)?;
+        snapshot.recall(project, MixerRecallOptions::default())?;
+        // Then
+        let track_guid = *track.guid();
+        assert!(changes.contains(&MixerChange::Mute {
+            track: track_guid,
+            new: true
+        }));
+        let changed_track = |c: &&MixerChange| match c {
+            MixerChange::Volume { track, .. } | MixerChange::SendVolume { track, .. } => {
+                *track == track_guid
+            }
+            _ => false,
+        };
+        assert_eq!(changes.iter().filter(changed_track).count(), 2);
+        assert!(changes.contains(&MixerChange::FxEnabled {
+            track: track_guid,
+            fx: fx.guid().ok_or("FX without GUID")?,
+            new: false
+        }));
+        assert!(!track.is_muted());
+        assert!(fx.is_enabled());
+        let send_recall_changes = changed_snapshot.diff(&after_send_recall);
+        assert!(send_recall_changes
+            .iter()
+            .all(|c| matches!(c, MixerChange::SendVolume { .. })));
+        let original_volume = Volume::from_reaper_value(snapshot.tracks[&track_guid].volume);
+        assert_abs_diff_eq!(
+            track.volume().soft_normalized_value(),
+            original_volume.soft_normalized_value(),
+            epsilon = 0.001
+        );
+        project.remove_track(&track);
+        project.remove_track(&other_track);
+        Ok(())
+    })
+}
+
+fn track_templates() -> TestStep {
+    step(AllVersions, "Track and FX chain templates", |_, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let track = project.add_track()?;
+        track.set_name("Template source");
+        let fx = track
+            .normal_fx_chain()
+            .add_fx_by_original_name("ReaEQ (Cockos)")
+            .ok_or("No FX added")?;
+        let other_track = project.add_track()?;
+        let dir = std::env::temp_dir();
+        let template_path = dir.join("reaper-rs-test.RTrackTemplate");
+        let fx_chain_path = dir.join("reaper-rs-test.RfxChain");
+        // When
+        track.select_exclusively();
+        project.export_selected_tracks_to_template_file(
+            &template_path,
+            TrackTemplateOptions {
+                include_items: false,
+                include_envelopes: false,
+            },
+        )?;
+        let inserted_tracks = project.insert_track_template_file(&template_path, 0)?;
+        track.normal_fx_chain().export_to_file(&fx_chain_path)?;
+        other_track
+            .normal_fx_chain()
+            .add_fxs_from_file(&fx_chain_path)?;
+        other_track
+            .normal_fx_chain()
+            .add_fxs_from_file(&fx_chain_path)?;
+        // Then
+        assert_eq!(inserted_tracks.len(), 1);
+        let inserted_track = &inserted_tracks[0];
+        assert_eq!(inserted_track.index(), Some(0));
+        assert_eq!(
+            inserted_track.name().ok_or("No name")?.to_str(),
+            "Template source"
+        );
+        assert_ne!(inserted_track.guid(), track.guid());
+        let inserted_fx = inserted_track
+            .normal_fx_chain()
+            .first_fx()
+            .ok_or("No FX in inserted track")?;
+        assert_ne!(inserted_fx.guid(), fx.guid());
+        let other_fx_chain = other_track.normal_fx_chain();
+        assert_eq!(other_fx_chain.fx_count(), 2);
+        assert_ne!(
+            other_fx_chain.fx_by_index(0).ok_or("No FX")?.guid(),
+            other_fx_chain.fx_by_index(1).ok_or("No FX")?.guid()
+        );
+        project.remove_track(inserted_track);
+        project.remove_track(&track);
+        project.remove_track(&other_track);
+        let _ = std::fs::remove_file(template_path);
+        let _ = std::fs::remove_file(fx_chain_path);
+        Ok(())
+    })
+}
+
+fn track_folder_hierarchy() -> TestStep {
+    step(AllVersions, "Track folder hierarchy", |_, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let folder = project.add_track()?;
+        let child = project.add_track()?;
+        let other = project.add_track()?;
+        // When
+        project.move_tracks(&[child.clone()], &folder, MoveMode::IntoTarget)?;
+        project.move_tracks(&[other.clone()], &folder, MoveMode::AboveTarget)?;
+        folder.set_folder_compact_state(FolderCompactState::Tiny);
+        // Then
+        assert_eq!(child.parent_track(), Some(folder.clone()));
+        assert_eq!(folder.parent_track(), None);
+        assert_eq!(folder.children(), vec![child.clone()]);
+        assert_eq!(folder.descendants(), vec![child.clone()]);
+        assert!(child.children().is_empty());
+        assert_eq!(other.index().map(|i| i + 1), folder.index());
+        assert_eq!(folder.folder_compact_state(), FolderCompactState::Tiny);
+        let tree = project.track_tree();
+        let folder_node = tree
+            .iter()
+            .find(|node| node.track == folder)
+            .ok_or("Folder not in tree")?;
+        assert_eq!(folder_node.children.len(), 1);
+        assert_eq!(folder_node.children[0].track, child);
+        project.remove_track(&child);
+        project.remove_track(&folder);
+        project.remove_track(&other);
+        Ok(())
+    })
+}
+
+fn installed_fxs() -> TestStep {
+    step(
+        VersionRestriction::Min(ReaperVersion::new("7.0")),
+        "Installed FX",
+        |_, _| {
+            // Given
+            let reaper = Reaper::get();
+            // When
+            let reaeq = reaper
+                .installed_fxs()
+                .find(|fx| fx.name() == "ReaEQ")
+                .ok_or("ReaEQ not installed")?;
+            let search_results = reaper.search_installed_fxs("reaeq");
+            // Then
+            assert_eq!(reaeq.vendor(), Some("Cockos"));
+            assert!(!reaeq.is_instrument());
+            assert_ne!(reaeq.kind(), InstalledFxKind::Unknown);
+            assert!(search_results.contains(&reaeq));
+            assert!(reaper.search_installed_fxs("xyzxyzxyz").is_empty());
+            Ok(())
+        },
+    )
+}
+
+fn fx_parameter_modulation() -> TestStep {
+    step(
+        VersionRestriction::Min(ReaperVersion::new("6.37")),
+        "FX parameter modulation",
+        |_, _| {
+            // Given
+            let project = Reaper::get().current_project();
+            let track = project.add_track()?;
+            let fx_chain = track.normal_fx_chain();
+            let source_fx = fx_chain
+                .add_fx_by_original_name("ReaEQ (Cockos)")
+                .ok_or("No FX added")?;
+            let fx = fx_chain
+                .add_fx_by_original_name("ReaComp (Cockos)")
+                .ok_or("No FX added")?;
+            let param = fx.parameter_by_index(0);
+            let mut modulation = param.modulation()?;
+            // When
+            modulation.active = true;
+            modulation.lfo.active = true;
+            modulation.lfo.shape = LfoShape::Triangle;
+            modulation.lfo.speed = 2.0;
+            modulation.link.active = true;
+            modulation.link.source = FxParameterLinkSource::Parameter {
+                fx_index: source_fx.index(),
+                param_index: 1,
+            };
+            param.set_modulation(&modulation)?;
+            // Then
+            let written = param.modulation()?;
+            assert!(written.active);
+            assert!(written.lfo.active);
+            assert_eq!(written.lfo.shape, LfoShape::Triangle);
+            assert_abs_diff_eq!(written.lfo.speed, 2.0, epsilon = 0.001);
+            assert!(written.link.active);
+            assert_eq!(written.link.source, modulation.link.source);
+            assert!(!written.macro_linked);
+            project.remove_track(&track);
+            Ok(())
+        },
+    )
+}
+
+fn fx_pin_matrix() -> TestStep {
+    step(AllVersions, "FX pin matrix", |_, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let track = project.add_track()?;
+        assert!(track.set_channel_count(3).is_err());
+        assert!(track.set_channel_count(130).is_err());
+        track.set_channel_count(4)?;
+        let fx = track
+            .normal_fx_chain()
+            .add_fx_by_original_name("ReaComp (Cockos)")
+            .ok_or("No FX added")?;
+        let mut matrix = fx.pin_matrix()?;
+        // When
+        matrix.route_sidechain_input(0)?;
+        matrix.connect(FxPinDirection::Output, 0, 3);
+        fx.set_pin_matrix(&matrix)?;
+        // Then
+        assert_eq!(track.channel_count(), 4);
+        assert_eq!(matrix.pin_count(FxPinDirection::Input), 4);
+        let written = fx.pin_matrix()?;
+        assert_eq!(written, matrix);
+        assert_eq!(
+            written.pin(FxPinDirection::Input, 2),
+            Some(FxPinMask::channel(0))
+        );
+        assert!(written
+            .pin(FxPinDirection::Output, 0)
+            .ok_or("No output pin")?
+            .contains_channel(3));
+        project.remove_track(&track);
+        Ok(())
+    })
+}
+
+fn fx_containers() -> TestStep {
+    step(
+        VersionRestriction::Min(ReaperVersion::new("7.0")),
+        "FX containers",
+        |_, _| {
+            // Given
+            let project = Reaper::get().current_project();
+            let track = project.add_track()?;
+            let fx_chain = track.normal_fx_chain();
+            let container = fx_chain
+                .add_fx_by_original_name("Container")
+                .ok_or("No container added")?;
+            let phaser = fx_chain
+                .add_fx_by_original_name("phaser")
+                .ok_or("No FX added")?;
+            // When
+            let container_chain = container.container_chain().ok_or("Not a container")?;
+            container_chain.move_fx_into(&phaser, 0)?;
+            let synth = container_chain
+                .add_fx_by_original_name("ReaSynth (Cockos)")
+                .ok_or("No FX added to container")?;
+            // Then
+            assert!(container.is_container());
+            assert!(!synth.is_container());
+            assert_eq!(fx_chain.fx_count(), 1);
+            let children = container.container_children();
+            assert_eq!(children.len(), 2);
+            assert_eq!(
+                children[0].name().into_inner().as_c_str(),
+                c_str!("JS: phaser")
+            );
+            assert_eq!(children[1], synth);
+            assert_eq!(synth.parent_container(), Some(&container));
+            assert_eq!(
+                track.fx_by_query_index(synth.query_index().to_raw()),
+                Some(synth.clone())
+            );
+            let template = container_chain.template()?;
+            assert!(template.content().contains("phaser"));
+            assert!(template.content().contains("ReaSynth"));
+            container_chain.add_fxs_from_template(&template)?;
+            assert_eq!(container_chain.fx_count(), 4);
+            assert_eq!(fx_chain.fx_count(), 1);
+            assert!(Reaper::get().monitoring_fx_chain().template().is_ok());
+            project.remove_track(&track);
+            Ok(())
+        },
+    )
+}
+
+fn take_fx_chain() -> TestStep {
+    step(AllVersions, "Take FX chain", |_, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let track = project.add_track()?;
+        let take = track.add_item()?.add_take()?;
+        let fx_chain = take.fx_chain();
+        // When
+        let fx = fx_chain
+            .add_fx_by_original_name("phaser")
+            .ok_or("No take FX added")?;
+        fx.parameter_by_index(0)
+            .set_reaper_normalized_value(ReaperNormalizedFxParamValue::new(0.5))?;
+        fx.disable();
+        // Then
+        assert!(fx_chain.is_available());
+        assert_eq!(fx_chain.fx_count(), 1);
+        assert_eq!(fx_chain.first_fx(), Some(fx.clone()));
+        assert_eq!(fx.name().into_inner().as_c_str(), c_str!("JS: phaser"));
+        assert!(fx.parameter_count() > 0);
+        assert_eq!(
+            fx.parameter_by_index(0).reaper_normalized_value(),
+            ReaperNormalizedFxParamValue::new(0.5)
+        );
+        assert!(!fx.is_enabled());
+        assert_eq!(fx_chain.project(), Some(project));
+        assert_eq!(take.track().as_ref(), Some(&track));
+        fx_chain.remove_fx(&fx)?;
+        assert_eq!(fx_chain.fx_count(), 0);
+        project.remove_track(&track);
+        Ok(())
+    })
+}
+
+fn item_properties() -> TestStep {
+    step(AllVersions, "Item properties", |_, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let track = project.add_track()?;
+        let item = track.add_item()?;
+        let color = RgbColor {
+            r: 200,
+            g: 100,
+            b: 50,
+        };
+        // When
+        item.set_muted(true)?;
+        item.set_loops_source(true)?;
+        item.set_locked(true)?;
+        item.set_volume(Volume::from_reaper_value(ReaperVolumeValue::new(0.5)))?;
+        item.set_snap_offset(DurationInSeconds::new(0.25))?;
+        item.set_fade_in_length(DurationInSeconds::new(0.5))?;
+        item.set_fade_out_length(DurationInSeconds::new(0.75))?;
+        item.set_fade_in_shape(FadeShape::FastStart)?;
+        item.set_fade_out_shape(FadeShape::SlowStartEndSteep)?;
+        item.set_group_id(Some(3))?;
+        item.set_custom_color(Some(color))?;
+        item.set_notes("reaper-rs item notes")?;
+        // Then
+        assert!(item.is_muted());
+        assert!(item.loops_source());
+        assert!(item.is_locked());
+        assert_eq!(item.volume().reaper_value(), ReaperVolumeValue::new(0.5));
+        assert_eq!(item.snap_offset(), DurationInSeconds::new(0.25));
+        assert_eq!(item.fade_in_length(), DurationInSeconds::new(0.5));
+        assert_eq!(item.fade_out_length(), DurationInSeconds::new(0.75));
+        assert_eq!(item.fade_in_shape(), FadeShape::FastStart);
+        assert_eq!(item.fade_out_shape(), FadeShape::SlowStartEndSteep);
+        assert_eq!(item.group_id(), Some(3));
+        assert_eq!(item.custom_color(), Some(color));
+        assert_eq!(item.notes().to_str(), "reaper-rs item notes");
+        // When
+        item.set_custom_color(None)?;
+        item.set_group_id(None)?;
+        // Then
+        assert_eq!(item.custom_color(), None);
+        assert_eq!(item.group_id(), None);
+        project.remove_track(&track);
+        Ok(())
+    })
+}
+
+fn edit_midi_take_events() -> TestStep {
+    step(AllVersions, "Edit MIDI take events", |reaper, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let track = project.add_track()?;
+        let item = track.add_midi_item(PositionInSeconds::new(0.0), PositionInSeconds::new(2.0))?;
+        let take = item.active_take().ok_or("MIDI item has no take")?;
+        let medium = reaper.medium_reaper();
+        let note = MidiNote {
+            selected: false,
+            muted: false,
+            start: PositionInPpq::new(0.0),
+            end: PositionInPpq::new(960.0),
+            channel: channel(1),
+            key_number: key_number(60),
+            velocity: u7(100),
+        };
+        let cc = MidiCc {
+            selected: false,
+            muted: false,
+            position: PositionInPpq::new(480.0),
+            channel_message: 0xB0,
+            channel: channel(1),
+            data_byte_1: u7(7),
+            data_byte_2: u7(64),
+        };
+        // When
+        take.insert_note(note)?;
+        unsafe { medium.midi_insert_cc(take.raw(), cc)? };
+        // Then
+        assert!(take.is_midi());
+        assert_eq!(take.notes().collect::<Vec<_>>(), vec![note]);
+        let counts = unsafe { medium.midi_count_evts(take.raw()) };
+        assert_eq!(counts.note_count, 1);
+        assert_eq!(counts.cc_count, 1);
+        assert_eq!(unsafe { medium.midi_get_cc(take.raw(), 0)? }, cc);
+        // When
+        let edited_note = MidiNote {
+            key_number: key_number(64),
+            velocity: u7(80),
+            ..note
+        };
+        take.set_note(0, edited_note)?;
+        let edited_cc = MidiCc {
+            data_byte_2: u7(127),
+            ..cc
+        };
+        unsafe { medium.midi_set_cc(take.raw(), 0, edited_cc, SortBehavior::Sort)? };
+        // Then
+        assert_eq!(take.note_by_index(0), Some(edited_note));
+        assert_eq!(unsafe { medium.midi_get_cc(take.raw(), 0)? }, edited_cc);
+        // When
+        take.delete_note(0)?;
+        unsafe { medium.midi_delete_cc(take.raw(), 0)? };
+        // Then
+        assert_eq!(take.note_count(), 0);
+        assert_eq!(unsafe { medium.midi_count_evts(take.raw()) }.cc_count, 0);
+        project.remove_track(&track);
+        Ok(())
+    })
+}
+
+fn read_track_audio_blocks() -> TestStep {
+    step(AllVersions, "Read track audio blocks", |_, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let track = project.add_track()?;
+        let wav_path = std::env::temp_dir().join("reaper-rs-test-audio-blocks.wav");
+        write_sine_wav(&wav_path, 44100, 44100)?;
+        add_audio_item(&track, &wav_path)?;
+        let spec = AudioBlockSpec {
+            sample_rate: Hz::new(44100.0),
+            channel_count: 2,
+            frames_per_block: 512,
+        };
+        let invalid_spec = AudioBlockSpec {
+            channel_count: 0,
+            ..spec
+        };
+        // When
+        let blocks: Result<Vec<_>, _> = track.audio_blocks(spec)?.collect();
+        // Then
+        let blocks = blocks?;
+        assert!(!blocks.is_empty());
+        assert!(blocks
+            .iter()
+            .all(|b| b.samples.len() == (b.frame_count() * 2) as usize));
+        assert!(blocks.iter().any(|b| b.has_audio));
+        assert!(blocks
+            .iter()
+            .any(|b| b.samples.iter().any(|s| s.abs() > 0.1)));
+        assert!(track.audio_blocks(invalid_spec).is_err());
+        let fractional_spec = AudioBlockSpec {
+            sample_rate: Hz::new(44100.5),
+            ..spec
+        };
+        assert!(track.audio_blocks(fractional_spec).is_err());
+        project.remove_track(&track);
+        Ok(())
+    })
+}
+
+fn read_peaks() -> TestStep {
+    step(AllVersions, "Read peaks", |reaper, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let track = project.add_track()?;
+        let wav_path = std::env::temp_dir().join("reaper-rs-test-peaks.wav");
+        write_sine_wav(&wav_path, 44100, 44100)?;
+        let item = add_audio_item(&track, &wav_path)?;
+        let take = item.active_take().ok_or("Item has no take")?;
+        let source = take.source().ok_or("Take has no source")?;
+        if source.as_raw().peaks_build_begin() {
+            while source.as_raw().peaks_build_run() {}
+            source.as_raw().peaks_build_finish();
+        }
+        let medium = reaper.medium_reaper();
+        let peak_rate = Hz::new(100.0);
+        let start = PositionInSeconds::new(0.0);
+        // When
+        let peaks = source.peaks(start..PositionInSeconds::new(1.0), peak_rate);
+        let mut source_buffer = vec![0.0; 100 * 2];
+        let source_result = medium.pcm_source_get_peaks(
+            source.as_raw(),
+            peak_rate,
+            start,
+            1,
+            100,
+            false,
+            &mut source_buffer,
+        );
+        let mut take_buffer = vec![0.0; 100 * 2];
+        let take_result = unsafe {
+            medium.get_media_item_take_peaks(
+                take.raw(),
+                peak_rate,
+                start,
+                1,
+                100,
+                false,
+                &mut take_buffer,
+            )
+        };
+        // Then
+        assert_eq!(peaks.peak_rate, peak_rate);
+        assert_eq!(peaks.channels.len(), 1);
+        let channel = &peaks.channels[0];
+        assert_eq!(channel.max.len(), 100);
+        assert_eq!(channel.min.len(), 100);
+        assert!(channel.max.iter().any(|v| *v > 0.4));
+        assert!(channel.min.iter().any(|v| *v < -0.4));
+        assert_eq!(source_result.sample_count, 100);
+        assert_eq!(source_result.output_mode, 0);
+        assert!(!source_result.has_spectral_info);
+        assert!(source_buffer[..100].iter().any(|v| *v > 0.4));
+        assert!(source_buffer[100..].iter().any(|v| *v < -0.4));
+        assert!(take_result.sample_count > 0);
+        assert!(take_buffer[..100].iter().any(|v| *v > 0.4));
+        // The peak file which REAPER has just built must be parseable.
+        let wav_path_c_string = CString::new(wav_path.to_str().ok_or("invalid path")?)?;
+        let mut peak_file_path = vec![0 as c_char; 4096];
+        unsafe {
+            medium.low().GetPeakFileNameEx(
+                wav_path_c_string.as_ptr(),
+                peak_file_path.as_mut_ptr(),
+                peak_file_path.len() as c_int,
+                false,
+            );
+        }
+        let peak_file_path = unsafe { CStr::from_ptr(peak_file_path.as_ptr()) };
+        let peak_file_bytes = std::fs::read(peak_file_path.to_str()?)?;
+        let peak_file = PeakFile::parse(&peak_file_bytes)?;
+        assert_eq!(peak_file.channel_count, 1);
+        assert_eq!(peak_file.sample_rate, 44100);
+        let finest_mipmap = peak_file
+            .mipmaps
+            .first()
+            .ok_or("peak file has no mipmaps")?;
+        assert!(finest_mipmap.peak_count(1) > 0);
+        assert!(finest_mipmap.peaks.iter().any(|p| p.max_f64() > 0.4));
+        assert!(finest_mipmap.peaks.iter().any(|p| p.min_f64() < -0.4));
+        assert!(finest_mipmap.peaks.iter().all(|p| p.min <= p.max));
+        project.remove_track(&track);
+        Ok(())
+    })
+}
+
+fn render_settings() -> TestStep {
+    step(AllVersions, "Render settings", |_, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let settings = RenderSettings::new()
+            .pattern("reaper-rs-test")
+            .source(RenderSource::Stems)
+            .bounds(RenderBounds::Custom {
+                start: PositionInSeconds::new(1.0),
+                end: PositionInSeconds::new(3.0),
+            })
+            .sample_rate(RenderSampleRate::Custom(Hz::new(48000.0)))
+            .channel_count(1);
+        // When
+        project.set_render_settings(&settings)?;
+        // Then
+        let actual = project.render_settings()?;
+        assert_eq!(actual.pattern.as_deref(), Some("reaper-rs-test"));
+        assert_eq!(actual.source, Some(RenderSource::Stems));
+        assert_eq!(actual.bounds, settings.bounds);
+        assert_eq!(actual.sample_rate, settings.sample_rate);
+        assert_eq!(actual.channel_count, Some(1));
+        Ok(())
+    })
+}
+
+fn save_open_and_close_project() -> TestStep {
+    step(AllVersions, "Save, open and close project", |_, _| {
+        // Given
+        let reaper = Reaper::get();
+        let project = reaper.create_empty_project_in_new_tab();
+        let path = std::env::temp_dir().join("reaper-rs-test-project.RPP");
+        // When
+        project.save_as(&path)?;
+        // Then
+        assert_eq!(project.name().to_str(), "reaper-rs-test-project.RPP");
+        assert_eq!(project.file().as_deref(), Some(path.as_path()));
+        // When
+        project.close()?;
+        // Then
+        assert!(!project.is_available());
+        // When
+        let project = reaper.open_project_in_new_tab(
+            &path,
+            OpenProjectBehavior {
+                prompt: false,
+                open_as_template: false,
+            },
+        )?;
+        // Then
+        assert_eq!(project.file().as_deref(), Some(path.as_path()));
+        assert_eq!(reaper.current_project(), project);
+        // When
+        project.close()?;
+        std::fs::remove_file(&path)?;
+        // Then
+        assert!(!project.is_available());
+        Ok(())
+    })
+}
+
+fn set_project_title() -> TestStep {
+    step(AllVersions, "Set project title", |_, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let original_title = project.title()?;
+        // When
+        project.set_title("reaper-rs test title")?;
+        // Then
+        assert_eq!(project.title()?.to_str(), "reaper-rs test title");
+        assert_ne!(project.name().to_str(), "reaper-rs test title");
+        project.set_title(original_title)?;
+        Ok(())
+    })
+}
+
+fn ext_state() -> TestStep {
+    step(AllVersions, "Extension state", |_, _| {
+        // Given
+        let global_store = ExtStateStore::temporary("reaper-rs-test");
+        let project_store = Reaper::get().current_project().ext_state("reaper-rs-test");
+        // When
+        global_store.set("foo", "bar");
+        project_store.set("foo", "baz");
+        project_store.set("qux", "quux");
+        // Then
+        assert!(global_store.has("foo"));
+        assert_eq!(global_store.get("foo").as_deref(), Some("bar"));
+        assert_eq!(project_store.get("foo")?.as_deref(), Some("baz"));
+        assert_eq!(project_store.entries().count(), 2);
+        // When
+        global_store.delete("foo");
+        project_store.delete("foo");
+        // Then
+        assert!(!global_store.has("foo"));
+        assert_eq!(project_store.get("foo")?, None);
+        // When
+        project_store.clear();
+        // Then
+        assert_eq!(project_store.entries().count(), 0);
+        Ok(())
+    })
+}
+
+fn tempo_markers() -> TestStep {
+    step(AllVersions, "Tempo markers", |_, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let count_before = project.tempo_map().markers().len();
+        // When
+        project.add_tempo_marker(
+            PositionInSeconds::new(30.0),
+            Tempo::from_bpm(Bpm::new(90.0)),
+            None,
+            false,
+        )?;
+        let tempo_map = project.tempo_map();
+        // Then
+        assert_eq!(tempo_map.markers().len(), count_before + 1);
+        let marker = tempo_map
+            .markers()
+            .find(|m| m.position == PositionInSeconds::new(30.0))
+            .ok_or("tempo marker not found")?;
+        assert_eq!(marker.tempo, Bpm::new(90.0));
+        assert_eq!(
+            tempo_map.tempo_at(PositionInSeconds::new(40.0)),
+            Bpm::new(90.0)
+        );
+        assert!(abs_diff_eq!(
+            tempo_map.time_to_qn(PositionInSeconds::new(40.0)).get(),
+            Reaper::get()
+                .medium_reaper()
+                .time_map_2_time_to_qn(CurrentProject, PositionInSeconds::new(40.0))
+                .get(),
+            epsilon = EPSILON
+        ));
+        // When
+        let index = tempo_map
+            .markers()
+            .position(|m| m.position == PositionInSeconds::new(30.0))
+            .unwrap();
+        project.delete_tempo_marker(index as u32)?;
+        // Then
+        assert_eq!(project.tempo_map().markers().len(), count_before);
+        Ok(())
+    })
+}
+
+fn project_markers_and_regions() -> TestStep {
+    step(AllVersions, "Project markers and regions", |_, _| {
+        // Given
+        let project = Reaper::get().current_project();
+        let count_before = project.bookmark_count().total_count;
+        // When
+        let marker_id = project.add_marker(PositionInSeconds::new(5.0), "Verse", None)?;
+        let region_id = project.add_region(
+            PositionInSeconds::new(10.0),
+            PositionInSeconds::new(20.0),
+            "Chorus",
+            None,
+        )?;
+        // Then
+        assert_eq!(project.bookmark_count().total_count, count_before + 2);
+        let region = project
+            .find_bookmark_by_type_and_id(BookmarkType::Region, region_id)
+            .ok_or("region not found")?;
+        assert_eq!(region.basic_info.position, PositionInSeconds::new(10.0));
+        assert_eq!(
+            region.basic_info.region_end_position,
+            Some(PositionInSeconds::new(20.0))
+        );
+        assert_eq!(region.bookmark.name(), "Chorus");
+        // When
+        region.bookmark.set_name("Bridge")?;
+        region
+            .bookmark
+            .set_region_range(PositionInSeconds::new(12.0), PositionInSeconds::new(24.0))?;
+        // Then
+        let region = project
+            .find_bookmark_by_type_and_id(BookmarkType::Region, region_id)
+            .ok_or("region not found after change")?;
+        assert_eq!(region.bookmark.name(), "Bridge");
+        assert_eq!(region.basic_info.position, PositionInSeconds::new(12.0));
+        assert_eq!(
+            region.basic_info.region_end_position,
+            Some(PositionInSeconds::new(24.0))
+        );
+        // When
+        region.bookmark.delete()?;
+        project.delete_bookmark(BookmarkType::Marker, marker_id)?;
+        // Then
+        assert_eq!(project.bookmark_count().total_count, count_before);
+        Ok(())
+    })
+}
+
+fn track_envelope_points() -> TestStep {
+    step(AllVersions, "Track envelope points", |_, _| {
+        // Given
+        let track = get_track(0)?;
+        track.select_exclusively();
+        // Track: Toggle track volume envelope visible
+        Reaper::get()
+            .main_section()
+            .action_by_command_id(CommandId::new(40406))
+            .invoke_as_trigger(Some(track.project()))?;
+        let envelope = track
+            .envelope_by_chunk_name(EnvChunkName::VolEnv)
+            .ok_or("volume envelope not created")?;
+        let point_count_before = envelope.point_count();
+        // When
+        let point = |secs: f64, value: f64| EnvelopePoint {
+            time: PositionInSeconds::new(secs),
+            value,
+            shape: EnvelopePointShape::Linear,
+            tension: 0.0,
+            selected: false,
+        };
+        envelope.insert_points([point(20.0, 0.5), point(10.0, 0.25)])?;
+        // Then
+        assert_eq!(envelope.point_count(), point_count_before + 2);
+        let points: Vec<_> = envelope.points().filter(|p| p.time.get() >= 10.0).collect();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].time, PositionInSeconds::new(10.0));
+        assert_eq!(points[1].time, PositionInSeconds::new(20.0));
+        assert!(abs_diff_eq!(
+            envelope.value_at(PositionInSeconds::new(15.0), Hz::new(44100.0)),
+            0.375,
+            epsilon = EPSILON
+        ));
+        // When
+        envelope
+            .delete_points_in_range(PositionInSeconds::new(9.0), PositionInSeconds::new(21.0))?;
+        // Then
+        assert_eq!(envelope.point_count(), point_count_before);
+        Ok(())
+    })
+}
+
 fn swell() -> TestStep {
     step(AllVersions, "SWELL", |_session, _| {
         let swell = Swell::load(*Reaper::get().medium_reaper().low().plugin_context());
@@ -528,6 +1390,294 @@ fn register_and_unregister_action() -> TestStep {
     )
 }
 
+fn register_and_unregister_action_with_help() -> TestStep {
+    step(
+        AllVersions,
+        "Register and unregister action with help",
+        |reaper, _| {
+            // Given
+            // When
+            let reg = reaper.register_action_with_help(
+                "reaperRsTestWithHelp",
+                "reaper-rs test action with help",
+                "Does nothing but demonstrating help texts.",
+                || {},
+                ActionKind::NotToggleable,
+            );
+            let action = Reaper::get().action_by_command_name("reaperRsTestWithHelp");
+            let help_text = |handle| {
+                reaper
+                    .medium_session()
+                    .action_help_register(handle)
+                    .map(|r| r.help().to_str().to_string())
+            };
+            // Then
+            assert!(action.is_available());
+            assert_eq!(action.name()?.to_str(), "reaper-rs test action with help");
+            let help_handle = reg.help_handle().ok_or("help not registered")?;
+            assert_eq!(
+                help_text(help_handle).as_deref(),
+                Some("Does nothing but demonstrating help texts.")
+            );
+            reaper.go_to_sleep()?;
+            assert!(!action.is_available());
+            assert_eq!(reg.help_handle(), None);
+            assert_eq!(help_text(help_handle), None);
+            reaper.wake_up()?;
+            assert!(action.is_available());
+            let help_handle = reg
+                .help_handle()
+                .ok_or("help not registered after wake-up")?;
+            assert!(help_text(help_handle).is_some());
+            reg.unregister();
+            assert!(!action.is_available());
+            assert_eq!(reg.help_handle(), None);
+            assert_eq!(help_text(help_handle), None);
+            Ok(())
+        },
+    )
+}
+
+fn register_and_unregister_action_in_section() -> TestStep {
+    step(
+        AllVersions,
+        "Register and unregister action in section",
+        |reaper, _| {
+            // Given
+            let midi_editor_section_id = SectionId::new(32060);
+            // When
+            let reg = reaper.register_action_in_section(
+                midi_editor_section_id,
+                "reaperRsTestMidiEditor",
+                "reaper-rs test MIDI editor action",
+                || {},
+            );
+            let command_id = reg.command_id().ok_or("action not registered")?;
+            let action = reaper
+                .section_by_id(midi_editor_section_id)
+                .action_by_command_id(command_id);
+            // Then
+            assert!(action.is_available());
+            assert_eq!(action.name()?.to_str(), "reaper-rs test MIDI editor action");
+            reg.unregister();
+            assert!(!action.is_available());
+            assert_eq!(reg.command_id(), None);
+            Ok(())
+        },
+    )
+}
+
+fn register_and_unregister_custom_menu_hook() -> TestStep {
+    step(
+        AllVersions,
+        "Register and unregister custom menu hook",
+        |reaper, _| {
+            // Given
+            thread_local! {
+                // Item count before and after the hook added its item.
+                static ITEM_COUNTS: Cell<Option<(u32, u32)>> = Cell::new(None);
+            }
+            struct TestMenuHook;
+            impl HookCustomMenu for TestMenuHook {
+                fn call(menu_id: &ReaperStr, menu: Hmenu, flag: MenuHookFlag) {
+                    if menu_id.to_str() != "reaper-rs test menu" || flag != MenuHookFlag::Init {
+                        return;
+                    }
+                    let menu = unsafe { SwellMenu::new(Swell::get(), menu) };
+                    let count_before = menu.item_count();
+                    menu.add_action(CommandId::new(40001), "Insert track");
+                    ITEM_COUNTS.with(|c| c.set(Some((count_before, menu.item_count()))));
+                }
+            }
+            let mut session = reaper.medium_session();
+            // When
+            session
+                .plugin_register_add_hook_custom_menu::<TestMenuHook>()
+                .map_err(|_| "couldn't register custom menu hook")?;
+            let result =
+                session
+                    .reaper()
+                    .add_customizable_menu("reaper-rs test menu", None, None, false);
+            session.plugin_register_remove_hook_custom_menu::<TestMenuHook>();
+            // Then
+            result.map_err(|_| "couldn't add customizable menu")?;
+            let (count_before, count_after) = ITEM_COUNTS
+                .with(Cell::get)
+                .ok_or("custom menu hook not called")?;
+            assert_eq!(count_after, count_before + 1);
+            Ok(())
+        },
+    )
+}
+
+fn build_menu() -> TestStep {
+    step(AllVersions, "Build menu", |_reaper, _| {
+        // Given
+        let swell = Swell::get();
+        let menu = NonNull::new(swell.CreatePopupMenu()).ok_or("couldn't create menu")?;
+        let menu = unsafe { SwellMenu::new(swell, menu) };
+        let command_id = CommandId::new(40001);
+        let nested_command_id = CommandId::new(40002);
+        // When
+        menu.add_action(command_id, "Insert track");
+        menu.add_separator();
+        menu.add_submenu("More", |submenu| {
+            submenu.add_action_with_state(
+                nested_command_id,
+                "Nested",
+                MenuItemState {
+                    checked: true,
+                    grayed: true,
+                },
+            );
+        })?;
+        // Then
+        assert_eq!(menu.item_count(), 3);
+        let checked = MenuItemState {
+            checked: true,
+            grayed: false,
+        };
+        menu.set_action_state(command_id, checked)?;
+        menu.set_action_state(nested_command_id, MenuItemState::default())?;
+        assert!(menu
+            .set_action_state(CommandId::new(40003), checked)
+            .is_err());
+        unsafe {
+            swell.DestroyMenu(menu.menu().as_ptr());
+        }
+        Ok(())
+    })
+}
+
+fn register_and_unregister_control_surface_type() -> TestStep {
+    step(
+        AllVersions,
+        "Register and unregister control surface type",
+        |reaper, _| {
+            // Given
+            #[derive(Debug)]
+            struct TestControlSurface;
+            impl ControlSurface for TestControlSurface {}
+            struct TestControlSurfaceType;
+            impl ControlSurfaceType for TestControlSurfaceType {
+                type ControlSurface = TestControlSurface;
+
+                fn create(
+                    _config_string: &ReaperStr,
+                    _device_errors: &mut BitFlags<ControlSurfaceDeviceError>,
+                ) -> Option<TestControlSurface> {
+                    Some(TestControlSurface)
+                }
+            }
+            let mut session = reaper.medium_session();
+            let register = OwnedCsurfRegister::new::<TestControlSurfaceType>(
+                "REAPERRSTEST",
+                "reaper-rs test control surface",
+            );
+            let create = register.as_ref().create.ok_or("no create callback")?;
+            // When
+            let handle = session
+                .plugin_register_add_csurf(register)
+                .map_err(|_| "couldn't register control surface type")?;
+            let surface = unsafe {
+                create(
+                    c_str!("REAPERRSTEST").as_ptr(),
+                    c_str!("reaper-rs test config").as_ptr(),
+                    null_mut(),
+                )
+            };
+            // Then
+            let surface = NonNull::new(surface).ok_or("control surface not created")?;
+            let config_string = unsafe { CStr::from_ptr(surface.as_ref().GetConfigString()) };
+            assert_eq!(config_string.to_str()?, "reaper-rs test config");
+            unsafe {
+                delete_cpp_control_surface(surface);
+            }
+            session.plugin_register_remove_csurf(handle);
+            Ok(())
+        },
+    )
+}
+
+fn register_and_unregister_project_config_extension() -> TestStep {
+    step(
+        AllVersions,
+        "Register and unregister project config extension",
+        |reaper, _| {
+            // Given
+            struct TestExtension {
+                id: u32,
+            }
+            impl ProjectConfigExtension for TestExtension {
+                fn process_extension_line(
+                    &mut self,
+                    _line: &ReaperStr,
+                    _ctx: &BorrowedProjectStateContext,
+                    _is_undo: bool,
+                ) -> bool {
+                    false
+                }
+
+                fn save_extension_config(
+                    &mut self,
+                    _ctx: &BorrowedProjectStateContext,
+                    _is_undo: bool,
+                ) {
+                }
+            }
+            let mut session = reaper.medium_session();
+            // When
+            let handle = session
+                .plugin_register_add_project_config(Box::new(TestExtension { id: 42 }))
+                .map_err(|_| "couldn't register project config extension")?;
+            let extension = session
+                .plugin_register_remove_project_config(handle)
+                .ok_or("couldn't unregister project config extension")?;
+            // Then
+            assert_eq!(extension.id, 42);
+            Ok(())
+        },
+    )
+}
+
+fn restore_project_config_on_undo() -> TestStep {
+    step(
+        AllVersions,
+        "Restore project config on undo",
+        |reaper, _| {
+            // Given
+            let project = Reaper::get().current_project();
+            let store: ProjectConfigStore<Vec<String>> = ProjectConfigStore::new("REAPERRSTEST");
+            let handle = reaper
+                .medium_session()
+                .plugin_register_add_project_config(Box::new(store.clone()))
+                .map_err(|_| "couldn't register project config store")?;
+            // When
+            project.undoable("reaper-rs project config 1", || {
+                store.set(project, vec!["first".to_string()]);
+            });
+            project.undoable("reaper-rs project config 2", || {
+                store.set(project, vec!["second".to_string()]);
+            });
+            let value_before_undo = store.get(project);
+            let undone = project.undo();
+            let value_after_undo = store.get(project);
+            let redone = project.redo();
+            let value_after_redo = store.get(project);
+            reaper
+                .medium_session()
+                .plugin_register_remove_project_config(handle);
+            // Then
+            assert_eq!(value_before_undo, Some(vec!["second".to_string()]));
+            assert!(undone);
+            assert_eq!(value_after_undo, Some(vec!["first".to_string()]));
+            assert!(redone);
+            assert_eq!(value_after_redo, Some(vec!["second".to_string()]));
+            Ok(())
+        },
+    )
+}
+
 fn main_section_functions() -> TestStep {
     step(AllVersions, "Main section functions", |_reaper, _| {
         // Given
@@ -3528,6 +4678,44 @@ fn add_track_fx_by_original_name(get_fx_chain: GetFxChain) -> TestStep {
     )
 }
 
+/// Writes a mono 16-bit WAV file containing a 440 Hz sine wave at half of full scale.
+fn write_sine_wav(path: &Path, sample_rate: u32, frame_count: u32) -> std::io::Result<()> {
+    let data_len = frame_count * 2;
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    // PCM, 1 channel
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    // Block align and bits per sample
+    bytes.extend_from_slice(&2u16.to_le_bytes());
+    bytes.extend_from_slice(&16u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for i in 0..frame_count {
+        let phase = 2.0 * std::f64::consts::PI * 440.0 * i as f64 / sample_rate as f64;
+        let sample = (phase.sin() * 0.5 * i16::MAX as f64) as i16;
+        bytes.extend_from_slice(&sample.to_le_bytes());
+    }
+    std::fs::write(path, bytes)
+}
+
+/// Adds an item at the start of the project which plays the given audio file.
+fn add_audio_item(track: &Track, file: &Path) -> Result<Item, Box<dyn std::error::Error>> {
+    let source = OwnedSource::from_file(file, MidiImportBehavior::ForceNoMidiImport)?;
+    let length = source.as_ref().length()?;
+    let item = track.add_item()?;
+    item.set_position(PositionInSeconds::new(0.0), UiRefreshBehavior::NoRefresh)?;
+    item.set_length(length, UiRefreshBehavior::NoRefresh)?;
+    let take = item.add_take()?;
+    take.set_source(source);
+    Ok(item)
+}
+
 fn get_track(index: u32) -> Result<Track, &'static str> {
     Reaper::get()
         .current_project()